@@ -0,0 +1,108 @@
+use std::{
+    hint::black_box,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+    thread,
+};
+
+use criterion::{Criterion, criterion_group, criterion_main};
+
+r18::init!("example/tr");
+
+/// The previous implementation, which locks a mutex on every lookup.
+///
+/// Translations are looked up like [`r18::translate`], so only the access to
+/// the locale differs.
+mod mutex {
+    use std::sync::{Mutex, OnceLock};
+
+    pub static CURRENT_LOCALE: OnceLock<Mutex<Option<&'static r18::Locale>>> = OnceLock::new();
+
+    pub fn translate(prefix: impl AsRef<str>, content: &str) -> &str {
+        let locale = CURRENT_LOCALE
+            .get_or_init(|| Mutex::new(None))
+            .lock()
+            .unwrap();
+        let Some(locale) = *locale else {
+            return content;
+        };
+
+        locale
+            .translate
+            .get(prefix.as_ref())
+            .and_then(|contents| contents.get(content))
+            .and_then(|tr| match tr {
+                r18::Translation::Text(text) => Some(*text),
+                r18::Translation::Plural(forms) => forms
+                    .iter()
+                    .find(|(category, _)| *category == r18::PluralCategory::Other)
+                    .map(|(_, form)| *form),
+            })
+            .unwrap_or(content)
+    }
+}
+
+fn setup() {
+    r18::set_locale!("zh-CN");
+    *mutex::CURRENT_LOCALE
+        .get_or_init(|| Mutex::new(None))
+        .lock()
+        .unwrap() = __r18_gen::CATALOG.locale();
+}
+
+/// Runs `f` with `threads` background threads calling `f` repeatedly.
+fn contended(threads: usize, f: fn(), run: impl FnOnce()) {
+    let stop = Arc::new(AtomicBool::new(false));
+    let handles = (0..threads)
+        .map(|_| {
+            let stop = stop.clone();
+            thread::spawn(move || {
+                while !stop.load(Ordering::Relaxed) {
+                    f();
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    run();
+
+    stop.store(true, Ordering::Relaxed);
+    handles.into_iter().for_each(|h| h.join().unwrap());
+}
+
+fn lookup_mutex() {
+    black_box(mutex::translate(black_box(".pua"), black_box("Hello, {}")));
+}
+
+fn lookup_atomic() {
+    black_box(r18::translate(
+        &__r18_gen::CATALOG,
+        black_box(".pua"),
+        black_box("Hello, {}"),
+    ));
+}
+
+fn translate(c: &mut Criterion) {
+    setup();
+
+    let mut group = c.benchmark_group("translate");
+    group.bench_function("mutex", |b| b.iter(lookup_mutex));
+    group.bench_function("atomic", |b| b.iter(lookup_atomic));
+    group.finish();
+
+    let threads = thread::available_parallelism().map_or(4, |n| n.get());
+
+    let mut group = c.benchmark_group("translate_contended");
+    contended(threads, lookup_mutex, || {
+        group.bench_function("mutex", |b| b.iter(lookup_mutex));
+    });
+    contended(threads, lookup_atomic, || {
+        group.bench_function("atomic", |b| b.iter(lookup_atomic));
+    });
+    group.finish();
+}
+
+criterion_group!(benches, translate);
+criterion_main!(benches);
@@ -0,0 +1,51 @@
+use std::{collections::HashMap, fs, path::Path};
+
+use oxilangtag::LanguageTag;
+use r18_trans_support::translation::{generate_android_strings, Translation};
+
+use crate::Result;
+
+/// Returns the resource directory of `locale`, like `values-zh-rCN`, or
+/// `values-b+zh+Hant+TW` of tags which are not only a language and a region.
+fn directory(locale: Option<&LanguageTag<String>>) -> String {
+    let Some(tag) = locale else {
+        return "values".to_string();
+    };
+
+    let simple = tag.extended_language().is_none()
+        && tag.script().is_none()
+        && tag.variant().is_none()
+        && tag.extension().is_none()
+        && tag.private_use().is_none()
+        && tag
+            .region()
+            .map_or(true, |r| r.chars().all(|c| c.is_ascii_alphabetic()));
+
+    match (simple, tag.region()) {
+        (true, Some(region)) => format!("values-{}-r{}", tag.primary_language(), region),
+        (true, None) => format!("values-{}", tag.primary_language()),
+        (false, _) => format!("values-b+{}", tag.as_str().replace('-', "+")),
+    }
+}
+
+/// Writes `strings.xml` of `locale` to its resource directory in `output`,
+/// or the default one of source texts if `locale` is `None`.
+pub(crate) fn export(
+    locale: Option<&LanguageTag<String>>,
+    translations: &HashMap<String, Translation>,
+    output: &Path,
+) -> Result<()> {
+    let dir = output.join(directory(locale));
+
+    fs::create_dir_all(&dir).map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+
+    let path = dir.join("strings.xml");
+    let document = generate_android_strings(translations)
+        .map_err(|e| format!("Failed to export {}: {}", directory(locale), e))?;
+
+    fs::write(&path, document).map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+
+    println!("Exported {}", path.display());
+
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use std::{collections::HashMap, fs, path::Path};
+
+use oxilangtag::LanguageTag;
+use r18_trans_support::translation::{
+    generate_apple_strings, generate_apple_stringsdict, Translation,
+};
+
+use crate::Result;
+
+/// Writes `Localizable.strings` of `locale` to `<tag>.lproj` in `output`, or
+/// the one of source texts to the `.lproj` of the source language if `locale`
+/// is `None`.
+///
+/// `Localizable.stringsdict` is also written if there are plural forms.
+pub(crate) fn export(
+    source: &LanguageTag<String>,
+    locale: Option<&LanguageTag<String>>,
+    translations: &HashMap<String, Translation>,
+    output: &Path,
+) -> Result<()> {
+    let tag = locale.unwrap_or(source).as_str();
+    let dir = output.join(format!("{}.lproj", tag));
+
+    fs::create_dir_all(&dir).map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+
+    let path = dir.join("Localizable.strings");
+    fs::write(&path, generate_apple_strings(translations)?)
+        .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+
+    println!("Exported {}", path.display());
+
+    if translations
+        .values()
+        .any(|t| matches!(t, Translation::Plural(_)))
+    {
+        let path = dir.join("Localizable.stringsdict");
+        fs::write(&path, generate_apple_stringsdict(translations)?)
+            .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+
+        println!("Exported {}", path.display());
+    }
+
+    Ok(())
+}
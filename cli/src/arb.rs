@@ -0,0 +1,82 @@
+use std::{collections::HashMap, fs, path::Path};
+
+use oxilangtag::LanguageTag;
+use r18_trans_support::{
+    source::Content,
+    translation::{arb_locale, arb_metadata, generate_arb, ArbMessage, ArbMetadata, Translation},
+};
+
+use crate::{sources, Result};
+
+/// Returns metadata of messages in all ARB files of the translation
+/// directory.
+pub(crate) fn metadata(dir: &Path) -> Result<HashMap<String, ArbMetadata>> {
+    let mut ret = HashMap::new();
+
+    for entry in fs::read_dir(dir)?.filter_map(|e| e.ok()) {
+        let path = entry.path();
+
+        if path.extension().and_then(|e| e.to_str()) == Some("arb") {
+            let source = fs::read_to_string(&path)?;
+
+            for (key, metadata) in arb_metadata(&source)? {
+                ret.entry(key).or_insert(metadata);
+            }
+        }
+    }
+
+    Ok(ret)
+}
+
+/// Writes the template `messages.arb` of source texts if `locale` is `None`,
+/// or the `.arb` file of `locale` to `output`.
+pub(crate) fn export(
+    keys: &[(&String, &Content)],
+    locale: Option<(&LanguageTag<String>, &HashMap<String, Translation>)>,
+    metadata: &HashMap<String, ArbMetadata>,
+    output: &Path,
+) -> Result<()> {
+    let sources = sources(keys);
+
+    let messages = keys
+        .iter()
+        .map(|(key, content)| ArbMessage {
+            key,
+            placeholders: content.names.iter().map(|n| n.as_str()).collect(),
+            metadata: metadata.get(*key),
+            translation: match locale {
+                Some((_, translations)) => translations.get(*key),
+                // the template has source texts
+                None => sources.get(*key),
+            },
+        })
+        .collect::<Vec<_>>();
+
+    let tag = locale.map(|(tag, _)| tag.as_str());
+    let path = output.join(match tag {
+        Some(tag) => format!("{}.arb", tag),
+        None => "messages.arb".to_string(),
+    });
+
+    fs::write(&path, generate_arb(tag, &messages)?)
+        .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+
+    println!("Exported {}", path.display());
+
+    Ok(())
+}
+
+/// Returns the locale and messages of an `.arb` file, the locale is declared
+/// by `@@locale` or named by the file.
+pub(crate) fn import(file: &str) -> Result<(String, HashMap<String, Translation>)> {
+    let source = fs::read_to_string(file).map_err(|e| format!("Failed to read {}: {}", file, e))?;
+
+    let locale = arb_locale(&source)
+        .or_else(|| Some(Path::new(file).file_stem()?.to_str()?.to_string()))
+        .and_then(|l| LanguageTag::parse_and_normalize(&l.replace('_', "-")).ok())
+        .ok_or_else(|| format!("Can not find the locale of {}", file))?;
+
+    let translations = r18_trans_support::translation::extract(file)?;
+
+    Ok((locale.to_string(), translations))
+}
@@ -1,4 +1,4 @@
-pub(crate) use clap::{Parser, Subcommand};
+pub(crate) use clap::{Parser, Subcommand, ValueEnum};
 
 #[derive(Parser)]
 #[command(author, version, about, bin_name = "cargo")]
@@ -33,6 +33,67 @@ pub(crate) enum Command {
     /// Generate translation files named with language tag
     Generate {
         locales: Vec<String>,
+    },
+    /// Export translations of locales for translators
+    Export {
+        /// Format of exported files
+        #[arg(short, long, value_enum)]
+        format: Format,
+
+        /// Output directory, relative to the project root [default: the name of the format]
+        #[arg(short, long)]
+        output: Option<String>,
+
+        locales: Vec<String>,
+
+        /// Target locales, the same as the positional ones
+        #[arg(short, long)]
+        target: Vec<String>,
+
+        /// Language of source texts
+        #[arg(short, long, default_value_t = { "en".to_string() })]
+        source: String,
+
+        /// Export i18next texts as ICU MessageFormat messages for i18next-icu
+        #[arg(long, default_value_t = false)]
+        icu: bool,
+    },
+    /// Import translated files into translation files
+    Import {
+        files: Vec<String>,
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub(crate) enum Format {
+    /// gettext PO files and a POT template
+    Po,
+    /// Flutter ARB files and a template of source texts
+    Arb,
+    /// XLIFF 2.0 files of source texts and their translations
+    Xliff,
+    /// A CSV sheet of source texts and translations of all locales
+    Csv,
+    /// i18next JSON files for web frontends
+    I18next,
+    /// Android string resources and the default ones of source texts
+    Android,
+    /// Apple strings and stringsdict files, and the ones of source texts
+    Apple,
+}
+
+impl Format {
+    /// Returns the default output directory of the format.
+    pub fn directory(self) -> &'static str {
+        match self {
+            Format::Po => "po",
+            Format::Arb => "arb",
+            Format::Xliff => "xliff",
+            Format::Csv => "csv",
+            Format::I18next => "i18next",
+            Format::Android => "android",
+            Format::Apple => "apple",
+        }
     }
 }
 
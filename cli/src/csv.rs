@@ -0,0 +1,209 @@
+use std::{collections::HashMap, fs, path::Path};
+
+use oxilangtag::LanguageTag;
+use r18_trans_support::{
+    source::{Content, Contents},
+    translation::{generate_csv, parse_csv, split_key, PluralCategory, Translation},
+};
+
+use crate::{load_locale, Imported, Result};
+
+const PREFIX: &str = "prefix";
+const SOURCE: &str = "source";
+const STATUS: &str = "status";
+/// Checksums of exported cells keyed by locales like `zh-CN:1a2b3c4d`, which
+/// are used to find cells changed since the export.
+const CHECKSUM: &str = "checksum";
+
+/// Returns the text of a cell, plural forms are written in lines like
+/// `one: {} file`.
+fn cell_of(translation: Option<&Translation>) -> String {
+    match translation {
+        Some(Translation::Text(text)) => text.clone(),
+        Some(Translation::Plural(forms)) => forms
+            .iter()
+            .map(|(category, form)| format!("{}: {}", category, form))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        None => String::new(),
+    }
+}
+
+/// Returns the translation of a cell, which has plural forms only if the text
+/// is `plural`.
+fn translation_of(cell: &str, plural: bool) -> Translation {
+    let forms = cell
+        .lines()
+        .map(|line| {
+            let (category, form) = line.split_once(": ")?;
+            Some((category.parse::<PluralCategory>().ok()?, form.to_string()))
+        })
+        .collect::<Option<HashMap<_, _>>>()
+        .filter(|forms| plural && forms.contains_key(&PluralCategory::Other));
+
+    match forms {
+        Some(forms) => Translation::Plural(forms.into_iter().collect()),
+        None => Translation::Text(cell.to_string()),
+    }
+}
+
+/// Returns whether spreadsheets evaluate a cell starting like `s` as a
+/// formula.
+fn formula(s: &str) -> bool {
+    s.starts_with(['=', '+', '-', '@', '\t', '\r'])
+}
+
+/// Quotes a cell which would be evaluated as a formula with `'`, cells
+/// already quoted like it are quoted again.
+fn escape_cell(cell: String) -> String {
+    match formula(cell.trim_start_matches('\'')) {
+        true => format!("'{}", cell),
+        false => cell,
+    }
+}
+
+/// Removes the `'` quoting a cell by [`escape_cell`].
+fn unescape_cell(cell: &str) -> &str {
+    match cell.strip_prefix('\'') {
+        Some(rest) if formula(rest.trim_start_matches('\'')) => rest,
+        _ => cell,
+    }
+}
+
+/// Returns the FNV-1a hash of a cell.
+fn checksum(cell: &str) -> String {
+    let hash = cell.bytes().fold(0x811c9dc5_u32, |hash, byte| {
+        (hash ^ byte as u32).wrapping_mul(0x01000193)
+    });
+
+    format!("{:08x}", hash)
+}
+
+/// Writes all translations of `locales` to the sheet `translations.csv` in
+/// `output`.
+pub(crate) fn export(
+    keys: &[(&String, &Content)],
+    locales: &[(LanguageTag<String>, HashMap<String, Translation>)],
+    output: &Path,
+) -> Result<()> {
+    let mut rows = vec![[PREFIX, SOURCE]
+        .into_iter()
+        .map(|h| h.to_string())
+        .chain(locales.iter().map(|(tag, _)| tag.to_string()))
+        .chain([STATUS.to_string(), CHECKSUM.to_string()])
+        .collect::<Vec<_>>()];
+
+    for (key, _) in keys {
+        let (prefix, content) = split_key(key)?;
+
+        let cells = locales
+            .iter()
+            .map(|(_, translations)| cell_of(translations.get(*key)))
+            .collect::<Vec<_>>();
+
+        let status = match cells.iter().filter(|c| !c.is_empty()).count() {
+            0 => "untranslated",
+            n if n == cells.len() => "translated",
+            _ => "partial",
+        };
+        let checksums = locales
+            .iter()
+            .zip(&cells)
+            .map(|((tag, _), cell)| format!("{}:{}", tag, checksum(cell)))
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let mut row = vec![prefix.trim_start_matches('.').to_string(), content.to_string()];
+        row.extend(cells);
+        row.extend([status.to_string(), checksums]);
+        rows.push(row.into_iter().map(escape_cell).collect());
+    }
+
+    let path = output.join("translations.csv");
+
+    fs::write(&path, generate_csv(&rows))
+        .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+
+    println!("Exported {}", path.display());
+
+    Ok(())
+}
+
+/// Returns cells of each locale changed in the sheet, cells whose
+/// translations also changed since the export are rejected as conflicts.
+pub(crate) fn import(file: &str, dir: &Path, contents: &Contents) -> Result<Vec<Imported>> {
+    let source = fs::read_to_string(file).map_err(|e| format!("Failed to read {}: {}", file, e))?;
+    let rows = parse_csv(&source).map_err(|e| format!("Failed to parse {}: {}", file, e))?;
+
+    let (header, rows) = rows
+        .split_first()
+        .ok_or_else(|| format!("Missing the header of {}", file))?;
+    let column = |name| header.iter().position(|h| h == name);
+
+    let (prefix, source) = column(PREFIX)
+        .zip(column(SOURCE))
+        .ok_or_else(|| format!("Missing `{}` or `{}` column in {}", PREFIX, SOURCE, file))?;
+    let checksums = column(CHECKSUM);
+
+    let locales = header
+        .iter()
+        .enumerate()
+        .filter(|(_, h)| ![PREFIX, SOURCE, STATUS, CHECKSUM].contains(&h.as_str()))
+        .map(|(i, h)| {
+            LanguageTag::parse_and_normalize(h)
+                .map(|tag| (i, tag))
+                .map_err(|e| format!("Invalid locale column: {}: {}", h, e).into())
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut ret = Vec::new();
+
+    for (i, tag) in locales {
+        let current = load_locale(dir, &tag)?;
+        let mut translations = HashMap::new();
+        let mut rejected = Vec::new();
+
+        for row in rows {
+            let field = |i: usize| row.get(i).map_or("", |f| unescape_cell(f));
+            let key = match field(prefix) {
+                "" => format!(" {}", field(source)),
+                prefix => format!(".{} {}", prefix, field(source)),
+            };
+
+            let cell = field(i);
+            let recorded = checksums.and_then(|c| {
+                field(c)
+                    .split_whitespace()
+                    .find_map(|checksum| checksum.strip_prefix(tag.as_str())?.strip_prefix(':'))
+            });
+            let translated = cell_of(current.get(&key));
+
+            // cells which are not changed in the sheet are skipped
+            if cell.is_empty() || cell == translated || recorded == Some(checksum(cell).as_str()) {
+                continue;
+            }
+
+            let Some(content) = contents.get(&key) else {
+                rejected.push((key, "not found in the source".to_string()));
+                continue;
+            };
+
+            if recorded.is_some_and(|r| r != checksum(&translated)) {
+                rejected.push((key, format!("{} changed since the export", tag)));
+                continue;
+            }
+
+            let translation = translation_of(cell, content.plural);
+            match translation.check() {
+                Ok(_) => {
+                    translations.insert(key, translation);
+                }
+                Err(reason) => rejected.push((key, reason)),
+            }
+        }
+
+        ret.push((tag.to_string(), translations, rejected));
+    }
+
+    Ok(ret)
+}
@@ -0,0 +1,31 @@
+use std::{collections::HashMap, fs, path::Path};
+
+use oxilangtag::LanguageTag;
+use r18_trans_support::translation::{generate_i18next, Translation};
+
+use crate::Result;
+
+/// Writes all translations of `locale` to `<tag>/translation.json` in
+/// `output`, which is the default path of i18next backends, texts are ICU
+/// MessageFormat messages if `icu`.
+pub(crate) fn export(
+    locale: (&LanguageTag<String>, &HashMap<String, Translation>),
+    icu: bool,
+    output: &Path,
+) -> Result<()> {
+    let (tag, translations) = locale;
+    let dir = output.join(tag.as_str());
+
+    fs::create_dir_all(&dir).map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+
+    let path = dir.join("translation.json");
+    let document = generate_i18next(translations, icu)
+        .map_err(|e| format!("Failed to export {}: {}", tag, e))?;
+
+    fs::write(&path, format!("{}\n", document))
+        .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+
+    println!("Exported {}", path.display());
+
+    Ok(())
+}
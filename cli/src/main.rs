@@ -1,15 +1,23 @@
 use std::{
-    collections::{HashMap, HashSet},
-    path::Path,
+    collections::{BTreeMap, BTreeSet, HashMap},
+    path::{Path, PathBuf},
 };
 
 use oxilangtag::LanguageTag;
+use r18_trans_support::{
+    source::{Content, Contents},
+    translation::{PluralCategory, Translation, split_key},
+};
 use walkdir::WalkDir;
 
-mod_use::mod_use!(args);
+mod_use::mod_use!(android, apple, args, arb, csv, i18next, po, xliff);
 
 pub(crate) type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
 
+/// The locale, translations and rejected keys with the reasons of an
+/// imported file.
+pub(crate) type Imported = (String, HashMap<String, Translation>, Vec<(String, String)>);
+
 fn main() {
     let args = args::Args::parse().inner_args();
 
@@ -28,13 +36,37 @@ fn main() {
                 generate(locales, &args.root)
             }
         }
+        Command::Export {
+            format,
+            output,
+            mut locales,
+            target,
+            source,
+            icu,
+        } => {
+            locales.extend(target);
+
+            if locales.is_empty() {
+                Err("No locale specified to export".into())
+            } else {
+                let output = output.unwrap_or_else(|| format.directory().to_string());
+                export(format, locales, &source, icu, &args.root, &output)
+            }
+        }
+        Command::Import { files } => {
+            if files.is_empty() {
+                Err("No file specified to import".into())
+            } else {
+                import(files, &args.root)
+            }
+        }
     } {
         eprintln!("Error: {}", e);
     }
 }
 
-fn extract_source(root: impl AsRef<Path>) -> Result<(HashSet<String>, String)> {
-    let mut contents = HashSet::new();
+fn extract_source(root: impl AsRef<Path>) -> Result<(Contents, String)> {
+    let mut contents = Contents::new();
     let mut locale_path = String::new();
 
     for entry in WalkDir::new(root.as_ref().join("src"))
@@ -56,6 +88,70 @@ fn extract_source(root: impl AsRef<Path>) -> Result<(HashSet<String>, String)> {
     Ok((contents, locale_path))
 }
 
+/// Returns source references of `content`, like `src/main.rs:5`.
+pub(crate) fn references(root: &Path, content: &Content) -> Vec<String> {
+    content
+        .references
+        .iter()
+        .map(|(path, line)| {
+            let path = path.strip_prefix(root).unwrap_or(path);
+            format!("{}:{}", path.display(), line)
+        })
+        .collect()
+}
+
+/// Returns source texts of `keys` as translations, texts of `tr_plural!` only
+/// have the `other` form.
+pub(crate) fn sources(keys: &[(&String, &Content)]) -> HashMap<String, Translation> {
+    keys.iter()
+        .map(|(key, content)| {
+            let text = split_key(key).map_or("", |(_, text)| text).to_string();
+            let source = match content.plural {
+                true => Translation::Plural([(PluralCategory::Other, text)].into_iter().collect()),
+                false => Translation::Text(text),
+            };
+
+            (key.to_string(), source)
+        })
+        .collect()
+}
+
+/// Returns `translations` of `keys`, where texts of `tr_plural!` are
+/// translated as the `other` form, since native platforms look up plural
+/// forms in other resources.
+fn plural_forms(
+    keys: &[(&String, &Content)],
+    translations: HashMap<String, Translation>,
+) -> HashMap<String, Translation> {
+    let plurals = keys
+        .iter()
+        .filter(|(_, content)| content.plural)
+        .map(|(key, _)| key.as_str())
+        .collect::<BTreeSet<_>>();
+
+    translations
+        .into_iter()
+        .map(|(key, translation)| match translation {
+            Translation::Text(text) if plurals.contains(key.as_str()) => {
+                let forms = [(PluralCategory::Other, text)].into_iter().collect();
+                (key, Translation::Plural(forms))
+            }
+            translation => (key, translation),
+        })
+        .collect()
+}
+
+/// Returns the placeholder of an untranslated text, which lists names of its
+/// arguments.
+fn todo_translation(names: &BTreeSet<String>) -> Translation {
+    names
+        .iter()
+        .fold("[TODO]".to_string(), |todo, name| {
+            format!("{todo} {{{name}}}")
+        })
+        .into()
+}
+
 fn update(root: impl AsRef<Path>, rm_unused: bool) -> Result<()> {
     let (contents, locale_path) = extract_source(root.as_ref())?;
 
@@ -68,47 +164,74 @@ fn update(root: impl AsRef<Path>, rm_unused: bool) -> Result<()> {
         return Ok(());
     }
 
+    // catalogs of the same locale in different formats are checked together
+    let mut catalogs = BTreeMap::<String, Vec<PathBuf>>::new();
+
     for entry in WalkDir::new(root.as_ref().join(locale_path))
+        .sort_by_file_name()
         .into_iter()
         .filter_map(|entry| {
             entry.ok().and_then(|entry| {
                 let mut parts = entry.path().file_name()?.to_str()?.split('.').rev();
 
-                (parts.next() == Some("json")
-                    && LanguageTag::parse(parts.next()?).is_ok()
+                (matches!(
+                    parts.next(),
+                    Some("json" | "jsonc" | "json5" | "ftl" | "arb" | "yaml" | "yml" | "toml")
+                ) && parts
+                    .next()
+                    .filter(|tag| *tag != "config")
+                    .is_some_and(|tag| LanguageTag::parse(tag).is_ok())
                     && parts.next().is_none())
                 .then_some(entry)
             })
         })
     {
-        let file_name = entry
+        let tag = entry
             .path()
-            .file_name()
+            .file_stem()
             .unwrap_or_default()
-            .to_str()
-            .unwrap_or_default();
+            .to_string_lossy()
+            .to_string();
+
+        catalogs.entry(tag).or_default().push(entry.into_path());
+    }
+
+    for paths in catalogs.into_values() {
+        let file_names = paths
+            .iter()
+            .map(|path| path.file_name().unwrap_or_default().to_string_lossy())
+            .collect::<Vec<_>>();
 
-        println!("\nChecking {file_name} for untranslated texts...");
+        println!(
+            "\nChecking {} for untranslated texts...",
+            file_names.join(", ")
+        );
+
+        let mut catalogs = paths
+            .iter()
+            .map(|path| r18_trans_support::translation::extract(path))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
 
         let mut todo = HashMap::new();
         let mut unused = Vec::new();
-        let mut translations = r18_trans_support::translation::extract(entry.path())?;
 
-        for content in contents.iter() {
-            if !translations.contains_key(content) {
-                todo.insert(content.to_string(), "[TODO]".to_string());
+        for (key, content) in contents.iter() {
+            if !catalogs.iter().any(|t| t.contains_key(key)) {
+                todo.insert(key.to_string(), todo_translation(&content.names));
             }
         }
 
-        for (key, _) in translations.iter() {
-            if !contents.contains(key) {
-                unused.push(key.clone());
+        for (index, translations) in catalogs.iter().enumerate() {
+            for (key, _) in translations.iter() {
+                if !contents.contains_key(key) {
+                    unused.push((index, key.clone()));
+                }
             }
         }
 
         if rm_unused {
-            for u in unused.iter() {
-                translations.remove(u);
+            for (index, key) in unused.iter() {
+                catalogs[*index].remove(key);
             }
 
             println!("{} unused translation(s) were removed", unused.len());
@@ -116,17 +239,26 @@ fn update(root: impl AsRef<Path>, rm_unused: bool) -> Result<()> {
             println!("{} unused translation(s) were found", unused.len());
         }
 
+        // untranslated texts are written to the first catalog of the locale
+        // which can have any text, or the first one of Fluent-only locales
+        let target = paths.iter().position(|path| extensible(path)).unwrap_or(0);
+
         if todo.is_empty() {
             println!("No untranslated text found");
         } else {
             println!("{} untranslated text(s) were found", todo.len());
-            println!("Writing to TODO.{}", file_name);
+            println!("Writing to TODO.{}", file_names[target]);
 
-            translations.extend(todo.clone());
+            catalogs[target].extend(todo.clone());
         }
 
-        if !todo.is_empty() || (rm_unused && !unused.is_empty()) {
-            r18_trans_support::translation::generate(entry.path(), translations)?;
+        for (index, (path, translations)) in paths.iter().zip(catalogs).enumerate() {
+            let changed = (index == target && !todo.is_empty())
+                || (rm_unused && unused.iter().any(|(i, _)| *i == index));
+
+            if changed {
+                r18_trans_support::translation::generate(path, translations)?;
+            }
         }
     }
 
@@ -165,7 +297,7 @@ fn generate(locales: Vec<String>, root: impl AsRef<Path>) -> Result<()> {
         // TODO: automatic translation
         let todo = contents
             .iter()
-            .map(|content| (content.clone(), "[TODO]".to_string()))
+            .map(|(key, content)| (key.clone(), todo_translation(&content.names)))
             .collect::<HashMap<_, _>>();
 
         r18_trans_support::translation::generate(expected_path, todo)?;
@@ -175,3 +307,217 @@ fn generate(locales: Vec<String>, root: impl AsRef<Path>) -> Result<()> {
 
     Ok(())
 }
+
+/// Returns translation files of `locale` which exist in `dir`.
+fn catalogs_of(dir: impl AsRef<Path>, locale: &str) -> Vec<PathBuf> {
+    [
+        "json", "jsonc", "json5", "ftl", "po", "mo", "arb", "yaml", "yml", "toml",
+    ]
+    .into_iter()
+    .map(|ext| dir.as_ref().join(format!("{}.{}", locale, ext)))
+    .filter(|path| path.is_file())
+    .collect()
+}
+
+/// Returns translations of `locale` merged from all of its translation files.
+fn load_locale(
+    dir: impl AsRef<Path>,
+    locale: &LanguageTag<String>,
+) -> Result<HashMap<String, Translation>> {
+    let mut translations = HashMap::new();
+
+    for path in catalogs_of(dir, locale) {
+        translations.extend(r18_trans_support::translation::extract(path)?);
+    }
+
+    Ok(translations)
+}
+
+/// Returns whether translations of `path` can be rewritten, gettext catalogs
+/// are maintained by gettext tools instead.
+fn writable(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|ext| ext != "po" && ext != "mo")
+}
+
+/// Returns whether any translation can be added to `path`, Fluent catalogs
+/// only have messages of valid identifiers.
+fn extensible(path: &Path) -> bool {
+    writable(path) && path.extension().is_some_and(|ext| ext != "ftl")
+}
+
+fn export(
+    format: Format,
+    locales: Vec<String>,
+    source: &str,
+    icu: bool,
+    root: impl AsRef<Path>,
+    output: impl AsRef<Path>,
+) -> Result<()> {
+    let (contents, locale_path) = extract_source(root.as_ref())?;
+
+    if locale_path.is_empty() {
+        return Err("Missing translation directory".into());
+    }
+
+    let output = root.as_ref().join(output);
+    std::fs::create_dir_all(&output)
+        .map_err(|e| format!("Failed to create {}: {}", output.display(), e))?;
+
+    let mut keys = contents.iter().collect::<Vec<_>>();
+    keys.sort_by(|a, b| a.0.cmp(b.0));
+
+    let locales = locales
+        .iter()
+        .map(|locale| {
+            LanguageTag::parse_and_normalize(locale)
+                .map_err(|e| format!("Invalid locale: {}: {}", locale, e).into())
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let source = LanguageTag::parse_and_normalize(source)
+        .map_err(|e| format!("Invalid source language: {}: {}", source, e))?;
+
+    let metadata = match format {
+        Format::Arb => arb::metadata(&root.as_ref().join(&locale_path))?,
+        _ => HashMap::new(),
+    };
+
+    match format {
+        Format::Po => po::export(root.as_ref(), &keys, None, &output)?,
+        Format::Arb => arb::export(&keys, None, &metadata, &output)?,
+        Format::Android => android::export(None, &sources(&keys), &output)?,
+        Format::Apple => apple::export(&source, None, &sources(&keys), &output)?,
+        // the other formats have no templates, and all locales are written
+        // into one sheet of CSV
+        Format::Xliff | Format::Csv | Format::I18next => {}
+    }
+
+    let mut sheet = Vec::new();
+
+    for locale in locales {
+        let translations = load_locale(root.as_ref().join(&locale_path), &locale)?;
+
+        match format {
+            Format::Po => po::export(
+                root.as_ref(),
+                &keys,
+                Some((&locale, &translations)),
+                &output,
+            )?,
+            Format::Arb => arb::export(&keys, Some((&locale, &translations)), &metadata, &output)?,
+            Format::Xliff => xliff::export(
+                root.as_ref(),
+                &keys,
+                &source,
+                (&locale, &translations),
+                &output,
+            )?,
+            Format::Csv => sheet.push((locale, translations)),
+            Format::I18next => i18next::export((&locale, &translations), icu, &output)?,
+            Format::Android => {
+                let translations = plural_forms(&keys, translations);
+                android::export(Some(&locale), &translations, &output)?
+            }
+            Format::Apple => {
+                let translations = plural_forms(&keys, translations);
+                apple::export(&source, Some(&locale), &translations, &output)?
+            }
+        }
+    }
+
+    if let Format::Csv = format {
+        csv::export(&keys, &sheet, &output)?;
+    }
+
+    println!("\nDone");
+
+    Ok(())
+}
+
+fn import(files: Vec<String>, root: impl AsRef<Path>) -> Result<()> {
+    let (contents, locale_path) = extract_source(root.as_ref())?;
+
+    if locale_path.is_empty() {
+        return Err("Missing translation directory".into());
+    }
+
+    for file in files {
+        let dir = root.as_ref().join(&locale_path);
+        let imported = match Path::new(&file).extension().and_then(|e| e.to_str()) {
+            Some("po") => vec![po::import(&file).map(|(l, t)| (l, t, Vec::new()))?],
+            Some("arb") => vec![arb::import(&file).map(|(l, t)| (l, t, Vec::new()))?],
+            Some("xlf" | "xliff") => vec![xliff::import(&file, &contents)?],
+            Some("csv") => csv::import(&file, &dir, &contents)?,
+            _ => return Err(format!("Unsupported file: {}", file).into()),
+        };
+
+        for (locale, imported, mut rejected) in imported {
+            // translations are written to the catalogs which have them, and
+            // new ones to the first catalog which can have them, or
+            // `<tag>.json` if there is none
+            let mut catalogs = catalogs_of(&dir, &locale)
+                .into_iter()
+                .map(|path| Ok((r18_trans_support::translation::extract(&path)?, path)))
+                .collect::<Result<Vec<_>>>()?;
+            let first = match catalogs.iter().position(|(_, path)| extensible(path)) {
+                Some(first) => first,
+                None => {
+                    catalogs.push((HashMap::new(), dir.join(format!("{}.json", locale))));
+                    catalogs.len() - 1
+                }
+            };
+
+            let mut imported = imported.into_iter().collect::<Vec<_>>();
+            imported.sort_by(|a, b| a.0.cmp(&b.0));
+
+            let (mut added, mut changed) = (0, 0);
+            let mut updated = BTreeSet::new();
+            for (key, translation) in imported {
+                let index = catalogs.iter().position(|(t, _)| t.contains_key(&key));
+                let (translations, path) = &mut catalogs[index.unwrap_or(first)];
+
+                match translations.get(&key) {
+                    Some(old) if *old == translation => continue,
+                    _ if !writable(path) => {
+                        let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+                        rejected.push((key, format!("{} can not be written", file_name)));
+                        continue;
+                    }
+                    None => {
+                        added += 1;
+                        println!("  added: {}", key.trim_start());
+                    }
+                    Some(_) => {
+                        changed += 1;
+                        println!("  changed: {}", key.trim_start());
+                    }
+                }
+
+                translations.insert(key, translation);
+                updated.insert(index.unwrap_or(first));
+            }
+            for (key, reason) in &rejected {
+                println!("  rejected: {}: {}", key.trim_start(), reason);
+            }
+
+            println!(
+                "{} added, {} changed, {} rejected",
+                added,
+                changed,
+                rejected.len()
+            );
+
+            for index in updated {
+                let (translations, path) = std::mem::take(&mut catalogs[index]);
+
+                println!("Imported {} into {}", file, path.display());
+                r18_trans_support::translation::write(path, translations)?;
+            }
+        }
+    }
+
+    println!("\nDone");
+
+    Ok(())
+}
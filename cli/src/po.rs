@@ -0,0 +1,56 @@
+use std::{collections::HashMap, fs, path::Path};
+
+use oxilangtag::LanguageTag;
+use r18_trans_support::{
+    source::Content,
+    translation::{generate_po, po_language, PoMessage, Translation},
+};
+
+use crate::{references, Result};
+
+/// Writes the `.pot` template if `locale` is `None`, or the `.po` file of
+/// `locale` to `output`.
+pub(crate) fn export(
+    root: &Path,
+    keys: &[(&String, &Content)],
+    locale: Option<(&LanguageTag<String>, &HashMap<String, Translation>)>,
+    output: &Path,
+) -> Result<()> {
+    let messages = keys
+        .iter()
+        .map(|(key, content)| PoMessage {
+            key,
+            plural: content.plural,
+            references: references(root, content),
+            translation: locale.and_then(|(_, translations)| translations.get(*key)),
+        })
+        .collect::<Vec<_>>();
+
+    let tag = locale.map(|(tag, _)| tag.as_str());
+    let path = output.join(match tag {
+        Some(tag) => format!("{}.po", tag),
+        None => "messages.pot".to_string(),
+    });
+
+    fs::write(&path, generate_po(tag, &messages)?)
+        .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+
+    println!("Exported {}", path.display());
+
+    Ok(())
+}
+
+/// Returns the locale and translated messages of a `.po` file, the locale is
+/// declared in its header or named by the file.
+pub(crate) fn import(file: &str) -> Result<(String, HashMap<String, Translation>)> {
+    let source = fs::read_to_string(file).map_err(|e| format!("Failed to read {}: {}", file, e))?;
+
+    let locale = po_language(&source)
+        .or_else(|| Some(Path::new(file).file_stem()?.to_str()?.to_string()))
+        .and_then(|l| LanguageTag::parse_and_normalize(&l.replace('_', "-")).ok())
+        .ok_or_else(|| format!("Can not find the locale of {}", file))?;
+
+    let translations = r18_trans_support::translation::extract(file)?;
+
+    Ok((locale.to_string(), translations))
+}
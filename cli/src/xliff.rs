@@ -0,0 +1,73 @@
+use std::{collections::HashMap, fs, path::Path};
+
+use oxilangtag::LanguageTag;
+use r18_trans_support::{
+    source::{Content, Contents},
+    translation::{generate_xliff, parse_xliff, Translation, XliffMessage},
+};
+
+use crate::{references, Imported, Result};
+
+/// Writes the `.xlf` file of `locale` to `output`.
+pub(crate) fn export(
+    root: &Path,
+    keys: &[(&String, &Content)],
+    source: &LanguageTag<String>,
+    locale: (&LanguageTag<String>, &HashMap<String, Translation>),
+    output: &Path,
+) -> Result<()> {
+    let (tag, translations) = locale;
+    let messages = keys
+        .iter()
+        .map(|(key, content)| XliffMessage {
+            key,
+            plural: content.plural,
+            references: references(root, content),
+            translation: translations.get(*key),
+        })
+        .collect::<Vec<_>>();
+
+    let path = output.join(format!("{}.xlf", tag));
+
+    fs::write(&path, generate_xliff(source.as_str(), tag.as_str(), &messages)?)
+        .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+
+    println!("Exported {}", path.display());
+
+    Ok(())
+}
+
+/// Returns the target locale, translated units and rejected units with the
+/// reasons of a `.xlf` file.
+pub(crate) fn import(file: &str, contents: &Contents) -> Result<Imported> {
+    let source = fs::read_to_string(file).map_err(|e| format!("Failed to read {}: {}", file, e))?;
+    let xliff = parse_xliff(&source).map_err(|e| format!("Failed to parse {}: {}", file, e))?;
+
+    let locale = xliff
+        .target_language
+        .or_else(|| Some(Path::new(file).file_stem()?.to_str()?.to_string()))
+        .and_then(|l| LanguageTag::parse_and_normalize(&l.replace('_', "-")).ok())
+        .ok_or_else(|| format!("Can not find the locale of {}", file))?;
+
+    let mut translations = HashMap::new();
+    let mut rejected = Vec::new();
+
+    for unit in xliff.units {
+        let checked = unit.translation.and_then(|translation| {
+            if !contents.contains_key(&unit.key) {
+                return Err("not found in the source".to_string());
+            }
+
+            translation.check().map(|_| translation)
+        });
+
+        match checked {
+            Ok(translation) => {
+                translations.insert(unit.key, translation);
+            }
+            Err(reason) => rejected.push((unit.key, reason)),
+        }
+    }
+
+    Ok((locale.to_string(), translations, rejected))
+}
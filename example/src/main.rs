@@ -5,10 +5,14 @@ fn main() {
 
     r18::auto_detect!();
     println!("{}", r18::tr!("Hello, {}", name));
+    println!("{}", r18::tr_plural!(3, "{} files"));
+    println!("{}", r18::tr!("{name} sent {count} messages", count = 3));
 }
 
 #[cfg(test)]
 mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
     #[test]
     fn functional_test() {
         let name = "ho-229";
@@ -32,17 +36,61 @@ mod tests {
             ),
             r18::tr!([".pua"] "Hello, {}", name)
         );
+        let err = std::io::Error::new(std::io::ErrorKind::Other, "An error");
+        assert_eq!(format!("调试：{:?}", err), r18::tr!("Debug: {:?}", err));
+        assert_eq!("合计：   12.50", r18::tr!("Total: {:.2}", 12.5));
+
+        let count = 3;
         assert_eq!(
-            format!(
-                "调试：{:?}",
-                std::io::Error::new(std::io::ErrorKind::Other, "An error")
-            ),
+            format!("3 条来自 {} 的消息", name),
+            r18::tr!("{name} sent {count} messages")
+        );
+        assert_eq!(
+            format!("5 条来自 {} 的消息", name),
+            r18::tr!("{name} sent {count} messages", count = count + 2)
+        );
+
+        assert_eq!(
+            format!("Bob 收到了 {} 的消息", name),
+            r18::tr!("{0} sent {1} a message", name, "Bob")
+        );
+
+        // the translation of zh-CN has a typo
+        static ERRORS: AtomicUsize = AtomicUsize::new(0);
+        r18::set_format_error_hook(|_| {
+            ERRORS.fetch_add(1, Ordering::Relaxed);
+        });
+        assert_eq!("Goodbye, ho-229", r18::tr!("Goodbye, {name}"));
+        assert_eq!(1, ERRORS.load(Ordering::Relaxed));
+
+        // hooks may replace themselves
+        r18::set_format_error_hook(|_| {
+            r18::set_format_error_hook(|_| {
+                ERRORS.fetch_add(2, Ordering::Relaxed);
+            });
+        });
+        assert_eq!("Goodbye, ho-229", r18::tr!("Goodbye, {name}"));
+        assert_eq!("Goodbye, ho-229", r18::tr!("Goodbye, {name}"));
+        assert_eq!(3, ERRORS.load(Ordering::Relaxed));
+
+        let err = r18::try_tr!("Goodbye, {name}").unwrap_err();
+        assert_eq!("{nmae}", err.placeholder);
+        assert_eq!(r18::FormatErrorKind::MissingArgument, err.kind);
+        assert_eq!(
+            Ok("你好，ho-229".to_string()),
+            r18::try_tr!("Hello, {}", name)
+        );
+
+        let gender = "female";
+        assert_eq!(
+            "她赞了你的帖子",
+            r18::tr!("{gender, select, female {She} male {He} other {They}} liked your post")
+        );
+        assert_eq!(
+            "他们赞了你的帖子",
             r18::tr!(
-                "Debug: {}",
-                format!(
-                    "{:?}",
-                    std::io::Error::new(std::io::ErrorKind::Other, "An error")
-                )
+                "{gender, select, female {She} male {He} other {They}} liked your post",
+                gender = "unknown"
             )
         );
 
@@ -52,8 +100,142 @@ mod tests {
         assert_eq!(Some("zh-TW"), r18::locale!());
 
         r18::set_locale!("zh-HK");
-        // zh-CN has higher priority than zh-TW
-        // but in this example we specify zh-TW as a fallback on config.json
+        assert_eq!(Some("zh-HK"), r18::locale!());
+        assert_eq!(format!("你好，{}", name), r18::tr!("Hello, {}", name));
+        // missing texts are looked up through the fallback chain of zh-HK on
+        // config.json
+        assert_eq!("1 個檔案", r18::tr_plural!(1, "{} files"));
+        assert_eq!(
+            format!("3 則來自 {} 的訊息", name),
+            r18::tr!("{name} sent {count} messages", count = 3)
+        );
+
+        r18::set_locale!("zh-MO");
+        assert_eq!(Some("zh-TW"), r18::locale!());
+
+        // zh-SG and zh-Hans-TW are inferred as Simplified Chinese by likely
+        // subtags, the fallback of zh on config.json is only used without
+        // translations of their likely scripts
+        r18::set_locale!("zh-SG");
+        assert_eq!(Some("zh-CN"), r18::locale!());
+        r18::set_locale!("zh-Hans-TW");
+        assert_eq!(Some("zh-CN"), r18::locale!());
+        r18::set_locale!("zh-Hant");
+        assert_eq!(Some("zh-TW"), r18::locale!());
+
+        // scripts take priority over regions
+        r18::set_locale!("zh-Hant-TW");
+        assert_eq!(Some("zh-TW"), r18::locale!());
+
+        r18::set_locale!("sr-Latn-RS");
+        assert_eq!(Some("sr-Latn"), r18::locale!());
+        assert_eq!(format!("Zdravo, {}", name), r18::tr!("Hello, {}", name));
+
+        r18::set_locale!("sr");
+        assert_eq!(Some("sr-Cyrl"), r18::locale!());
+        assert_eq!(format!("Здраво, {}", name), r18::tr!("Hello, {}", name));
+
+        r18::set_locale!("sr-BA");
+        assert_eq!(Some("sr-Cyrl"), r18::locale!());
+        // sr-ME is inferred as sr-Latn-ME, but the fallback on config.json
+        // overrides it
+        r18::set_locale!("sr-ME");
+        assert_eq!(Some("sr-Cyrl"), r18::locale!());
+
+        // locales matching no translations use the default one
+        r18::set_locale!("it");
+        assert_eq!(Some("en"), r18::locale!());
+        assert_eq!("1 file", r18::tr_plural!(1, "{} files"));
+
+        r18::set_locale!("en");
+        assert_eq!("1 file", r18::tr_plural!(1, "{} files"));
+        assert_eq!("2 files", r18::tr_plural!(2, "{} files"));
+        assert_eq!(
+            "You finished 22nd",
+            r18::tr!(
+                "You finished {place, selectordinal, one {#st} two {#nd} few {#rd} other {#th}}",
+                place = 22
+            )
+        );
+
+        r18::set_locale!("fr");
+        assert_eq!(
+            format!("Bonjour, {} ! Bienvenue dans r18.", name),
+            r18::tr!("welcome", name = name)
+        );
+        assert_eq!("Vous n'avez aucun e-mail.", r18::tr!("emails", count = 0));
+        assert_eq!("Vous avez 1 e-mail.", r18::tr!("emails", count = 1));
+        assert_eq!("Vous avez 3 e-mails.", r18::tr!("emails", count = 3));
+        assert_eq!("Connexion à r18", r18::tr!([".login"] "title"));
+        assert_eq!(
+            Ok("{e-mail}".to_string()),
+            r18::try_tr!([".login"] "placeholder")
+        );
+
+        r18::set_locale!("de");
+        assert_eq!(format!("Hallo, {}", name), r18::tr!("Hello, {}", name));
+        assert_eq!(
+            format!("Guten Tag, {}", name),
+            r18::tr!([".pua"] "Hello, {}", name)
+        );
+        assert_eq!("1 Datei", r18::tr_plural!(1, "{} files"));
+        assert_eq!("2 Dateien", r18::tr_plural!(2, "{} files"));
+        // fuzzy translations are skipped
+        assert_eq!(
+            format!("{} sent 3 messages", name),
+            r18::tr!("{name} sent {count} messages")
+        );
+
+        r18::set_locale!("ru");
+        assert_eq!("1 файл", r18::tr_plural!(1, "{} files"));
+        assert_eq!("3 файла", r18::tr_plural!(3, "{} files"));
+        assert_eq!("5 файлов", r18::tr_plural!(5, "{} files"));
+        assert_eq!("1.5 файла", r18::tr_plural!(1.5, "{} files"));
+        assert_eq!(format!("Привет, {}", name), r18::tr!("Hello, {}", name));
+
+        r18::set_locale!("ja");
+        assert_eq!(format!("こんにちは、{}", name), r18::tr!("Hello, {}", name));
+        assert_eq!(
+            format!("お疲れさまです、{} さん", name),
+            r18::tr!([".pua"] "Hello, {}", name)
+        );
+        assert_eq!("3 個のファイル", r18::tr_plural!(3, "{} files"));
+
+        r18::set_locale!("ko");
+        assert_eq!(format!("안녕하세요, {}", name), r18::tr!("Hello, {}", name));
+        assert_eq!(
+            format!("{}님이 메시지 3개를 보냈습니다", name),
+            r18::tr!("{name} sent {count} messages")
+        );
+        assert_eq!("파일 1개", r18::tr_plural!(1, "{} files"));
+        assert_eq!(
+            format!("수고하셨습니다, {}님", name),
+            r18::tr!([".pua"] "Hello, {}", name)
+        );
+
+        r18::set_locale!("es");
+        assert_eq!(format!("Hola, {}", name), r18::tr!("Hello, {}", name));
+        assert_eq!(
+            format!("Buen trabajo, {}", name),
+            r18::tr!([".pua"] "Hello, {}", name)
+        );
+        assert_eq!(
+            format!("{} envió un mensaje", name),
+            r18::tr!("{name} sent {count} messages", count = 1)
+        );
+        assert_eq!(
+            format!("{} envió 3 mensajes", name),
+            r18::tr!("{name} sent {count} messages")
+        );
+        assert_eq!("3 archivos", r18::tr_plural!(3, "{} files"));
+
+        r18::set_locale!("zh-TW");
+        assert_eq!("1 個檔案", r18::tr_plural!(1, "{} files"));
+
+        r18::with_locale("zh-CN", || {
+            assert_eq!(Some("zh-CN"), r18::locale!());
+            assert_eq!(format!("你好，{}", name), r18::tr!("Hello, {}", name));
+        });
         assert_eq!(Some("zh-TW"), r18::locale!());
     }
 }
@@ -0,0 +1,16 @@
+-brand = r18
+
+# Messages of the login form
+hello-user = Bonjour, { $name } !
+welcome = { hello-user } Bienvenue dans { -brand }.
+
+emails =
+    { $count ->
+        [0] Vous n'avez aucun e-mail.
+        [one] Vous avez { $count } e-mail.
+       *[other] Vous avez { $count } e-mails.
+    }
+
+login =
+    .title = Connexion à { -brand }
+    .placeholder = { "{" }e-mail{ "}" }
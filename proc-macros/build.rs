@@ -1,4 +1,6 @@
 fn main() {
+    println!("cargo:rustc-check-cfg=cfg(feature, values(\"nightly-features\"))");
+
     if std::env::var("RUSTUP_TOOLCHAIN")
         .map(|t| t.starts_with("nightly"))
         .unwrap_or(false)
@@ -0,0 +1,145 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+    Expr, ExprLit, Lit, Token,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+};
+
+enum Argument {
+    Positional(Expr),
+    Named(Ident, Expr),
+}
+
+impl Parse for Argument {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(syn::Ident) && input.peek2(Token![=]) && !input.peek2(Token![==]) {
+            let name = input.parse()?;
+            input.parse::<Token![=]>()?;
+
+            Ok(Self::Named(name, input.parse()?))
+        } else {
+            input.parse().map(Self::Positional)
+        }
+    }
+}
+
+/// `[try] catalog, template, content, args...`
+pub(crate) struct TrFormat {
+    /// returns a `Result` instead of falling back to `content`
+    fallible: bool,
+    catalog: Expr,
+    template: Expr,
+    content: Expr,
+    args: Punctuated<Argument, Token![,]>,
+}
+
+impl Parse for TrFormat {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let fallible = input.parse::<Option<Token![try]>>()?.is_some();
+        let catalog = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let template = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let content = input.parse()?;
+
+        let args = match input.parse::<Option<Token![,]>>()? {
+            Some(_) => Punctuated::parse_terminated(input)?,
+            None => Punctuated::new(),
+        };
+
+        Ok(Self {
+            fallible,
+            catalog,
+            template,
+            content,
+            args,
+        })
+    }
+}
+
+/// Returns the string literal of `expr` which may be wrapped by an invisible
+/// group from `macro_rules`.
+fn literal(expr: &Expr) -> Option<&syn::LitStr> {
+    match expr {
+        Expr::Group(g) => literal(&g.expr),
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(s), ..
+        }) => Some(s),
+        _ => None,
+    }
+}
+
+impl TrFormat {
+    pub(crate) fn expand(self) -> TokenStream {
+        let mut positional = Vec::new();
+        let mut named = Vec::new();
+
+        for arg in self.args {
+            match arg {
+                Argument::Positional(expr) => positional.push(expr),
+                Argument::Named(name, expr) => named.push((name.to_string(), quote!(#expr))),
+            }
+        }
+
+        // inline captures, like `format!("{name}")`
+        if let Some(content) = literal(&self.content) {
+            for name in r18_trans_support::source::placeholder_names(&content.value()) {
+                if !named.iter().any(|(n, _)| *n == name) {
+                    let ident = Ident::new(&name, content.span());
+                    named.push((name, quote!(#ident)));
+                }
+            }
+        }
+
+        let catalog = self.catalog;
+        let template = self.template;
+        let content = self.content;
+
+        if positional.is_empty() && named.is_empty() && !self.fallible {
+            return quote!(#template);
+        }
+
+        // bind the arguments once, like `format_args!`
+        let (bindings, exprs): (Vec<_>, Vec<_>) = positional
+            .iter()
+            .map(|expr| quote!(#expr))
+            .chain(named.iter().map(|(_, expr)| expr.clone()))
+            .enumerate()
+            .map(|(i, expr)| (format_ident!("__r18_arg{}", i), expr))
+            .unzip();
+        let names = positional
+            .iter()
+            .map(|_| quote!(None))
+            .chain(named.iter().map(|(name, _)| quote!(Some(#name))));
+
+        let format = if self.fallible {
+            quote!(::r18::format(#template, &|| ::r18::Catalog::language(#catalog), __r18_args))
+        } else {
+            quote!(::r18::format_translation(#catalog, #template, #content, __r18_args))
+        };
+
+        quote! {
+            match (#( &(#exprs), )*) {
+                (#( #bindings, )*) => {
+                    #[allow(unused_imports)]
+                    use ::r18::{
+                        DebugFallback as _, DebugProbe as _, DisplayFallback as _,
+                        DisplayProbe as _, NumberFallback as _, NumberProbe as _,
+                    };
+
+                    let __r18_args: &[::r18::Argument] = &[#(
+                        ::r18::Argument {
+                            name: #names,
+                            display: (&::r18::Probe(#bindings)).r18_display(),
+                            debug: (&::r18::Probe(#bindings)).r18_debug(),
+                            number: (&::r18::Probe(#bindings)).r18_number(),
+                        },
+                    )*];
+
+                    #format
+                }
+            }
+        }
+    }
+}
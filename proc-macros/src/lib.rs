@@ -1,6 +1,6 @@
 #![cfg_attr(feature = "nightly-features", feature(track_path))]
 use std::{
-    collections::{BTreeMap, HashMap},
+    collections::{BTreeMap, BTreeSet, HashMap},
     fs::File,
     io::BufReader,
     path::{Path, PathBuf},
@@ -9,9 +9,15 @@ use std::{
 use oxilangtag::LanguageTag;
 use proc_macro2::Ident;
 use quote::{format_ident, quote};
+use r18_trans_support::{
+    likely::{maximize, regional_scripts},
+    translation::Translation,
+};
 use serde::Deserialize;
 use walkdir::WalkDir;
 
+mod format;
+
 struct PathStr(String);
 
 impl syn::parse::Parse for PathStr {
@@ -20,19 +26,57 @@ impl syn::parse::Parse for PathStr {
     }
 }
 
+/// Fallback locales of a locale, in the order of priority.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Fallback {
+    Locale(String),
+    Chain(Vec<String>),
+}
+
 #[derive(Debug, Default, Deserialize)]
 struct Config {
-    fallback: HashMap<String, String>,
+    #[serde(default)]
+    fallback: BTreeMap<String, Fallback>,
+    /// The locale for locales matching no translations.
+    default: Option<String>,
 }
 
+impl Config {
+    /// Returns fallback chains of locales.
+    fn chains(&self) -> Chains {
+        self.fallback
+            .iter()
+            .map(|(locale, fallback)| {
+                let chain = match fallback {
+                    Fallback::Locale(fallback) => vec![parse_locale(fallback)],
+                    Fallback::Chain(chain) => chain.iter().map(|l| parse_locale(l)).collect(),
+                };
+
+                (parse_locale(locale), chain)
+            })
+            .collect()
+    }
+}
+
+type Subtags = (
+    String, // primary language
+    String, // script
+    String, // region
+);
+type Chains = BTreeMap<Subtags, Vec<Subtags>>;
+
 struct LocaleExtra {
     name: String,
     ident: Ident,
-    translations: HashMap<String, String>,
+    translations: HashMap<String, Translation>,
 }
 
 type LocaleModel = BTreeMap<
-    String, // region
+    (
+        String, // script
+        String, // region
+    ),
     LocaleExtra,
 >;
 type TranslationModel = HashMap<
@@ -40,7 +84,7 @@ type TranslationModel = HashMap<
     LocaleModel,
 >;
 
-/// Generate translation models and functions `set_locale` and `locale` to setup
+/// Generate translation models and the catalog of the calling crate to setup
 /// `r18` environment with given translation directory.
 ///
 /// ## Example
@@ -64,7 +108,8 @@ pub fn init(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
         Err(e) => return e.to_compile_error().into(),
     };
 
-    let (config, model) = scan_locales(path);
+    let (config, mut model) = scan_locales(path);
+    merge_fallbacks(&config, &mut model);
     let locales = generate_locales(&model);
     let locale_helpers = generate_helpers(&config, &model);
 
@@ -78,11 +123,27 @@ pub fn init(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     .into()
 }
 
+/// Format translated content with positional, named and captured arguments.
+///
+/// This macro is used by `r18::tr!`, do not use it directly.
+#[doc(hidden)]
+#[proc_macro]
+pub fn __tr_format(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    match syn::parse::<format::TrFormat>(input) {
+        Ok(format) => format.expand().into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
 fn scan_locales(path: impl AsRef<Path>) -> (Config, TranslationModel) {
     let mut model = TranslationModel::new();
     let mut config = Config::default();
 
+    // files of each key, so catalogs of a locale must not translate the same key
+    let mut origins = HashMap::<(String, String), PathBuf>::new();
+
     WalkDir::new(path)
+        .sort_by_file_name()
         .into_iter()
         .filter_map(|p| {
             let path = p.ok()?;
@@ -93,7 +154,14 @@ fn scan_locales(path: impl AsRef<Path>) -> (Config, TranslationModel) {
                     config = load_config(path.path());
                     None
                 }
-                (Some("json"), Some(tag), None) => LanguageTag::parse_and_normalize(tag).ok(),
+                (
+                    Some(
+                        "json" | "jsonc" | "json5" | "ftl" | "po" | "mo" | "arb" | "yaml" | "yml"
+                        | "toml",
+                    ),
+                    Some(tag),
+                    None,
+                ) => LanguageTag::parse_and_normalize(tag).ok(),
                 _ => None,
             }?;
 
@@ -103,22 +171,41 @@ fn scan_locales(path: impl AsRef<Path>) -> (Config, TranslationModel) {
             #[cfg(feature = "nightly-features")]
             proc_macro::tracked_path::path(path.path().to_str().unwrap_or_default());
 
-            let region = language.region().unwrap_or_default().to_string();
-            let name = region
-                .is_empty()
-                .then_some(language.primary_language().into())
-                .unwrap_or_else(|| format!("{}-{}", language.primary_language(), region));
+            let (primary, script, region) = subtags_of(&language);
+            let name = [primary.as_str(), &script, &region]
+                .into_iter()
+                .filter(|subtag| !subtag.is_empty())
+                .collect::<Vec<_>>()
+                .join("-");
+
+            let translations = r18_trans_support::translation::extract(path.path()).unwrap();
 
-            let extra = LocaleExtra {
-                ident: format_ident!("{}", name.replace('-', "_").to_uppercase()),
-                name,
-                translations: r18_trans_support::translation::extract(path.path()).unwrap(),
-            };
+            for key in translations.keys() {
+                if let Some(origin) =
+                    origins.insert((name.clone(), key.clone()), path.path().into())
+                {
+                    panic!(
+                        "`{}` of {} is translated in both {} and {}",
+                        key,
+                        name,
+                        origin.display(),
+                        path.path().display()
+                    );
+                }
+            }
 
+            // catalogs of the same locale in different formats are merged
             model
-                .entry(language.primary_language().into())
+                .entry(primary)
                 .or_default()
-                .insert(region, extra);
+                .entry((script, region))
+                .or_insert_with(|| LocaleExtra {
+                    ident: format_ident!("{}", name.replace('-', "_").to_uppercase()),
+                    name,
+                    translations: HashMap::new(),
+                })
+                .translations
+                .extend(translations);
         });
 
     (config, model)
@@ -131,13 +218,111 @@ fn load_config(path: impl AsRef<Path>) -> Config {
         .unwrap_or_default()
 }
 
+fn subtags_of(tag: &LanguageTag<String>) -> Subtags {
+    (
+        tag.primary_language().to_string(),
+        tag.script().unwrap_or_default().to_string(),
+        tag.region().unwrap_or_default().to_string(),
+    )
+}
+
+fn parse_locale(locale: &str) -> Subtags {
+    let tag = LanguageTag::parse_and_normalize(locale)
+        .unwrap_or_else(|e| panic!("Invalid locale in config.json: {}: {}", locale, e));
+
+    subtags_of(&tag)
+}
+
+/// Merges translations of fallback locales into each translation, so that
+/// missing texts are looked up through its fallback chain key by key.
+///
+/// Fallback locales of a translation are its fallback chain, the translations
+/// of its script and its language without other subtags and the default
+/// locale, in the order of priority.
+fn merge_fallbacks(config: &Config, model: &mut TranslationModel) {
+    let chains = &config.chains();
+    let default = config.default.as_deref().map(parse_locale);
+
+    let merged = model
+        .iter()
+        .flat_map(|(primary, locales)| {
+            locales.iter().map(move |((script, region), extra)| {
+                ((primary.clone(), script.clone(), region.clone()), extra)
+            })
+        })
+        .map(|(locale, extra)| {
+            let mut fallbacks = chains
+                .get(&locale)
+                .into_iter()
+                .flatten()
+                .filter_map(|l| entry(chains, model, l))
+                .collect::<Vec<_>>();
+
+            let (primary, script, region) = &locale;
+            if !script.is_empty() && !region.is_empty() {
+                fallbacks.extend(exact(
+                    model,
+                    &(primary.clone(), script.clone(), String::new()),
+                ));
+            }
+            if !script.is_empty() || !region.is_empty() {
+                fallbacks.extend(exact(
+                    model,
+                    &(primary.clone(), String::new(), String::new()),
+                ));
+            }
+            fallbacks.extend(default.as_ref().and_then(|l| entry(chains, model, l)));
+
+            // fallbacks with higher priority are merged later
+            let mut translations = HashMap::new();
+            for fallback in fallbacks.into_iter().rev() {
+                if fallback.name != extra.name {
+                    translations.extend(fallback.translations.clone());
+                }
+            }
+
+            (locale, translations)
+        })
+        .collect::<Vec<_>>();
+
+    for ((primary, script, region), mut translations) in merged {
+        if let Some(extra) = model
+            .get_mut(&primary)
+            .and_then(|l| l.get_mut(&(script, region)))
+        {
+            translations.extend(std::mem::take(&mut extra.translations));
+            extra.translations = translations;
+        }
+    }
+}
+
 fn generate_primary(locales: &LocaleModel) -> proc_macro2::TokenStream {
     locales
-        .iter()
-        .map(|(_, extra)| {
+        .values()
+        .map(|extra| {
             let code = &extra.ident;
             let name = &extra.name;
-            let translation = extra.translations.iter().map(|(k, v)| quote!( #k => #v ));
+
+            let mut prefixes = BTreeMap::<_, Vec<_>>::new();
+            extra
+                .translations
+                .iter()
+                .filter_map(|(k, v)| Some((k.split_once(' ')?, v)))
+                .for_each(|((prefix, content), v)| {
+                    let v = generate_translation(v);
+                    prefixes
+                        .entry(prefix)
+                        .or_default()
+                        .push(quote!( #content => #v ))
+                });
+
+            let translation = prefixes.into_iter().map(|(prefix, contents)| {
+                quote! {
+                    #prefix => phf::phf_map! {
+                        #( #contents ),*
+                    }
+                }
+            });
 
             quote! {
                 #[doc(hidden)]
@@ -155,77 +340,240 @@ fn generate_primary(locales: &LocaleModel) -> proc_macro2::TokenStream {
         .collect()
 }
 
+fn generate_translation(translation: &Translation) -> proc_macro2::TokenStream {
+    match translation {
+        Translation::Text(text) => quote!(::r18::Translation::Text(#text)),
+        Translation::Plural(forms) => {
+            let forms = forms.iter().map(|(category, form)| {
+                let category = format_ident!("{}", format!("{:?}", category));
+                quote!((::r18::PluralCategory::#category, #form))
+            });
+
+            quote!(::r18::Translation::Plural(&[ #( #forms ),* ]))
+        }
+    }
+}
+
 fn generate_locales(model: &TranslationModel) -> proc_macro2::TokenStream {
-    model
+    model.values().map(generate_primary).collect()
+}
+
+/// Returns the translation of `locale` exactly.
+fn exact<'m>(model: &'m TranslationModel, locale: &Subtags) -> Option<&'m LocaleExtra> {
+    let (primary, script, region) = locale;
+    model.get(primary)?.get(&(script.clone(), region.clone()))
+}
+
+/// Returns the translation closest to `locale` by likely subtags, which is
+/// the one of the same likely script and region, the likely region of the
+/// script, or the first one of the script.
+fn closest<'m>(model: &'m TranslationModel, locale: &Subtags) -> Option<&'m LocaleExtra> {
+    let (primary, script, region) = locale;
+    let locales = model
+        .get(primary)?
         .iter()
-        .map(|(_, locales)| generate_primary(locales))
-        .collect()
+        .map(|((s, r), extra)| (maximize(primary, s, r), extra))
+        .collect::<Vec<_>>();
+
+    let likely = maximize(primary, script, region);
+    let likely_region = maximize(primary, &likely.0, "");
+
+    locales
+        .iter()
+        .find(|(l, _)| *l == likely)
+        .or_else(|| locales.iter().find(|(l, _)| *l == likely_region))
+        .or_else(|| locales.iter().find(|(l, _)| l.0 == likely.0))
+        .map(|(_, extra)| *extra)
+}
+
+/// Returns the translation of `primary` language, which is the first one
+/// available in its fallback chain, the closest one to its likely subtags, or
+/// the one by the highest alphabetical order.
+fn language<'m>(
+    chains: &Chains,
+    model: &'m TranslationModel,
+    primary: &str,
+) -> Option<&'m LocaleExtra> {
+    chains
+        .get(&(primary.to_string(), String::new(), String::new()))
+        .and_then(|chain| chain.iter().find_map(|locale| exact(model, locale)))
+        .or_else(|| closest(model, &(primary.to_string(), String::new(), String::new())))
+        .or_else(|| model.get(primary)?.values().next())
+}
+
+/// Returns the translation of a locale in fallback chains, languages without
+/// other subtags are resolved by [`language`], and other locales are resolved
+/// by [`closest`].
+fn entry<'m>(
+    chains: &Chains,
+    model: &'m TranslationModel,
+    locale: &Subtags,
+) -> Option<&'m LocaleExtra> {
+    let (primary, script, region) = locale;
+
+    exact(model, locale).or_else(|| match script.is_empty() && region.is_empty() {
+        true => language(chains, model, primary),
+        false => closest(model, locale),
+    })
 }
 
-fn generate_lang_matches(
-    config: &Config,
-    primary: &String,
-    locales: &LocaleModel,
-) -> proc_macro2::TokenStream {
-    let exact_matches =
+/// Returns the pattern of `(primary, script, region)` matching `locale`, and
+/// its priority, where more specific patterns have higher priorities.
+///
+/// Locales without scripts also match `likely_script` if it is not empty.
+fn pattern(locale: &Subtags, likely_script: &str) -> (usize, proc_macro2::TokenStream) {
+    let (primary, script, region) = locale;
+    let subtag = |subtag: &String| match subtag.is_empty() {
+        true => quote!(_),
+        false => quote!(Some(#subtag)),
+    };
+    let script_pattern = match (script.is_empty(), likely_script.is_empty()) {
+        (true, false) => quote!(None | Some(#likely_script)),
+        _ => subtag(script),
+    };
+    let region_pattern = subtag(region);
+
+    // scripts take priority over regions
+    let priority = match (script.is_empty(), region.is_empty()) {
+        (false, false) => 0,
+        (false, true) => 1,
+        (true, false) => 2,
+        (true, true) => 3,
+    };
+
+    (
+        priority,
+        quote!((#primary, #script_pattern, #region_pattern)),
+    )
+}
+
+fn generate_helpers(config: &Config, model: &TranslationModel) -> proc_macro2::TokenStream {
+    let chains = &config.chains();
+    let arm = |pattern: proc_macro2::TokenStream, extra: &LocaleExtra| {
+        let ident = &extra.ident;
+        quote! { #pattern => Some(&#ident) , }
+    };
+
+    let exact_matches = model.iter().flat_map(|(primary, locales)| {
         locales
             .iter()
-            .filter(|(region, _)| !region.is_empty())
-            .map(|(region, extra)| {
-                let ident = &extra.ident;
-                quote! { (#primary, Some(#region)) => Some(&#ident) , }
-            });
+            .filter(|((script, region), _)| !script.is_empty() || !region.is_empty())
+            .map(move |((script, region), extra)| {
+                let locale = (primary.clone(), script.clone(), region.clone());
+
+                // translations without scripts also match their likely
+                // scripts, unless the scripts have their own matches
+                let (likely_script, _) = maximize(primary, script, region);
+                let script_locale = (primary.clone(), likely_script.clone(), String::new());
+                let likely_script = match exact(model, &script_locale).is_none()
+                    && !chains.contains_key(&script_locale)
+                {
+                    true => likely_script,
+                    false => String::new(),
+                };
 
-    if let Some((_, extra)) = locales.first_key_value() {
-        let fallback_region = config
-            .fallback
-            .get(primary)
-            .and_then(|fallback| {
-                LanguageTag::parse_and_normalize(fallback)
-                    .ok()?
-                    .region()
-                    .map(|r| r.to_string())
+                let (priority, pattern) = pattern(&locale, &likely_script);
+                (priority, arm(pattern, extra))
             })
-            .unwrap_or_default();
+    });
 
-        let fallback_match = locales
-            .get(&fallback_region)
-            .map(|extra| {
-                let ident = &extra.ident;
-                quote! { (#primary, _) => Some(&#ident) , }
+    // locales without translations walk their fallback chains
+    let chain_matches = chains
+        .iter()
+        .filter(|(locale, _)| {
+            (!locale.1.is_empty() || !locale.2.is_empty()) && exact(model, locale).is_none()
+        })
+        .filter_map(|(locale, chain)| {
+            let extra = chain
+                .iter()
+                .find_map(|locale| entry(chains, model, locale))?;
+            let (priority, pattern) = pattern(locale, "");
+            Some((priority, arm(pattern, extra)))
+        });
+
+    // other locales are matched by their likely subtags, which are overridden
+    // by the matches above, and locales without translations of their likely
+    // scripts or regions walk the fallback chains of their languages below
+    let matched = |locale: &Subtags| exact(model, locale).is_some() || chains.contains_key(locale);
+    let likely_matches = model.iter().flat_map(|(primary, locales)| {
+        let locale =
+            |script: &str, region: &str| (primary.clone(), script.to_string(), region.to_string());
+
+        // regions whose likely scripts differ from the one of the language
+        let regions = regional_scripts(primary)
+            .filter(|(region, _)| !matched(&locale("", region)))
+            .filter_map(|(region, script)| {
+                let extra = closest(model, &locale(script, region))?;
+                Some(arm(quote!((#primary, None, Some(#region))), extra))
             })
-            .unwrap_or_else(|| {
-                let ident = &extra.ident;
-                quote! { (#primary, _) => Some(&#ident) , }
-            });
+            .collect::<Vec<_>>();
 
-        exact_matches.chain([fallback_match]).collect()
-    } else {
-        quote!()
-    }
-}
+        let scripts = locales
+            .keys()
+            .map(|(script, region)| maximize(primary, script, region).0)
+            .filter(|script| !script.is_empty() && !matched(&locale(script, "")))
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .filter_map(|script| {
+                let extra = closest(model, &locale(&script, ""))?;
+                Some(arm(quote!((#primary, Some(#script), _)), extra))
+            })
+            .collect::<Vec<_>>();
 
-fn generate_helpers(config: &Config, model: &TranslationModel) -> proc_macro2::TokenStream {
-    let matches = model
-        .iter()
-        .map(|(primary, locales)| generate_lang_matches(config, primary, locales))
+        // other regions have the likely script of the language
+        let others = closest(model, &locale("", ""))
+            .map(|extra| arm(quote!((#primary, None, Some(_))), extra));
+
+        regions.into_iter().chain(scripts).chain(others)
+    });
+
+    let language_matches = model
+        .keys()
+        .chain(chains.keys().map(|(primary, ..)| primary))
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .filter_map(|primary| {
+            let extra = chains
+                .get(&(primary.clone(), String::new(), String::new()))
+                .and_then(|chain| chain.iter().find_map(|locale| entry(chains, model, locale)))
+                .or_else(|| language(chains, model, primary))?;
+            let (_, pattern) = pattern(&(primary.clone(), String::new(), String::new()), "");
+            Some(arm(pattern, extra))
+        });
+
+    let mut matches = exact_matches.chain(chain_matches).collect::<Vec<_>>();
+    matches.sort_by_key(|(priority, _)| *priority);
+
+    let default_match = match &config.default {
+        Some(default) => {
+            let extra = entry(chains, model, &parse_locale(default)).unwrap_or_else(|| {
+                panic!(
+                    "The default locale in config.json is not translated: {}",
+                    default
+                )
+            });
+            arm(quote!(_), extra)
+        }
+        None => quote! { _ => None, },
+    };
+
+    let matches = matches
+        .into_iter()
+        .map(|(_, arm)| arm)
+        .chain(likely_matches)
+        .chain(language_matches)
+        .chain([default_match])
         .collect::<proc_macro2::TokenStream>();
 
     quote! {
         #[doc(hidden)]
-        pub(crate) fn set_locale(locale: impl AsRef<str>) {
-            *::r18::CURRENT_LOCALE
-                .get_or_init(|| ::std::sync::Mutex::new(None))
-                .lock()
-                .unwrap() = match ::r18::LanguageTag::parse_and_normalize(locale.as_ref()) {
-                    Ok(lang) => {
-                        match (lang.primary_language(), lang.region()) {
-                            #matches
-                            _ => None,
-                        }
-                    }
-                    Err(_) => None,
-                };
+        pub(crate) static CATALOG: ::r18::Catalog = ::r18::Catalog { resolve };
+
+        #[doc(hidden)]
+        fn resolve(lang: &::r18::LanguageTag<String>) -> Option<&'static ::r18::Locale> {
+            match (lang.primary_language(), lang.script(), lang.region()) {
+                #matches
+            }
         }
     }
 }
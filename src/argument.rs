@@ -0,0 +1,160 @@
+use std::fmt::{self, Debug, Display, Formatter};
+
+use crate::PluralOperands;
+
+/// An argument for formatting translated content.
+///
+/// Arguments are created by [`tr!`](crate::tr), which detects the traits
+/// implemented by each value with [`Probe`].
+#[doc(hidden)]
+pub struct Argument<'a> {
+    pub name: Option<&'static str>,
+    pub display: Option<&'a dyn Display>,
+    pub debug: Option<&'a dyn Debug>,
+    pub number: Option<&'a dyn Number>,
+}
+
+/// A primitive number.
+#[doc(hidden)]
+pub trait Number {
+    /// Formats the number with `{:x}`, `{:X}`, `{:o}`, `{:b}`, `{:e}` or
+    /// `{:E}`, returns `None` if the type is not supported by the number.
+    fn fmt_as(&self, ty: &str, f: &mut Formatter) -> Option<fmt::Result>;
+
+    /// Returns the number as a width or precision.
+    fn to_usize(&self) -> Option<usize>;
+
+    /// Returns the plural operands for selecting plural forms.
+    fn to_plural_operands(&self) -> PluralOperands;
+}
+
+impl<T: Number + ?Sized> Number for &T {
+    fn fmt_as(&self, ty: &str, f: &mut Formatter) -> Option<fmt::Result> {
+        (**self).fmt_as(ty, f)
+    }
+
+    fn to_usize(&self) -> Option<usize> {
+        (**self).to_usize()
+    }
+
+    fn to_plural_operands(&self) -> PluralOperands {
+        (**self).to_plural_operands()
+    }
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Number for $t {
+                fn fmt_as(&self, ty: &str, f: &mut Formatter) -> Option<fmt::Result> {
+                    Some(match ty {
+                        "x" => fmt::LowerHex::fmt(self, f),
+                        "X" => fmt::UpperHex::fmt(self, f),
+                        "o" => fmt::Octal::fmt(self, f),
+                        "b" => fmt::Binary::fmt(self, f),
+                        "e" => fmt::LowerExp::fmt(self, f),
+                        "E" => fmt::UpperExp::fmt(self, f),
+                        _ => return None,
+                    })
+                }
+
+                fn to_usize(&self) -> Option<usize> {
+                    usize::try_from(*self).ok()
+                }
+
+                fn to_plural_operands(&self) -> PluralOperands {
+                    PluralOperands::from(*self)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_float {
+    ($($t:ty),*) => {
+        $(
+            impl Number for $t {
+                fn fmt_as(&self, ty: &str, f: &mut Formatter) -> Option<fmt::Result> {
+                    Some(match ty {
+                        "e" => fmt::LowerExp::fmt(self, f),
+                        "E" => fmt::UpperExp::fmt(self, f),
+                        _ => return None,
+                    })
+                }
+
+                fn to_usize(&self) -> Option<usize> {
+                    None
+                }
+
+                fn to_plural_operands(&self) -> PluralOperands {
+                    PluralOperands::from(*self)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+impl_float!(f32, f64);
+
+/// Detects traits implemented by a value with autoref specialization, the
+/// methods are only usable on concrete types, e.g. `(&Probe(&1)).r18_debug()`.
+#[doc(hidden)]
+pub struct Probe<'a, T>(pub &'a T);
+
+macro_rules! probe {
+    ($probe:ident, $fallback:ident, $method:ident, $bound:path, $ret:ty) => {
+        #[doc(hidden)]
+        pub trait $probe<'a> {
+            fn $method(&self) -> Option<&'a $ret>;
+        }
+
+        impl<'a, T: $bound> $probe<'a> for Probe<'a, T> {
+            fn $method(&self) -> Option<&'a $ret> {
+                Some(self.0)
+            }
+        }
+
+        #[doc(hidden)]
+        pub trait $fallback<'a> {
+            fn $method(&self) -> Option<&'a $ret> {
+                None
+            }
+        }
+
+        impl<'a, T> $fallback<'a> for &Probe<'a, T> {}
+    };
+}
+
+probe!(DisplayProbe, DisplayFallback, r18_display, Display, dyn Display);
+probe!(DebugProbe, DebugFallback, r18_debug, Debug, dyn Debug);
+probe!(NumberProbe, NumberFallback, r18_number, Number, dyn Number);
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        DebugFallback, DebugProbe, DisplayFallback, DisplayProbe, NumberFallback, NumberProbe,
+        Probe,
+    };
+
+    #[derive(Debug)]
+    struct DebugOnly;
+
+    struct Opaque;
+
+    // the explicit borrow picks the fallback if the trait is not implemented
+    #[allow(clippy::needless_borrow)]
+    #[test]
+    fn probe() {
+        assert!((&Probe(&DebugOnly)).r18_display().is_none());
+        assert!((&Probe(&DebugOnly)).r18_debug().is_some());
+        assert!((&Probe(&DebugOnly)).r18_number().is_none());
+
+        assert!((&Probe(&&1)).r18_display().is_some());
+        assert!((&Probe(&&1)).r18_number().is_some());
+        assert!((&Probe(&"str")).r18_number().is_none());
+
+        assert!((&Probe(&Opaque)).r18_display().is_none());
+        assert!((&Probe(&Opaque)).r18_debug().is_none());
+    }
+}
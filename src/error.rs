@@ -0,0 +1,83 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    sync::{Arc, PoisonError, RwLock},
+};
+
+/// The reason of a [`FormatError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FormatErrorKind {
+    /// A `{` is not closed, or a `}` is not escaped by `}}`.
+    UnmatchedBrace,
+    /// The format spec of the placeholder is invalid, like `{:y}`.
+    InvalidSpec,
+    /// No argument matches the placeholder, the width or the precision, like
+    /// `{2}` with two arguments.
+    MissingArgument,
+    /// The argument does not support the format spec, like `{:x}` with a
+    /// string, or failed to format.
+    UnsupportedArgument,
+}
+
+/// An error of formatting a translated content, returned by [`try_tr`] and
+/// reported to the hook set by [`set_format_error_hook`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatError {
+    /// The template failed to format, which is usually a translation.
+    pub template: String,
+    /// The placeholder which caused the error, like `{0:x}`.
+    pub placeholder: String,
+    pub kind: FormatErrorKind,
+}
+
+impl Display for FormatError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let reason = match self.kind {
+            FormatErrorKind::UnmatchedBrace => "unmatched brace",
+            FormatErrorKind::InvalidSpec => "invalid format spec",
+            FormatErrorKind::MissingArgument => "missing argument for",
+            FormatErrorKind::UnsupportedArgument => "unsupported argument for",
+        };
+
+        write!(f, "{reason} `{}` in `{}`", self.placeholder, self.template)
+    }
+}
+
+impl Error for FormatError {}
+
+type Hook = Arc<dyn Fn(&FormatError) + Send + Sync>;
+
+static FORMAT_ERROR_HOOK: RwLock<Option<Hook>> = RwLock::new(None);
+
+/// Sets the hook called when [`tr`] or [`tr_plural`] failed to format a
+/// translation, replacing the previous one.
+///
+/// When a translation fails to format, e.g. it has a placeholder without a
+/// matching argument, the untranslated content will be formatted instead. By
+/// default, the error is printed to the standard error in debug builds.
+///
+/// ## Example
+///
+/// ```ignore
+/// r18::set_format_error_hook(|err| log::warn!("{err}"));
+/// ```
+pub fn set_format_error_hook(hook: impl Fn(&FormatError) + Send + Sync + 'static) {
+    *FORMAT_ERROR_HOOK
+        .write()
+        .unwrap_or_else(PoisonError::into_inner) = Some(Arc::new(hook));
+}
+
+pub(crate) fn report(err: &FormatError) {
+    // the guard is dropped before calling the hook, which may set a new hook
+    let hook = FORMAT_ERROR_HOOK
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone();
+
+    match hook {
+        Some(hook) => hook(err),
+        None if cfg!(debug_assertions) => eprintln!("r18: {err}"),
+        None => {}
+    }
+}
@@ -0,0 +1,745 @@
+use std::fmt::{self, Debug, Display, Formatter, Write};
+
+use crate::{
+    ordinal_category, plural_category, report, Argument, Catalog, FormatError, FormatErrorKind,
+    PluralCategory,
+};
+
+#[derive(Clone, Copy)]
+enum Align {
+    Left,
+    Center,
+    Right,
+}
+
+/// A width or precision.
+enum Count<'a> {
+    Literal(usize),
+    /// `0$` or `name$`
+    Argument(&'a str),
+    /// `.*`
+    Next,
+}
+
+/// `[[fill]align][sign]['#']['0'][width]['.' precision][type]`
+struct Spec<'a> {
+    fill: char,
+    align: Option<Align>,
+    plus: bool,
+    alternate: bool,
+    zero: bool,
+    width: Option<Count<'a>>,
+    precision: Option<Count<'a>>,
+    ty: &'a str,
+}
+
+fn align(c: char) -> Option<Align> {
+    match c {
+        '<' => Some(Align::Left),
+        '^' => Some(Align::Center),
+        '>' => Some(Align::Right),
+        _ => None,
+    }
+}
+
+/// Parses an integer or an argument followed by `$`.
+fn parse_count(s: &str) -> (Option<Count<'_>>, &str) {
+    let end = if s.starts_with(|c: char| c.is_ascii_digit()) {
+        s.find(|c: char| !c.is_ascii_digit())
+    } else {
+        s.find(|c: char| !(c.is_alphanumeric() || c == '_'))
+    }
+    .unwrap_or(s.len());
+    let (count, rest) = s.split_at(end);
+
+    if count.is_empty() {
+        return (None, s);
+    }
+
+    match rest.strip_prefix('$') {
+        Some(rest) => (Some(Count::Argument(count)), rest),
+        None => match count.parse() {
+            Ok(n) => (Some(Count::Literal(n)), rest),
+            Err(_) => (None, s),
+        },
+    }
+}
+
+impl<'a> Spec<'a> {
+    fn parse(mut s: &'a str) -> Option<Self> {
+        let mut spec = Spec {
+            fill: ' ',
+            align: None,
+            plus: false,
+            alternate: false,
+            zero: false,
+            width: None,
+            precision: None,
+            ty: "",
+        };
+
+        let mut chars = s.chars();
+        match (chars.next(), chars.next().and_then(align)) {
+            (Some(fill), Some(align)) => {
+                spec.fill = fill;
+                spec.align = Some(align);
+                s = &s[fill.len_utf8() + 1..];
+            }
+            (Some(c), _) if align(c).is_some() => {
+                spec.align = align(c);
+                s = &s[1..];
+            }
+            _ => {}
+        }
+
+        if let Some(rest) = s.strip_prefix('+') {
+            spec.plus = true;
+            s = rest;
+        } else if let Some(rest) = s.strip_prefix('-') {
+            s = rest;
+        }
+
+        if let Some(rest) = s.strip_prefix('#') {
+            spec.alternate = true;
+            s = rest;
+        }
+
+        // `0$` is a width argument rather than the flag
+        if let Some(rest) = s.strip_prefix('0').filter(|rest| !rest.starts_with('$')) {
+            spec.zero = true;
+            s = rest;
+        }
+
+        (spec.width, s) = parse_count(s);
+
+        if let Some(rest) = s.strip_prefix('.') {
+            (spec.precision, s) = match rest.strip_prefix('*') {
+                Some(rest) => (Some(Count::Next), rest),
+                None => match parse_count(rest) {
+                    (Some(count), rest) => (Some(count), rest),
+                    (None, _) => return None,
+                },
+            };
+        }
+
+        match s {
+            "" | "?" | "x?" | "X?" | "x" | "X" | "o" | "b" | "e" | "E" => spec.ty = s,
+            _ => return None,
+        }
+
+        Some(spec)
+    }
+}
+
+/// An argument formatted with the type of a [`Spec`].
+struct Value<'a> {
+    arg: &'a Argument<'a>,
+    ty: &'a str,
+}
+
+impl Display for Value<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.ty {
+            "" => Display::fmt(self.arg.display.ok_or(fmt::Error)?, f),
+            "?" => Debug::fmt(self.arg.debug.ok_or(fmt::Error)?, f),
+            // the debug hex flags are not accessible from a formatter
+            "x?" => write!(f, "{:x?}", self.arg.debug.ok_or(fmt::Error)?),
+            "X?" => write!(f, "{:X?}", self.arg.debug.ok_or(fmt::Error)?),
+            ty => self
+                .arg
+                .number
+                .ok_or(fmt::Error)?
+                .fmt_as(ty, f)
+                .unwrap_or(Err(fmt::Error)),
+        }
+    }
+}
+
+/// Writes `v` with the flags of `spec`, `w` and `p`.
+fn write_value(
+    out: &mut String,
+    v: &Value,
+    spec: &Spec,
+    w: usize,
+    p: Option<usize>,
+) -> fmt::Result {
+    match (spec.plus, spec.alternate, spec.zero, p) {
+        (false, false, false, None) => write!(out, "{v:w$}"),
+        (false, false, false, Some(p)) => write!(out, "{v:w$.p$}"),
+        (false, false, true, None) => write!(out, "{v:0w$}"),
+        (false, false, true, Some(p)) => write!(out, "{v:0w$.p$}"),
+        (false, true, false, None) => write!(out, "{v:#w$}"),
+        (false, true, false, Some(p)) => write!(out, "{v:#w$.p$}"),
+        (false, true, true, None) => write!(out, "{v:#0w$}"),
+        (false, true, true, Some(p)) => write!(out, "{v:#0w$.p$}"),
+        (true, false, false, None) => write!(out, "{v:+w$}"),
+        (true, false, false, Some(p)) => write!(out, "{v:+w$.p$}"),
+        (true, false, true, None) => write!(out, "{v:+0w$}"),
+        (true, false, true, Some(p)) => write!(out, "{v:+0w$.p$}"),
+        (true, true, false, None) => write!(out, "{v:+#w$}"),
+        (true, true, false, Some(p)) => write!(out, "{v:+#w$.p$}"),
+        (true, true, true, None) => write!(out, "{v:+#0w$}"),
+        (true, true, true, Some(p)) => write!(out, "{v:+#0w$.p$}"),
+    }
+}
+
+fn push_fill(out: &mut String, fill: char, n: usize) {
+    out.extend(std::iter::repeat(fill).take(n));
+}
+
+/// Returns the index of the `}` closing a sub-message or an ICU argument, `s`
+/// starts after the opening `{`.
+fn skip(s: &str) -> Option<usize> {
+    let mut depth = 0;
+    let mut quoted = false;
+    let mut chars = s.char_indices().peekable();
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\'' if quoted => quoted = false,
+            '\'' if matches!(chars.peek(), Some((_, '\''))) => {
+                chars.next();
+            }
+            '\'' if matches!(chars.peek(), Some((_, '{' | '}' | '#'))) => quoted = true,
+            _ if quoted => {}
+            '{' => depth += 1,
+            '}' if depth == 0 => return Some(i),
+            '}' => depth -= 1,
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// The state of formatting a template.
+struct Message<'a, 't> {
+    template: &'t str,
+    args: &'a [Argument<'a>],
+    language: &'a dyn Fn() -> String,
+    /// The language returned by `language`, computed for the first plural
+    /// argument.
+    plural_language: Option<String>,
+    /// The index of the next positional argument.
+    next: usize,
+}
+
+impl<'a, 't> Message<'a, 't> {
+    fn error(&self, placeholder: &str, kind: FormatErrorKind) -> FormatError {
+        FormatError {
+            template: self.template.to_string(),
+            placeholder: placeholder.to_string(),
+            kind,
+        }
+    }
+
+    fn find(&self, key: &str) -> Option<&'a Argument<'a>> {
+        if key.bytes().all(|b| b.is_ascii_digit()) {
+            self.args.get(key.parse::<usize>().ok()?)
+        } else {
+            self.args.iter().find(|arg| arg.name == Some(key))
+        }
+    }
+
+    fn next(&mut self) -> Option<&'a Argument<'a>> {
+        self.next += 1;
+        self.args.get(self.next - 1)
+    }
+
+    fn count(&mut self, count: &Option<Count>, placeholder: &str) -> Result<Option<usize>, FormatError> {
+        let arg = match count {
+            None => return Ok(None),
+            Some(Count::Literal(n)) => return Ok(Some(*n)),
+            Some(Count::Argument(key)) => self.find(key),
+            Some(Count::Next) => self.next(),
+        }
+        .ok_or_else(|| self.error(placeholder, FormatErrorKind::MissingArgument))?;
+
+        match arg.number.and_then(|n| n.to_usize()) {
+            Some(n) => Ok(Some(n)),
+            None => Err(self.error(placeholder, FormatErrorKind::UnsupportedArgument)),
+        }
+    }
+
+    /// Formats `s` until the end, or the `}` closing the sub-message if
+    /// `nested`, returns the rest after the `}`.
+    ///
+    /// In sub-messages, braces are not escaped, `#` is replaced by `pound`, and
+    /// an apostrophe quotes `{`, `}` and `#` like ICU MessageFormat.
+    fn format(
+        &mut self,
+        mut s: &'t str,
+        out: &mut String,
+        nested: bool,
+        pound: Option<&'a Argument<'a>>,
+    ) -> Result<&'t str, FormatError> {
+        loop {
+            let Some(i) = s.find(|c| {
+                matches!(c, '{' | '}') || (nested && c == '\'') || (pound.is_some() && c == '#')
+            }) else {
+                if nested {
+                    return Err(self.error(s, FormatErrorKind::UnmatchedBrace));
+                }
+
+                out.push_str(s);
+                return Ok("");
+            };
+
+            out.push_str(&s[..i]);
+            let tail = &s[i + 1..];
+
+            s = match (&s[i..i + 1], pound) {
+                ("'", _) if tail.starts_with(['{', '}', '#']) => {
+                    let end = tail.find('\'').unwrap_or(tail.len());
+                    out.push_str(&tail[..end]);
+                    tail.get(end + 1..).unwrap_or_default()
+                }
+                ("'", _) => {
+                    out.push('\'');
+                    tail.strip_prefix('\'').unwrap_or(tail)
+                }
+                ("#", Some(arg)) => {
+                    let display = arg
+                        .display
+                        .ok_or_else(|| self.error("#", FormatErrorKind::UnsupportedArgument))?;
+                    write!(out, "{display}")
+                        .map_err(|_| self.error("#", FormatErrorKind::UnsupportedArgument))?;
+                    tail
+                }
+                ("}", _) if nested => return Ok(tail),
+                (brace, _) if !nested && tail.starts_with(brace) => {
+                    out.push_str(brace);
+                    &tail[1..]
+                }
+                ("}", _) => return Err(self.error(&s[i..], FormatErrorKind::UnmatchedBrace)),
+                _ => self.placeholder(&s[i..], out, pound)?,
+            };
+        }
+    }
+
+    /// Formats the placeholder at the start of `s`, returns the rest after it.
+    fn placeholder(
+        &mut self,
+        s: &'t str,
+        out: &mut String,
+        pound: Option<&'a Argument<'a>>,
+    ) -> Result<&'t str, FormatError> {
+        let tail = &s[1..];
+
+        // `{name, select, ...}`
+        if tail.find([',', '{', '}', ':']).map(|i| &tail[i..i + 1]) == Some(",") {
+            return self.select(s, out, pound);
+        }
+
+        let end = tail
+            .find('}')
+            .ok_or_else(|| self.error(s, FormatErrorKind::UnmatchedBrace))?;
+        let placeholder = &s[..end + 2];
+
+        let (key, spec) = tail[..end].split_once(':').unwrap_or((&tail[..end], ""));
+        let spec = Spec::parse(spec)
+            .ok_or_else(|| self.error(placeholder, FormatErrorKind::InvalidSpec))?;
+
+        let width = self.count(&spec.width, placeholder)?.unwrap_or_default();
+        // `.*` takes the precision before the value
+        let precision = self.count(&spec.precision, placeholder)?;
+
+        let arg = match key {
+            "" => self.next(),
+            key => self.find(key),
+        }
+        .ok_or_else(|| self.error(placeholder, FormatErrorKind::MissingArgument))?;
+        let value = Value { arg, ty: spec.ty };
+        let unsupported = |_| self.error(placeholder, FormatErrorKind::UnsupportedArgument);
+
+        match spec.align {
+            Some(align) if !spec.zero || spec.ty.ends_with('?') => {
+                let start = out.len();
+                write_value(out, &value, &spec, 0, precision).map_err(unsupported)?;
+
+                let pad = width.saturating_sub(out[start..].chars().count());
+                let (left, right) = match align {
+                    Align::Left => (0, pad),
+                    Align::Center => (pad / 2, pad - pad / 2),
+                    Align::Right => (pad, 0),
+                };
+
+                let value = out.split_off(start);
+                push_fill(out, spec.fill, left);
+                out.push_str(&value);
+                push_fill(out, spec.fill, right);
+            }
+            _ => write_value(out, &value, &spec, width, precision).map_err(unsupported)?,
+        }
+
+        Ok(&tail[end + 1..])
+    }
+
+    /// Formats the ICU MessageFormat `select`, `plural` or `selectordinal`
+    /// argument at the start of `s`, returns the rest after it.
+    fn select(
+        &mut self,
+        s: &'t str,
+        out: &mut String,
+        pound: Option<&'a Argument<'a>>,
+    ) -> Result<&'t str, FormatError> {
+        let tail = &s[1..];
+        let end = skip(tail).ok_or_else(|| self.error(s, FormatErrorKind::UnmatchedBrace))?;
+        let placeholder = &s[..end + 2];
+        let invalid = |this: &Self| this.error(placeholder, FormatErrorKind::InvalidSpec);
+
+        let mut parts = tail[..end].splitn(3, ',');
+        let (key, kind, mut options) = match (parts.next(), parts.next(), parts.next()) {
+            (Some(key), Some(kind), Some(options)) => (key.trim(), kind.trim(), options),
+            _ => return Err(invalid(self)),
+        };
+
+        let arg = self
+            .find(key)
+            .ok_or_else(|| self.error(placeholder, FormatErrorKind::MissingArgument))?;
+        let unsupported = |this: &Self| this.error(placeholder, FormatErrorKind::UnsupportedArgument);
+
+        let (value, operands, category) = match kind {
+            "select" => {
+                let display = arg.display.ok_or_else(|| unsupported(self))?;
+                (display.to_string(), None, PluralCategory::Other)
+            }
+            "plural" | "selectordinal" => {
+                let operands = arg.number.ok_or_else(|| unsupported(self))?.to_plural_operands();
+                let language = self.plural_language.get_or_insert_with(self.language);
+                let category = match kind {
+                    "plural" => plural_category(language, &operands),
+                    _ => ordinal_category(language, &operands),
+                };
+
+                (String::new(), Some(operands), category)
+            }
+            _ => return Err(invalid(self)),
+        };
+
+        // exact match, keyword match and `other`
+        let mut selected = [None; 3];
+
+        loop {
+            options = options.trim_start();
+            if options.is_empty() {
+                break;
+            }
+
+            let (selector, message) = options
+                .find(|c: char| c.is_whitespace() || c == '{')
+                .map(|i| options.split_at(i))
+                .and_then(|(selector, rest)| Some((selector, rest.trim_start().strip_prefix('{')?)))
+                .ok_or_else(|| invalid(self))?;
+            options = &message[skip(message).ok_or_else(|| invalid(self))? + 1..];
+
+            let exact = match (operands, selector.strip_prefix('=')) {
+                (Some(o), Some(n)) => n.parse() == Ok(o.i) && o.f == 0,
+                (None, _) => selector == value,
+                _ => false,
+            };
+            let keyword = operands.is_some() && selector == category.as_str();
+
+            for (slot, matched) in [exact, keyword, selector == "other"].into_iter().enumerate() {
+                if matched {
+                    selected[slot] = selected[slot].or(Some(message));
+                }
+            }
+        }
+
+        // `other` is required
+        let message = match selected {
+            [_, _, None] => return Err(invalid(self)),
+            [a, b, c] => a.or(b).or(c).unwrap_or_default(),
+        };
+        let pound = match kind {
+            "select" => pound,
+            _ => Some(arg),
+        };
+
+        self.format(message, out, true, pound)?;
+
+        Ok(&tail[end + 1..])
+    }
+}
+
+/// Formats `template` with `args`, returns an error if a placeholder is
+/// invalid, does not match any argument or the argument does not support the
+/// format spec.
+///
+/// `{}` takes the next argument, `{0}` takes the argument at the index,
+/// `{name}` takes the argument with the name, `{{` and `}}` are escaped
+/// braces. Placeholders accept the format spec of [`std::fmt`] after a `:`,
+/// like `{:>8}`, `{0:.2}` or `{name:?}`.
+///
+/// ICU MessageFormat `select`, `plural` and `selectordinal` arguments are
+/// supported, like `{gender, select, female {She} other {They}}`, plural forms
+/// are selected by the rules of `language`.
+#[doc(hidden)]
+pub fn format(
+    template: &str,
+    language: &dyn Fn() -> String,
+    args: &[Argument],
+) -> Result<String, FormatError> {
+    let mut ret = String::with_capacity(template.len());
+
+    Message {
+        template,
+        args,
+        language,
+        plural_language: None,
+        next: 0,
+    }
+    .format(template, &mut ret, false, None)?;
+
+    Ok(ret)
+}
+
+/// Formats the translated `template` of `content` with `args`, formats
+/// `content` instead if failed, returns `content` as is if both failed.
+///
+/// Errors are reported to the hook set by [`set_format_error_hook`].
+#[doc(hidden)]
+pub fn format_translation(
+    catalog: &Catalog,
+    template: &str,
+    content: &str,
+    args: &[Argument],
+) -> String {
+    let language = || catalog.language();
+
+    format(template, &language, args).unwrap_or_else(|err| {
+        report(&err);
+
+        if template == content {
+            return content.to_string();
+        }
+
+        format(content, &language, args).unwrap_or_else(|err| {
+            report(&err);
+            content.to_string()
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::{Debug, Display};
+
+    use crate::{Argument, Catalog, FormatError, FormatErrorKind};
+
+    fn format(template: &str, args: &[Argument]) -> Result<String, FormatError> {
+        super::format(template, &|| "en".to_string(), args)
+    }
+
+    fn text<'a>(name: Option<&'static str>, value: &'a (impl Display + Debug)) -> Argument<'a> {
+        Argument {
+            name,
+            display: Some(value),
+            debug: Some(value),
+            number: None,
+        }
+    }
+
+    fn error(template: &str, args: &[Argument]) -> Option<(String, FormatErrorKind)> {
+        format(template, args)
+            .err()
+            .map(|err| (err.placeholder, err.kind))
+    }
+
+    fn number<'a>(name: Option<&'static str>, value: &'a i32) -> Argument<'a> {
+        Argument {
+            number: Some(value),
+            ..text(name, value)
+        }
+    }
+
+    #[test]
+    fn named_arguments() {
+        let args = [number(None, &1), text(Some("name"), &"ho-229")];
+
+        assert_eq!(
+            Ok("ho-229 has 1 {message}".to_string()),
+            format("{name} has {} {{message}}", &args)
+        );
+        assert_eq!(
+            Some(("{unknown}".to_string(), FormatErrorKind::MissingArgument)),
+            error("{name} {unknown}", &args)
+        );
+        assert_eq!(
+            Some(("{name".to_string(), FormatErrorKind::UnmatchedBrace)),
+            error("{name", &args)
+        );
+        assert_eq!(
+            Some(("} {name}".to_string(), FormatErrorKind::UnmatchedBrace)),
+            error("} {name}", &args)
+        );
+        assert_eq!(
+            Some(("{}".to_string(), FormatErrorKind::MissingArgument)),
+            error("{} {} {}", &args)
+        );
+    }
+
+    #[test]
+    fn positional_arguments() {
+        let args = [text(None, &"Alice"), text(None, &"Bob")];
+
+        assert_eq!(
+            Ok("Bob received a message from Alice".to_string()),
+            format("{1} received a message from {0}", &args)
+        );
+        assert_eq!(Ok("Alice Alice Bob".to_string()), format("{0} {} {}", &args));
+        assert_eq!(
+            Some(("{2}".to_string(), FormatErrorKind::MissingArgument)),
+            error("{2}", &args)
+        );
+    }
+
+    #[test]
+    fn format_spec() {
+        let float = 1.23456;
+        let args = [
+            number(None, &255),
+            Argument {
+                number: Some(&float),
+                ..text(None, &float)
+            },
+            text(Some("name"), &"ho-229"),
+            number(Some("width"), &8),
+            number(Some("precision"), &2),
+        ];
+
+        for (expected, template) in [
+            ("     255", "{:>8}"),
+            ("255     ", "{:<8}"),
+            ("**255***", "{:*^8}"),
+            ("00000255", "{:08}"),
+            ("+255", "{:+}"),
+            ("0xff", "{:#x}"),
+            ("FF", "{0:X}"),
+            ("0o377", "{0:#o}"),
+            ("11111111", "{0:b}"),
+            ("1.23", "{1:.2}"),
+            ("1.23", "{1:.precision$}"),
+            ("1.23", "{1:.4$}"),
+            ("  1.2", "{1:5.1}"),
+            ("1.2e0", "{1:.1e}"),
+            ("\"ho-229\"", "{name:?}"),
+            ("ho-229  ", "{name:width$}"),
+            ("  ho-229", "{name:>3$}"),
+            ("\u{3000}ho", "{name:\u{3000}>3.2}"),
+        ] {
+            assert_eq!(Ok(expected.to_string()), format(template, &args), "{template}");
+        }
+
+        // `.*` takes the precision before the value
+        assert_eq!(
+            Ok("1.2".to_string()),
+            format(
+                "{:.*}",
+                &[
+                    number(None, &1),
+                    Argument {
+                        number: Some(&float),
+                        ..text(None, &float)
+                    }
+                ]
+            )
+        );
+        assert_eq!(
+            Ok("[ff]".to_string()),
+            format(
+                "{:x?}",
+                &[Argument {
+                    debug: Some(&[255]),
+                    ..number(None, &255)
+                }]
+            )
+        );
+
+        assert_eq!(
+            Some(("{name:x}".to_string(), FormatErrorKind::UnsupportedArgument)),
+            error("{name:x}", &args)
+        );
+        assert_eq!(
+            Some(("{name:y}".to_string(), FormatErrorKind::InvalidSpec)),
+            error("{name:y}", &args)
+        );
+        assert_eq!(
+            Some(("{:.}".to_string(), FormatErrorKind::InvalidSpec)),
+            error("{:.}", &args)
+        );
+        assert_eq!(
+            Some(("{:name$}".to_string(), FormatErrorKind::UnsupportedArgument)),
+            error("{:name$}", &args)
+        );
+    }
+
+    #[test]
+    fn format_translation() {
+        let catalog = Catalog { resolve: |_| None };
+        let args = [text(Some("name"), &"ho-229")];
+
+        assert_eq!(
+            "Hello, ho-229",
+            super::format_translation(&catalog, "你好，{nmae}", "Hello, {name}", &args)
+        );
+        assert_eq!(
+            "Hello, {name:y}",
+            super::format_translation(&catalog, "你好，{nmae}", "Hello, {name:y}", &args)
+        );
+    }
+
+    #[test]
+    fn message_format() {
+        let gender = "female";
+        let template = "{gender, select, female {She} male {He} other {They}} liked your post";
+
+        for (expected, gender) in [("She", "female"), ("He", "male"), ("They", "other"), ("They", "x")]
+        {
+            assert_eq!(
+                Ok(format!("{expected} liked your post")),
+                format(template, &[text(Some("gender"), &gender)])
+            );
+        }
+
+        let template = "{name} {count, plural, =0 {has no files} one {has # file} other {has # files}}";
+        for (expected, count) in [("has no files", 0), ("has 1 file", 1), ("has 5 files", 5)] {
+            assert_eq!(
+                Ok(format!("ho-229 {expected}")),
+                format(template, &[text(Some("name"), &"ho-229"), number(Some("count"), &count)])
+            );
+        }
+
+        let template = "{n, selectordinal, one {#st} two {#nd} few {#rd} other {#th}}";
+        for (expected, n) in [("1st", 1), ("2nd", 2), ("3rd", 3), ("4th", 4), ("11th", 11), ("22nd", 22)] {
+            assert_eq!(Ok(expected.to_string()), format(template, &[number(Some("n"), &n)]));
+        }
+
+        // nested, with a placeholder and quoted braces in sub-messages
+        let template = "{gender, select, \
+            female {{count, plural, one {She has '{'#'}' {what}} other {She has # {what}s}}} \
+            other {{count, plural, one {They have # {what}} other {They have # {what}s}}}}";
+        let args = [
+            text(Some("gender"), &gender),
+            number(Some("count"), &1),
+            text(Some("what"), &"cat"),
+        ];
+        assert_eq!(Ok("She has {1} cat".to_string()), format(template, &args));
+        assert_eq!(
+            Ok("## 1 it's".to_string()),
+            format("#{count, plural, other {'#' # it''s}}", &args)
+        );
+
+        for (kind, template) in [
+            (FormatErrorKind::InvalidSpec, "{gender, select, female {She}}"),
+            (FormatErrorKind::InvalidSpec, "{gender, choice, other {They}}"),
+            (FormatErrorKind::InvalidSpec, "{count, plural, offset:1 other {#}}"),
+            (FormatErrorKind::UnmatchedBrace, "{gender, select, other {They}"),
+            (FormatErrorKind::UnsupportedArgument, "{gender, plural, other {#}}"),
+            (FormatErrorKind::MissingArgument, "{unknown, select, other {They}}"),
+        ] {
+            assert_eq!(Some(kind), format(template, &args).err().map(|e| e.kind), "{template}");
+        }
+    }
+}
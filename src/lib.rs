@@ -24,6 +24,10 @@
 //! }
 //! ```
 //!
+//! Comments, trailing commas and the other extensions of
+//! [JSON5](https://json5.org) are accepted in translation files, which can
+//! also be named `<tag>.jsonc` or `<tag>.json5`.
+//!
 //! Then add [`init`] to the global scope of your code with
 //! the directory where translation files in (in following example is `./tr`).
 //!
@@ -51,6 +55,28 @@
 //! }
 //! ```
 //!
+//! Arguments can also be named, and named placeholders capture variables in
+//! scope like [`format!`]. Translations may use named placeholders or indexed
+//! positional placeholders such as `{0}` in any order.
+//! Placeholders accept the same format spec as [`format!`], like `{:>8}`,
+//! `{:.2}` or `{:?}`, and a translation may use a different spec from its
+//! source text.
+//!
+//! ```ignore
+//! let count = 3;
+//! println!("{}", r18::tr!("{name} sent {count} messages", name = "ho-229"));
+//! ```
+//!
+//! If a translation fails to format, e.g. it has a placeholder without a
+//! matching argument, [`tr`] formats the untranslated content instead and
+//! reports the error to the hook set by [`set_format_error_hook`], use
+//! [`try_tr`] to get the error as a [`Result`].
+//!
+//! ```ignore
+//! r18::set_format_error_hook(|err| log::warn!("{err}"));
+//! let text = r18::try_tr!("Hello, {}", name)?;
+//! ```
+//!
 //! ### Fallback Configuration
 //!
 //! Sometimes your translation may not fully match the user's locale,
@@ -59,61 +85,442 @@
 //!
 //! By default, if the translation does not match the user's locale,
 //! `r18` will fallback to the translation which is the same language
-//! by the highest alphabetical order.
+//! by the [CLDR likely subtags](https://cldr.unicode.org/index/cldr-spec/language-tag-equivalences),
+//! eg. `zh-HK` and `zh-Hant` match `zh-TW.json`, while `zh-SG` matches
+//! `zh-CN.json`, or by the highest alphabetical order for unknown languages.
+//! Translations of the same script like `sr-Latn.json` or `zh-Hant.json`
+//! take priority over the ones of the same region.
 //!
 //! You can also specify a fallback translation for a language in `config.json`
-//! which placed with other translation files.
+//! which placed with other translation files, or a chain of fallback
+//! translations for a language or a locale, which are tried in order and
+//! override the inferred ones.
+//! Locales matching no translations use the `default` one.
 //!
 //! eg.
 //! ```json
 //! {
 //!     "fallback": {
-//!         "zh": "zh-TW"
+//!         "zh": "zh-TW",
+//!         "zh-HK": ["zh-TW", "zh-Hant", "en"]
+//!     },
+//!     "default": "en"
+//! }
+//! ```
+//!
+//! Texts missing in a translation are looked up in its fallback chain, the
+//! translation of its language without regions and the default one in turn,
+//! so that a translation like `zh-HK.json` only needs texts differing from
+//! its fallbacks.
+//!
+//! ### Plurals
+//!
+//! Use [`tr_plural`] to translate content in the plural form of a number,
+//! which is selected by the
+//! [CLDR plural rules](https://cldr.unicode.org/index/cldr-spec/plural-rules)
+//! of the current locale. Plural forms are written as an object whose keys are
+//! plural categories (`zero`, `one`, `two`, `few`, `many` and `other`), and
+//! `other` is required.
+//!
+//! ```json
+//! // PATH: ./tr/ru.json
+//! {
+//!     "{} files": {
+//!         "one": "{} файл",
+//!         "few": "{} файла",
+//!         "other": "{} файлов"
 //!     }
 //! }
 //! ```
+//!
+//! ```ignore
+//! r18::set_locale!("ru");
+//! assert_eq!("3 файла", r18::tr_plural!(3, "{} files"));
+//! ```
+//!
+//! As the source text only has one form, you may also add a translation file
+//! for the language of your source text.
+//!
+//! Content and translations may also use the ICU MessageFormat `select`,
+//! `plural` and `selectordinal` arguments, which can be nested. `#` in a
+//! plural message is replaced by the number, and in those messages braces are
+//! quoted by apostrophes (`'{'`) instead of doubled.
+//!
+//! ```ignore
+//! let gender = "female";
+//! let count = 2;
+//! r18::tr!("{gender, select, female {She} male {He} other {They}} liked your post");
+//! r18::tr!("{count, plural, =0 {No files} one {# file} other {# files}}");
+//! r18::tr!("{count, selectordinal, one {#st} two {#nd} few {#rd} other {#th}}");
+//! ```
+//!
+//! ### Fluent
+//!
+//! Translations can also be written in [Project Fluent](https://projectfluent.org)
+//! as `<tag>.ftl`, next to or in place of `<tag>.json`. Messages are looked
+//! up by their identifiers and attributes by their names with the message as
+//! the prefix. Variables become named placeholders, terms and message
+//! references are inlined, and selectors become `plural` or `select`
+//! arguments.
+//!
+//! ```ignore
+//! // PATH: ./tr/fr.ftl
+//! // emails = { $count ->
+//! //         [one] Vous avez { $count } e-mail.
+//! //        *[other] Vous avez { $count } e-mails.
+//! //     }
+//! // login =
+//! //     .title = Connexion
+//! r18::set_locale!("fr");
+//! assert_eq!("Vous avez 3 e-mails.", r18::tr!("emails", count = 3));
+//! assert_eq!("Connexion", r18::tr!([".login"] "title"));
+//! ```
+//!
+//! ### gettext
+//!
+//! Legacy gettext catalogs can be used as `<tag>.po` or compiled `<tag>.mo`.
+//! `msgctxt` is mapped onto the prefix, and `msgstr[n]` of plural messages are
+//! mapped onto plural categories by evaluating the `Plural-Forms` of the
+//! catalog. Plural messages can be looked up by either `msgid` or
+//! `msgid_plural`. Fuzzy messages are skipped.
+//!
+//! ```ignore
+//! // PATH: ./tr/de.po
+//! // msgctxt "pua"
+//! // msgid "Hello, {}"
+//! // msgstr "Guten Tag, {}"
+//! r18::set_locale!("de");
+//! r18::tr!([".pua"] "Hello, {}", name);
+//! ```
+//!
+//! ### ARB
+//!
+//! Flutter ARB files can be used as `<tag>.arb`. Messages are named by their
+//! contents, messages of a prefix are named `<prefix>.<content>` with the
+//! prefix as their `context`. The `@<name>` metadata of messages is ignored.
+//!
+//! ```ignore
+//! // PATH: ./tr/es.arb
+//! // "pua.Hello, {}": "Buen trabajo, {}",
+//! // "@pua.Hello, {}": { "context": "pua" }
+//! r18::set_locale!("es");
+//! r18::tr!([".pua"] "Hello, {}", name);
+//! ```
+//!
+//! ### YAML and TOML
+//!
+//! Translations can also be written in `<tag>.yaml` (or `<tag>.yml`) and
+//! `<tag>.toml`, which are flattened by the same nested prefix rules as JSON.
+//!
+//! ```ignore
+//! // PATH: ./tr/ja.yaml
+//! // "Hello, {}": こんにちは、{}
+//! // pua:
+//! //   "Hello, {}": お疲れさまです、{} さん
+//! r18::set_locale!("ja");
+//! r18::tr!([".pua"] "Hello, {}", name);
+//! ```
+//!
+//! ### Scoped Locale
+//!
+//! [`set_locale`] changes the locale of the whole process. To translate with
+//! another locale on the current thread only, e.g. while handling a request,
+//! use [`scope_locale`] or [`with_locale`].
+//!
+//! ```ignore
+//! let text = r18::with_locale("zh-TW", || r18::tr!("Hello, {}", name));
+//! ```
+//!
+//! In asynchronous code, a task may move to another thread at `.await`
+//! points, use [`LocaleScope`] to keep the locale with the future.
+//!
+//! ```ignore
+//! let text = r18::LocaleScope::new("zh-TW")
+//!     .scope(async { r18::tr!("Hello, {}", name) })
+//!     .await;
+//! ```
+//!
+//! ### Libraries
+//!
+//! Each crate calling [`init`] owns its translations, so a library can ship
+//! its own translation files. The locale set by [`set_locale`] is shared by
+//! all crates, and every crate resolves it against its own translations.
 
-use std::sync::{Mutex, OnceLock};
+use std::sync::{
+    Mutex, PoisonError,
+    atomic::{AtomicPtr, Ordering},
+};
 
-#[doc(hidden)]
-pub use dynfmt::{Format, SimpleCurlyFormat};
 #[doc(hidden)]
 pub use oxilangtag::{LanguageTag, LanguageTagParseError};
 #[doc(hidden)]
 pub use phf;
+#[doc(hidden)]
+pub use r18_proc_macros::__tr_format;
 pub use r18_proc_macros::init;
 #[doc(hidden)]
 pub use sys_locale::get_locale;
 
-mod_use::mod_use!(macros);
+mod_use::mod_use!(argument, error, format, macros, plural, scope, task);
 
 #[doc(hidden)]
 pub struct Locale {
+    /// Language tag of the translation, like `zh-CN` or `sr-Latn`.
     pub name: &'static str,
-    pub translate: phf::Map<&'static str, &'static str>,
+    /// Translations grouped by prefix, then keyed by content.
+    pub translate: phf::Map<&'static str, phf::Map<&'static str, Translation>>,
+}
+
+#[doc(hidden)]
+pub enum Translation {
+    Text(&'static str),
+    /// Plural forms, which always contain [`PluralCategory::Other`].
+    Plural(&'static [(PluralCategory, &'static str)]),
+}
+
+impl Translation {
+    fn select(&self, category: PluralCategory) -> Option<&'static str> {
+        match self {
+            Translation::Text(text) => Some(text),
+            Translation::Plural(forms) => forms
+                .iter()
+                .find(|(c, _)| *c == category)
+                .or_else(|| forms.iter().find(|(c, _)| *c == PluralCategory::Other))
+                .map(|(_, form)| *form),
+        }
+    }
+}
+
+/// Translations of a single crate, generated by [`init`].
+///
+/// Every crate calling [`init`] owns its catalog, which resolves the current
+/// locale on its own, so that libraries can ship their translations without
+/// colliding with the application.
+#[doc(hidden)]
+pub struct Catalog {
+    pub resolve: fn(&LanguageTag<String>) -> Option<&'static Locale>,
+}
+
+impl Catalog {
+    /// Returns the translation of this catalog matching the current locale.
+    ///
+    /// The locale override of the current thread takes precedence over the
+    /// global one.
+    pub fn locale(&self) -> Option<&'static Locale> {
+        if let Some(locale) = scoped_locale(|locale| locale.and_then(self.resolve)) {
+            return locale;
+        }
+
+        current_locale().and_then(self.resolve)
+    }
+
+    /// Returns the language for selecting plural forms, which is the
+    /// translation matching the current locale, or the current locale itself if
+    /// it is not translated.
+    pub fn language(&self) -> String {
+        if let Some(locale) = self.locale() {
+            return locale.name.to_string();
+        }
+
+        scoped_locale(|locale| locale.map(|l| l.as_str().to_string()))
+            .unwrap_or_else(|| current_locale().map(|l| l.as_str().to_string()))
+            .unwrap_or_default()
+    }
+}
+
+/// The global locale, which is null or points to an interned language tag.
+static CURRENT_LOCALE: AtomicPtr<LanguageTag<String>> = AtomicPtr::new(std::ptr::null_mut());
+
+/// Language tags that have been set as the global locale.
+///
+/// Each tag is leaked once, so that [`CURRENT_LOCALE`] can be read without
+/// locking.
+static INTERNED_LOCALES: Mutex<Vec<&'static LanguageTag<String>>> = Mutex::new(Vec::new());
+
+fn intern(locale: LanguageTag<String>) -> &'static LanguageTag<String> {
+    let mut interned = INTERNED_LOCALES
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+
+    match interned.iter().copied().find(|l| **l == locale) {
+        Some(l) => l,
+        None => {
+            let l = Box::leak(Box::new(locale));
+            interned.push(l);
+            l
+        }
+    }
+}
+
+fn current_locale() -> Option<&'static LanguageTag<String>> {
+    // SAFETY: the pointer is either null or obtained from a leaked reference
+    unsafe { CURRENT_LOCALE.load(Ordering::Acquire).as_ref() }
 }
 
 #[doc(hidden)]
-pub static CURRENT_LOCALE: OnceLock<Mutex<Option<&'static Locale>>> = OnceLock::new();
+pub fn set_current_locale(locale: impl AsRef<str>) {
+    let locale = match LanguageTag::parse_and_normalize(locale.as_ref()) {
+        Ok(locale) => intern(locale) as *const _ as *mut _,
+        Err(_) => std::ptr::null_mut(),
+    };
+
+    CURRENT_LOCALE.store(locale, Ordering::Release);
+}
 
 /// Translate content with the locale setting and given prefix.
 ///
 /// We recommend using [`tr!`] instead of [`translate`] for translate your
 /// content.
-pub fn translate(prefix: impl AsRef<str>, content: &str) -> &str {
-    let locale = CURRENT_LOCALE
-        .get_or_init(|| Mutex::new(None))
-        .lock()
-        .unwrap();
-    let Some(locale) = *locale else {
+pub fn translate<'a>(catalog: &Catalog, prefix: impl AsRef<str>, content: &'a str) -> &'a str {
+    let Some(locale) = catalog.locale() else {
+        return content;
+    };
+
+    lookup(locale, prefix.as_ref(), content)
+        .and_then(|tr| tr.select(PluralCategory::Other))
+        .unwrap_or(content)
+}
+
+/// Translate content in the plural form of `count` with the locale setting and
+/// given prefix.
+///
+/// We recommend using [`tr_plural!`] instead of [`translate_plural`] for
+/// translate your content.
+pub fn translate_plural<'a>(
+    catalog: &Catalog,
+    prefix: impl AsRef<str>,
+    content: &'a str,
+    count: impl Into<PluralOperands>,
+) -> &'a str {
+    let Some(locale) = catalog.locale() else {
         return content;
     };
 
-    match locale
+    lookup(locale, prefix.as_ref(), content)
+        .and_then(|tr| tr.select(plural_category(locale.name, &count.into())))
+        .unwrap_or(content)
+}
+
+fn lookup(locale: &'static Locale, prefix: &str, content: &str) -> Option<&'static Translation> {
+    locale
         .translate
-        .get(format!("{} {}", prefix.as_ref(), content).as_str())
-    {
-        Some(tr) => tr,
-        None => content,
+        .get(prefix)
+        .and_then(|contents| contents.get(content))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        alloc::{GlobalAlloc, Layout, System},
+        cell::Cell,
+    };
+
+    use super::{Catalog, LanguageTag, Locale, Translation};
+
+    thread_local! {
+        static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    /// Counts allocations of each thread.
+    struct CountingAllocator;
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+            System.alloc(layout)
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout)
+        }
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    const APP: Locale = Locale {
+        name: "zh-CN",
+        translate: phf::phf_map! { "" => phf::phf_map! { "Hello" => Translation::Text("你好") } },
+    };
+
+    const LIB: Locale = Locale {
+        name: "zh-TW",
+        translate: phf::phf_map! { "" => phf::phf_map! { "Hello" => Translation::Text("妳好") } },
+    };
+
+    fn resolve_app(lang: &LanguageTag<String>) -> Option<&'static Locale> {
+        match lang.primary_language() {
+            "zh" => Some(&APP),
+            _ => None,
+        }
+    }
+
+    fn resolve_lib(lang: &LanguageTag<String>) -> Option<&'static Locale> {
+        match (lang.primary_language(), lang.region()) {
+            ("zh", Some("TW")) => Some(&LIB),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn per_crate_catalog() {
+        let app = Catalog {
+            resolve: resolve_app,
+        };
+        let lib = Catalog {
+            resolve: resolve_lib,
+        };
+
+        super::set_current_locale("zh-TW");
+        assert_eq!("你好", super::translate(&app, "", "Hello"));
+        assert_eq!("妳好", super::translate(&lib, "", "Hello"));
+
+        super::set_current_locale("zh-CN");
+        assert_eq!("你好", super::translate(&app, "", "Hello"));
+        assert_eq!("Hello", super::translate(&lib, "", "Hello"));
+
+        super::set_current_locale("");
+        assert_eq!("Hello", super::translate(&app, "", "Hello"));
+    }
+
+    #[test]
+    fn allocation_free_lookup() {
+        let lib = Catalog {
+            resolve: resolve_lib,
+        };
+
+        let _guard = super::scope_locale("zh-TW");
+        let before = ALLOCATIONS.with(Cell::get);
+        assert_eq!("妳好", super::translate(&lib, "", "Hello"));
+        assert_eq!("Hi", super::translate(&lib, ".greeting", "Hi"));
+        assert_eq!(before, ALLOCATIONS.with(Cell::get));
+    }
+
+    #[test]
+    fn scoped_locale() {
+        let lib = Catalog {
+            resolve: resolve_lib,
+        };
+
+        let handles =
+            [("zh-TW", "妳好"), ("zh-CN", "Hello"), ("", "Hello")].map(|(tag, expected)| {
+                std::thread::spawn(move || {
+                    let lib = Catalog {
+                        resolve: resolve_lib,
+                    };
+
+                    super::with_locale(tag, || {
+                        assert_eq!(expected, super::translate(&lib, "", "Hello"));
+                    })
+                })
+            });
+        handles.into_iter().for_each(|h| h.join().unwrap());
+
+        let _outer = super::scope_locale("zh-TW");
+        {
+            let _inner = super::scope_locale("en");
+            assert_eq!("Hello", super::translate(&lib, "", "Hello"));
+        }
+        assert_eq!("妳好", super::translate(&lib, "", "Hello"));
     }
 }
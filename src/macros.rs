@@ -1,5 +1,10 @@
 /// Translate and content formatting.
 ///
+/// Arguments can be positional or named, and named placeholders can also
+/// capture variables in scope, like [`format!`]. Translations may use named
+/// placeholders or indexed positional placeholders such as `{0}` in any order,
+/// with the format spec of [`format!`] like `{:>8}`, `{:.2}` or `{:?}`.
+///
 /// ## Example
 ///
 /// Assume that translate file are as follows:
@@ -7,6 +12,8 @@
 /// ```json
 /// {
 ///     "Hello, {}": "Hello, {}",
+///     "{name} has {count} messages": "{count} messages for {name}",
+///     "{0} sent {1} a message": "{1} received a message from {0}",
 ///     "birthday": {
 ///         "Hello, {}": "Happy birthday, {}"
 ///     }
@@ -20,31 +27,130 @@
 /// assert_eq!("Hello, ho-229", r18::tr!("Hello, {}", name));
 /// assert_eq!("Hello, ho-229", r18::tr!([""] "Hello, {}", name));
 /// assert_eq!("Happy birthday, ho-229", r18::tr!([".birthday"] "Hello, {}", name));
+/// assert_eq!("2 messages for ho-229", r18::tr!("{name} has {count} messages", count = 2));
+/// assert_eq!("Bob received a message from ho-229", r18::tr!("{0} sent {1} a message", name, "Bob"));
 /// ```
 #[macro_export]
+#[allow(clippy::crate_in_macro_def)]
 macro_rules! tr {
-    ($content:expr) => {
-        ::r18::translate("", $content)
+    ([$prefix:expr] $content:expr $(, $($arg:tt)+)?) => {
+        ::r18::__tr_format!(
+            &crate::__r18_gen::CATALOG,
+            ::r18::translate(&crate::__r18_gen::CATALOG, $prefix, $content),
+            $content
+            $(, $($arg)+)?
+        )
     };
-    ($content:expr, $($arg:expr),+) => {{
-        use ::r18::{Format, SimpleCurlyFormat};
-        SimpleCurlyFormat.format(::r18::translate("", $content), &[$($arg),+])
-            .unwrap_or_default()
-    }};
-    ([$prefix:expr] $content:expr) => {
-        ::r18::translate($prefix, $content)
+    ($content:expr $(, $($arg:tt)+)?) => {
+        ::r18::tr!([""] $content $(, $($arg)+)?)
+    };
+}
+
+/// Translate and content formatting, returns a [`FormatError`](crate::FormatError)
+/// if the translation failed to format.
+///
+/// Unlike [`tr`], which formats the untranslated content instead when the
+/// translation is broken, e.g. it has a placeholder without a matching
+/// argument, [`try_tr`] returns the error. The content is always formatted, so
+/// `{{` and `}}` are unescaped even if no argument is given.
+///
+/// ## Example
+///
+/// Assume that translate file are as follows:
+///
+/// ```json
+/// {
+///     "Hello, {name}": "Hello, {nmae}"
+/// }
+/// ```
+///
+/// [`try_tr`] will work like:
+///
+/// ```ignore
+/// let name = "ho-229";
+/// assert!(r18::try_tr!("Hello, {name}").is_err());
+/// assert_eq!("Hello, ho-229", r18::tr!("Hello, {name}"));
+/// ```
+#[macro_export]
+#[allow(clippy::crate_in_macro_def)]
+macro_rules! try_tr {
+    ([$prefix:expr] $content:expr $(, $($arg:tt)+)?) => {
+        ::r18::__tr_format!(
+            try &crate::__r18_gen::CATALOG,
+            ::r18::translate(&crate::__r18_gen::CATALOG, $prefix, $content),
+            $content
+            $(, $($arg)+)?
+        )
+    };
+    ($content:expr $(, $($arg:tt)+)?) => {
+        ::r18::try_tr!([""] $content $(, $($arg)+)?)
     };
-    ([$prefix:expr] $content:expr, $($arg:expr),+) => {{
-        use ::r18::{Format, SimpleCurlyFormat};
-        SimpleCurlyFormat.format(::r18::translate($prefix, $content), &[$($arg),+])
-            .unwrap_or_default()
+}
+
+/// Translate and content formatting in the plural form of `count`.
+///
+/// The plural form is selected by the
+/// [CLDR plural rules](https://cldr.unicode.org/index/cldr-spec/plural-rules)
+/// of the current locale. `count` is the first positional argument for
+/// formatting if no argument is given. Arguments work the same as [`tr`].
+///
+/// ## Example
+///
+/// Assume that translate file are as follows:
+///
+/// ```json
+/// {
+///     "{} files": {
+///         "one": "{} file",
+///         "other": "{} files"
+///     },
+///     "dir": {
+///         "{count} files in {dir}": {
+///             "one": "{count} file in {dir}",
+///             "other": "{count} files in {dir}"
+///         }
+///     }
+/// }
+/// ```
+///
+/// [`tr_plural`] will work like:
+///
+/// ```ignore
+/// let dir = "src";
+/// assert_eq!("1 file", r18::tr_plural!(1, "{} files"));
+/// assert_eq!("2 files", r18::tr_plural!(2, "{} files"));
+/// assert_eq!("1 file in src", r18::tr_plural!(1, [".dir"] "{count} files in {dir}", count = 1));
+/// ```
+#[macro_export]
+#[allow(clippy::crate_in_macro_def)]
+macro_rules! tr_plural {
+    ($count:expr, [$prefix:expr] $content:expr) => {{
+        let count = $count;
+        ::r18::__tr_format!(
+            &crate::__r18_gen::CATALOG,
+            ::r18::translate_plural(&crate::__r18_gen::CATALOG, $prefix, $content, count),
+            $content,
+            count
+        )
     }};
+    ($count:expr, [$prefix:expr] $content:expr, $($arg:tt)+) => {
+        ::r18::__tr_format!(
+            &crate::__r18_gen::CATALOG,
+            ::r18::translate_plural(&crate::__r18_gen::CATALOG, $prefix, $content, $count),
+            $content,
+            $($arg)+
+        )
+    };
+    ($count:expr, $content:expr $(, $($arg:tt)+)?) => {
+        ::r18::tr_plural!($count, [""] $content $(, $($arg)+)?)
+    };
 }
 
 /// Sets the current locale.
 ///
-/// If the input language tag is invalid or not translated,
-/// the translation will be disabled.
+/// The locale is shared by all crates using `r18`. If the input language tag
+/// is invalid or not translated by a crate, the translation of that crate will
+/// be disabled.
 ///
 /// ## Example
 ///
@@ -55,24 +161,20 @@ macro_rules! tr {
 /// assert_eq!(None, r18::locale!());
 /// ```
 #[macro_export]
-#[allow(clippy::crate_in_macro_def)]
 macro_rules! set_locale {
     ($locale:expr) => {
-        // this function is generated by r18::init
-        crate::__r18_gen::set_locale($locale)
+        $crate::set_current_locale($locale)
     };
 }
 
-/// Returns the current locale.
+/// Returns the current locale matched by the translations of the calling
+/// crate.
 #[macro_export]
+#[allow(clippy::crate_in_macro_def)]
 macro_rules! locale {
     () => {
-        $crate::CURRENT_LOCALE
-            .get_or_init(|| ::std::sync::Mutex::new(None))
-            .lock()
-            .unwrap()
-            .as_ref()
-            .map(|l| l.name)
+        // this static is generated by r18::init
+        crate::__r18_gen::CATALOG.locale().map(|l| l.name)
     };
 }
 
@@ -0,0 +1,640 @@
+//! [CLDR plural rules](https://cldr.unicode.org/index/cldr-spec/plural-rules),
+//! bundled from `plurals.xml` and `ordinals.xml` of CLDR 44.
+
+/// Plural category of a number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+impl PluralCategory {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PluralCategory::Zero => "zero",
+            PluralCategory::One => "one",
+            PluralCategory::Two => "two",
+            PluralCategory::Few => "few",
+            PluralCategory::Many => "many",
+            PluralCategory::Other => "other",
+        }
+    }
+}
+
+/// Plural operands of a number, see
+/// [Operands](https://unicode.org/reports/tr35/tr35-numbers.html#Operands).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PluralOperands {
+    /// Integer digits.
+    pub i: u64,
+    /// Number of visible fraction digits, with trailing zeros.
+    pub v: usize,
+    /// Number of visible fraction digits, without trailing zeros.
+    pub w: usize,
+    /// Visible fraction digits, with trailing zeros.
+    pub f: u64,
+    /// Visible fraction digits, without trailing zeros.
+    pub t: u64,
+}
+
+impl PluralOperands {
+    /// Returns the absolute value if it is an integer.
+    fn n(&self) -> Option<u64> {
+        (self.f == 0).then_some(self.i)
+    }
+
+    /// Whether the absolute value is an integer in `range`.
+    fn n_in(&self, range: impl IntoIterator<Item = u64>) -> bool {
+        self.n().map(|n| range.into_iter().any(|r| r == n)) == Some(true)
+    }
+
+    /// Whether the absolute value modulo `modulo` is an integer in `range`.
+    fn n_mod_in(&self, modulo: u64, range: impl IntoIterator<Item = u64>) -> bool {
+        self.n()
+            .map(|n| range.into_iter().any(|r| r == n % modulo))
+            == Some(true)
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for PluralOperands {
+                fn from(n: $t) -> Self {
+                    Self {
+                        i: n.unsigned_abs() as u64,
+                        ..Default::default()
+                    }
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_from_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for PluralOperands {
+                fn from(n: $t) -> Self {
+                    Self {
+                        i: n as u64,
+                        ..Default::default()
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(i8, i16, i32, i64, isize);
+impl_from_unsigned!(u8, u16, u32, u64, usize);
+
+impl From<f64> for PluralOperands {
+    fn from(n: f64) -> Self {
+        n.to_string().parse().unwrap_or_default()
+    }
+}
+
+impl From<f32> for PluralOperands {
+    fn from(n: f32) -> Self {
+        n.to_string().parse().unwrap_or_default()
+    }
+}
+
+impl std::str::FromStr for PluralOperands {
+    type Err = std::num::ParseIntError;
+
+    /// Parses a decimal number such as `-1.50`, trailing zeros of the fraction
+    /// are significant.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim_start_matches(['-', '+']);
+        let (integer, fraction) = s.split_once('.').unwrap_or((s, ""));
+        let trimmed = fraction.trim_end_matches('0');
+
+        let parse = |digits: &str| match digits.is_empty() {
+            true => Ok(0),
+            false => digits.parse::<u64>(),
+        };
+
+        Ok(Self {
+            i: integer.parse()?,
+            v: fraction.len(),
+            w: trimmed.len(),
+            f: parse(fraction)?,
+            t: parse(trimmed)?,
+        })
+    }
+}
+
+type Rule = fn(&PluralOperands) -> PluralCategory;
+
+/// Returns the cardinal plural category of `operands` in `language`.
+pub fn plural_category(language: &str, operands: &PluralOperands) -> PluralCategory {
+    cardinal_rule(language)(operands)
+}
+
+// rules are kept as they are written in CLDR
+#[allow(clippy::nonminimal_bool)]
+fn cardinal_rule(language: &str) -> Rule {
+    use PluralCategory::*;
+
+    let mut subtags = language.split('-');
+    let primary = subtags.next().unwrap_or_default();
+
+    if primary == "pt" && subtags.any(|s| s == "PT") {
+        return |o| match o {
+            o if o.i == 1 && o.v == 0 => One,
+            o if is_million(o) => Many,
+            _ => Other,
+        };
+    }
+
+    match primary {
+        "am" | "as" | "bn" | "doi" | "fa" | "gu" | "hi" | "kn" | "pcm" | "zu" => |o| match o {
+            o if o.i == 0 || o.n_in([1]) => One,
+            _ => Other,
+        },
+        "ff" | "hy" | "kab" => |o| match o.i {
+            0 | 1 => One,
+            _ => Other,
+        },
+        "ast" | "de" | "en" | "et" | "fi" | "fy" | "gl" | "ia" | "io" | "ji" | "lij" | "nl"
+        | "sc" | "sv" | "sw" | "ur" | "yi" => |o| match o {
+            o if o.i == 1 && o.v == 0 => One,
+            _ => Other,
+        },
+        "si" => |o| match o {
+            o if o.n_in([0, 1]) || (o.i == 0 && o.f == 1) => One,
+            _ => Other,
+        },
+        "ak" | "bho" | "guw" | "ln" | "mg" | "nso" | "pa" | "ti" | "wa" => |o| match o {
+            o if o.n_in(0..=1) => One,
+            _ => Other,
+        },
+        "tzm" => |o| match o {
+            o if o.n_in(0..=1) || o.n_in(11..=99) => One,
+            _ => Other,
+        },
+        "af" | "an" | "asa" | "az" | "bal" | "bem" | "bez" | "bg" | "brx" | "ce" | "cgg"
+        | "chr" | "ckb" | "dv" | "ee" | "el" | "eo" | "eu" | "fo" | "fur" | "gsw" | "ha"
+        | "haw" | "hu" | "jgo" | "jmc" | "ka" | "kaj" | "kcg" | "kk" | "kkj" | "kl" | "ks"
+        | "ksb" | "ku" | "ky" | "lb" | "lg" | "mas" | "mgo" | "ml" | "mn" | "mr" | "nah"
+        | "nb" | "nd" | "ne" | "nn" | "nnh" | "no" | "nr" | "ny" | "nyn" | "om" | "or" | "os"
+        | "pap" | "ps" | "rm" | "rof" | "rwk" | "saq" | "sd" | "sdh" | "seh" | "sn" | "so"
+        | "sq" | "ss" | "ssy" | "st" | "syr" | "ta" | "te" | "teo" | "tig" | "tk" | "tn"
+        | "tr" | "ts" | "ug" | "uz" | "ve" | "vo" | "vun" | "wae" | "xh" | "xog" => |o| match o {
+            o if o.n_in([1]) => One,
+            _ => Other,
+        },
+        "da" => |o| match o {
+            o if o.n_in([1]) || (o.t != 0 && o.i <= 1) => One,
+            _ => Other,
+        },
+        "is" => |o| match o {
+            o if (o.t == 0 && o.i % 10 == 1 && o.i % 100 != 11)
+                || (o.t % 10 == 1 && o.t % 100 != 11) =>
+            {
+                One
+            }
+            _ => Other,
+        },
+        "mk" => |o| match o {
+            o if (o.v == 0 && o.i % 10 == 1 && o.i % 100 != 11)
+                || (o.f % 10 == 1 && o.f % 100 != 11) =>
+            {
+                One
+            }
+            _ => Other,
+        },
+        "ceb" | "fil" | "tl" => |o| match o {
+            o if (o.v == 0 && matches!(o.i, 1..=3))
+                || (o.v == 0 && !matches!(o.i % 10, 4 | 6 | 9))
+                || (o.v != 0 && !matches!(o.f % 10, 4 | 6 | 9)) =>
+            {
+                One
+            }
+            _ => Other,
+        },
+        "lv" | "prg" => |o| match o {
+            o if o.n_mod_in(10, [0])
+                || o.n_mod_in(100, 11..=19)
+                || (o.v == 2 && matches!(o.f % 100, 11..=19)) =>
+            {
+                Zero
+            }
+            o if (o.n_mod_in(10, [1]) && !o.n_mod_in(100, [11]))
+                || (o.v == 2 && o.f % 10 == 1 && o.f % 100 != 11)
+                || (o.v != 2 && o.f % 10 == 1) =>
+            {
+                One
+            }
+            _ => Other,
+        },
+        "lag" => |o| match o {
+            o if o.n_in([0]) => Zero,
+            o if o.i <= 1 => One,
+            _ => Other,
+        },
+        "ksh" => |o| match o {
+            o if o.n_in([0]) => Zero,
+            o if o.n_in([1]) => One,
+            _ => Other,
+        },
+        "he" => |o| match o {
+            o if (o.i == 1 && o.v == 0) || (o.i == 0 && o.v != 0) => One,
+            o if o.i == 2 && o.v == 0 => Two,
+            _ => Other,
+        },
+        "iu" | "naq" | "sat" | "se" | "sma" | "smi" | "smj" | "smn" | "sms" => |o| match o {
+            o if o.n_in([1]) => One,
+            o if o.n_in([2]) => Two,
+            _ => Other,
+        },
+        "shi" => |o| match o {
+            o if o.i == 0 || o.n_in([1]) => One,
+            o if o.n_in(2..=10) => Few,
+            _ => Other,
+        },
+        "mo" | "ro" => |o| match o {
+            o if o.i == 1 && o.v == 0 => One,
+            o if o.v != 0 || o.n_in([0]) || (!o.n_in([1]) && o.n_mod_in(100, 1..=19)) => Few,
+            _ => Other,
+        },
+        "bs" | "hr" | "sh" | "sr" => |o| match o {
+            o if (o.v == 0 && o.i % 10 == 1 && o.i % 100 != 11)
+                || (o.f % 10 == 1 && o.f % 100 != 11) =>
+            {
+                One
+            }
+            o if (o.v == 0 && matches!(o.i % 10, 2..=4) && !matches!(o.i % 100, 12..=14))
+                || (matches!(o.f % 10, 2..=4) && !matches!(o.f % 100, 12..=14)) =>
+            {
+                Few
+            }
+            _ => Other,
+        },
+        "fr" => |o| match o {
+            o if o.i <= 1 => One,
+            o if is_million(o) => Many,
+            _ => Other,
+        },
+        "pt" => |o| match o {
+            o if o.i <= 1 => One,
+            o if is_million(o) => Many,
+            _ => Other,
+        },
+        "ca" | "it" | "vec" => |o| match o {
+            o if o.i == 1 && o.v == 0 => One,
+            o if is_million(o) => Many,
+            _ => Other,
+        },
+        "es" => |o| match o {
+            o if o.n_in([1]) => One,
+            o if is_million(o) => Many,
+            _ => Other,
+        },
+        "gd" => |o| match o {
+            o if o.n_in([1, 11]) => One,
+            o if o.n_in([2, 12]) => Two,
+            o if o.n_in(3..=10) || o.n_in(13..=19) => Few,
+            _ => Other,
+        },
+        "sl" => |o| match o {
+            o if o.v == 0 && o.i % 100 == 1 => One,
+            o if o.v == 0 && o.i % 100 == 2 => Two,
+            o if (o.v == 0 && matches!(o.i % 100, 3..=4)) || o.v != 0 => Few,
+            _ => Other,
+        },
+        "dsb" | "hsb" => |o| match o {
+            o if (o.v == 0 && o.i % 100 == 1) || o.f % 100 == 1 => One,
+            o if (o.v == 0 && o.i % 100 == 2) || o.f % 100 == 2 => Two,
+            o if (o.v == 0 && matches!(o.i % 100, 3..=4)) || matches!(o.f % 100, 3..=4) => Few,
+            _ => Other,
+        },
+        "cs" | "sk" => |o| match o {
+            o if o.i == 1 && o.v == 0 => One,
+            o if matches!(o.i, 2..=4) && o.v == 0 => Few,
+            o if o.v != 0 => Many,
+            _ => Other,
+        },
+        "pl" => |o| match o {
+            o if o.i == 1 && o.v == 0 => One,
+            o if o.v == 0 && matches!(o.i % 10, 2..=4) && !matches!(o.i % 100, 12..=14) => Few,
+            o if o.v == 0
+                && ((o.i != 1 && o.i % 10 <= 1)
+                    || matches!(o.i % 10, 5..=9)
+                    || matches!(o.i % 100, 12..=14)) =>
+            {
+                Many
+            }
+            _ => Other,
+        },
+        "be" => |o| match o {
+            o if o.n_mod_in(10, [1]) && !o.n_mod_in(100, [11]) => One,
+            o if o.n_mod_in(10, 2..=4) && !o.n_mod_in(100, 12..=14) => Few,
+            o if o.n_mod_in(10, [0]) || o.n_mod_in(10, 5..=9) || o.n_mod_in(100, 11..=14) => {
+                Many
+            }
+            _ => Other,
+        },
+        "lt" => |o| match o {
+            o if o.n_mod_in(10, [1]) && !o.n_mod_in(100, 11..=19) => One,
+            o if o.n_mod_in(10, 2..=9) && !o.n_mod_in(100, 11..=19) => Few,
+            o if o.f != 0 => Many,
+            _ => Other,
+        },
+        "ru" | "uk" => |o| match o {
+            o if o.v == 0 && o.i % 10 == 1 && o.i % 100 != 11 => One,
+            o if o.v == 0 && matches!(o.i % 10, 2..=4) && !matches!(o.i % 100, 12..=14) => Few,
+            o if o.v == 0
+                && (o.i % 10 == 0 || matches!(o.i % 10, 5..=9) || matches!(o.i % 100, 11..=14)) =>
+            {
+                Many
+            }
+            _ => Other,
+        },
+        "br" => |o| match o {
+            o if o.n_mod_in(10, [1]) && !o.n_mod_in(100, [11, 71, 91]) => One,
+            o if o.n_mod_in(10, [2]) && !o.n_mod_in(100, [12, 72, 92]) => Two,
+            o if o.n_mod_in(10, [3, 4, 9])
+                && !o.n_mod_in(100, (10..=19).chain(70..=79).chain(90..=99)) =>
+            {
+                Few
+            }
+            o if !o.n_in([0]) && o.n_mod_in(1000000, [0]) => Many,
+            _ => Other,
+        },
+        "mt" => |o| match o {
+            o if o.n_in([1]) => One,
+            o if o.n_in([2]) => Two,
+            o if o.n_in([0]) || o.n_mod_in(100, 3..=10) => Few,
+            o if o.n_mod_in(100, 11..=19) => Many,
+            _ => Other,
+        },
+        "ga" => |o| match o {
+            o if o.n_in([1]) => One,
+            o if o.n_in([2]) => Two,
+            o if o.n_in(3..=6) => Few,
+            o if o.n_in(7..=10) => Many,
+            _ => Other,
+        },
+        "ar" | "ars" => |o| match o {
+            o if o.n_in([0]) => Zero,
+            o if o.n_in([1]) => One,
+            o if o.n_in([2]) => Two,
+            o if o.n_mod_in(100, 3..=10) => Few,
+            o if o.n_mod_in(100, 11..=99) => Many,
+            _ => Other,
+        },
+        "cy" => |o| match o {
+            o if o.n_in([0]) => Zero,
+            o if o.n_in([1]) => One,
+            o if o.n_in([2]) => Two,
+            o if o.n_in([3]) => Few,
+            o if o.n_in([6]) => Many,
+            _ => Other,
+        },
+        // bm bo dz hnj id ig ii in ja jbo jv jw kde kea km ko lkt lo ms my nqo osa
+        // sah ses sg su th to tpi vi wo yo yue zh and unknown languages
+        _ => |_| Other,
+    }
+}
+
+/// Returns the ordinal plural category of `operands` in `language`, like the
+/// `two` of "2nd" in English.
+pub fn ordinal_category(language: &str, operands: &PluralOperands) -> PluralCategory {
+    ordinal_rule(language)(operands)
+}
+
+// rules are kept as they are written in CLDR
+#[allow(clippy::nonminimal_bool)]
+fn ordinal_rule(language: &str) -> Rule {
+    use PluralCategory::*;
+
+    match language.split('-').next().unwrap_or_default() {
+        "en" => |o| match o {
+            o if o.n_mod_in(10, [1]) && !o.n_mod_in(100, [11]) => One,
+            o if o.n_mod_in(10, [2]) && !o.n_mod_in(100, [12]) => Two,
+            o if o.n_mod_in(10, [3]) && !o.n_mod_in(100, [13]) => Few,
+            _ => Other,
+        },
+        "sv" => |o| match o {
+            o if o.n_mod_in(10, [1, 2]) && !o.n_mod_in(100, [11, 12]) => One,
+            _ => Other,
+        },
+        "bal" | "fil" | "fr" | "ga" | "hy" | "lo" | "mo" | "ms" | "ro" | "tl" | "vi" => {
+            |o| match o {
+                o if o.n_in([1]) => One,
+                _ => Other,
+            }
+        }
+        "hu" => |o| match o {
+            o if o.n_in([1, 5]) => One,
+            _ => Other,
+        },
+        "ne" => |o| match o {
+            o if o.n_in(1..=4) => One,
+            _ => Other,
+        },
+        "be" => |o| match o {
+            o if o.n_mod_in(10, [2, 3]) && !o.n_mod_in(100, [12, 13]) => Few,
+            _ => Other,
+        },
+        "uk" => |o| match o {
+            o if o.n_mod_in(10, [3]) && !o.n_mod_in(100, [13]) => Few,
+            _ => Other,
+        },
+        "tk" => |o| match o {
+            o if o.n_mod_in(10, [6, 9]) || o.n_in([10]) => Few,
+            _ => Other,
+        },
+        "kk" => |o| match o {
+            o if o.n_mod_in(10, [6]) || o.n_mod_in(10, [9]) || o.n_mod_in(10, [0]) && !o.n_in([0]) => {
+                Many
+            }
+            _ => Other,
+        },
+        "it" | "sc" | "scn" => |o| match o {
+            o if o.n_in([11, 8, 80, 800]) => Many,
+            _ => Other,
+        },
+        "lij" => |o| match o {
+            o if o.n_in([11, 8]) || o.n_in(80..=89) || o.n_in(800..=899) => Many,
+            _ => Other,
+        },
+        "ka" => |o| match o.i {
+            1 => One,
+            i if i == 0 || matches!(i % 100, 2..=20 | 40 | 60 | 80) => Many,
+            _ => Other,
+        },
+        "sq" => |o| match o {
+            o if o.n_in([1]) => One,
+            o if o.n_mod_in(10, [4]) && !o.n_mod_in(100, [14]) => Many,
+            _ => Other,
+        },
+        "mk" => |o| match o.i {
+            i if i % 10 == 1 && i % 100 != 11 => One,
+            i if i % 10 == 2 && i % 100 != 12 => Two,
+            i if matches!(i % 10, 7 | 8) && !matches!(i % 100, 17 | 18) => Many,
+            _ => Other,
+        },
+        "az" => |o| match o.i {
+            i if matches!(i % 10, 1 | 2 | 5 | 7 | 8) || matches!(i % 100, 20 | 50 | 70 | 80) => {
+                One
+            }
+            i if matches!(i % 10, 3 | 4) || (i % 1000 != 0 && i % 1000 % 100 == 0) => Few,
+            i if i == 0 || i % 10 == 6 || matches!(i % 100, 40 | 60 | 90) => Many,
+            _ => Other,
+        },
+        "ca" => |o| match o {
+            o if o.n_in([1, 3]) => One,
+            o if o.n_in([2]) => Two,
+            o if o.n_in([4]) => Few,
+            _ => Other,
+        },
+        "mr" => |o| match o {
+            o if o.n_in([1]) => One,
+            o if o.n_in([2, 3]) => Two,
+            o if o.n_in([4]) => Few,
+            _ => Other,
+        },
+        "gd" => |o| match o {
+            o if o.n_in([1, 11]) => One,
+            o if o.n_in([2, 12]) => Two,
+            o if o.n_in([3, 13]) => Few,
+            _ => Other,
+        },
+        "gu" | "hi" => |o| match o {
+            o if o.n_in([1]) => One,
+            o if o.n_in([2, 3]) => Two,
+            o if o.n_in([4]) => Few,
+            o if o.n_in([6]) => Many,
+            _ => Other,
+        },
+        "as" | "bn" => |o| match o {
+            o if o.n_in([1, 5, 7, 8, 9, 10]) => One,
+            o if o.n_in([2, 3]) => Two,
+            o if o.n_in([4]) => Few,
+            o if o.n_in([6]) => Many,
+            _ => Other,
+        },
+        "or" => |o| match o {
+            o if o.n_in([1, 5]) || o.n_in(7..=9) => One,
+            o if o.n_in([2, 3]) => Two,
+            o if o.n_in([4]) => Few,
+            o if o.n_in([6]) => Many,
+            _ => Other,
+        },
+        "cy" => |o| match o {
+            o if o.n_in([0, 7, 8, 9]) => Zero,
+            o if o.n_in([1]) => One,
+            o if o.n_in([2]) => Two,
+            o if o.n_in([3, 4]) => Few,
+            o if o.n_in([5, 6]) => Many,
+            _ => Other,
+        },
+        // languages without ordinal forms, like de ja ru zh, and unknown
+        // languages
+        _ => |_| Other,
+    }
+}
+
+/// `e = 0 and i != 0 and i % 1000000 = 0 and v = 0`
+fn is_million(o: &PluralOperands) -> bool {
+    o.i != 0 && o.i % 1000000 == 0 && o.v == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ordinal_category, plural_category, PluralCategory::*, PluralOperands};
+
+    fn category(language: &str, n: &str) -> super::PluralCategory {
+        plural_category(language, &n.parse::<PluralOperands>().unwrap())
+    }
+
+    fn ordinal(language: &str, n: &str) -> super::PluralCategory {
+        ordinal_category(language, &n.parse::<PluralOperands>().unwrap())
+    }
+
+    #[test]
+    fn operands() {
+        assert_eq!(
+            PluralOperands {
+                i: 1,
+                v: 2,
+                w: 1,
+                f: 50,
+                t: 5
+            },
+            "-1.50".parse().unwrap()
+        );
+        assert_eq!(PluralOperands::from(2.5), "2.5".parse().unwrap());
+        assert_eq!(PluralOperands::from(-3), "3".parse().unwrap());
+    }
+
+    #[test]
+    fn cardinal() {
+        assert_eq!(One, category("en", "1"));
+        assert_eq!(Other, category("en-US", "1.0"));
+        assert_eq!(Other, category("zh-CN", "1"));
+        assert_eq!(One, category("fr", "1.5"));
+        assert_eq!(Many, category("fr", "1000000"));
+        assert_eq!(One, category("pt-PT", "1"));
+        assert_eq!(Other, category("pt-PT", "0"));
+        assert_eq!(One, category("pt", "0"));
+
+        for (n, expected) in [
+            ("1", One),
+            ("21", One),
+            ("11", Many),
+            ("3", Few),
+            ("14", Many),
+            ("25", Many),
+            ("1.5", Other),
+        ] {
+            assert_eq!(expected, category("ru", n), "ru {}", n);
+        }
+
+        for (n, expected) in [
+            ("0", Zero),
+            ("1", One),
+            ("2", Two),
+            ("105", Few),
+            ("111", Many),
+            ("100", Other),
+        ] {
+            assert_eq!(expected, category("ar", n), "ar {}", n);
+        }
+
+        for (n, expected) in [("1", One), ("4", Few), ("12", Many), ("22", Few), ("0.5", Other)] {
+            assert_eq!(expected, category("pl", n), "pl {}", n);
+        }
+    }
+
+    #[test]
+    fn ordinal_rules() {
+        for (n, expected) in [
+            ("1", One),
+            ("2", Two),
+            ("3", Few),
+            ("4", Other),
+            ("11", Other),
+            ("12", Other),
+            ("21", One),
+            ("102", Two),
+        ] {
+            assert_eq!(expected, ordinal("en", n), "en {}", n);
+        }
+
+        assert_eq!(One, ordinal("fr", "1"));
+        assert_eq!(Other, ordinal("fr", "2"));
+        assert_eq!(Many, ordinal("it", "800"));
+        assert_eq!(Few, ordinal("az", "300"));
+        assert_eq!(Other, ordinal("az", "1000"));
+        assert_eq!(Other, ordinal("zh-CN", "1"));
+    }
+}
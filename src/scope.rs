@@ -0,0 +1,78 @@
+use std::{cell::RefCell, marker::PhantomData};
+
+use crate::LanguageTag;
+
+/// A locale override, `None` disables the translation.
+pub(crate) type Override = Option<LanguageTag<String>>;
+
+thread_local! {
+    static SCOPED_LOCALE: RefCell<Option<Override>> = const { RefCell::new(None) };
+}
+
+/// Guard of a thread-local locale override created by [`scope_locale`].
+///
+/// The previous locale of the current thread is restored when the guard is
+/// dropped.
+#[must_use = "the locale override is reverted when the guard is dropped"]
+pub struct LocaleGuard {
+    previous: Option<Override>,
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Drop for LocaleGuard {
+    fn drop(&mut self) {
+        replace_scoped(self.previous.take());
+    }
+}
+
+/// Overrides the locale of the current thread until the returned guard is
+/// dropped.
+///
+/// [`tr!`](crate::tr) checks the override before the locale set by
+/// [`set_locale!`](crate::set_locale). If the input language tag is invalid,
+/// the translation will be disabled in the scope.
+///
+/// ## Example
+///
+/// ```ignore
+/// r18::set_locale!("zh-CN");
+/// {
+///     let _guard = r18::scope_locale("zh-TW");
+///     assert_eq!(Some("zh-TW"), r18::locale!());
+/// }
+/// assert_eq!(Some("zh-CN"), r18::locale!());
+/// ```
+pub fn scope_locale(locale: impl AsRef<str>) -> LocaleGuard {
+    let locale = LanguageTag::parse_and_normalize(locale.as_ref()).ok();
+
+    LocaleGuard {
+        previous: replace_scoped(Some(locale)),
+        _not_send: PhantomData,
+    }
+}
+
+/// Calls `f` with the locale of the current thread overridden.
+///
+/// See [`scope_locale`] for details.
+///
+/// ## Example
+///
+/// ```ignore
+/// let text = r18::with_locale("zh-TW", || r18::tr!("Hello, {}", name));
+/// ```
+pub fn with_locale<R>(locale: impl AsRef<str>, f: impl FnOnce() -> R) -> R {
+    let _guard = scope_locale(locale);
+    f()
+}
+
+/// Calls `f` with the locale override of the current thread, returns `None`
+/// if there is no override.
+pub(crate) fn scoped_locale<R>(f: impl FnOnce(Option<&LanguageTag<String>>) -> R) -> Option<R> {
+    SCOPED_LOCALE.with(|scoped| scoped.borrow().as_ref().map(|locale| f(locale.as_ref())))
+}
+
+/// Replaces the locale override of the current thread, returns the previous
+/// one.
+pub(crate) fn replace_scoped(locale: Option<Override>) -> Option<Override> {
+    SCOPED_LOCALE.with(|scoped| scoped.replace(locale))
+}
@@ -0,0 +1,173 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use crate::{scope::Override, LanguageTag};
+
+/// A task-local locale override for futures.
+///
+/// Unlike [`scope_locale`](crate::scope_locale), the override follows the
+/// future across `.await` points, even if it is moved to another thread
+/// between polls. It works with any executor.
+///
+/// ## Example
+///
+/// ```ignore
+/// async fn handle(request: Request) -> String {
+///     r18::LocaleScope::new(request.locale())
+///         .scope(async move { r18::tr!("Hello, {}", request.name()) })
+///         .await
+/// }
+/// ```
+pub struct LocaleScope {
+    locale: Override,
+}
+
+impl LocaleScope {
+    /// Creates a locale scope. If the input language tag is invalid,
+    /// the translation will be disabled in the scope.
+    pub fn new(locale: impl AsRef<str>) -> Self {
+        Self {
+            locale: LanguageTag::parse_and_normalize(locale.as_ref()).ok(),
+        }
+    }
+
+    /// Wraps `future` to be polled with the locale of this scope.
+    pub fn scope<F: Future>(self, future: F) -> Scoped<F> {
+        Scoped {
+            locale: Some(self.locale),
+            future,
+        }
+    }
+}
+
+/// A future polled with a task-local locale, created by
+/// [`LocaleScope::scope`].
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Scoped<F> {
+    // taken by the thread-local override while polling
+    locale: Option<Override>,
+    future: F,
+}
+
+impl<F: Future> Future for Scoped<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        struct Restore<'a> {
+            locale: &'a mut Option<Override>,
+            previous: Option<Override>,
+        }
+
+        impl Drop for Restore<'_> {
+            fn drop(&mut self) {
+                *self.locale = crate::scope::replace_scoped(self.previous.take());
+            }
+        }
+
+        // SAFETY: `future` is structurally pinned, it is never moved out of `self`.
+        let this = unsafe { self.get_unchecked_mut() };
+        let previous = crate::scope::replace_scoped(this.locale.take());
+        let _restore = Restore {
+            locale: &mut this.locale,
+            previous,
+        };
+
+        // SAFETY: see above.
+        unsafe { Pin::new_unchecked(&mut this.future) }.poll(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        future::Future,
+        pin::Pin,
+        sync::{
+            atomic::{AtomicBool, Ordering},
+            Arc,
+        },
+        task::{Context, Poll, Wake, Waker},
+    };
+
+    use crate::{Catalog, LanguageTag, Locale, Translation};
+
+    const ZH_TW: Locale = Locale {
+        name: "zh-TW",
+        translate: phf::phf_map! { "" => phf::phf_map! { "Hello" => Translation::Text("妳好") } },
+    };
+
+    static CATALOG: Catalog = Catalog { resolve };
+
+    fn resolve(lang: &LanguageTag<String>) -> Option<&'static Locale> {
+        match (lang.primary_language(), lang.region()) {
+            ("zh", Some("TW")) => Some(&ZH_TW),
+            _ => None,
+        }
+    }
+
+    #[derive(Default)]
+    struct Notify(AtomicBool);
+
+    impl Wake for Notify {
+        fn wake(self: Arc<Self>) {
+            self.0.store(true, Ordering::Release);
+        }
+    }
+
+    /// Polls `future` once on the current thread.
+    fn poll_once<F: Future + Unpin>(future: &mut F, notify: &Arc<Notify>) -> Poll<F::Output> {
+        let waker = Waker::from(notify.clone());
+        Pin::new(future).poll(&mut Context::from_waker(&waker))
+    }
+
+    /// Returns `Pending` once before completion.
+    #[derive(Default)]
+    struct YieldNow(bool);
+
+    impl Future for YieldNow {
+        type Output = ();
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            if std::mem::replace(&mut self.0, true) {
+                return Poll::Ready(());
+            }
+
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+
+    #[test]
+    fn scope_across_threads() {
+        let notify = Arc::new(Notify::default());
+        let mut future = Box::pin(super::LocaleScope::new("zh-TW").scope(async {
+            let before = crate::translate(&CATALOG, "", "Hello");
+            YieldNow::default().await;
+            (before, crate::translate(&CATALOG, "", "Hello"))
+        }));
+
+        assert!(poll_once(&mut future, &notify).is_pending());
+        assert_eq!("Hello", crate::with_locale("en", || crate::translate(&CATALOG, "", "Hello")));
+        assert!(notify.0.load(Ordering::Acquire));
+
+        let output = std::thread::spawn(move || {
+            let notify = Arc::new(Notify::default());
+            let _guard = crate::scope_locale("en");
+
+            match poll_once(&mut future, &notify) {
+                Poll::Ready(output) => {
+                    assert_eq!("Hello", crate::translate(&CATALOG, "", "Hello"));
+                    output
+                }
+                Poll::Pending => panic!("future is not ready"),
+            }
+        })
+        .join()
+        .unwrap();
+
+        assert_eq!(("妳好", "妳好"), output);
+    }
+}
@@ -1,4 +1,5 @@
 pub type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
 
+pub mod likely;
 pub mod source;
 pub mod translation;
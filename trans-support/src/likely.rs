@@ -0,0 +1,82 @@
+//! Likely subtags of [CLDR](https://cldr.unicode.org/index/cldr-spec/language-tag-equivalences),
+//! which infer scripts and regions of locales like `zh-Hant-TW` of `zh-TW`.
+//!
+//! The tables of [`tables`] are bundled from CLDR 48.2.
+
+mod tables;
+
+use tables::{LANGUAGES, REGIONS, SCRIPTS};
+
+/// Returns the likely script and region of a locale, subtags which are not
+/// empty are kept, and unknown ones are empty.
+///
+/// Like the lookup of CLDR, missing subtags are taken from the language and
+/// its script or region first, then from the language.
+pub fn maximize(language: &str, script: &str, region: &str) -> (String, String) {
+    let likely = LANGUAGES
+        .binary_search_by(|(l, ..)| (*l).cmp(language))
+        .ok()
+        .map(|i| &LANGUAGES[i]);
+
+    let script = match script {
+        "" => REGIONS
+            .iter()
+            .find(|(l, r, _)| *l == language && *r == region)
+            .map(|(.., s)| *s)
+            .or(likely.map(|(_, s, _)| *s))
+            .unwrap_or_default(),
+        script => script,
+    };
+
+    let region = match region {
+        "" => SCRIPTS
+            .iter()
+            .find(|(l, s, _)| *l == language && *s == script)
+            .map(|(.., r)| *r)
+            .or(likely.map(|(.., r)| *r))
+            .unwrap_or_default(),
+        region => region,
+    };
+
+    (script.to_string(), region.to_string())
+}
+
+/// Returns regions of `language` whose likely scripts differ from the one of
+/// the language, with the scripts.
+pub fn regional_scripts(language: &str) -> impl Iterator<Item = (&'static str, &'static str)> + '_ {
+    REGIONS
+        .iter()
+        .filter(move |(l, ..)| *l == language)
+        .map(|(_, r, s)| (*r, *s))
+}
+
+#[cfg(test)]
+mod tests {
+    fn maximize(language: &str, script: &str, region: &str) -> String {
+        let (script, region) = super::maximize(language, script, region);
+        format!("{}-{}", script, region)
+    }
+
+    #[test]
+    fn maximize_test() {
+        assert_eq!("Hans-CN", maximize("zh", "", ""));
+        assert_eq!("Hant-TW", maximize("zh", "Hant", ""));
+        assert_eq!("Hant-HK", maximize("zh", "", "HK"));
+        assert_eq!("Hans-SG", maximize("zh", "", "SG"));
+        assert_eq!("Latn-RS", maximize("sr", "Latn", ""));
+        assert_eq!("Latn-ME", maximize("sr", "", "ME"));
+        assert_eq!("Latn-GB", maximize("en", "", "GB"));
+        assert_eq!("Cyrl-US", maximize("en", "Cyrl", ""));
+        assert_eq!("-", maximize("tlh", "", ""));
+
+        assert_eq!("Latn-NG", maximize("yo", "", ""));
+        assert_eq!("Hant-US", maximize("zh", "", "US"));
+
+        assert_eq!(
+            vec![("ME", "Latn"), ("RO", "Latn"), ("TR", "Latn")],
+            super::regional_scripts("sr").collect::<Vec<_>>()
+        );
+        assert!(super::regional_scripts("zh").any(|r| r == ("MO", "Hant")));
+        assert!(super::tables::LANGUAGES.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+}
@@ -0,0 +1,7329 @@
+//! Likely subtags generated from `likelySubtags.json` of CLDR 48.2, as baked
+//! by `icu_locale_data` 2.2.0, which are sorted by languages.
+//!
+//! Likely scripts of regions and regions of scripts are only listed if they
+//! differ from the ones of the languages.
+
+/// Likely scripts and regions of languages.
+pub(super) const LANGUAGES: &[(&str, &str, &str)] = &[
+    ("aa", "Latn", "ET"),
+    ("aaa", "Latn", "NG"),
+    ("aab", "Latn", "NG"),
+    ("aac", "Latn", "PG"),
+    ("aad", "Latn", "PG"),
+    ("aae", "Latn", "IT"),
+    ("aaf", "Mlym", "IN"),
+    ("aag", "Latn", "PG"),
+    ("aah", "Latn", "PG"),
+    ("aai", "Latn", "PG"),
+    ("aak", "Latn", "PG"),
+    ("aal", "Latn", "CM"),
+    ("aan", "Latn", "BR"),
+    ("aao", "Arab", "DZ"),
+    ("aap", "Latn", "BR"),
+    ("aaq", "Latn", "US"),
+    ("aas", "Latn", "TZ"),
+    ("aat", "Grek", "GR"),
+    ("aau", "Latn", "PG"),
+    ("aaw", "Latn", "PG"),
+    ("aax", "Latn", "ID"),
+    ("aaz", "Latn", "ID"),
+    ("ab", "Cyrl", "GE"),
+    ("aba", "Latn", "CI"),
+    ("abb", "Latn", "CM"),
+    ("abc", "Latn", "PH"),
+    ("abd", "Latn", "PH"),
+    ("abe", "Latn", "CA"),
+    ("abf", "Latn", "MY"),
+    ("abg", "Latn", "PG"),
+    ("abh", "Arab", "TJ"),
+    ("abi", "Latn", "CI"),
+    ("abl", "Rjng", "ID"),
+    ("abm", "Latn", "NG"),
+    ("abn", "Latn", "NG"),
+    ("abo", "Latn", "NG"),
+    ("abp", "Latn", "PH"),
+    ("abq", "Cyrl", "RU"),
+    ("abr", "Latn", "GH"),
+    ("abs", "Latn", "ID"),
+    ("abt", "Latn", "PG"),
+    ("abu", "Latn", "CI"),
+    ("abv", "Arab", "BH"),
+    ("abw", "Latn", "PG"),
+    ("abx", "Latn", "PH"),
+    ("aby", "Latn", "PG"),
+    ("abz", "Latn", "ID"),
+    ("aca", "Latn", "CO"),
+    ("acb", "Latn", "NG"),
+    ("acd", "Latn", "GH"),
+    ("ace", "Latn", "ID"),
+    ("acf", "Latn", "LC"),
+    ("ach", "Latn", "UG"),
+    ("acm", "Arab", "IQ"),
+    ("acn", "Latn", "CN"),
+    ("acp", "Latn", "NG"),
+    ("acq", "Arab", "YE"),
+    ("acr", "Latn", "GT"),
+    ("acs", "Latn", "BR"),
+    ("act", "Latn", "NL"),
+    ("acu", "Latn", "EC"),
+    ("acv", "Latn", "US"),
+    ("acw", "Arab", "SA"),
+    ("acx", "Arab", "OM"),
+    ("acy", "Latn", "CY"),
+    ("acz", "Latn", "SD"),
+    ("ada", "Latn", "GH"),
+    ("adb", "Latn", "TL"),
+    ("add", "Latn", "CM"),
+    ("ade", "Latn", "TG"),
+    ("adf", "Arab", "OM"),
+    ("adg", "Latn", "AU"),
+    ("adh", "Latn", "UG"),
+    ("adi", "Latn", "IN"),
+    ("adj", "Latn", "CI"),
+    ("adl", "Latn", "IN"),
+    ("adn", "Latn", "ID"),
+    ("ado", "Latn", "PG"),
+    ("adq", "Latn", "GH"),
+    ("adr", "Latn", "ID"),
+    ("adt", "Latn", "AU"),
+    ("adu", "Latn", "NG"),
+    ("adw", "Latn", "BR"),
+    ("adx", "Tibt", "CN"),
+    ("ady", "Cyrl", "RU"),
+    ("adz", "Latn", "PG"),
+    ("ae", "Avst", "IR"),
+    ("aea", "Latn", "AU"),
+    ("aeb", "Arab", "TN"),
+    ("aec", "Arab", "EG"),
+    ("aee", "Arab", "AF"),
+    ("aek", "Latn", "NC"),
+    ("ael", "Latn", "CM"),
+    ("aem", "Latn", "VN"),
+    ("aeq", "Arab", "PK"),
+    ("aer", "Latn", "AU"),
+    ("aes", "Latn", "US"),
+    ("aeu", "Latn", "CN"),
+    ("aew", "Latn", "PG"),
+    ("aey", "Latn", "PG"),
+    ("aez", "Latn", "PG"),
+    ("af", "Latn", "ZA"),
+    ("afb", "Arab", "KW"),
+    ("afd", "Latn", "PG"),
+    ("afe", "Latn", "NG"),
+    ("afh", "Latn", "GH"),
+    ("afi", "Latn", "PG"),
+    ("afk", "Latn", "PG"),
+    ("afn", "Latn", "NG"),
+    ("afo", "Latn", "NG"),
+    ("afp", "Latn", "PG"),
+    ("afs", "Latn", "MX"),
+    ("afu", "Latn", "GH"),
+    ("afz", "Latn", "ID"),
+    ("aga", "Latn", "PE"),
+    ("agb", "Latn", "NG"),
+    ("agc", "Latn", "NG"),
+    ("agd", "Latn", "PG"),
+    ("age", "Latn", "PG"),
+    ("agf", "Latn", "ID"),
+    ("agg", "Latn", "PG"),
+    ("agh", "Latn", "CD"),
+    ("agi", "Deva", "IN"),
+    ("agj", "Ethi", "ET"),
+    ("agk", "Latn", "PH"),
+    ("agl", "Latn", "PG"),
+    ("agm", "Latn", "PG"),
+    ("agn", "Latn", "PH"),
+    ("ago", "Latn", "PG"),
+    ("agq", "Latn", "CM"),
+    ("agr", "Latn", "PE"),
+    ("ags", "Latn", "CM"),
+    ("agt", "Latn", "PH"),
+    ("agu", "Latn", "GT"),
+    ("agv", "Latn", "PH"),
+    ("agw", "Latn", "SB"),
+    ("agx", "Cyrl", "RU"),
+    ("agy", "Latn", "PH"),
+    ("agz", "Latn", "PH"),
+    ("aha", "Latn", "GH"),
+    ("ahb", "Latn", "VU"),
+    ("ahg", "Ethi", "ET"),
+    ("ahh", "Latn", "ID"),
+    ("ahi", "Latn", "CI"),
+    ("ahk", "Latn", "MM"),
+    ("ahl", "Latn", "TG"),
+    ("ahm", "Latn", "CI"),
+    ("ahn", "Latn", "NG"),
+    ("aho", "Ahom", "IN"),
+    ("ahp", "Latn", "CI"),
+    ("ahr", "Deva", "IN"),
+    ("ahs", "Latn", "NG"),
+    ("aht", "Latn", "US"),
+    ("aia", "Latn", "SB"),
+    ("aib", "Arab", "CN"),
+    ("aic", "Latn", "PG"),
+    ("aid", "Latn", "AU"),
+    ("aie", "Latn", "PG"),
+    ("aif", "Latn", "PG"),
+    ("aig", "Latn", "AG"),
+    ("aii", "Syrc", "IQ"),
+    ("aij", "Hebr", "IL"),
+    ("aik", "Latn", "NG"),
+    ("ail", "Latn", "PG"),
+    ("aim", "Latn", "IN"),
+    ("ain", "Kana", "JP"),
+    ("aio", "Mymr", "IN"),
+    ("aip", "Latn", "ID"),
+    ("aiq", "Arab", "AF"),
+    ("air", "Latn", "ID"),
+    ("ait", "Latn", "BR"),
+    ("aiw", "Latn", "ET"),
+    ("aix", "Latn", "PG"),
+    ("aiy", "Latn", "CF"),
+    ("aja", "Latn", "SS"),
+    ("ajg", "Latn", "BJ"),
+    ("aji", "Latn", "NC"),
+    ("ajn", "Latn", "AU"),
+    ("ajw", "Latn", "NG"),
+    ("ajz", "Latn", "IN"),
+    ("ak", "Latn", "GH"),
+    ("akb", "Latn", "ID"),
+    ("akc", "Latn", "ID"),
+    ("akd", "Latn", "NG"),
+    ("ake", "Latn", "GY"),
+    ("akf", "Latn", "NG"),
+    ("akg", "Latn", "ID"),
+    ("akh", "Latn", "PG"),
+    ("aki", "Latn", "PG"),
+    ("akk", "Xsux", "IQ"),
+    ("akl", "Latn", "PH"),
+    ("ako", "Latn", "SR"),
+    ("akp", "Latn", "GH"),
+    ("akq", "Latn", "PG"),
+    ("akr", "Latn", "VU"),
+    ("aks", "Latn", "TG"),
+    ("akt", "Latn", "PG"),
+    ("aku", "Latn", "CM"),
+    ("akv", "Cyrl", "RU"),
+    ("akw", "Latn", "CG"),
+    ("akz", "Latn", "US"),
+    ("ala", "Latn", "NG"),
+    ("alc", "Latn", "CL"),
+    ("ald", "Latn", "CI"),
+    ("ale", "Latn", "US"),
+    ("alf", "Latn", "NG"),
+    ("alh", "Latn", "AU"),
+    ("ali", "Latn", "PG"),
+    ("alj", "Latn", "PH"),
+    ("alk", "Laoo", "LA"),
+    ("all", "Mlym", "IN"),
+    ("alm", "Latn", "VU"),
+    ("aln", "Latn", "XK"),
+    ("alo", "Latn", "ID"),
+    ("alp", "Latn", "ID"),
+    ("alq", "Latn", "CA"),
+    ("alr", "Cyrl", "RU"),
+    ("alt", "Cyrl", "RU"),
+    ("alu", "Latn", "SB"),
+    ("alw", "Ethi", "ET"),
+    ("alx", "Latn", "PG"),
+    ("aly", "Latn", "AU"),
+    ("alz", "Latn", "CD"),
+    ("am", "Ethi", "ET"),
+    ("ama", "Latn", "BR"),
+    ("amb", "Latn", "NG"),
+    ("amc", "Latn", "PE"),
+    ("ame", "Latn", "PE"),
+    ("amf", "Latn", "ET"),
+    ("amg", "Latn", "AU"),
+    ("ami", "Latn", "TW"),
+    ("amj", "Latn", "TD"),
+    ("amk", "Latn", "ID"),
+    ("amm", "Latn", "PG"),
+    ("amn", "Latn", "PG"),
+    ("amo", "Latn", "NG"),
+    ("amp", "Latn", "PG"),
+    ("amq", "Latn", "ID"),
+    ("amr", "Latn", "PE"),
+    ("ams", "Jpan", "JP"),
+    ("amt", "Latn", "PG"),
+    ("amu", "Latn", "MX"),
+    ("amv", "Latn", "ID"),
+    ("amw", "Syrc", "SY"),
+    ("amx", "Latn", "AU"),
+    ("amy", "Latn", "AU"),
+    ("amz", "Latn", "AU"),
+    ("an", "Latn", "ES"),
+    ("ana", "Latn", "CO"),
+    ("anb", "Latn", "PE"),
+    ("anc", "Latn", "NG"),
+    ("and", "Latn", "ID"),
+    ("ane", "Latn", "NC"),
+    ("anf", "Latn", "GH"),
+    ("ang", "Latn", "GB"),
+    ("anh", "Latn", "PG"),
+    ("ani", "Cyrl", "RU"),
+    ("anj", "Latn", "PG"),
+    ("ank", "Latn", "NG"),
+    ("anl", "Latn", "MM"),
+    ("anm", "Latn", "IN"),
+    ("ann", "Latn", "NG"),
+    ("ano", "Latn", "CO"),
+    ("anp", "Deva", "IN"),
+    ("anq", "Deva", "IN"),
+    ("anr", "Deva", "IN"),
+    ("ans", "Latn", "CO"),
+    ("ant", "Latn", "AU"),
+    ("anu", "Ethi", "ET"),
+    ("anv", "Latn", "CM"),
+    ("anw", "Latn", "NG"),
+    ("anx", "Latn", "PG"),
+    ("any", "Latn", "CI"),
+    ("anz", "Latn", "PG"),
+    ("aoa", "Latn", "ST"),
+    ("aob", "Latn", "PG"),
+    ("aoc", "Latn", "VE"),
+    ("aod", "Latn", "PG"),
+    ("aoe", "Latn", "PG"),
+    ("aof", "Latn", "PG"),
+    ("aog", "Latn", "PG"),
+    ("aoi", "Latn", "AU"),
+    ("aoj", "Latn", "PG"),
+    ("aok", "Latn", "NC"),
+    ("aol", "Latn", "ID"),
+    ("aom", "Latn", "PG"),
+    ("aon", "Latn", "PG"),
+    ("aor", "Latn", "VU"),
+    ("aos", "Latn", "ID"),
+    ("aot", "Beng", "BD"),
+    ("aox", "Latn", "GY"),
+    ("aoz", "Latn", "ID"),
+    ("apb", "Latn", "SB"),
+    ("apc", "Arab", "SY"),
+    ("apd", "Arab", "SD"),
+    ("ape", "Latn", "PG"),
+    ("apf", "Latn", "PH"),
+    ("apg", "Latn", "ID"),
+    ("aph", "Deva", "NP"),
+    ("api", "Latn", "BR"),
+    ("apj", "Latn", "US"),
+    ("apk", "Latn", "US"),
+    ("apl", "Latn", "US"),
+    ("apm", "Latn", "US"),
+    ("apn", "Latn", "BR"),
+    ("apo", "Latn", "PG"),
+    ("app", "Latn", "VU"),
+    ("apr", "Latn", "PG"),
+    ("aps", "Latn", "PG"),
+    ("apt", "Latn", "IN"),
+    ("apu", "Latn", "BR"),
+    ("apv", "Latn", "BR"),
+    ("apw", "Latn", "US"),
+    ("apx", "Latn", "ID"),
+    ("apy", "Latn", "BR"),
+    ("apz", "Latn", "PG"),
+    ("aqc", "Cyrl", "RU"),
+    ("aqd", "Latn", "ML"),
+    ("aqg", "Latn", "NG"),
+    ("aqk", "Latn", "NG"),
+    ("aqm", "Latn", "ID"),
+    ("aqn", "Latn", "PH"),
+    ("aqr", "Latn", "NC"),
+    ("aqt", "Latn", "PY"),
+    ("aqz", "Latn", "BR"),
+    ("ar", "Arab", "EG"),
+    ("arc", "Armi", "IR"),
+    ("ard", "Latn", "AU"),
+    ("are", "Latn", "AU"),
+    ("arh", "Latn", "CO"),
+    ("ari", "Latn", "US"),
+    ("arj", "Latn", "BR"),
+    ("ark", "Latn", "BR"),
+    ("arl", "Latn", "PE"),
+    ("arn", "Latn", "CL"),
+    ("aro", "Latn", "BO"),
+    ("arp", "Latn", "US"),
+    ("arq", "Arab", "DZ"),
+    ("arr", "Latn", "BR"),
+    ("ars", "Arab", "SA"),
+    ("aru", "Latn", "BR"),
+    ("arv", "Latn", "ET"),
+    ("arw", "Latn", "SR"),
+    ("arx", "Latn", "BR"),
+    ("ary", "Arab", "MA"),
+    ("arz", "Arab", "EG"),
+    ("as", "Beng", "IN"),
+    ("asa", "Latn", "TZ"),
+    ("asb", "Latn", "CA"),
+    ("asc", "Latn", "ID"),
+    ("ase", "Sgnw", "US"),
+    ("asg", "Latn", "NG"),
+    ("ash", "Latn", "PE"),
+    ("asi", "Latn", "ID"),
+    ("asj", "Latn", "CM"),
+    ("ask", "Arab", "AF"),
+    ("asl", "Latn", "ID"),
+    ("asn", "Latn", "BR"),
+    ("aso", "Latn", "PG"),
+    ("asr", "Deva", "IN"),
+    ("ass", "Latn", "CM"),
+    ("ast", "Latn", "ES"),
+    ("asu", "Latn", "BR"),
+    ("asv", "Latn", "CD"),
+    ("asx", "Latn", "PG"),
+    ("asy", "Latn", "ID"),
+    ("asz", "Latn", "ID"),
+    ("ata", "Latn", "PG"),
+    ("atb", "Latn", "CN"),
+    ("atc", "Latn", "PE"),
+    ("atd", "Latn", "PH"),
+    ("ate", "Latn", "PG"),
+    ("atg", "Latn", "NG"),
+    ("ati", "Latn", "CI"),
+    ("atj", "Latn", "CA"),
+    ("atk", "Latn", "PH"),
+    ("atl", "Latn", "PH"),
+    ("atm", "Latn", "PH"),
+    ("atn", "Arab", "IR"),
+    ("ato", "Latn", "CM"),
+    ("atp", "Latn", "PH"),
+    ("atq", "Latn", "ID"),
+    ("atr", "Latn", "BR"),
+    ("ats", "Latn", "US"),
+    ("att", "Latn", "PH"),
+    ("atu", "Latn", "SS"),
+    ("atv", "Cyrl", "RU"),
+    ("atw", "Latn", "US"),
+    ("atx", "Latn", "BR"),
+    ("aty", "Latn", "VU"),
+    ("atz", "Latn", "PH"),
+    ("aua", "Latn", "SB"),
+    ("auc", "Latn", "EC"),
+    ("aud", "Latn", "SB"),
+    ("aug", "Latn", "BJ"),
+    ("auh", "Latn", "ZM"),
+    ("aui", "Latn", "PG"),
+    ("auj", "Arab", "LY"),
+    ("auk", "Latn", "PG"),
+    ("aul", "Latn", "VU"),
+    ("aum", "Latn", "NG"),
+    ("aun", "Latn", "PG"),
+    ("auo", "Latn", "NG"),
+    ("aup", "Latn", "PG"),
+    ("auq", "Latn", "ID"),
+    ("aur", "Latn", "PG"),
+    ("aut", "Latn", "PF"),
+    ("auu", "Latn", "ID"),
+    ("auw", "Latn", "ID"),
+    ("auy", "Latn", "PG"),
+    ("auz", "Arab", "UZ"),
+    ("av", "Cyrl", "RU"),
+    ("avb", "Latn", "PG"),
+    ("avd", "Arab", "IR"),
+    ("avi", "Latn", "CI"),
+    ("avk", "Latn", "001"),
+    ("avl", "Arab", "EG"),
+    ("avm", "Latn", "AU"),
+    ("avn", "Latn", "GH"),
+    ("avo", "Latn", "BR"),
+    ("avs", "Latn", "PE"),
+    ("avt", "Latn", "PG"),
+    ("avu", "Latn", "SS"),
+    ("avv", "Latn", "BR"),
+    ("awa", "Deva", "IN"),
+    ("awb", "Latn", "PG"),
+    ("awc", "Latn", "NG"),
+    ("awe", "Latn", "BR"),
+    ("awg", "Latn", "AU"),
+    ("awh", "Latn", "ID"),
+    ("awi", "Latn", "PG"),
+    ("awk", "Latn", "AU"),
+    ("awm", "Latn", "PG"),
+    ("awn", "Ethi", "ET"),
+    ("awo", "Latn", "NG"),
+    ("awr", "Latn", "ID"),
+    ("aws", "Latn", "ID"),
+    ("awt", "Latn", "BR"),
+    ("awu", "Latn", "ID"),
+    ("awv", "Latn", "ID"),
+    ("aww", "Latn", "PG"),
+    ("awx", "Latn", "PG"),
+    ("awy", "Latn", "ID"),
+    ("axb", "Latn", "AR"),
+    ("axe", "Latn", "AU"),
+    ("axg", "Latn", "BR"),
+    ("axk", "Latn", "CF"),
+    ("axl", "Latn", "AU"),
+    ("axm", "Armn", "AM"),
+    ("axx", "Latn", "NC"),
+    ("ay", "Latn", "BO"),
+    ("aya", "Latn", "PG"),
+    ("ayb", "Latn", "BJ"),
+    ("ayc", "Latn", "PE"),
+    ("ayd", "Latn", "AU"),
+    ("aye", "Latn", "NG"),
+    ("ayg", "Latn", "TG"),
+    ("ayh", "Arab", "YE"),
+    ("ayi", "Latn", "NG"),
+    ("ayk", "Latn", "NG"),
+    ("ayl", "Arab", "LY"),
+    ("ayn", "Arab", "YE"),
+    ("ayo", "Latn", "PY"),
+    ("ayp", "Arab", "IQ"),
+    ("ayq", "Latn", "PG"),
+    ("ays", "Latn", "PH"),
+    ("ayt", "Latn", "PH"),
+    ("ayu", "Latn", "NG"),
+    ("ayz", "Latn", "ID"),
+    ("az", "Latn", "AZ"),
+    ("azb", "Arab", "IR"),
+    ("azd", "Latn", "MX"),
+    ("azg", "Latn", "MX"),
+    ("azm", "Latn", "MX"),
+    ("azn", "Latn", "MX"),
+    ("azo", "Latn", "CM"),
+    ("azt", "Latn", "PH"),
+    ("azz", "Latn", "MX"),
+    ("ba", "Cyrl", "RU"),
+    ("baa", "Latn", "SB"),
+    ("bab", "Latn", "GW"),
+    ("bac", "Latn", "ID"),
+    ("bae", "Latn", "VE"),
+    ("baf", "Latn", "CM"),
+    ("bag", "Latn", "CM"),
+    ("bah", "Latn", "BS"),
+    ("baj", "Latn", "ID"),
+    ("bal", "Arab", "PK"),
+    ("ban", "Latn", "ID"),
+    ("bao", "Latn", "CO"),
+    ("bap", "Deva", "NP"),
+    ("bar", "Latn", "AT"),
+    ("bas", "Latn", "CM"),
+    ("bau", "Latn", "NG"),
+    ("bav", "Latn", "CM"),
+    ("baw", "Latn", "CM"),
+    ("bax", "Bamu", "CM"),
+    ("bay", "Latn", "ID"),
+    ("bba", "Latn", "BJ"),
+    ("bbb", "Latn", "PG"),
+    ("bbc", "Latn", "ID"),
+    ("bbd", "Latn", "PG"),
+    ("bbe", "Latn", "CD"),
+    ("bbf", "Latn", "PG"),
+    ("bbg", "Latn", "GA"),
+    ("bbi", "Latn", "CM"),
+    ("bbj", "Latn", "CM"),
+    ("bbk", "Latn", "CM"),
+    ("bbl", "Geor", "GE"),
+    ("bbm", "Latn", "CD"),
+    ("bbn", "Latn", "PG"),
+    ("bbo", "Latn", "BF"),
+    ("bbp", "Latn", "CF"),
+    ("bbq", "Latn", "CM"),
+    ("bbr", "Latn", "PG"),
+    ("bbs", "Latn", "NG"),
+    ("bbt", "Latn", "NG"),
+    ("bbu", "Latn", "NG"),
+    ("bbv", "Latn", "PG"),
+    ("bbw", "Latn", "CM"),
+    ("bbx", "Latn", "CM"),
+    ("bby", "Latn", "CM"),
+    ("bca", "Latn", "CN"),
+    ("bcb", "Latn", "SN"),
+    ("bcd", "Latn", "ID"),
+    ("bce", "Latn", "CM"),
+    ("bcf", "Latn", "PG"),
+    ("bcg", "Latn", "GN"),
+    ("bch", "Latn", "PG"),
+    ("bci", "Latn", "CI"),
+    ("bcj", "Latn", "AU"),
+    ("bck", "Latn", "AU"),
+    ("bcm", "Latn", "PG"),
+    ("bcn", "Latn", "NG"),
+    ("bco", "Latn", "PG"),
+    ("bcp", "Latn", "CD"),
+    ("bcq", "Ethi", "ET"),
+    ("bcr", "Latn", "CA"),
+    ("bcs", "Latn", "NG"),
+    ("bct", "Latn", "CD"),
+    ("bcu", "Latn", "PG"),
+    ("bcv", "Latn", "NG"),
+    ("bcw", "Latn", "CM"),
+    ("bcy", "Latn", "NG"),
+    ("bcz", "Latn", "SN"),
+    ("bda", "Latn", "SN"),
+    ("bdb", "Latn", "ID"),
+    ("bdc", "Latn", "CO"),
+    ("bdd", "Latn", "PG"),
+    ("bde", "Latn", "NG"),
+    ("bdf", "Latn", "PG"),
+    ("bdg", "Latn", "MY"),
+    ("bdh", "Latn", "SS"),
+    ("bdi", "Latn", "SD"),
+    ("bdj", "Latn", "SS"),
+    ("bdk", "Latn", "AZ"),
+    ("bdl", "Latn", "ID"),
+    ("bdm", "Latn", "TD"),
+    ("bdn", "Latn", "CM"),
+    ("bdo", "Latn", "TD"),
+    ("bdp", "Latn", "TZ"),
+    ("bdq", "Latn", "VN"),
+    ("bdr", "Latn", "MY"),
+    ("bds", "Latn", "TZ"),
+    ("bdt", "Latn", "CF"),
+    ("bdu", "Latn", "CM"),
+    ("bdv", "Orya", "IN"),
+    ("bdw", "Latn", "ID"),
+    ("bdx", "Latn", "ID"),
+    ("bdy", "Latn", "AU"),
+    ("bdz", "Arab", "PK"),
+    ("be", "Cyrl", "BY"),
+    ("bea", "Latn", "CA"),
+    ("beb", "Latn", "CM"),
+    ("bec", "Latn", "CM"),
+    ("bed", "Latn", "ID"),
+    ("bee", "Deva", "IN"),
+    ("bef", "Latn", "PG"),
+    ("beh", "Latn", "BJ"),
+    ("bei", "Latn", "ID"),
+    ("bej", "Arab", "SD"),
+    ("bek", "Latn", "PG"),
+    ("bem", "Latn", "ZM"),
+    ("beo", "Latn", "PG"),
+    ("bep", "Latn", "ID"),
+    ("beq", "Latn", "CG"),
+    ("bes", "Latn", "TD"),
+    ("bet", "Latn", "CI"),
+    ("beu", "Latn", "ID"),
+    ("bev", "Latn", "CI"),
+    ("bew", "Latn", "ID"),
+    ("bex", "Latn", "SS"),
+    ("bey", "Latn", "PG"),
+    ("bez", "Latn", "TZ"),
+    ("bfa", "Latn", "SS"),
+    ("bfb", "Deva", "IN"),
+    ("bfc", "Latn", "CN"),
+    ("bfd", "Latn", "CM"),
+    ("bfe", "Latn", "ID"),
+    ("bff", "Latn", "CF"),
+    ("bfg", "Latn", "ID"),
+    ("bfh", "Latn", "PG"),
+    ("bfj", "Latn", "CM"),
+    ("bfl", "Latn", "CF"),
+    ("bfm", "Latn", "CM"),
+    ("bfn", "Latn", "TL"),
+    ("bfo", "Latn", "BF"),
+    ("bfp", "Latn", "CM"),
+    ("bfq", "Taml", "IN"),
+    ("bfs", "Latn", "CN"),
+    ("bft", "Arab", "PK"),
+    ("bfu", "Tibt", "IN"),
+    ("bfw", "Orya", "IN"),
+    ("bfx", "Latn", "PH"),
+    ("bfy", "Deva", "IN"),
+    ("bfz", "Deva", "IN"),
+    ("bg", "Cyrl", "BG"),
+    ("bga", "Latn", "NG"),
+    ("bgb", "Latn", "ID"),
+    ("bgc", "Deva", "IN"),
+    ("bgd", "Deva", "IN"),
+    ("bgf", "Latn", "CM"),
+    ("bgg", "Latn", "IN"),
+    ("bgi", "Latn", "PH"),
+    ("bgj", "Latn", "CM"),
+    ("bgn", "Arab", "PK"),
+    ("bgo", "Latn", "GN"),
+    ("bgp", "Arab", "PK"),
+    ("bgq", "Deva", "IN"),
+    ("bgr", "Latn", "IN"),
+    ("bgs", "Latn", "PH"),
+    ("bgt", "Latn", "SB"),
+    ("bgu", "Latn", "NG"),
+    ("bgv", "Latn", "ID"),
+    ("bgw", "Deva", "IN"),
+    ("bgx", "Grek", "TR"),
+    ("bgy", "Latn", "ID"),
+    ("bgz", "Latn", "ID"),
+    ("bha", "Deva", "IN"),
+    ("bhb", "Deva", "IN"),
+    ("bhc", "Latn", "ID"),
+    ("bhd", "Deva", "IN"),
+    ("bhe", "Arab", "PK"),
+    ("bhf", "Latn", "PG"),
+    ("bhg", "Latn", "PG"),
+    ("bhh", "Cyrl", "IL"),
+    ("bhi", "Deva", "IN"),
+    ("bhj", "Deva", "NP"),
+    ("bhl", "Latn", "PG"),
+    ("bhm", "Arab", "OM"),
+    ("bhn", "Syrc", "GE"),
+    ("bho", "Deva", "IN"),
+    ("bhp", "Latn", "ID"),
+    ("bhq", "Latn", "ID"),
+    ("bhr", "Latn", "MG"),
+    ("bhs", "Latn", "CM"),
+    ("bht", "Deva", "IN"),
+    ("bhu", "Deva", "IN"),
+    ("bhv", "Latn", "ID"),
+    ("bhw", "Latn", "ID"),
+    ("bhy", "Latn", "CD"),
+    ("bhz", "Latn", "ID"),
+    ("bi", "Latn", "VU"),
+    ("bia", "Latn", "AU"),
+    ("bib", "Latn", "BF"),
+    ("bid", "Latn", "TD"),
+    ("bie", "Latn", "PG"),
+    ("bif", "Latn", "GW"),
+    ("big", "Latn", "PG"),
+    ("bik", "Latn", "PH"),
+    ("bil", "Latn", "NG"),
+    ("bim", "Latn", "GH"),
+    ("bin", "Latn", "NG"),
+    ("bio", "Latn", "PG"),
+    ("bip", "Latn", "CD"),
+    ("biq", "Latn", "PG"),
+    ("bir", "Latn", "PG"),
+    ("bit", "Latn", "PG"),
+    ("biu", "Latn", "IN"),
+    ("biv", "Latn", "GH"),
+    ("biw", "Latn", "CM"),
+    ("bix", "Deva", "IN"),
+    ("biy", "Deva", "IN"),
+    ("biz", "Latn", "CD"),
+    ("bja", "Latn", "CD"),
+    ("bjb", "Latn", "AU"),
+    ("bjc", "Latn", "PG"),
+    ("bjf", "Syrc", "IL"),
+    ("bjg", "Latn", "GW"),
+    ("bjh", "Latn", "PG"),
+    ("bji", "Latn", "ET"),
+    ("bjj", "Deva", "IN"),
+    ("bjk", "Latn", "PG"),
+    ("bjl", "Latn", "PG"),
+    ("bjm", "Arab", "IQ"),
+    ("bjn", "Latn", "ID"),
+    ("bjo", "Latn", "CF"),
+    ("bjp", "Latn", "PG"),
+    ("bjr", "Latn", "PG"),
+    ("bjs", "Latn", "BB"),
+    ("bjt", "Latn", "SN"),
+    ("bju", "Latn", "CM"),
+    ("bjv", "Latn", "TD"),
+    ("bjw", "Latn", "CI"),
+    ("bjx", "Latn", "PH"),
+    ("bjy", "Latn", "AU"),
+    ("bjz", "Latn", "PG"),
+    ("bka", "Latn", "NG"),
+    ("bkc", "Latn", "CM"),
+    ("bkd", "Latn", "PH"),
+    ("bkf", "Latn", "CD"),
+    ("bkg", "Latn", "CF"),
+    ("bkh", "Latn", "CM"),
+    ("bki", "Latn", "VU"),
+    ("bkj", "Latn", "CF"),
+    ("bkk", "Tibt", "IN"),
+    ("bkl", "Latn", "ID"),
+    ("bkm", "Latn", "CM"),
+    ("bkn", "Latn", "ID"),
+    ("bko", "Latn", "CM"),
+    ("bkp", "Latn", "CD"),
+    ("bkq", "Latn", "BR"),
+    ("bkr", "Latn", "ID"),
+    ("bks", "Latn", "PH"),
+    ("bkt", "Latn", "CD"),
+    ("bku", "Latn", "PH"),
+    ("bkv", "Latn", "NG"),
+    ("bkw", "Latn", "CG"),
+    ("bkx", "Latn", "TL"),
+    ("bky", "Latn", "NG"),
+    ("bkz", "Latn", "ID"),
+    ("bla", "Latn", "CA"),
+    ("blb", "Latn", "SB"),
+    ("blc", "Latn", "CA"),
+    ("bld", "Latn", "ID"),
+    ("ble", "Latn", "GW"),
+    ("blf", "Latn", "ID"),
+    ("blh", "Latn", "LR"),
+    ("bli", "Latn", "CD"),
+    ("blj", "Latn", "ID"),
+    ("blk", "Mymr", "MM"),
+    ("blm", "Latn", "SS"),
+    ("bln", "Latn", "PH"),
+    ("blo", "Latn", "BJ"),
+    ("blp", "Latn", "SB"),
+    ("blq", "Latn", "PG"),
+    ("blr", "Latn", "CN"),
+    ("bls", "Latn", "ID"),
+    ("blt", "Tavt", "VN"),
+    ("blv", "Latn", "AO"),
+    ("blw", "Latn", "PH"),
+    ("blx", "Latn", "PH"),
+    ("bly", "Latn", "BJ"),
+    ("blz", "Latn", "ID"),
+    ("bm", "Latn", "ML"),
+    ("bma", "Latn", "NG"),
+    ("bmb", "Latn", "CD"),
+    ("bmc", "Latn", "PG"),
+    ("bmd", "Latn", "GN"),
+    ("bme", "Latn", "CF"),
+    ("bmf", "Latn", "SL"),
+    ("bmg", "Latn", "CD"),
+    ("bmh", "Latn", "PG"),
+    ("bmi", "Latn", "TD"),
+    ("bmj", "Deva", "NP"),
+    ("bmk", "Latn", "PG"),
+    ("bml", "Latn", "CD"),
+    ("bmm", "Latn", "MG"),
+    ("bmn", "Latn", "PG"),
+    ("bmo", "Latn", "CM"),
+    ("bmp", "Latn", "PG"),
+    ("bmq", "Latn", "ML"),
+    ("bmr", "Latn", "CO"),
+    ("bms", "Latn", "NE"),
+    ("bmu", "Latn", "PG"),
+    ("bmv", "Latn", "CM"),
+    ("bmw", "Latn", "CG"),
+    ("bmx", "Latn", "PG"),
+    ("bmz", "Latn", "PG"),
+    ("bn", "Beng", "BD"),
+    ("bna", "Latn", "ID"),
+    ("bnb", "Latn", "MY"),
+    ("bnc", "Latn", "PH"),
+    ("bnd", "Latn", "ID"),
+    ("bne", "Latn", "ID"),
+    ("bnf", "Latn", "ID"),
+    ("bng", "Latn", "GQ"),
+    ("bni", "Latn", "CD"),
+    ("bnj", "Latn", "PH"),
+    ("bnk", "Latn", "VU"),
+    ("bnm", "Latn", "GQ"),
+    ("bnn", "Latn", "TW"),
+    ("bno", "Latn", "PH"),
+    ("bnp", "Latn", "PG"),
+    ("bnq", "Latn", "ID"),
+    ("bnr", "Latn", "VU"),
+    ("bns", "Deva", "IN"),
+    ("bnu", "Latn", "ID"),
+    ("bnv", "Latn", "ID"),
+    ("bnw", "Latn", "PG"),
+    ("bnx", "Latn", "CD"),
+    ("bny", "Latn", "MY"),
+    ("bnz", "Latn", "CM"),
+    ("bo", "Tibt", "CN"),
+    ("boa", "Latn", "PE"),
+    ("bob", "Latn", "KE"),
+    ("boe", "Latn", "CM"),
+    ("bof", "Latn", "BF"),
+    ("boh", "Latn", "CD"),
+    ("boj", "Latn", "PG"),
+    ("bok", "Latn", "CG"),
+    ("bol", "Latn", "NG"),
+    ("bom", "Latn", "NG"),
+    ("bon", "Latn", "PG"),
+    ("boo", "Latn", "ML"),
+    ("bop", "Latn", "PG"),
+    ("boq", "Latn", "PG"),
+    ("bor", "Latn", "BR"),
+    ("bot", "Latn", "SS"),
+    ("bou", "Latn", "TZ"),
+    ("bov", "Latn", "GH"),
+    ("bow", "Latn", "PG"),
+    ("box", "Latn", "BF"),
+    ("boy", "Latn", "CF"),
+    ("boz", "Latn", "ML"),
+    ("bpa", "Latn", "VU"),
+    ("bpc", "Latn", "CM"),
+    ("bpd", "Latn", "CF"),
+    ("bpe", "Latn", "PG"),
+    ("bpg", "Latn", "ID"),
+    ("bph", "Cyrl", "RU"),
+    ("bpi", "Latn", "PG"),
+    ("bpj", "Latn", "CD"),
+    ("bpk", "Latn", "NC"),
+    ("bpl", "Latn", "AU"),
+    ("bpm", "Latn", "PG"),
+    ("bpo", "Latn", "ID"),
+    ("bpp", "Latn", "ID"),
+    ("bpq", "Latn", "ID"),
+    ("bpr", "Latn", "PH"),
+    ("bps", "Latn", "PH"),
+    ("bpt", "Latn", "AU"),
+    ("bpu", "Latn", "PG"),
+    ("bpv", "Latn", "ID"),
+    ("bpw", "Latn", "PG"),
+    ("bpx", "Deva", "IN"),
+    ("bpy", "Beng", "IN"),
+    ("bpz", "Latn", "ID"),
+    ("bqa", "Latn", "BJ"),
+    ("bqb", "Latn", "ID"),
+    ("bqc", "Latn", "BJ"),
+    ("bqd", "Latn", "CM"),
+    ("bqf", "Latn", "GN"),
+    ("bqg", "Latn", "TG"),
+    ("bqi", "Arab", "IR"),
+    ("bqj", "Latn", "SN"),
+    ("bqk", "Latn", "CF"),
+    ("bql", "Latn", "PG"),
+    ("bqm", "Latn", "CM"),
+    ("bqo", "Latn", "CM"),
+    ("bqp", "Latn", "NG"),
+    ("bqq", "Latn", "ID"),
+    ("bqr", "Latn", "ID"),
+    ("bqs", "Latn", "PG"),
+    ("bqt", "Latn", "CM"),
+    ("bqu", "Latn", "CD"),
+    ("bqv", "Latn", "CI"),
+    ("bqw", "Latn", "NG"),
+    ("bqx", "Latn", "NG"),
+    ("bqz", "Latn", "CM"),
+    ("br", "Latn", "FR"),
+    ("bra", "Deva", "IN"),
+    ("brb", "Khmr", "KH"),
+    ("brc", "Latn", "GY"),
+    ("brd", "Deva", "NP"),
+    ("brf", "Latn", "CD"),
+    ("brg", "Latn", "BO"),
+    ("brh", "Arab", "PK"),
+    ("bri", "Latn", "CM"),
+    ("brj", "Latn", "VU"),
+    ("brk", "Arab", "SD"),
+    ("brl", "Latn", "BW"),
+    ("brm", "Latn", "CD"),
+    ("brn", "Latn", "CR"),
+    ("bro", "Tibt", "BT"),
+    ("brp", "Latn", "ID"),
+    ("brq", "Latn", "PG"),
+    ("brr", "Latn", "SB"),
+    ("brs", "Latn", "ID"),
+    ("brt", "Latn", "NG"),
+    ("bru", "Latn", "VN"),
+    ("brv", "Laoo", "LA"),
+    ("brw", "Knda", "IN"),
+    ("brx", "Deva", "IN"),
+    ("bry", "Latn", "PG"),
+    ("brz", "Latn", "PG"),
+    ("bs", "Latn", "BA"),
+    ("bsa", "Latn", "ID"),
+    ("bsb", "Latn", "BN"),
+    ("bsc", "Latn", "SN"),
+    ("bse", "Latn", "CM"),
+    ("bsf", "Latn", "NG"),
+    ("bsh", "Arab", "AF"),
+    ("bsi", "Latn", "CM"),
+    ("bsj", "Latn", "NG"),
+    ("bsk", "Arab", "PK"),
+    ("bsl", "Latn", "NG"),
+    ("bsm", "Latn", "ID"),
+    ("bsn", "Latn", "CO"),
+    ("bso", "Latn", "TD"),
+    ("bsp", "Latn", "GN"),
+    ("bsq", "Latn", "LR"),
+    ("bsr", "Latn", "NG"),
+    ("bss", "Latn", "CM"),
+    ("bst", "Ethi", "ET"),
+    ("bsu", "Latn", "ID"),
+    ("bsv", "Latn", "GN"),
+    ("bsw", "Latn", "ET"),
+    ("bsx", "Latn", "NG"),
+    ("bsy", "Latn", "MY"),
+    ("bta", "Latn", "NG"),
+    ("btc", "Latn", "CM"),
+    ("btd", "Batk", "ID"),
+    ("bte", "Latn", "NG"),
+    ("btf", "Latn", "TD"),
+    ("btg", "Latn", "CI"),
+    ("bth", "Latn", "MY"),
+    ("bti", "Latn", "ID"),
+    ("btj", "Latn", "ID"),
+    ("btm", "Batk", "ID"),
+    ("btn", "Latn", "PH"),
+    ("bto", "Latn", "PH"),
+    ("btp", "Latn", "PG"),
+    ("btq", "Latn", "MY"),
+    ("btr", "Latn", "VU"),
+    ("bts", "Latn", "ID"),
+    ("btt", "Latn", "NG"),
+    ("btu", "Latn", "NG"),
+    ("btv", "Deva", "PK"),
+    ("btw", "Latn", "PH"),
+    ("btx", "Latn", "ID"),
+    ("bty", "Latn", "ID"),
+    ("btz", "Latn", "ID"),
+    ("bua", "Cyrl", "RU"),
+    ("bub", "Latn", "TD"),
+    ("buc", "Latn", "YT"),
+    ("bud", "Latn", "TG"),
+    ("bue", "Latn", "CA"),
+    ("buf", "Latn", "CD"),
+    ("bug", "Latn", "ID"),
+    ("buh", "Latn", "CN"),
+    ("bui", "Latn", "CG"),
+    ("buj", "Latn", "NG"),
+    ("buk", "Latn", "PG"),
+    ("bum", "Latn", "CM"),
+    ("bun", "Latn", "SL"),
+    ("buo", "Latn", "PG"),
+    ("bup", "Latn", "ID"),
+    ("buq", "Latn", "PG"),
+    ("bus", "Latn", "NG"),
+    ("but", "Latn", "PG"),
+    ("buu", "Latn", "CD"),
+    ("buv", "Latn", "PG"),
+    ("buw", "Latn", "GA"),
+    ("bux", "Latn", "NG"),
+    ("buy", "Latn", "SL"),
+    ("buz", "Latn", "NG"),
+    ("bva", "Latn", "TD"),
+    ("bvb", "Latn", "GQ"),
+    ("bvc", "Latn", "SB"),
+    ("bvd", "Latn", "SB"),
+    ("bve", "Latn", "ID"),
+    ("bvf", "Latn", "TD"),
+    ("bvg", "Latn", "CM"),
+    ("bvh", "Latn", "NG"),
+    ("bvi", "Latn", "SS"),
+    ("bvj", "Latn", "NG"),
+    ("bvk", "Latn", "ID"),
+    ("bvm", "Latn", "CM"),
+    ("bvn", "Latn", "PG"),
+    ("bvo", "Latn", "TD"),
+    ("bvq", "Latn", "CF"),
+    ("bvr", "Latn", "AU"),
+    ("bvt", "Latn", "ID"),
+    ("bvu", "Latn", "ID"),
+    ("bvv", "Latn", "VE"),
+    ("bvw", "Latn", "NG"),
+    ("bvx", "Latn", "CG"),
+    ("bvy", "Latn", "PH"),
+    ("bvz", "Latn", "ID"),
+    ("bwa", "Latn", "NC"),
+    ("bwb", "Latn", "FJ"),
+    ("bwc", "Latn", "ZM"),
+    ("bwd", "Latn", "PG"),
+    ("bwe", "Mymr", "MM"),
+    ("bwf", "Latn", "PG"),
+    ("bwg", "Latn", "MZ"),
+    ("bwh", "Latn", "CM"),
+    ("bwi", "Latn", "VE"),
+    ("bwj", "Latn", "BF"),
+    ("bwk", "Latn", "PG"),
+    ("bwl", "Latn", "CD"),
+    ("bwm", "Latn", "PG"),
+    ("bwo", "Latn", "ET"),
+    ("bwp", "Latn", "ID"),
+    ("bwq", "Latn", "BF"),
+    ("bwr", "Latn", "NG"),
+    ("bws", "Latn", "CD"),
+    ("bwt", "Latn", "CM"),
+    ("bwu", "Latn", "GH"),
+    ("bww", "Latn", "CD"),
+    ("bwx", "Latn", "CN"),
+    ("bwy", "Latn", "BF"),
+    ("bwz", "Latn", "CG"),
+    ("bxa", "Latn", "SB"),
+    ("bxb", "Latn", "SS"),
+    ("bxc", "Latn", "GQ"),
+    ("bxf", "Latn", "PG"),
+    ("bxg", "Latn", "CD"),
+    ("bxh", "Latn", "PG"),
+    ("bxi", "Latn", "AU"),
+    ("bxj", "Latn", "AU"),
+    ("bxl", "Latn", "BF"),
+    ("bxm", "Cyrl", "MN"),
+    ("bxn", "Latn", "AU"),
+    ("bxo", "Latn", "NG"),
+    ("bxp", "Latn", "CM"),
+    ("bxq", "Latn", "NG"),
+    ("bxs", "Latn", "CM"),
+    ("bxu", "Mong", "CN"),
+    ("bxv", "Latn", "TD"),
+    ("bxw", "Latn", "ML"),
+    ("bxz", "Latn", "PG"),
+    ("bya", "Latn", "PH"),
+    ("byb", "Latn", "CM"),
+    ("byc", "Latn", "NG"),
+    ("byd", "Latn", "ID"),
+    ("bye", "Latn", "PG"),
+    ("byf", "Latn", "NG"),
+    ("byh", "Deva", "NP"),
+    ("byi", "Latn", "CD"),
+    ("byj", "Latn", "NG"),
+    ("byk", "Latn", "CN"),
+    ("byl", "Latn", "ID"),
+    ("bym", "Latn", "AU"),
+    ("byn", "Ethi", "ER"),
+    ("byp", "Latn", "NG"),
+    ("byr", "Latn", "PG"),
+    ("bys", "Latn", "NG"),
+    ("byv", "Latn", "CM"),
+    ("byw", "Deva", "NP"),
+    ("byx", "Latn", "PG"),
+    ("byz", "Latn", "PG"),
+    ("bza", "Latn", "LR"),
+    ("bzb", "Latn", "ID"),
+    ("bzc", "Latn", "MG"),
+    ("bzd", "Latn", "CR"),
+    ("bze", "Latn", "ML"),
+    ("bzf", "Latn", "PG"),
+    ("bzh", "Latn", "PG"),
+    ("bzi", "Thai", "TH"),
+    ("bzj", "Latn", "BZ"),
+    ("bzk", "Latn", "NI"),
+    ("bzl", "Latn", "ID"),
+    ("bzm", "Latn", "CD"),
+    ("bzn", "Latn", "ID"),
+    ("bzo", "Latn", "CD"),
+    ("bzp", "Latn", "ID"),
+    ("bzq", "Latn", "ID"),
+    ("bzr", "Latn", "AU"),
+    ("bzt", "Latn", "001"),
+    ("bzu", "Latn", "ID"),
+    ("bzv", "Latn", "CM"),
+    ("bzw", "Latn", "NG"),
+    ("bzx", "Latn", "ML"),
+    ("bzy", "Latn", "NG"),
+    ("bzz", "Latn", "NG"),
+    ("ca", "Latn", "ES"),
+    ("caa", "Latn", "GT"),
+    ("cab", "Latn", "HN"),
+    ("cac", "Latn", "GT"),
+    ("cad", "Latn", "US"),
+    ("cae", "Latn", "SN"),
+    ("caf", "Latn", "CA"),
+    ("cag", "Latn", "PY"),
+    ("cah", "Latn", "PE"),
+    ("caj", "Latn", "BO"),
+    ("cak", "Latn", "GT"),
+    ("cal", "Latn", "MP"),
+    ("cam", "Latn", "NC"),
+    ("can", "Latn", "PG"),
+    ("cao", "Latn", "BO"),
+    ("cap", "Latn", "BO"),
+    ("caq", "Latn", "IN"),
+    ("car", "Latn", "VE"),
+    ("cas", "Latn", "BO"),
+    ("cav", "Latn", "BO"),
+    ("caw", "Latn", "BO"),
+    ("cax", "Latn", "BO"),
+    ("cay", "Latn", "CA"),
+    ("caz", "Latn", "BO"),
+    ("cbb", "Latn", "CO"),
+    ("cbc", "Latn", "CO"),
+    ("cbd", "Latn", "CO"),
+    ("cbg", "Latn", "CO"),
+    ("cbi", "Latn", "EC"),
+    ("cbj", "Latn", "BJ"),
+    ("cbk", "Latn", "PH"),
+    ("cbl", "Latn", "MM"),
+    ("cbn", "Thai", "TH"),
+    ("cbo", "Latn", "NG"),
+    ("cbq", "Latn", "NG"),
+    ("cbr", "Latn", "PE"),
+    ("cbs", "Latn", "PE"),
+    ("cbt", "Latn", "PE"),
+    ("cbu", "Latn", "PE"),
+    ("cbv", "Latn", "CO"),
+    ("cbw", "Latn", "PH"),
+    ("cby", "Latn", "CO"),
+    ("ccc", "Latn", "PE"),
+    ("ccd", "Latn", "BR"),
+    ("cce", "Latn", "MZ"),
+    ("ccg", "Latn", "NG"),
+    ("cch", "Latn", "NG"),
+    ("ccj", "Latn", "GW"),
+    ("ccl", "Latn", "TZ"),
+    ("ccm", "Latn", "MY"),
+    ("cco", "Latn", "MX"),
+    ("ccp", "Cakm", "BD"),
+    ("ccr", "Latn", "SV"),
+    ("cde", "Telu", "IN"),
+    ("cdf", "Latn", "IN"),
+    ("cdh", "Deva", "IN"),
+    ("cdi", "Gujr", "IN"),
+    ("cdj", "Deva", "IN"),
+    ("cdm", "Deva", "NP"),
+    ("cdn", "Deva", "IN"),
+    ("cdo", "Hans", "CN"),
+    ("cdr", "Latn", "NG"),
+    ("cdz", "Beng", "IN"),
+    ("ce", "Cyrl", "RU"),
+    ("cea", "Latn", "US"),
+    ("ceb", "Latn", "PH"),
+    ("ceg", "Latn", "PY"),
+    ("cek", "Latn", "MM"),
+    ("cen", "Latn", "NG"),
+    ("cet", "Latn", "NG"),
+    ("cey", "Latn", "MM"),
+    ("cfa", "Latn", "NG"),
+    ("cfd", "Latn", "NG"),
+    ("cfg", "Latn", "NG"),
+    ("cfm", "Latn", "MM"),
+    ("cga", "Latn", "PG"),
+    ("cgc", "Latn", "PH"),
+    ("cgg", "Latn", "UG"),
+    ("cgk", "Tibt", "BT"),
+    ("ch", "Latn", "GU"),
+    ("chb", "Latn", "CO"),
+    ("chd", "Latn", "MX"),
+    ("chf", "Latn", "MX"),
+    ("chg", "Arab", "TM"),
+    ("chh", "Latn", "US"),
+    ("chj", "Latn", "MX"),
+    ("chk", "Latn", "FM"),
+    ("chl", "Latn", "US"),
+    ("chm", "Cyrl", "RU"),
+    ("chn", "Latn", "US"),
+    ("cho", "Latn", "US"),
+    ("chp", "Latn", "CA"),
+    ("chq", "Latn", "MX"),
+    ("chr", "Cher", "US"),
+    ("cht", "Latn", "PE"),
+    ("chw", "Latn", "MZ"),
+    ("chx", "Deva", "NP"),
+    ("chy", "Latn", "US"),
+    ("chz", "Latn", "MX"),
+    ("cia", "Latn", "ID"),
+    ("cib", "Latn", "BJ"),
+    ("cic", "Latn", "US"),
+    ("cie", "Latn", "NG"),
+    ("cih", "Deva", "IN"),
+    ("cim", "Latn", "IT"),
+    ("cin", "Latn", "BR"),
+    ("cip", "Latn", "MX"),
+    ("cir", "Latn", "NC"),
+    ("ciw", "Latn", "US"),
+    ("ciy", "Latn", "VE"),
+    ("cja", "Arab", "KH"),
+    ("cje", "Latn", "VN"),
+    ("cjh", "Latn", "US"),
+    ("cji", "Cyrl", "RU"),
+    ("cjk", "Latn", "AO"),
+    ("cjm", "Cham", "VN"),
+    ("cjn", "Latn", "PG"),
+    ("cjo", "Latn", "PE"),
+    ("cjp", "Latn", "CR"),
+    ("cjs", "Latn", "RU"),
+    ("cjv", "Latn", "PG"),
+    ("cjy", "Hans", "CN"),
+    ("ckb", "Arab", "IQ"),
+    ("ckl", "Latn", "NG"),
+    ("ckm", "Latn", "HR"),
+    ("ckn", "Latn", "MM"),
+    ("cko", "Latn", "GH"),
+    ("ckq", "Latn", "TD"),
+    ("ckr", "Latn", "PG"),
+    ("cks", "Latn", "NC"),
+    ("ckt", "Cyrl", "RU"),
+    ("cku", "Latn", "US"),
+    ("ckv", "Latn", "TW"),
+    ("ckx", "Latn", "CM"),
+    ("cky", "Latn", "NG"),
+    ("ckz", "Latn", "GT"),
+    ("cla", "Latn", "NG"),
+    ("clc", "Latn", "CA"),
+    ("cle", "Latn", "MX"),
+    ("clh", "Arab", "PK"),
+    ("cli", "Latn", "GH"),
+    ("clj", "Latn", "MM"),
+    ("clk", "Latn", "IN"),
+    ("cll", "Latn", "GH"),
+    ("clm", "Latn", "US"),
+    ("clo", "Latn", "MX"),
+    ("clt", "Latn", "MM"),
+    ("clu", "Latn", "PH"),
+    ("clw", "Cyrl", "RU"),
+    ("cly", "Latn", "MX"),
+    ("cma", "Latn", "VN"),
+    ("cme", "Latn", "BF"),
+    ("cmg", "Soyo", "MN"),
+    ("cmi", "Latn", "CO"),
+    ("cml", "Latn", "ID"),
+    ("cmo", "Latn", "VN"),
+    ("cmr", "Latn", "MM"),
+    ("cms", "Latn", "IT"),
+    ("cmt", "Latn", "ZA"),
+    ("cna", "Tibt", "IN"),
+    ("cnb", "Latn", "MM"),
+    ("cnc", "Latn", "VN"),
+    ("cng", "Latn", "CN"),
+    ("cnh", "Latn", "MM"),
+    ("cni", "Latn", "PE"),
+    ("cnk", "Latn", "MM"),
+    ("cnl", "Latn", "MX"),
+    ("cnp", "Hans", "CN"),
+    ("cnq", "Latn", "CM"),
+    ("cns", "Latn", "ID"),
+    ("cnt", "Latn", "MX"),
+    ("cnw", "Latn", "MM"),
+    ("cnx", "Latn", "GB"),
+    ("co", "Latn", "FR"),
+    ("coa", "Latn", "AU"),
+    ("cob", "Latn", "MX"),
+    ("coc", "Latn", "MX"),
+    ("cod", "Latn", "PE"),
+    ("coe", "Latn", "CO"),
+    ("cof", "Latn", "EC"),
+    ("cog", "Thai", "TH"),
+    ("coh", "Latn", "KE"),
+    ("coj", "Latn", "MX"),
+    ("cok", "Latn", "MX"),
+    ("col", "Latn", "US"),
+    ("com", "Latn", "US"),
+    ("coo", "Latn", "CA"),
+    ("cop", "Copt", "EG"),
+    ("coq", "Latn", "US"),
+    ("cot", "Latn", "PE"),
+    ("cou", "Latn", "SN"),
+    ("cox", "Latn", "PE"),
+    ("coz", "Latn", "MX"),
+    ("cpa", "Latn", "MX"),
+    ("cpb", "Latn", "PE"),
+    ("cpc", "Latn", "PE"),
+    ("cpg", "Grek", "GR"),
+    ("cpi", "Latn", "NR"),
+    ("cpn", "Latn", "GH"),
+    ("cpo", "Latn", "BF"),
+    ("cps", "Latn", "PH"),
+    ("cpu", "Latn", "PE"),
+    ("cpx", "Latn", "CN"),
+    ("cpy", "Latn", "PE"),
+    ("cqd", "Latn", "CN"),
+    ("cr", "Cans", "CA"),
+    ("cra", "Latn", "ET"),
+    ("crb", "Latn", "VC"),
+    ("crc", "Latn", "VU"),
+    ("crd", "Latn", "US"),
+    ("crf", "Latn", "CO"),
+    ("crg", "Latn", "CA"),
+    ("crh", "Cyrl", "UA"),
+    ("cri", "Latn", "ST"),
+    ("crj", "Cans", "CA"),
+    ("crk", "Cans", "CA"),
+    ("crl", "Cans", "CA"),
+    ("crm", "Cans", "CA"),
+    ("crn", "Latn", "MX"),
+    ("cro", "Latn", "US"),
+    ("crq", "Latn", "AR"),
+    ("crs", "Latn", "SC"),
+    ("crt", "Latn", "AR"),
+    ("crv", "Latn", "IN"),
+    ("crw", "Latn", "VN"),
+    ("crx", "Latn", "CA"),
+    ("cry", "Latn", "NG"),
+    ("crz", "Latn", "US"),
+    ("cs", "Latn", "CZ"),
+    ("csa", "Latn", "MX"),
+    ("csb", "Latn", "PL"),
+    ("csh", "Mymr", "MM"),
+    ("csj", "Latn", "MM"),
+    ("csk", "Latn", "SN"),
+    ("csm", "Latn", "US"),
+    ("cso", "Latn", "MX"),
+    ("csp", "Hans", "CN"),
+    ("css", "Latn", "US"),
+    ("cst", "Latn", "US"),
+    ("csv", "Latn", "MM"),
+    ("csw", "Cans", "CA"),
+    ("csy", "Latn", "MM"),
+    ("csz", "Latn", "US"),
+    ("cta", "Latn", "MX"),
+    ("ctc", "Latn", "US"),
+    ("ctd", "Pauc", "MM"),
+    ("cte", "Latn", "MX"),
+    ("ctg", "Beng", "BD"),
+    ("cth", "Latn", "MM"),
+    ("ctl", "Latn", "MX"),
+    ("ctm", "Latn", "US"),
+    ("ctn", "Deva", "NP"),
+    ("cto", "Latn", "CO"),
+    ("ctp", "Latn", "MX"),
+    ("cts", "Latn", "PH"),
+    ("ctt", "Taml", "IN"),
+    ("ctu", "Latn", "MX"),
+    ("cty", "Taml", "IN"),
+    ("ctz", "Latn", "MX"),
+    ("cu", "Cyrl", "RU"),
+    ("cua", "Latn", "VN"),
+    ("cub", "Latn", "CO"),
+    ("cuc", "Latn", "MX"),
+    ("cuh", "Latn", "KE"),
+    ("cui", "Latn", "CO"),
+    ("cuj", "Latn", "PE"),
+    ("cuk", "Latn", "PA"),
+    ("cul", "Latn", "BR"),
+    ("cuo", "Latn", "VE"),
+    ("cup", "Latn", "US"),
+    ("cut", "Latn", "MX"),
+    ("cuu", "Lana", "CN"),
+    ("cuv", "Latn", "CM"),
+    ("cux", "Latn", "MX"),
+    ("cuy", "Latn", "MX"),
+    ("cv", "Cyrl", "RU"),
+    ("cvg", "Latn", "IN"),
+    ("cvn", "Latn", "MX"),
+    ("cwa", "Latn", "TZ"),
+    ("cwb", "Latn", "MZ"),
+    ("cwe", "Latn", "TZ"),
+    ("cwg", "Latn", "MY"),
+    ("cwt", "Latn", "SN"),
+    ("cxh", "Latn", "NG"),
+    ("cy", "Latn", "GB"),
+    ("cya", "Latn", "MX"),
+    ("cyb", "Latn", "BO"),
+    ("cyo", "Latn", "PH"),
+    ("czh", "Hans", "CN"),
+    ("czk", "Hebr", "CZ"),
+    ("czn", "Latn", "MX"),
+    ("czt", "Latn", "MM"),
+    ("da", "Latn", "DK"),
+    ("daa", "Latn", "TD"),
+    ("dac", "Latn", "PG"),
+    ("dad", "Latn", "PG"),
+    ("dae", "Latn", "CM"),
+    ("dag", "Latn", "GH"),
+    ("dah", "Latn", "PG"),
+    ("dai", "Latn", "TD"),
+    ("daj", "Latn", "SD"),
+    ("dak", "Latn", "US"),
+    ("dal", "Latn", "KE"),
+    ("dam", "Latn", "NG"),
+    ("dao", "Latn", "MM"),
+    ("daq", "Deva", "IN"),
+    ("dar", "Cyrl", "RU"),
+    ("das", "Latn", "CI"),
+    ("dau", "Latn", "TD"),
+    ("dav", "Latn", "KE"),
+    ("daw", "Latn", "PH"),
+    ("dax", "Latn", "AU"),
+    ("daz", "Latn", "ID"),
+    ("dba", "Latn", "ML"),
+    ("dbb", "Latn", "NG"),
+    ("dbd", "Latn", "NG"),
+    ("dbe", "Latn", "ID"),
+    ("dbf", "Latn", "ID"),
+    ("dbg", "Latn", "ML"),
+    ("dbi", "Latn", "NG"),
+    ("dbj", "Latn", "MY"),
+    ("dbl", "Latn", "AU"),
+    ("dbm", "Latn", "NG"),
+    ("dbn", "Latn", "ID"),
+    ("dbo", "Latn", "NG"),
+    ("dbp", "Latn", "NG"),
+    ("dbq", "Latn", "CM"),
+    ("dbt", "Latn", "ML"),
+    ("dbu", "Latn", "ML"),
+    ("dbv", "Latn", "NG"),
+    ("dbw", "Latn", "ML"),
+    ("dby", "Latn", "PG"),
+    ("dcc", "Arab", "IN"),
+    ("dcr", "Latn", "VI"),
+    ("dda", "Latn", "AU"),
+    ("ddd", "Latn", "SS"),
+    ("dde", "Latn", "CG"),
+    ("ddg", "Latn", "TL"),
+    ("ddi", "Latn", "PG"),
+    ("ddj", "Latn", "AU"),
+    ("ddn", "Latn", "BJ"),
+    ("ddo", "Cyrl", "RU"),
+    ("ddr", "Latn", "AU"),
+    ("dds", "Latn", "ML"),
+    ("ddw", "Latn", "ID"),
+    ("de", "Latn", "DE"),
+    ("dec", "Latn", "SD"),
+    ("ded", "Latn", "PG"),
+    ("dee", "Latn", "LR"),
+    ("def", "Arab", "IR"),
+    ("deg", "Latn", "NG"),
+    ("deh", "Arab", "PK"),
+    ("dei", "Latn", "ID"),
+    ("del", "Latn", "US"),
+    ("dem", "Latn", "ID"),
+    ("den", "Latn", "CA"),
+    ("deq", "Latn", "CF"),
+    ("der", "Beng", "IN"),
+    ("des", "Latn", "BR"),
+    ("dev", "Latn", "PG"),
+    ("dez", "Latn", "CD"),
+    ("dga", "Latn", "GH"),
+    ("dgb", "Latn", "ML"),
+    ("dgc", "Latn", "PH"),
+    ("dgd", "Latn", "BF"),
+    ("dge", "Latn", "PG"),
+    ("dgg", "Latn", "PG"),
+    ("dgh", "Latn", "NG"),
+    ("dgi", "Latn", "BF"),
+    ("dgk", "Latn", "CF"),
+    ("dgl", "Arab", "SD"),
+    ("dgn", "Latn", "AU"),
+    ("dgr", "Latn", "CA"),
+    ("dgs", "Latn", "BF"),
+    ("dgt", "Latn", "AU"),
+    ("dgw", "Latn", "AU"),
+    ("dgx", "Latn", "PG"),
+    ("dgz", "Latn", "PG"),
+    ("dhg", "Latn", "AU"),
+    ("dhi", "Deva", "NP"),
+    ("dhl", "Latn", "AU"),
+    ("dhm", "Latn", "AO"),
+    ("dhn", "Gujr", "IN"),
+    ("dho", "Gujr", "IN"),
+    ("dhr", "Latn", "AU"),
+    ("dhs", "Latn", "TZ"),
+    ("dhu", "Latn", "AU"),
+    ("dhv", "Latn", "NC"),
+    ("dhw", "Deva", "NP"),
+    ("dhx", "Latn", "AU"),
+    ("dia", "Latn", "PG"),
+    ("dib", "Latn", "SS"),
+    ("dic", "Latn", "CI"),
+    ("did", "Latn", "SS"),
+    ("dif", "Latn", "AU"),
+    ("dig", "Latn", "KE"),
+    ("dih", "Latn", "MX"),
+    ("dii", "Latn", "CM"),
+    ("dij", "Latn", "ID"),
+    ("dil", "Latn", "SD"),
+    ("din", "Latn", "SS"),
+    ("dio", "Latn", "NG"),
+    ("dip", "Latn", "SS"),
+    ("dir", "Latn", "NG"),
+    ("dis", "Latn", "IN"),
+    ("diu", "Latn", "NA"),
+    ("diw", "Latn", "SS"),
+    ("dix", "Latn", "VU"),
+    ("diy", "Latn", "ID"),
+    ("diz", "Latn", "CD"),
+    ("dja", "Latn", "AU"),
+    ("djb", "Latn", "AU"),
+    ("djc", "Latn", "TD"),
+    ("djd", "Latn", "AU"),
+    ("dje", "Latn", "NE"),
+    ("djf", "Latn", "AU"),
+    ("dji", "Latn", "AU"),
+    ("djj", "Latn", "AU"),
+    ("djk", "Latn", "SR"),
+    ("djm", "Latn", "ML"),
+    ("djn", "Latn", "AU"),
+    ("djo", "Latn", "ID"),
+    ("djr", "Latn", "AU"),
+    ("dju", "Latn", "PG"),
+    ("djw", "Latn", "AU"),
+    ("dka", "Tibt", "BT"),
+    ("dkg", "Latn", "NG"),
+    ("dkk", "Latn", "ID"),
+    ("dkr", "Latn", "MY"),
+    ("dks", "Latn", "SS"),
+    ("dkx", "Latn", "CM"),
+    ("dlg", "Cyrl", "RU"),
+    ("dlm", "Latn", "HR"),
+    ("dln", "Latn", "IN"),
+    ("dma", "Latn", "GA"),
+    ("dmb", "Latn", "ML"),
+    ("dmc", "Latn", "PG"),
+    ("dmd", "Latn", "AU"),
+    ("dme", "Latn", "CM"),
+    ("dmf", "Medf", "NG"),
+    ("dmg", "Latn", "MY"),
+    ("dmk", "Arab", "PK"),
+    ("dml", "Arab", "PK"),
+    ("dmm", "Latn", "CM"),
+    ("dmo", "Latn", "CM"),
+    ("dmr", "Latn", "ID"),
+    ("dms", "Latn", "ID"),
+    ("dmu", "Latn", "ID"),
+    ("dmv", "Latn", "MY"),
+    ("dmw", "Latn", "AU"),
+    ("dmx", "Latn", "MZ"),
+    ("dmy", "Latn", "ID"),
+    ("dna", "Latn", "ID"),
+    ("dnd", "Latn", "PG"),
+    ("dne", "Latn", "TZ"),
+    ("dng", "Cyrl", "KG"),
+    ("dni", "Latn", "ID"),
+    ("dnj", "Latn", "CI"),
+    ("dnk", "Latn", "ID"),
+    ("dnn", "Latn", "BF"),
+    ("dno", "Latn", "CD"),
+    ("dnr", "Latn", "PG"),
+    ("dnt", "Latn", "ID"),
+    ("dnu", "Mymr", "MM"),
+    ("dnv", "Mymr", "MM"),
+    ("dnw", "Latn", "ID"),
+    ("dny", "Latn", "BR"),
+    ("doa", "Latn", "PG"),
+    ("dob", "Latn", "PG"),
+    ("doc", "Latn", "CN"),
+    ("doe", "Latn", "TZ"),
+    ("dof", "Latn", "PG"),
+    ("doh", "Latn", "NG"),
+    ("doi", "Deva", "IN"),
+    ("dok", "Latn", "ID"),
+    ("dol", "Latn", "PG"),
+    ("don", "Latn", "PG"),
+    ("doo", "Latn", "CD"),
+    ("dop", "Latn", "BJ"),
+    ("dor", "Latn", "SB"),
+    ("dos", "Latn", "BF"),
+    ("dot", "Latn", "NG"),
+    ("dov", "Latn", "ZW"),
+    ("dow", "Latn", "CM"),
+    ("dox", "Ethi", "ET"),
+    ("doy", "Latn", "GH"),
+    ("dpp", "Latn", "MY"),
+    ("drc", "Latn", "PT"),
+    ("dre", "Tibt", "NP"),
+    ("drg", "Latn", "MY"),
+    ("dri", "Latn", "NG"),
+    ("drl", "Latn", "AU"),
+    ("drn", "Latn", "ID"),
+    ("dro", "Latn", "MY"),
+    ("drq", "Deva", "NP"),
+    ("drs", "Ethi", "ET"),
+    ("drt", "Latn", "NL"),
+    ("dru", "Latn", "TW"),
+    ("dry", "Deva", "NP"),
+    ("dsb", "Latn", "DE"),
+    ("dsh", "Latn", "KE"),
+    ("dsi", "Latn", "TD"),
+    ("dsk", "Latn", "NG"),
+    ("dsn", "Latn", "ID"),
+    ("dso", "Orya", "IN"),
+    ("dsq", "Latn", "ML"),
+    ("dta", "Latn", "CN"),
+    ("dtb", "Latn", "MY"),
+    ("dtd", "Latn", "CA"),
+    ("dth", "Latn", "AU"),
+    ("dti", "Latn", "ML"),
+    ("dtk", "Latn", "ML"),
+    ("dtm", "Latn", "ML"),
+    ("dto", "Latn", "ML"),
+    ("dtp", "Latn", "MY"),
+    ("dtr", "Latn", "MY"),
+    ("dts", "Latn", "ML"),
+    ("dtt", "Latn", "ML"),
+    ("dtu", "Latn", "ML"),
+    ("dty", "Deva", "NP"),
+    ("dua", "Latn", "CM"),
+    ("dub", "Gujr", "IN"),
+    ("duc", "Latn", "PG"),
+    ("due", "Latn", "PH"),
+    ("duf", "Latn", "NC"),
+    ("dug", "Latn", "KE"),
+    ("duh", "Deva", "IN"),
+    ("dui", "Latn", "PG"),
+    ("duk", "Latn", "PG"),
+    ("dul", "Latn", "PH"),
+    ("dum", "Latn", "NL"),
+    ("dun", "Latn", "ID"),
+    ("duo", "Latn", "PH"),
+    ("dup", "Latn", "ID"),
+    ("duq", "Latn", "ID"),
+    ("dur", "Latn", "CM"),
+    ("dus", "Deva", "NP"),
+    ("duu", "Latn", "CN"),
+    ("duv", "Latn", "ID"),
+    ("duw", "Latn", "ID"),
+    ("dux", "Latn", "ML"),
+    ("duy", "Latn", "PH"),
+    ("duz", "Latn", "CM"),
+    ("dv", "Thaa", "MV"),
+    ("dva", "Latn", "PG"),
+    ("dwa", "Latn", "NG"),
+    ("dwk", "Orya", "IN"),
+    ("dwr", "Latn", "ET"),
+    ("dws", "Latn", "001"),
+    ("dwu", "Latn", "AU"),
+    ("dww", "Latn", "PG"),
+    ("dwy", "Latn", "AU"),
+    ("dwz", "Deva", "NP"),
+    ("dya", "Latn", "BF"),
+    ("dyb", "Latn", "AU"),
+    ("dyd", "Latn", "AU"),
+    ("dyg", "Latn", "PH"),
+    ("dyi", "Latn", "CI"),
+    ("dym", "Latn", "ML"),
+    ("dyn", "Latn", "AU"),
+    ("dyo", "Latn", "SN"),
+    ("dyr", "Latn", "NG"),
+    ("dyu", "Latn", "BF"),
+    ("dyy", "Latn", "AU"),
+    ("dz", "Tibt", "BT"),
+    ("dza", "Latn", "NG"),
+    ("dzd", "Latn", "NG"),
+    ("dze", "Latn", "AU"),
+    ("dzg", "Latn", "TD"),
+    ("dzl", "Tibt", "BT"),
+    ("dzn", "Latn", "CD"),
+    ("eaa", "Latn", "AU"),
+    ("ebc", "Latn", "ID"),
+    ("ebg", "Latn", "NG"),
+    ("ebk", "Latn", "PH"),
+    ("ebo", "Latn", "CG"),
+    ("ebr", "Latn", "CI"),
+    ("ebu", "Latn", "KE"),
+    ("ecr", "Grek", "GR"),
+    ("ecy", "Cprt", "CY"),
+    ("ee", "Latn", "GH"),
+    ("efa", "Latn", "NG"),
+    ("efe", "Latn", "CD"),
+    ("efi", "Latn", "NG"),
+    ("ega", "Latn", "CI"),
+    ("egl", "Latn", "IT"),
+    ("egm", "Latn", "TZ"),
+    ("ego", "Latn", "NG"),
+    ("egy", "Egyp", "EG"),
+    ("ehu", "Latn", "NG"),
+    ("eip", "Latn", "ID"),
+    ("eit", "Latn", "PG"),
+    ("eiv", "Latn", "PG"),
+    ("eja", "Latn", "GW"),
+    ("eka", "Latn", "NG"),
+    ("eke", "Latn", "NG"),
+    ("ekg", "Latn", "ID"),
+    ("eki", "Latn", "NG"),
+    ("ekl", "Latn", "BD"),
+    ("ekm", "Latn", "CM"),
+    ("eko", "Latn", "MZ"),
+    ("ekp", "Latn", "NG"),
+    ("ekr", "Latn", "NG"),
+    ("eky", "Kali", "MM"),
+    ("el", "Grek", "GR"),
+    ("ele", "Latn", "PG"),
+    ("elk", "Latn", "PG"),
+    ("elm", "Latn", "NG"),
+    ("elo", "Latn", "KE"),
+    ("elu", "Latn", "PG"),
+    ("ema", "Latn", "NG"),
+    ("emb", "Latn", "ID"),
+    ("eme", "Latn", "GF"),
+    ("emg", "Deva", "NP"),
+    ("emi", "Latn", "PG"),
+    ("emm", "Latn", "MX"),
+    ("emn", "Latn", "CM"),
+    ("emp", "Latn", "PA"),
+    ("ems", "Latn", "US"),
+    ("emu", "Deva", "IN"),
+    ("emw", "Latn", "ID"),
+    ("emx", "Latn", "FR"),
+    ("emz", "Latn", "CM"),
+    ("en", "Latn", "US"),
+    ("ena", "Latn", "PG"),
+    ("enb", "Latn", "KE"),
+    ("enc", "Latn", "VN"),
+    ("end", "Latn", "ID"),
+    ("enf", "Cyrl", "RU"),
+    ("enh", "Cyrl", "RU"),
+    ("enl", "Latn", "PY"),
+    ("enm", "Latn", "GB"),
+    ("enn", "Latn", "NG"),
+    ("eno", "Latn", "ID"),
+    ("enq", "Latn", "PG"),
+    ("enr", "Latn", "ID"),
+    ("env", "Latn", "NG"),
+    ("enw", "Latn", "NG"),
+    ("enx", "Latn", "PY"),
+    ("eo", "Latn", "001"),
+    ("eot", "Latn", "CI"),
+    ("epi", "Latn", "NG"),
+    ("era", "Taml", "IN"),
+    ("erg", "Latn", "VU"),
+    ("erh", "Latn", "NG"),
+    ("eri", "Latn", "PG"),
+    ("erk", "Latn", "VU"),
+    ("err", "Latn", "AU"),
+    ("ers", "Latn", "CN"),
+    ("ert", "Latn", "ID"),
+    ("erw", "Latn", "ID"),
+    ("es", "Latn", "ES"),
+    ("ese", "Latn", "BO"),
+    ("esg", "Gonm", "IN"),
+    ("esh", "Arab", "IR"),
+    ("esi", "Latn", "US"),
+    ("esm", "Latn", "CI"),
+    ("ess", "Latn", "US"),
+    ("esu", "Latn", "US"),
+    ("esy", "Latn", "PH"),
+    ("et", "Latn", "EE"),
+    ("etb", "Latn", "NG"),
+    ("etn", "Latn", "VU"),
+    ("eto", "Latn", "CM"),
+    ("etr", "Latn", "PG"),
+    ("ets", "Latn", "NG"),
+    ("ett", "Ital", "IT"),
+    ("etu", "Latn", "NG"),
+    ("etx", "Latn", "NG"),
+    ("etz", "Latn", "ID"),
+    ("eu", "Latn", "ES"),
+    ("eud", "Latn", "MX"),
+    ("eve", "Cyrl", "RU"),
+    ("evh", "Latn", "NG"),
+    ("evn", "Cyrl", "RU"),
+    ("ewo", "Latn", "CM"),
+    ("ext", "Latn", "ES"),
+    ("eya", "Latn", "US"),
+    ("eyo", "Latn", "KE"),
+    ("eza", "Latn", "NG"),
+    ("eze", "Latn", "NG"),
+    ("fa", "Arab", "IR"),
+    ("faa", "Latn", "PG"),
+    ("fab", "Latn", "GQ"),
+    ("fad", "Latn", "PG"),
+    ("faf", "Latn", "SB"),
+    ("fag", "Latn", "PG"),
+    ("fah", "Latn", "NG"),
+    ("fai", "Latn", "PG"),
+    ("faj", "Latn", "PG"),
+    ("fak", "Latn", "CM"),
+    ("fal", "Latn", "CM"),
+    ("fam", "Latn", "NG"),
+    ("fan", "Latn", "GQ"),
+    ("fap", "Latn", "SN"),
+    ("far", "Latn", "SB"),
+    ("fau", "Latn", "ID"),
+    ("fax", "Latn", "ES"),
+    ("fay", "Arab", "IR"),
+    ("faz", "Arab", "IR"),
+    ("fbl", "Latn", "PH"),
+    ("fer", "Latn", "SS"),
+    ("ff", "Latn", "SN"),
+    ("ffi", "Latn", "PG"),
+    ("ffm", "Latn", "ML"),
+    ("fgr", "Latn", "TD"),
+    ("fi", "Latn", "FI"),
+    ("fia", "Arab", "SD"),
+    ("fie", "Latn", "NG"),
+    ("fif", "Latn", "SA"),
+    ("fil", "Latn", "PH"),
+    ("fip", "Latn", "TZ"),
+    ("fir", "Latn", "NG"),
+    ("fit", "Latn", "SE"),
+    ("fiw", "Latn", "PG"),
+    ("fj", "Latn", "FJ"),
+    ("fkk", "Latn", "NG"),
+    ("fkv", "Latn", "NO"),
+    ("fla", "Latn", "US"),
+    ("flh", "Latn", "ID"),
+    ("fli", "Latn", "NG"),
+    ("fll", "Latn", "CM"),
+    ("fln", "Latn", "AU"),
+    ("flr", "Latn", "CD"),
+    ("fly", "Latn", "ZA"),
+    ("fmp", "Latn", "CM"),
+    ("fmu", "Deva", "IN"),
+    ("fnb", "Latn", "VU"),
+    ("fng", "Latn", "ZA"),
+    ("fni", "Latn", "TD"),
+    ("fo", "Latn", "FO"),
+    ("fod", "Latn", "BJ"),
+    ("foi", "Latn", "PG"),
+    ("fom", "Latn", "CD"),
+    ("fon", "Latn", "BJ"),
+    ("for", "Latn", "PG"),
+    ("fos", "Latn", "TW"),
+    ("fpe", "Latn", "GQ"),
+    ("fqs", "Latn", "PG"),
+    ("fr", "Latn", "FR"),
+    ("frc", "Latn", "US"),
+    ("frd", "Latn", "ID"),
+    ("frk", "Latn", "DE"),
+    ("frm", "Latn", "FR"),
+    ("fro", "Latn", "FR"),
+    ("frp", "Latn", "FR"),
+    ("frq", "Latn", "PG"),
+    ("frr", "Latn", "DE"),
+    ("frs", "Latn", "DE"),
+    ("frt", "Latn", "VU"),
+    ("fub", "Arab", "CM"),
+    ("fud", "Latn", "WF"),
+    ("fue", "Latn", "BJ"),
+    ("fuf", "Latn", "GN"),
+    ("fuh", "Latn", "NE"),
+    ("fui", "Latn", "TD"),
+    ("fum", "Latn", "NG"),
+    ("fun", "Latn", "BR"),
+    ("fuq", "Latn", "NE"),
+    ("fur", "Latn", "IT"),
+    ("fut", "Latn", "VU"),
+    ("fuu", "Latn", "CD"),
+    ("fuv", "Latn", "NG"),
+    ("fuy", "Latn", "PG"),
+    ("fvr", "Latn", "SD"),
+    ("fwa", "Latn", "NC"),
+    ("fwe", "Latn", "NA"),
+    ("fy", "Latn", "NL"),
+    ("ga", "Latn", "IE"),
+    ("gaa", "Latn", "GH"),
+    ("gab", "Latn", "TD"),
+    ("gac", "Latn", "IN"),
+    ("gad", "Latn", "PH"),
+    ("gae", "Latn", "VE"),
+    ("gaf", "Latn", "PG"),
+    ("gag", "Latn", "MD"),
+    ("gah", "Latn", "PG"),
+    ("gai", "Latn", "PG"),
+    ("gaj", "Latn", "PG"),
+    ("gak", "Latn", "ID"),
+    ("gal", "Latn", "TL"),
+    ("gam", "Latn", "PG"),
+    ("gan", "Hans", "CN"),
+    ("gao", "Latn", "PG"),
+    ("gap", "Latn", "PG"),
+    ("gaq", "Orya", "IN"),
+    ("gar", "Latn", "PG"),
+    ("gas", "Gujr", "IN"),
+    ("gat", "Latn", "PG"),
+    ("gau", "Telu", "IN"),
+    ("gaw", "Latn", "PG"),
+    ("gax", "Latn", "ET"),
+    ("gay", "Latn", "ID"),
+    ("gba", "Latn", "CF"),
+    ("gbb", "Latn", "AU"),
+    ("gbd", "Latn", "AU"),
+    ("gbe", "Latn", "PG"),
+    ("gbf", "Latn", "PG"),
+    ("gbg", "Latn", "CF"),
+    ("gbh", "Latn", "BJ"),
+    ("gbi", "Latn", "ID"),
+    ("gbj", "Orya", "IN"),
+    ("gbk", "Deva", "IN"),
+    ("gbl", "Gujr", "IN"),
+    ("gbm", "Deva", "IN"),
+    ("gbn", "Latn", "SS"),
+    ("gbp", "Latn", "CF"),
+    ("gbq", "Latn", "CF"),
+    ("gbr", "Latn", "NG"),
+    ("gbs", "Latn", "BJ"),
+    ("gbu", "Latn", "AU"),
+    ("gbv", "Latn", "CF"),
+    ("gbw", "Latn", "AU"),
+    ("gbx", "Latn", "BJ"),
+    ("gby", "Latn", "NG"),
+    ("gbz", "Arab", "IR"),
+    ("gcc", "Latn", "PG"),
+    ("gcd", "Latn", "AU"),
+    ("gcf", "Latn", "GP"),
+    ("gcl", "Latn", "GD"),
+    ("gcn", "Latn", "PG"),
+    ("gcr", "Latn", "GF"),
+    ("gct", "Latn", "VE"),
+    ("gd", "Latn", "GB"),
+    ("gdb", "Orya", "IN"),
+    ("gdc", "Latn", "AU"),
+    ("gdd", "Latn", "PG"),
+    ("gde", "Latn", "NG"),
+    ("gdf", "Latn", "NG"),
+    ("gdg", "Latn", "PH"),
+    ("gdh", "Latn", "AU"),
+    ("gdi", "Latn", "CF"),
+    ("gdj", "Latn", "AU"),
+    ("gdk", "Latn", "TD"),
+    ("gdl", "Latn", "ET"),
+    ("gdm", "Latn", "TD"),
+    ("gdn", "Latn", "PG"),
+    ("gdo", "Cyrl", "RU"),
+    ("gdq", "Latn", "YE"),
+    ("gdr", "Latn", "PG"),
+    ("gdt", "Latn", "AU"),
+    ("gdu", "Latn", "NG"),
+    ("gdx", "Deva", "IN"),
+    ("gea", "Latn", "NG"),
+    ("geb", "Latn", "PG"),
+    ("gec", "Latn", "LR"),
+    ("ged", "Latn", "NG"),
+    ("gef", "Latn", "ID"),
+    ("geg", "Latn", "NG"),
+    ("geh", "Latn", "CA"),
+    ("gei", "Latn", "ID"),
+    ("gej", "Latn", "TG"),
+    ("gek", "Latn", "NG"),
+    ("gel", "Latn", "NG"),
+    ("geq", "Latn", "CF"),
+    ("ges", "Latn", "ID"),
+    ("gev", "Latn", "GA"),
+    ("gew", "Latn", "NG"),
+    ("gex", "Latn", "SO"),
+    ("gey", "Latn", "CD"),
+    ("gez", "Ethi", "ET"),
+    ("gfk", "Latn", "PG"),
+    ("gga", "Latn", "SB"),
+    ("ggb", "Latn", "LR"),
+    ("ggd", "Latn", "AU"),
+    ("gge", "Latn", "AU"),
+    ("ggg", "Arab", "PK"),
+    ("ggk", "Latn", "AU"),
+    ("ggl", "Latn", "PG"),
+    ("ggt", "Latn", "PG"),
+    ("ggu", "Latn", "CI"),
+    ("ggw", "Latn", "PG"),
+    ("gha", "Arab", "LY"),
+    ("ghc", "Latn", "GB"),
+    ("ghe", "Deva", "NP"),
+    ("ghk", "Latn", "MM"),
+    ("ghn", "Latn", "SB"),
+    ("gho", "Tfng", "MA"),
+    ("ghr", "Arab", "PK"),
+    ("ghs", "Latn", "PG"),
+    ("ght", "Tibt", "NP"),
+    ("gia", "Latn", "AU"),
+    ("gib", "Latn", "NG"),
+    ("gic", "Latn", "ZA"),
+    ("gid", "Latn", "CM"),
+    ("gie", "Latn", "CI"),
+    ("gig", "Arab", "PK"),
+    ("gih", "Latn", "AU"),
+    ("gil", "Latn", "KI"),
+    ("gim", "Latn", "PG"),
+    ("gin", "Cyrl", "RU"),
+    ("gip", "Latn", "PG"),
+    ("giq", "Latn", "VN"),
+    ("gir", "Latn", "VN"),
+    ("gis", "Latn", "CM"),
+    ("git", "Latn", "CA"),
+    ("gix", "Latn", "CD"),
+    ("giy", "Latn", "AU"),
+    ("giz", "Latn", "CM"),
+    ("gjk", "Arab", "PK"),
+    ("gjm", "Latn", "AU"),
+    ("gjn", "Latn", "GH"),
+    ("gjr", "Latn", "AU"),
+    ("gju", "Arab", "PK"),
+    ("gka", "Latn", "PG"),
+    ("gkd", "Latn", "PG"),
+    ("gke", "Latn", "CM"),
+    ("gkn", "Latn", "NG"),
+    ("gko", "Latn", "AU"),
+    ("gkp", "Latn", "GN"),
+    ("gku", "Latn", "ZA"),
+    ("gl", "Latn", "ES"),
+    ("glb", "Latn", "NG"),
+    ("glc", "Latn", "TD"),
+    ("gld", "Cyrl", "RU"),
+    ("glh", "Arab", "AF"),
+    ("glj", "Latn", "TD"),
+    ("glk", "Arab", "IR"),
+    ("gll", "Latn", "AU"),
+    ("glo", "Latn", "NG"),
+    ("glr", "Latn", "LR"),
+    ("glu", "Latn", "TD"),
+    ("glw", "Latn", "NG"),
+    ("gma", "Latn", "AU"),
+    ("gmb", "Latn", "SB"),
+    ("gmd", "Latn", "NG"),
+    ("gmg", "Latn", "PG"),
+    ("gmh", "Latn", "DE"),
+    ("gml", "Latf", "DE"),
+    ("gmm", "Latn", "CM"),
+    ("gmn", "Latn", "CM"),
+    ("gmr", "Latn", "AU"),
+    ("gmu", "Latn", "PG"),
+    ("gmv", "Ethi", "ET"),
+    ("gmx", "Latn", "TZ"),
+    ("gmy", "Linb", "GR"),
+    ("gmz", "Latn", "NG"),
+    ("gn", "Latn", "PY"),
+    ("gna", "Latn", "BF"),
+    ("gnb", "Latn", "IN"),
+    ("gnc", "Latn", "ES"),
+    ("gnd", "Latn", "CM"),
+    ("gne", "Latn", "NG"),
+    ("gng", "Latn", "TG"),
+    ("gnh", "Latn", "NG"),
+    ("gni", "Latn", "AU"),
+    ("gnj", "Latn", "CI"),
+    ("gnk", "Latn", "BW"),
+    ("gnl", "Latn", "AU"),
+    ("gnm", "Latn", "PG"),
+    ("gnn", "Latn", "AU"),
+    ("gnq", "Latn", "MY"),
+    ("gnr", "Latn", "AU"),
+    ("gnt", "Latn", "PG"),
+    ("gnu", "Latn", "PG"),
+    ("gnw", "Latn", "BO"),
+    ("gnz", "Latn", "CF"),
+    ("goa", "Latn", "CI"),
+    ("gob", "Latn", "CO"),
+    ("goc", "Latn", "PG"),
+    ("god", "Latn", "CI"),
+    ("goe", "Tibt", "BT"),
+    ("gof", "Ethi", "ET"),
+    ("gog", "Latn", "TZ"),
+    ("goh", "Latn", "DE"),
+    ("goi", "Latn", "PG"),
+    ("goj", "Deva", "IN"),
+    ("gok", "Deva", "IN"),
+    ("gol", "Latn", "LR"),
+    ("gon", "Deva", "IN"),
+    ("goo", "Latn", "FJ"),
+    ("gop", "Latn", "ID"),
+    ("goq", "Latn", "ID"),
+    ("gor", "Latn", "ID"),
+    ("gos", "Latn", "NL"),
+    ("got", "Goth", "UA"),
+    ("gou", "Latn", "CM"),
+    ("gov", "Latn", "CI"),
+    ("gow", "Latn", "TZ"),
+    ("gox", "Latn", "CD"),
+    ("goy", "Latn", "TD"),
+    ("gpa", "Latn", "NG"),
+    ("gpe", "Latn", "GH"),
+    ("gpn", "Latn", "PG"),
+    ("gqa", "Latn", "NG"),
+    ("gqn", "Latn", "BR"),
+    ("gqr", "Latn", "TD"),
+    ("gra", "Deva", "IN"),
+    ("grb", "Latn", "LR"),
+    ("grc", "Grek", "GR"),
+    ("grd", "Latn", "NG"),
+    ("grg", "Latn", "PG"),
+    ("grh", "Latn", "NG"),
+    ("gri", "Latn", "SB"),
+    ("grj", "Latn", "LR"),
+    ("grm", "Latn", "MY"),
+    ("grq", "Latn", "PG"),
+    ("grr", "Arab", "DZ"),
+    ("grs", "Latn", "ID"),
+    ("grt", "Beng", "IN"),
+    ("gru", "Ethi", "ET"),
+    ("grv", "Latn", "LR"),
+    ("grw", "Latn", "PG"),
+    ("grx", "Latn", "PG"),
+    ("gry", "Latn", "LR"),
+    ("grz", "Latn", "PG"),
+    ("gsl", "Latn", "SN"),
+    ("gsn", "Latn", "PG"),
+    ("gso", "Latn", "CF"),
+    ("gsp", "Latn", "PG"),
+    ("gsw", "Latn", "CH"),
+    ("gta", "Latn", "BR"),
+    ("gtu", "Latn", "AU"),
+    ("gu", "Gujr", "IN"),
+    ("gua", "Latn", "NG"),
+    ("gub", "Latn", "BR"),
+    ("guc", "Latn", "CO"),
+    ("gud", "Latn", "CI"),
+    ("gue", "Latn", "AU"),
+    ("guf", "Latn", "AU"),
+    ("guh", "Latn", "CO"),
+    ("gui", "Latn", "BO"),
+    ("guk", "Latn", "ET"),
+    ("gul", "Latn", "US"),
+    ("gum", "Latn", "CO"),
+    ("gun", "Latn", "BR"),
+    ("guo", "Latn", "CO"),
+    ("gup", "Latn", "AU"),
+    ("guq", "Latn", "PY"),
+    ("gur", "Latn", "GH"),
+    ("gut", "Latn", "CR"),
+    ("guu", "Latn", "VE"),
+    ("guw", "Latn", "BJ"),
+    ("gux", "Latn", "BF"),
+    ("guz", "Latn", "KE"),
+    ("gv", "Latn", "IM"),
+    ("gva", "Latn", "PY"),
+    ("gvc", "Latn", "BR"),
+    ("gve", "Latn", "PG"),
+    ("gvf", "Latn", "PG"),
+    ("gvj", "Latn", "BR"),
+    ("gvl", "Latn", "TD"),
+    ("gvm", "Latn", "NG"),
+    ("gvn", "Latn", "AU"),
+    ("gvo", "Latn", "BR"),
+    ("gvp", "Latn", "BR"),
+    ("gvr", "Deva", "NP"),
+    ("gvs", "Latn", "PG"),
+    ("gvy", "Latn", "AU"),
+    ("gwa", "Latn", "CI"),
+    ("gwb", "Latn", "NG"),
+    ("gwc", "Arab", "PK"),
+    ("gwd", "Latn", "ET"),
+    ("gwe", "Latn", "TZ"),
+    ("gwf", "Arab", "PK"),
+    ("gwg", "Latn", "NG"),
+    ("gwi", "Latn", "CA"),
+    ("gwj", "Latn", "BW"),
+    ("gwm", "Latn", "AU"),
+    ("gwn", "Latn", "NG"),
+    ("gwr", "Latn", "UG"),
+    ("gwt", "Arab", "AF"),
+    ("gwu", "Latn", "AU"),
+    ("gww", "Latn", "AU"),
+    ("gwx", "Latn", "GH"),
+    ("gxx", "Latn", "CI"),
+    ("gyb", "Latn", "PG"),
+    ("gyd", "Latn", "AU"),
+    ("gye", "Latn", "NG"),
+    ("gyf", "Latn", "AU"),
+    ("gyg", "Latn", "CF"),
+    ("gyi", "Latn", "CM"),
+    ("gyl", "Latn", "ET"),
+    ("gym", "Latn", "PA"),
+    ("gyn", "Latn", "GY"),
+    ("gyo", "Deva", "NP"),
+    ("gyr", "Latn", "BO"),
+    ("gyy", "Latn", "AU"),
+    ("gyz", "Latn", "NG"),
+    ("gza", "Latn", "SD"),
+    ("gzi", "Arab", "IR"),
+    ("gzn", "Latn", "ID"),
+    ("ha", "Latn", "NG"),
+    ("haa", "Latn", "US"),
+    ("hac", "Arab", "IR"),
+    ("had", "Latn", "ID"),
+    ("hae", "Latn", "ET"),
+    ("hag", "Latn", "GH"),
+    ("hah", "Latn", "PG"),
+    ("hai", "Latn", "CA"),
+    ("haj", "Latn", "IN"),
+    ("hak", "Hans", "CN"),
+    ("hal", "Latn", "VN"),
+    ("ham", "Latn", "PG"),
+    ("han", "Latn", "TZ"),
+    ("hao", "Latn", "PG"),
+    ("hap", "Latn", "ID"),
+    ("haq", "Latn", "TZ"),
+    ("har", "Ethi", "ET"),
+    ("has", "Latn", "CA"),
+    ("hav", "Latn", "CD"),
+    ("haw", "Latn", "US"),
+    ("hax", "Latn", "CA"),
+    ("hay", "Latn", "TZ"),
+    ("haz", "Arab", "AF"),
+    ("hba", "Latn", "CD"),
+    ("hbb", "Latn", "NG"),
+    ("hbn", "Latn", "SD"),
+    ("hbo", "Hebr", "IL"),
+    ("hbu", "Latn", "TL"),
+    ("hch", "Latn", "MX"),
+    ("hdy", "Ethi", "ET"),
+    ("he", "Hebr", "IL"),
+    ("hed", "Latn", "TD"),
+    ("heg", "Latn", "ID"),
+    ("heh", "Latn", "TZ"),
+    ("hei", "Latn", "CA"),
+    ("hem", "Latn", "CD"),
+    ("hgm", "Latn", "NA"),
+    ("hgw", "Latn", "PG"),
+    ("hhi", "Latn", "PG"),
+    ("hhr", "Latn", "SN"),
+    ("hhy", "Latn", "PG"),
+    ("hi", "Deva", "IN"),
+    ("hia", "Latn", "NG"),
+    ("hib", "Latn", "PE"),
+    ("hid", "Latn", "US"),
+    ("hif", "Deva", "FJ"),
+    ("hig", "Latn", "NG"),
+    ("hih", "Latn", "PG"),
+    ("hii", "Takr", "IN"),
+    ("hij", "Latn", "CM"),
+    ("hik", "Latn", "ID"),
+    ("hil", "Latn", "PH"),
+    ("hio", "Latn", "BW"),
+    ("hir", "Latn", "BR"),
+    ("hit", "Xsux", "TR"),
+    ("hiw", "Latn", "VU"),
+    ("hix", "Latn", "BR"),
+    ("hji", "Latn", "ID"),
+    ("hka", "Latn", "TZ"),
+    ("hke", "Latn", "CD"),
+    ("hkh", "Arab", "IN"),
+    ("hkk", "Latn", "PG"),
+    ("hla", "Latn", "PG"),
+    ("hlb", "Deva", "IN"),
+    ("hld", "Latn", "VN"),
+    ("hlt", "Latn", "MM"),
+    ("hlu", "Hluw", "TR"),
+    ("hma", "Latn", "CN"),
+    ("hmb", "Latn", "ML"),
+    ("hmd", "Plrd", "CN"),
+    ("hmf", "Latn", "VN"),
+    ("hmj", "Bopo", "CN"),
+    ("hmm", "Latn", "CN"),
+    ("hmn", "Latn", "CN"),
+    ("hmp", "Latn", "CN"),
+    ("hmq", "Bopo", "CN"),
+    ("hmr", "Latn", "IN"),
+    ("hms", "Latn", "CN"),
+    ("hmt", "Latn", "PG"),
+    ("hmu", "Latn", "ID"),
+    ("hmv", "Latn", "VN"),
+    ("hmw", "Latn", "CN"),
+    ("hmy", "Latn", "CN"),
+    ("hmz", "Latn", "CN"),
+    ("hna", "Latn", "CM"),
+    ("hnd", "Arab", "PK"),
+    ("hne", "Deva", "IN"),
+    ("hng", "Latn", "AO"),
+    ("hnh", "Latn", "BW"),
+    ("hni", "Latn", "CN"),
+    ("hnj", "Hmnp", "US"),
+    ("hnm", "Hans", "CN"),
+    ("hnn", "Latn", "PH"),
+    ("hno", "Arab", "PK"),
+    ("hns", "Latn", "SR"),
+    ("ho", "Latn", "PG"),
+    ("hoa", "Latn", "SB"),
+    ("hob", "Latn", "PG"),
+    ("hoc", "Deva", "IN"),
+    ("hod", "Latn", "NG"),
+    ("hoe", "Latn", "NG"),
+    ("hoh", "Arab", "OM"),
+    ("hoi", "Latn", "US"),
+    ("hoj", "Deva", "IN"),
+    ("hol", "Latn", "AO"),
+    ("hom", "Latn", "SS"),
+    ("hoo", "Latn", "CD"),
+    ("hop", "Latn", "US"),
+    ("hor", "Latn", "TD"),
+    ("hot", "Latn", "PG"),
+    ("hov", "Latn", "ID"),
+    ("how", "Hani", "CN"),
+    ("hoy", "Deva", "IN"),
+    ("hpo", "Mymr", "MM"),
+    ("hr", "Latn", "HR"),
+    ("hra", "Latn", "IN"),
+    ("hrc", "Latn", "PG"),
+    ("hre", "Latn", "VN"),
+    ("hrk", "Latn", "ID"),
+    ("hrm", "Latn", "CN"),
+    ("hro", "Latn", "VN"),
+    ("hrp", "Latn", "AU"),
+    ("hrt", "Syrc", "TR"),
+    ("hru", "Latn", "IN"),
+    ("hrw", "Latn", "PG"),
+    ("hrx", "Latn", "BR"),
+    ("hrz", "Arab", "IR"),
+    ("hsb", "Latn", "DE"),
+    ("hsn", "Hans", "CN"),
+    ("hss", "Arab", "OM"),
+    ("ht", "Latn", "HT"),
+    ("hti", "Latn", "ID"),
+    ("hto", "Latn", "CO"),
+    ("hts", "Latn", "TZ"),
+    ("htu", "Latn", "ID"),
+    ("htx", "Xsux", "TR"),
+    ("hu", "Latn", "HU"),
+    ("hub", "Latn", "PE"),
+    ("huc", "Latn", "BW"),
+    ("hud", "Latn", "ID"),
+    ("hue", "Latn", "MX"),
+    ("huf", "Latn", "PG"),
+    ("hug", "Latn", "PE"),
+    ("huh", "Latn", "CL"),
+    ("hui", "Latn", "PG"),
+    ("huk", "Latn", "ID"),
+    ("hul", "Latn", "PG"),
+    ("hum", "Latn", "CD"),
+    ("hup", "Latn", "US"),
+    ("hur", "Latn", "CA"),
+    ("hus", "Latn", "MX"),
+    ("hut", "Deva", "NP"),
+    ("huu", "Latn", "PE"),
+    ("huv", "Latn", "MX"),
+    ("huw", "Latn", "ID"),
+    ("hux", "Latn", "PE"),
+    ("huy", "Hebr", "IL"),
+    ("huz", "Cyrl", "RU"),
+    ("hvc", "Latn", "HT"),
+    ("hve", "Latn", "MX"),
+    ("hvk", "Latn", "NC"),
+    ("hvn", "Latn", "ID"),
+    ("hvv", "Latn", "MX"),
+    ("hwa", "Latn", "CI"),
+    ("hwc", "Latn", "US"),
+    ("hwo", "Latn", "NG"),
+    ("hy", "Armn", "AM"),
+    ("hya", "Latn", "CM"),
+    ("hyw", "Armn", "AM"),
+    ("hz", "Latn", "NA"),
+    ("ia", "Latn", "001"),
+    ("iai", "Latn", "NC"),
+    ("ian", "Latn", "PG"),
+    ("iar", "Latn", "PG"),
+    ("iba", "Latn", "MY"),
+    ("ibb", "Latn", "NG"),
+    ("ibd", "Latn", "AU"),
+    ("ibe", "Latn", "NG"),
+    ("ibg", "Latn", "PH"),
+    ("ibh", "Latn", "VN"),
+    ("ibl", "Latn", "PH"),
+    ("ibm", "Latn", "NG"),
+    ("ibn", "Latn", "NG"),
+    ("ibr", "Latn", "NG"),
+    ("ibu", "Latn", "ID"),
+    ("iby", "Latn", "NG"),
+    ("ica", "Latn", "BJ"),
+    ("ich", "Latn", "NG"),
+    ("icr", "Latn", "CO"),
+    ("id", "Latn", "ID"),
+    ("ida", "Latn", "KE"),
+    ("idb", "Latn", "IN"),
+    ("idc", "Latn", "NG"),
+    ("idd", "Latn", "BJ"),
+    ("ide", "Latn", "NG"),
+    ("idi", "Latn", "PG"),
+    ("idr", "Latn", "SS"),
+    ("ids", "Latn", "NG"),
+    ("idt", "Latn", "TL"),
+    ("idu", "Latn", "NG"),
+    ("ie", "Latn", "EE"),
+    ("ifa", "Latn", "PH"),
+    ("ifb", "Latn", "PH"),
+    ("ife", "Latn", "TG"),
+    ("iff", "Latn", "VU"),
+    ("ifk", "Latn", "PH"),
+    ("ifm", "Latn", "CG"),
+    ("ifu", "Latn", "PH"),
+    ("ify", "Latn", "PH"),
+    ("ig", "Latn", "NG"),
+    ("igb", "Latn", "NG"),
+    ("ige", "Latn", "NG"),
+    ("igg", "Latn", "PG"),
+    ("igl", "Latn", "NG"),
+    ("igm", "Latn", "PG"),
+    ("ign", "Latn", "BO"),
+    ("igo", "Latn", "PG"),
+    ("igs", "Latn", "001"),
+    ("igw", "Latn", "NG"),
+    ("ihb", "Latn", "ID"),
+    ("ihi", "Latn", "NG"),
+    ("ihp", "Latn", "ID"),
+    ("ihw", "Latn", "AU"),
+    ("ii", "Yiii", "CN"),
+    ("iin", "Latn", "AU"),
+    ("ijc", "Latn", "NG"),
+    ("ije", "Latn", "NG"),
+    ("ijj", "Latn", "BJ"),
+    ("ijn", "Latn", "NG"),
+    ("ijs", "Latn", "NG"),
+    ("ik", "Latn", "US"),
+    ("ikh", "Latn", "NG"),
+    ("iki", "Latn", "NG"),
+    ("ikk", "Latn", "NG"),
+    ("ikl", "Latn", "NG"),
+    ("iko", "Latn", "NG"),
+    ("ikp", "Latn", "NG"),
+    ("ikr", "Latn", "AU"),
+    ("ikt", "Latn", "CA"),
+    ("ikv", "Latn", "NG"),
+    ("ikw", "Latn", "NG"),
+    ("ikx", "Latn", "UG"),
+    ("ikz", "Latn", "TZ"),
+    ("ila", "Latn", "ID"),
+    ("ilb", "Latn", "ZM"),
+    ("ilg", "Latn", "AU"),
+    ("ili", "Latn", "CN"),
+    ("ilk", "Latn", "PH"),
+    ("ilm", "Latn", "MY"),
+    ("ilo", "Latn", "PH"),
+    ("ilp", "Latn", "PH"),
+    ("ilu", "Latn", "ID"),
+    ("ilv", "Latn", "NG"),
+    ("imi", "Latn", "PG"),
+    ("iml", "Latn", "US"),
+    ("imn", "Latn", "PG"),
+    ("imo", "Latn", "PG"),
+    ("imr", "Latn", "ID"),
+    ("ims", "Latn", "IT"),
+    ("imt", "Latn", "SS"),
+    ("imy", "Lyci", "TR"),
+    ("in", "Latn", "ID"),
+    ("inb", "Latn", "CO"),
+    ("ing", "Latn", "US"),
+    ("inh", "Cyrl", "RU"),
+    ("inj", "Latn", "CO"),
+    ("inn", "Latn", "PH"),
+    ("ino", "Latn", "PG"),
+    ("inp", "Latn", "PE"),
+    ("int", "Mymr", "MM"),
+    ("io", "Latn", "001"),
+    ("ior", "Ethi", "ET"),
+    ("iou", "Latn", "PG"),
+    ("iow", "Latn", "US"),
+    ("ipi", "Latn", "PG"),
+    ("ipo", "Latn", "PG"),
+    ("iqu", "Latn", "PE"),
+    ("iqw", "Latn", "NG"),
+    ("ire", "Latn", "ID"),
+    ("irh", "Latn", "ID"),
+    ("iri", "Latn", "NG"),
+    ("irk", "Latn", "TZ"),
+    ("irn", "Latn", "BR"),
+    ("iru", "Taml", "IN"),
+    ("irx", "Latn", "ID"),
+    ("iry", "Latn", "PH"),
+    ("is", "Latn", "IS"),
+    ("isa", "Latn", "PG"),
+    ("isc", "Latn", "PE"),
+    ("isd", "Latn", "PH"),
+    ("ish", "Latn", "NG"),
+    ("isi", "Latn", "NG"),
+    ("isk", "Arab", "AF"),
+    ("ism", "Latn", "ID"),
+    ("isn", "Latn", "TZ"),
+    ("iso", "Latn", "NG"),
+    ("ist", "Latn", "HR"),
+    ("isu", "Latn", "CM"),
+    ("it", "Latn", "IT"),
+    ("itb", "Latn", "PH"),
+    ("itd", "Latn", "ID"),
+    ("ite", "Latn", "BO"),
+    ("iti", "Latn", "PH"),
+    ("itk", "Hebr", "IT"),
+    ("itl", "Cyrl", "RU"),
+    ("itm", "Latn", "NG"),
+    ("ito", "Latn", "BO"),
+    ("itr", "Latn", "PG"),
+    ("its", "Latn", "NG"),
+    ("itt", "Latn", "PH"),
+    ("itv", "Latn", "PH"),
+    ("itw", "Latn", "NG"),
+    ("itx", "Latn", "ID"),
+    ("ity", "Latn", "PH"),
+    ("itz", "Latn", "GT"),
+    ("iu", "Cans", "CA"),
+    ("ium", "Latn", "CN"),
+    ("ivb", "Latn", "PH"),
+    ("ivv", "Latn", "PH"),
+    ("iw", "Hebr", "IL"),
+    ("iwk", "Latn", "PH"),
+    ("iwm", "Latn", "PG"),
+    ("iwo", "Latn", "ID"),
+    ("iws", "Latn", "PG"),
+    ("ixc", "Latn", "MX"),
+    ("ixl", "Latn", "GT"),
+    ("iya", "Latn", "NG"),
+    ("iyo", "Latn", "CM"),
+    ("iyx", "Latn", "CG"),
+    ("izh", "Latn", "RU"),
+    ("izm", "Latn", "NG"),
+    ("izr", "Latn", "NG"),
+    ("izz", "Latn", "NG"),
+    ("ja", "Jpan", "JP"),
+    ("jaa", "Latn", "BR"),
+    ("jab", "Latn", "NG"),
+    ("jac", "Latn", "GT"),
+    ("jad", "Arab", "GN"),
+    ("jae", "Latn", "PG"),
+    ("jaf", "Latn", "NG"),
+    ("jah", "Latn", "MY"),
+    ("jaj", "Latn", "SB"),
+    ("jak", "Latn", "MY"),
+    ("jal", "Latn", "ID"),
+    ("jam", "Latn", "JM"),
+    ("jan", "Latn", "AU"),
+    ("jao", "Latn", "AU"),
+    ("jaq", "Latn", "ID"),
+    ("jas", "Latn", "NC"),
+    ("jat", "Arab", "AF"),
+    ("jau", "Latn", "ID"),
+    ("jax", "Latn", "ID"),
+    ("jay", "Latn", "AU"),
+    ("jaz", "Latn", "NC"),
+    ("jbe", "Hebr", "IL"),
+    ("jbi", "Latn", "AU"),
+    ("jbj", "Latn", "ID"),
+    ("jbk", "Latn", "PG"),
+    ("jbm", "Latn", "NG"),
+    ("jbn", "Arab", "LY"),
+    ("jbo", "Latn", "001"),
+    ("jbr", "Latn", "ID"),
+    ("jbt", "Latn", "BR"),
+    ("jbu", "Latn", "CM"),
+    ("jbw", "Latn", "AU"),
+    ("jct", "Cyrl", "UA"),
+    ("jda", "Tibt", "IN"),
+    ("jdg", "Arab", "PK"),
+    ("jdt", "Cyrl", "RU"),
+    ("jeb", "Latn", "PE"),
+    ("jee", "Deva", "NP"),
+    ("jeh", "Latn", "VN"),
+    ("jei", "Latn", "ID"),
+    ("jek", "Latn", "CI"),
+    ("jel", "Latn", "ID"),
+    ("jen", "Latn", "NG"),
+    ("jer", "Latn", "NG"),
+    ("jet", "Latn", "PG"),
+    ("jeu", "Latn", "TD"),
+    ("jgb", "Latn", "CD"),
+    ("jge", "Geor", "GE"),
+    ("jgk", "Latn", "NG"),
+    ("jgo", "Latn", "CM"),
+    ("jhi", "Latn", "MY"),
+    ("ji", "Hebr", "UA"),
+    ("jia", "Latn", "CM"),
+    ("jib", "Latn", "NG"),
+    ("jic", "Latn", "HN"),
+    ("jid", "Latn", "NG"),
+    ("jie", "Latn", "NG"),
+    ("jig", "Latn", "AU"),
+    ("jil", "Latn", "PG"),
+    ("jim", "Latn", "CM"),
+    ("jit", "Latn", "TZ"),
+    ("jiu", "Latn", "CN"),
+    ("jiv", "Latn", "EC"),
+    ("jiy", "Latn", "CN"),
+    ("jje", "Hang", "KR"),
+    ("jjr", "Latn", "NG"),
+    ("jka", "Latn", "ID"),
+    ("jkm", "Mymr", "MM"),
+    ("jko", "Latn", "PG"),
+    ("jku", "Latn", "NG"),
+    ("jle", "Latn", "SD"),
+    ("jma", "Latn", "PG"),
+    ("jmb", "Latn", "NG"),
+    ("jmc", "Latn", "TZ"),
+    ("jmd", "Latn", "ID"),
+    ("jmi", "Latn", "NG"),
+    ("jml", "Deva", "NP"),
+    ("jmn", "Latn", "MM"),
+    ("jmr", "Latn", "GH"),
+    ("jms", "Latn", "NG"),
+    ("jmw", "Latn", "PG"),
+    ("jmx", "Latn", "MX"),
+    ("jna", "Takr", "IN"),
+    ("jnd", "Arab", "PK"),
+    ("jng", "Latn", "AU"),
+    ("jni", "Latn", "NG"),
+    ("jnj", "Latn", "ET"),
+    ("jnl", "Deva", "IN"),
+    ("jns", "Deva", "IN"),
+    ("job", "Latn", "CD"),
+    ("jod", "Latn", "CI"),
+    ("jog", "Arab", "PK"),
+    ("jor", "Latn", "BO"),
+    ("jow", "Latn", "ML"),
+    ("jpa", "Hebr", "PS"),
+    ("jpr", "Hebr", "IL"),
+    ("jqr", "Latn", "PE"),
+    ("jra", "Latn", "VN"),
+    ("jrb", "Hebr", "IL"),
+    ("jrr", "Latn", "NG"),
+    ("jrt", "Latn", "NG"),
+    ("jru", "Latn", "VE"),
+    ("jua", "Latn", "BR"),
+    ("jub", "Latn", "NG"),
+    ("jud", "Latn", "CI"),
+    ("juh", "Latn", "NG"),
+    ("jui", "Latn", "AU"),
+    ("juk", "Latn", "NG"),
+    ("jul", "Deva", "NP"),
+    ("jum", "Latn", "SD"),
+    ("jun", "Orya", "IN"),
+    ("juo", "Latn", "NG"),
+    ("jup", "Latn", "BR"),
+    ("jur", "Latn", "BR"),
+    ("jut", "Latn", "DK"),
+    ("juu", "Latn", "NG"),
+    ("juw", "Latn", "NG"),
+    ("juy", "Orya", "IN"),
+    ("jv", "Latn", "ID"),
+    ("jvd", "Latn", "ID"),
+    ("jvn", "Latn", "SR"),
+    ("jw", "Latn", "ID"),
+    ("jwi", "Latn", "GH"),
+    ("jya", "Tibt", "CN"),
+    ("jye", "Hebr", "IL"),
+    ("jyy", "Latn", "TD"),
+    ("ka", "Geor", "GE"),
+    ("kaa", "Cyrl", "UZ"),
+    ("kab", "Latn", "DZ"),
+    ("kac", "Latn", "MM"),
+    ("kad", "Latn", "NG"),
+    ("kag", "Latn", "MY"),
+    ("kah", "Latn", "CF"),
+    ("kai", "Latn", "NG"),
+    ("kaj", "Latn", "NG"),
+    ("kak", "Latn", "PH"),
+    ("kam", "Latn", "KE"),
+    ("kao", "Latn", "ML"),
+    ("kap", "Cyrl", "RU"),
+    ("kaq", "Latn", "PE"),
+    ("kav", "Latn", "BR"),
+    ("kaw", "Bali", "ID"),
+    ("kax", "Latn", "ID"),
+    ("kay", "Latn", "BR"),
+    ("kba", "Latn", "AU"),
+    ("kbb", "Latn", "BR"),
+    ("kbc", "Latn", "BR"),
+    ("kbd", "Cyrl", "RU"),
+    ("kbe", "Latn", "AU"),
+    ("kbg", "Tibt", "IN"),
+    ("kbh", "Latn", "CO"),
+    ("kbi", "Latn", "ID"),
+    ("kbj", "Latn", "CD"),
+    ("kbk", "Latn", "PG"),
+    ("kbl", "Latn", "TD"),
+    ("kbm", "Latn", "PG"),
+    ("kbn", "Latn", "CF"),
+    ("kbo", "Latn", "SS"),
+    ("kbp", "Latn", "TG"),
+    ("kbq", "Latn", "PG"),
+    ("kbr", "Latn", "ET"),
+    ("kbs", "Latn", "GA"),
+    ("kbt", "Latn", "PG"),
+    ("kbu", "Arab", "PK"),
+    ("kbv", "Latn", "ID"),
+    ("kbw", "Latn", "PG"),
+    ("kbx", "Latn", "PG"),
+    ("kby", "Arab", "NE"),
+    ("kbz", "Latn", "NG"),
+    ("kca", "Cyrl", "RU"),
+    ("kcb", "Latn", "PG"),
+    ("kcc", "Latn", "NG"),
+    ("kcd", "Latn", "ID"),
+    ("kce", "Latn", "NG"),
+    ("kcf", "Latn", "NG"),
+    ("kcg", "Latn", "NG"),
+    ("kch", "Latn", "NG"),
+    ("kci", "Latn", "NG"),
+    ("kcj", "Latn", "GW"),
+    ("kck", "Latn", "ZW"),
+    ("kcl", "Latn", "PG"),
+    ("kcm", "Latn", "CF"),
+    ("kcn", "Latn", "UG"),
+    ("kco", "Latn", "PG"),
+    ("kcp", "Latn", "SD"),
+    ("kcq", "Latn", "NG"),
+    ("kcs", "Latn", "NG"),
+    ("kct", "Latn", "PG"),
+    ("kcu", "Latn", "TZ"),
+    ("kcv", "Latn", "CD"),
+    ("kcw", "Latn", "CD"),
+    ("kcy", "Arab", "DZ"),
+    ("kcz", "Latn", "TZ"),
+    ("kda", "Latn", "AU"),
+    ("kdc", "Latn", "TZ"),
+    ("kdd", "Latn", "AU"),
+    ("kde", "Latn", "TZ"),
+    ("kdf", "Latn", "PG"),
+    ("kdg", "Latn", "CD"),
+    ("kdh", "Latn", "TG"),
+    ("kdi", "Latn", "UG"),
+    ("kdj", "Latn", "UG"),
+    ("kdk", "Latn", "NC"),
+    ("kdl", "Latn", "NG"),
+    ("kdm", "Latn", "NG"),
+    ("kdn", "Latn", "ZW"),
+    ("kdp", "Latn", "NG"),
+    ("kdq", "Beng", "IN"),
+    ("kdr", "Latn", "LT"),
+    ("kdt", "Thai", "TH"),
+    ("kdw", "Latn", "ID"),
+    ("kdx", "Latn", "NG"),
+    ("kdy", "Latn", "ID"),
+    ("kdz", "Latn", "CM"),
+    ("kea", "Latn", "CV"),
+    ("keb", "Latn", "GA"),
+    ("kec", "Latn", "SD"),
+    ("ked", "Latn", "TZ"),
+    ("kee", "Latn", "US"),
+    ("kef", "Latn", "TG"),
+    ("keg", "Latn", "SD"),
+    ("keh", "Latn", "PG"),
+    ("kei", "Latn", "ID"),
+    ("kek", "Latn", "GT"),
+    ("kel", "Latn", "CD"),
+    ("kem", "Latn", "TL"),
+    ("ken", "Latn", "CM"),
+    ("keo", "Latn", "UG"),
+    ("ker", "Latn", "TD"),
+    ("kes", "Latn", "NG"),
+    ("ket", "Cyrl", "RU"),
+    ("keu", "Latn", "TG"),
+    ("kev", "Mlym", "IN"),
+    ("kew", "Latn", "PG"),
+    ("kex", "Deva", "IN"),
+    ("key", "Telu", "IN"),
+    ("kez", "Latn", "NG"),
+    ("kfa", "Knda", "IN"),
+    ("kfb", "Deva", "IN"),
+    ("kfc", "Telu", "IN"),
+    ("kfd", "Knda", "IN"),
+    ("kfe", "Taml", "IN"),
+    ("kff", "Latn", "IN"),
+    ("kfg", "Knda", "IN"),
+    ("kfh", "Mlym", "IN"),
+    ("kfi", "Taml", "IN"),
+    ("kfk", "Deva", "IN"),
+    ("kfl", "Latn", "CM"),
+    ("kfm", "Arab", "IR"),
+    ("kfn", "Latn", "CM"),
+    ("kfo", "Latn", "CI"),
+    ("kfp", "Deva", "IN"),
+    ("kfq", "Deva", "IN"),
+    ("kfr", "Deva", "IN"),
+    ("kfs", "Deva", "IN"),
+    ("kfu", "Deva", "IN"),
+    ("kfv", "Latn", "IN"),
+    ("kfw", "Latn", "IN"),
+    ("kfx", "Deva", "IN"),
+    ("kfy", "Deva", "IN"),
+    ("kfz", "Latn", "BF"),
+    ("kg", "Latn", "CD"),
+    ("kga", "Latn", "CI"),
+    ("kgb", "Latn", "ID"),
+    ("kge", "Latn", "ID"),
+    ("kgf", "Latn", "PG"),
+    ("kgj", "Deva", "NP"),
+    ("kgk", "Latn", "BR"),
+    ("kgl", "Latn", "AU"),
+    ("kgo", "Latn", "SD"),
+    ("kgp", "Latn", "BR"),
+    ("kgq", "Latn", "ID"),
+    ("kgr", "Latn", "ID"),
+    ("kgs", "Latn", "AU"),
+    ("kgt", "Latn", "NG"),
+    ("kgu", "Latn", "PG"),
+    ("kgv", "Latn", "ID"),
+    ("kgw", "Latn", "ID"),
+    ("kgx", "Latn", "ID"),
+    ("kgy", "Tibt", "NP"),
+    ("kha", "Latn", "IN"),
+    ("khb", "Talu", "CN"),
+    ("khc", "Latn", "ID"),
+    ("khd", "Latn", "ID"),
+    ("khe", "Latn", "ID"),
+    ("khf", "Thai", "LA"),
+    ("khg", "Tibt", "CN"),
+    ("khh", "Latn", "ID"),
+    ("khj", "Latn", "NG"),
+    ("khl", "Latn", "PG"),
+    ("khn", "Deva", "IN"),
+    ("kho", "Brah", "IR"),
+    ("khp", "Latn", "ID"),
+    ("khq", "Latn", "ML"),
+    ("khr", "Latn", "IN"),
+    ("khs", "Latn", "PG"),
+    ("kht", "Mymr", "IN"),
+    ("khu", "Latn", "AO"),
+    ("khv", "Cyrl", "RU"),
+    ("khw", "Arab", "PK"),
+    ("khx", "Latn", "CD"),
+    ("khy", "Latn", "CD"),
+    ("khz", "Latn", "PG"),
+    ("ki", "Latn", "KE"),
+    ("kia", "Latn", "TD"),
+    ("kib", "Latn", "SD"),
+    ("kic", "Latn", "US"),
+    ("kid", "Latn", "CM"),
+    ("kie", "Latn", "TD"),
+    ("kif", "Deva", "NP"),
+    ("kig", "Latn", "ID"),
+    ("kih", "Latn", "PG"),
+    ("kij", "Latn", "PG"),
+    ("kil", "Latn", "NG"),
+    ("kim", "Cyrl", "RU"),
+    ("kio", "Latn", "US"),
+    ("kip", "Deva", "NP"),
+    ("kiq", "Latn", "ID"),
+    ("kis", "Latn", "PG"),
+    ("kit", "Latn", "PG"),
+    ("kiu", "Latn", "TR"),
+    ("kiv", "Latn", "TZ"),
+    ("kiw", "Latn", "PG"),
+    ("kix", "Latn", "IN"),
+    ("kiy", "Latn", "ID"),
+    ("kiz", "Latn", "TZ"),
+    ("kj", "Latn", "NA"),
+    ("kja", "Latn", "ID"),
+    ("kjb", "Latn", "GT"),
+    ("kjc", "Latn", "ID"),
+    ("kjd", "Latn", "PG"),
+    ("kje", "Latn", "ID"),
+    ("kjg", "Laoo", "LA"),
+    ("kjh", "Cyrl", "RU"),
+    ("kji", "Latn", "SB"),
+    ("kjj", "Latn", "AZ"),
+    ("kjk", "Latn", "ID"),
+    ("kjl", "Deva", "NP"),
+    ("kjm", "Latn", "VN"),
+    ("kjn", "Latn", "AU"),
+    ("kjo", "Deva", "IN"),
+    ("kjp", "Mymr", "MM"),
+    ("kjq", "Latn", "US"),
+    ("kjr", "Latn", "ID"),
+    ("kjs", "Latn", "PG"),
+    ("kjt", "Thai", "TH"),
+    ("kju", "Latn", "US"),
+    ("kjx", "Latn", "PG"),
+    ("kjy", "Latn", "PG"),
+    ("kjz", "Tibt", "BT"),
+    ("kk", "Cyrl", "KZ"),
+    ("kka", "Latn", "NG"),
+    ("kkb", "Latn", "ID"),
+    ("kkc", "Latn", "PG"),
+    ("kkd", "Latn", "NG"),
+    ("kke", "Latn", "GN"),
+    ("kkf", "Tibt", "IN"),
+    ("kkg", "Latn", "PH"),
+    ("kkh", "Lana", "MM"),
+    ("kki", "Latn", "TZ"),
+    ("kkj", "Latn", "CM"),
+    ("kkk", "Latn", "SB"),
+    ("kkl", "Latn", "ID"),
+    ("kkm", "Latn", "NG"),
+    ("kko", "Latn", "SD"),
+    ("kkp", "Latn", "AU"),
+    ("kkq", "Latn", "CD"),
+    ("kkr", "Latn", "NG"),
+    ("kks", "Latn", "NG"),
+    ("kkt", "Deva", "NP"),
+    ("kku", "Latn", "NG"),
+    ("kkv", "Latn", "ID"),
+    ("kkw", "Latn", "CG"),
+    ("kkx", "Latn", "ID"),
+    ("kky", "Latn", "AU"),
+    ("kkz", "Latn", "CA"),
+    ("kl", "Latn", "GL"),
+    ("kla", "Latn", "US"),
+    ("klb", "Latn", "MX"),
+    ("klc", "Latn", "CM"),
+    ("kld", "Latn", "AU"),
+    ("kle", "Deva", "NP"),
+    ("klf", "Latn", "TD"),
+    ("klg", "Latn", "PH"),
+    ("klh", "Latn", "PG"),
+    ("kli", "Latn", "ID"),
+    ("klj", "Arab", "IR"),
+    ("klk", "Latn", "NG"),
+    ("kll", "Latn", "PH"),
+    ("klm", "Latn", "PG"),
+    ("kln", "Latn", "KE"),
+    ("klo", "Latn", "NG"),
+    ("klp", "Latn", "PG"),
+    ("klq", "Latn", "PG"),
+    ("klr", "Deva", "NP"),
+    ("kls", "Latn", "PK"),
+    ("klt", "Latn", "PG"),
+    ("klu", "Latn", "LR"),
+    ("klv", "Latn", "VU"),
+    ("klw", "Latn", "ID"),
+    ("klx", "Latn", "PG"),
+    ("kly", "Latn", "ID"),
+    ("klz", "Latn", "ID"),
+    ("km", "Khmr", "KH"),
+    ("kma", "Latn", "GH"),
+    ("kmb", "Latn", "AO"),
+    ("kmc", "Latn", "CN"),
+    ("kmd", "Latn", "PH"),
+    ("kme", "Latn", "CM"),
+    ("kmf", "Latn", "PG"),
+    ("kmg", "Latn", "PG"),
+    ("kmh", "Latn", "PG"),
+    ("kmi", "Latn", "NG"),
+    ("kmj", "Deva", "IN"),
+    ("kmk", "Latn", "PH"),
+    ("kml", "Latn", "PH"),
+    ("kmm", "Latn", "IN"),
+    ("kmn", "Latn", "PG"),
+    ("kmo", "Latn", "PG"),
+    ("kmp", "Latn", "CM"),
+    ("kmq", "Latn", "ET"),
+    ("kms", "Latn", "PG"),
+    ("kmt", "Latn", "ID"),
+    ("kmu", "Latn", "PG"),
+    ("kmv", "Latn", "BR"),
+    ("kmw", "Latn", "CD"),
+    ("kmx", "Latn", "PG"),
+    ("kmy", "Latn", "NG"),
+    ("kmz", "Arab", "IR"),
+    ("kn", "Knda", "IN"),
+    ("kna", "Latn", "NG"),
+    ("knb", "Latn", "PH"),
+    ("knd", "Latn", "ID"),
+    ("kne", "Latn", "PH"),
+    ("knf", "Latn", "GW"),
+    ("kni", "Latn", "NG"),
+    ("knj", "Latn", "GT"),
+    ("knk", "Latn", "SL"),
+    ("knl", "Latn", "ID"),
+    ("knm", "Latn", "BR"),
+    ("knn", "Deva", "IN"),
+    ("kno", "Latn", "SL"),
+    ("knp", "Latn", "CM"),
+    ("knq", "Latn", "MY"),
+    ("knr", "Latn", "PG"),
+    ("kns", "Latn", "MY"),
+    ("knt", "Latn", "BR"),
+    ("knu", "Latn", "GN"),
+    ("knv", "Latn", "PG"),
+    ("knw", "Latn", "NA"),
+    ("knx", "Latn", "ID"),
+    ("kny", "Latn", "CD"),
+    ("knz", "Latn", "BF"),
+    ("ko", "Kore", "KR"),
+    ("koa", "Latn", "PG"),
+    ("koc", "Latn", "NG"),
+    ("kod", "Latn", "ID"),
+    ("koe", "Latn", "SS"),
+    ("kof", "Latn", "NG"),
+    ("kog", "Latn", "CO"),
+    ("koh", "Latn", "CG"),
+    ("koi", "Cyrl", "RU"),
+    ("kok", "Deva", "IN"),
+    ("kol", "Latn", "PG"),
+    ("koo", "Latn", "UG"),
+    ("kop", "Latn", "PG"),
+    ("koq", "Latn", "GA"),
+    ("kos", "Latn", "FM"),
+    ("kot", "Latn", "CM"),
+    ("kou", "Latn", "TD"),
+    ("kov", "Latn", "NG"),
+    ("kow", "Latn", "NG"),
+    ("koy", "Latn", "US"),
+    ("koz", "Latn", "PG"),
+    ("kpa", "Latn", "NG"),
+    ("kpc", "Latn", "CO"),
+    ("kpd", "Latn", "ID"),
+    ("kpe", "Latn", "LR"),
+    ("kpf", "Latn", "PG"),
+    ("kpg", "Latn", "FM"),
+    ("kph", "Latn", "GH"),
+    ("kpi", "Latn", "ID"),
+    ("kpj", "Latn", "BR"),
+    ("kpk", "Latn", "NG"),
+    ("kpl", "Latn", "CD"),
+    ("kpm", "Latn", "VN"),
+    ("kpn", "Latn", "BR"),
+    ("kpo", "Latn", "TG"),
+    ("kpq", "Latn", "ID"),
+    ("kpr", "Latn", "PG"),
+    ("kps", "Latn", "ID"),
+    ("kpt", "Cyrl", "RU"),
+    ("kpu", "Latn", "ID"),
+    ("kpw", "Latn", "PG"),
+    ("kpx", "Latn", "PG"),
+    ("kpy", "Cyrl", "RU"),
+    ("kpz", "Latn", "UG"),
+    ("kqa", "Latn", "PG"),
+    ("kqb", "Latn", "PG"),
+    ("kqc", "Latn", "PG"),
+    ("kqd", "Syrc", "IQ"),
+    ("kqe", "Latn", "PH"),
+    ("kqf", "Latn", "PG"),
+    ("kqg", "Latn", "BF"),
+    ("kqh", "Latn", "TZ"),
+    ("kqi", "Latn", "PG"),
+    ("kqj", "Latn", "PG"),
+    ("kqk", "Latn", "BJ"),
+    ("kql", "Latn", "PG"),
+    ("kqm", "Latn", "CI"),
+    ("kqn", "Latn", "ZM"),
+    ("kqo", "Latn", "LR"),
+    ("kqp", "Latn", "TD"),
+    ("kqq", "Latn", "BR"),
+    ("kqr", "Latn", "MY"),
+    ("kqs", "Latn", "GN"),
+    ("kqt", "Latn", "MY"),
+    ("kqu", "Latn", "ZA"),
+    ("kqv", "Latn", "ID"),
+    ("kqw", "Latn", "PG"),
+    ("kqx", "Latn", "CM"),
+    ("kqy", "Ethi", "ET"),
+    ("kqz", "Latn", "ZA"),
+    ("kr", "Latn", "NG"),
+    ("kra", "Deva", "NP"),
+    ("krb", "Latn", "US"),
+    ("krc", "Cyrl", "RU"),
+    ("krd", "Latn", "TL"),
+    ("kre", "Latn", "BR"),
+    ("krf", "Latn", "VU"),
+    ("krh", "Latn", "NG"),
+    ("kri", "Latn", "SL"),
+    ("krj", "Latn", "PH"),
+    ("krk", "Cyrl", "RU"),
+    ("krl", "Latn", "RU"),
+    ("krn", "Latn", "LR"),
+    ("kro", "Latn", "LR"),
+    ("krp", "Latn", "NG"),
+    ("krr", "Khmr", "KH"),
+    ("krs", "Latn", "SS"),
+    ("krt", "Latn", "NE"),
+    ("kru", "Deva", "IN"),
+    ("krv", "Khmr", "KH"),
+    ("krw", "Latn", "LR"),
+    ("krx", "Latn", "SN"),
+    ("kry", "Latn", "AZ"),
+    ("krz", "Latn", "ID"),
+    ("ks", "Arab", "IN"),
+    ("ksb", "Latn", "TZ"),
+    ("ksc", "Latn", "PH"),
+    ("ksd", "Latn", "PG"),
+    ("kse", "Latn", "PG"),
+    ("ksf", "Latn", "CM"),
+    ("ksg", "Latn", "SB"),
+    ("ksh", "Latn", "DE"),
+    ("ksi", "Latn", "PG"),
+    ("ksj", "Latn", "PG"),
+    ("ksk", "Latn", "US"),
+    ("ksl", "Latn", "PG"),
+    ("ksm", "Latn", "NG"),
+    ("ksn", "Latn", "PH"),
+    ("kso", "Latn", "NG"),
+    ("ksp", "Latn", "CF"),
+    ("ksq", "Latn", "NG"),
+    ("ksr", "Latn", "PG"),
+    ("kss", "Latn", "LR"),
+    ("kst", "Latn", "BF"),
+    ("ksu", "Mymr", "IN"),
+    ("ksv", "Latn", "CD"),
+    ("ksw", "Mymr", "MM"),
+    ("ksx", "Latn", "ID"),
+    ("ksz", "Deva", "IN"),
+    ("kta", "Latn", "VN"),
+    ("ktb", "Ethi", "ET"),
+    ("ktc", "Latn", "NG"),
+    ("ktd", "Latn", "AU"),
+    ("kte", "Deva", "NP"),
+    ("ktf", "Latn", "CD"),
+    ("ktg", "Latn", "AU"),
+    ("kth", "Latn", "TD"),
+    ("kti", "Latn", "ID"),
+    ("ktj", "Latn", "CI"),
+    ("ktk", "Latn", "PG"),
+    ("ktl", "Arab", "IR"),
+    ("ktm", "Latn", "PG"),
+    ("ktn", "Latn", "BR"),
+    ("kto", "Latn", "PG"),
+    ("ktp", "Plrd", "CN"),
+    ("ktq", "Latn", "PH"),
+    ("kts", "Latn", "ID"),
+    ("ktt", "Latn", "ID"),
+    ("ktu", "Latn", "CD"),
+    ("ktv", "Latn", "VN"),
+    ("ktw", "Latn", "US"),
+    ("ktx", "Latn", "BR"),
+    ("kty", "Latn", "CD"),
+    ("ktz", "Latn", "NA"),
+    ("ku", "Latn", "TR"),
+    ("kub", "Latn", "NG"),
+    ("kuc", "Latn", "ID"),
+    ("kud", "Latn", "PG"),
+    ("kue", "Latn", "PG"),
+    ("kuf", "Laoo", "LA"),
+    ("kug", "Latn", "NG"),
+    ("kuh", "Latn", "NG"),
+    ("kui", "Latn", "BR"),
+    ("kuj", "Latn", "TZ"),
+    ("kuk", "Latn", "ID"),
+    ("kul", "Latn", "NG"),
+    ("kum", "Cyrl", "RU"),
+    ("kun", "Latn", "ER"),
+    ("kuo", "Latn", "PG"),
+    ("kup", "Latn", "PG"),
+    ("kuq", "Latn", "BR"),
+    ("kus", "Latn", "GH"),
+    ("kut", "Latn", "CA"),
+    ("kuu", "Latn", "US"),
+    ("kuv", "Latn", "ID"),
+    ("kuw", "Latn", "CF"),
+    ("kux", "Latn", "AU"),
+    ("kuy", "Latn", "AU"),
+    ("kuz", "Latn", "CL"),
+    ("kv", "Cyrl", "RU"),
+    ("kva", "Cyrl", "RU"),
+    ("kvb", "Latn", "ID"),
+    ("kvc", "Latn", "PG"),
+    ("kvd", "Latn", "ID"),
+    ("kve", "Latn", "MY"),
+    ("kvf", "Latn", "TD"),
+    ("kvg", "Latn", "PG"),
+    ("kvh", "Latn", "ID"),
+    ("kvi", "Latn", "TD"),
+    ("kvj", "Latn", "CM"),
+    ("kvl", "Latn", "MM"),
+    ("kvm", "Latn", "CM"),
+    ("kvn", "Latn", "CO"),
+    ("kvo", "Latn", "ID"),
+    ("kvp", "Latn", "ID"),
+    ("kvq", "Mymr", "MM"),
+    ("kvr", "Latn", "ID"),
+    ("kvt", "Mymr", "MM"),
+    ("kvv", "Latn", "ID"),
+    ("kvw", "Latn", "ID"),
+    ("kvx", "Arab", "PK"),
+    ("kvy", "Kali", "MM"),
+    ("kvz", "Latn", "ID"),
+    ("kw", "Latn", "GB"),
+    ("kwa", "Latn", "BR"),
+    ("kwb", "Latn", "NG"),
+    ("kwc", "Latn", "CG"),
+    ("kwd", "Latn", "SB"),
+    ("kwe", "Latn", "ID"),
+    ("kwf", "Latn", "SB"),
+    ("kwg", "Latn", "TD"),
+    ("kwh", "Latn", "ID"),
+    ("kwi", "Latn", "CO"),
+    ("kwj", "Latn", "PG"),
+    ("kwk", "Latn", "CA"),
+    ("kwl", "Latn", "NG"),
+    ("kwm", "Latn", "NA"),
+    ("kwn", "Latn", "NA"),
+    ("kwo", "Latn", "PG"),
+    ("kwp", "Latn", "CI"),
+    ("kwr", "Latn", "ID"),
+    ("kws", "Latn", "CD"),
+    ("kwt", "Latn", "ID"),
+    ("kwu", "Latn", "CM"),
+    ("kwv", "Latn", "TD"),
+    ("kww", "Latn", "SR"),
+    ("kwx", "Deva", "IN"),
+    ("kwy", "Latn", "AO"),
+    ("kwz", "Latn", "AO"),
+    ("kxa", "Latn", "PG"),
+    ("kxb", "Latn", "CI"),
+    ("kxc", "Latn", "ET"),
+    ("kxd", "Latn", "BN"),
+    ("kxf", "Mymr", "MM"),
+    ("kxi", "Latn", "MY"),
+    ("kxj", "Latn", "TD"),
+    ("kxk", "Mymr", "MM"),
+    ("kxm", "Thai", "TH"),
+    ("kxn", "Latn", "MY"),
+    ("kxo", "Latn", "BR"),
+    ("kxp", "Arab", "PK"),
+    ("kxq", "Latn", "ID"),
+    ("kxr", "Latn", "PG"),
+    ("kxt", "Latn", "PG"),
+    ("kxv", "Latn", "IN"),
+    ("kxw", "Latn", "PG"),
+    ("kxx", "Latn", "CG"),
+    ("kxy", "Latn", "VN"),
+    ("kxz", "Latn", "PG"),
+    ("ky", "Cyrl", "KG"),
+    ("kya", "Latn", "TZ"),
+    ("kyb", "Latn", "PH"),
+    ("kyc", "Latn", "PG"),
+    ("kyd", "Latn", "ID"),
+    ("kye", "Latn", "GH"),
+    ("kyf", "Latn", "CI"),
+    ("kyg", "Latn", "PG"),
+    ("kyh", "Latn", "US"),
+    ("kyi", "Latn", "MY"),
+    ("kyj", "Latn", "PH"),
+    ("kyk", "Latn", "PH"),
+    ("kyl", "Latn", "US"),
+    ("kym", "Latn", "CF"),
+    ("kyn", "Latn", "PH"),
+    ("kyo", "Latn", "ID"),
+    ("kyq", "Latn", "TD"),
+    ("kyr", "Latn", "BR"),
+    ("kys", "Latn", "MY"),
+    ("kyt", "Latn", "ID"),
+    ("kyu", "Kali", "MM"),
+    ("kyv", "Deva", "NP"),
+    ("kyw", "Deva", "IN"),
+    ("kyx", "Latn", "PG"),
+    ("kyy", "Latn", "PG"),
+    ("kyz", "Latn", "BR"),
+    ("kza", "Latn", "BF"),
+    ("kzb", "Latn", "ID"),
+    ("kzc", "Latn", "CI"),
+    ("kzd", "Latn", "ID"),
+    ("kze", "Latn", "PG"),
+    ("kzf", "Latn", "ID"),
+    ("kzi", "Latn", "MY"),
+    ("kzk", "Latn", "SB"),
+    ("kzl", "Latn", "ID"),
+    ("kzm", "Latn", "ID"),
+    ("kzn", "Latn", "MW"),
+    ("kzo", "Latn", "GA"),
+    ("kzp", "Latn", "ID"),
+    ("kzr", "Latn", "CM"),
+    ("kzs", "Latn", "MY"),
+    ("kzu", "Latn", "ID"),
+    ("kzv", "Latn", "ID"),
+    ("kzw", "Latn", "BR"),
+    ("kzx", "Latn", "ID"),
+    ("kzy", "Latn", "CD"),
+    ("kzz", "Latn", "ID"),
+    ("la", "Latn", "VA"),
+    ("laa", "Latn", "PH"),
+    ("lab", "Lina", "GR"),
+    ("lac", "Latn", "MX"),
+    ("lad", "Hebr", "IL"),
+    ("lae", "Deva", "IN"),
+    ("lag", "Latn", "TZ"),
+    ("lah", "Arab", "PK"),
+    ("lai", "Latn", "MW"),
+    ("laj", "Latn", "UG"),
+    ("lal", "Latn", "CD"),
+    ("lam", "Latn", "ZM"),
+    ("lan", "Latn", "NG"),
+    ("lap", "Latn", "TD"),
+    ("laq", "Latn", "VN"),
+    ("lar", "Latn", "GH"),
+    ("las", "Latn", "TG"),
+    ("lau", "Latn", "ID"),
+    ("law", "Latn", "ID"),
+    ("lax", "Latn", "IN"),
+    ("laz", "Latn", "PG"),
+    ("lb", "Latn", "LU"),
+    ("lbb", "Latn", "PG"),
+    ("lbe", "Cyrl", "RU"),
+    ("lbf", "Deva", "IN"),
+    ("lbi", "Latn", "CM"),
+    ("lbj", "Tibt", "IN"),
+    ("lbl", "Latn", "PH"),
+    ("lbm", "Deva", "IN"),
+    ("lbn", "Latn", "LA"),
+    ("lbo", "Laoo", "LA"),
+    ("lbq", "Latn", "PG"),
+    ("lbr", "Deva", "NP"),
+    ("lbt", "Latn", "VN"),
+    ("lbu", "Latn", "PG"),
+    ("lbv", "Latn", "PG"),
+    ("lbw", "Latn", "ID"),
+    ("lbx", "Latn", "ID"),
+    ("lby", "Latn", "AU"),
+    ("lbz", "Latn", "AU"),
+    ("lcc", "Latn", "ID"),
+    ("lcd", "Latn", "ID"),
+    ("lce", "Latn", "ID"),
+    ("lcf", "Latn", "ID"),
+    ("lch", "Latn", "AO"),
+    ("lcl", "Latn", "ID"),
+    ("lcm", "Latn", "PG"),
+    ("lcp", "Thai", "CN"),
+    ("lcq", "Latn", "ID"),
+    ("lcs", "Latn", "ID"),
+    ("lda", "Latn", "CI"),
+    ("ldb", "Latn", "NG"),
+    ("ldd", "Latn", "NG"),
+    ("ldg", "Latn", "NG"),
+    ("ldh", "Latn", "NG"),
+    ("ldi", "Latn", "CG"),
+    ("ldj", "Latn", "NG"),
+    ("ldk", "Latn", "NG"),
+    ("ldl", "Latn", "NG"),
+    ("ldm", "Latn", "GN"),
+    ("ldn", "Latn", "001"),
+    ("ldo", "Latn", "NG"),
+    ("ldp", "Latn", "NG"),
+    ("ldq", "Latn", "NG"),
+    ("lea", "Latn", "CD"),
+    ("leb", "Latn", "ZM"),
+    ("lec", "Latn", "BO"),
+    ("led", "Latn", "CD"),
+    ("lee", "Latn", "BF"),
+    ("lef", "Latn", "GH"),
+    ("leh", "Latn", "ZM"),
+    ("lei", "Latn", "PG"),
+    ("lej", "Latn", "CD"),
+    ("lek", "Latn", "PG"),
+    ("lel", "Latn", "CD"),
+    ("lem", "Latn", "CM"),
+    ("len", "Latn", "SV"),
+    ("leo", "Latn", "CM"),
+    ("lep", "Lepc", "IN"),
+    ("leq", "Latn", "PG"),
+    ("ler", "Latn", "PG"),
+    ("les", "Latn", "CD"),
+    ("let", "Latn", "PG"),
+    ("leu", "Latn", "PG"),
+    ("lev", "Latn", "ID"),
+    ("lew", "Latn", "ID"),
+    ("lex", "Latn", "ID"),
+    ("ley", "Latn", "ID"),
+    ("lez", "Cyrl", "RU"),
+    ("lfa", "Latn", "CM"),
+    ("lfn", "Latn", "001"),
+    ("lg", "Latn", "UG"),
+    ("lga", "Latn", "SB"),
+    ("lgb", "Latn", "SB"),
+    ("lgg", "Latn", "UG"),
+    ("lgh", "Latn", "VN"),
+    ("lgi", "Latn", "ID"),
+    ("lgk", "Latn", "VU"),
+    ("lgl", "Latn", "SB"),
+    ("lgm", "Latn", "CD"),
+    ("lgn", "Latn", "ET"),
+    ("lgo", "Latn", "SS"),
+    ("lgq", "Latn", "GH"),
+    ("lgr", "Latn", "SB"),
+    ("lgt", "Latn", "PG"),
+    ("lgu", "Latn", "SB"),
+    ("lgz", "Latn", "CD"),
+    ("lha", "Latn", "VN"),
+    ("lhh", "Latn", "ID"),
+    ("lhi", "Latn", "CN"),
+    ("lhm", "Deva", "NP"),
+    ("lhn", "Latn", "MY"),
+    ("lhs", "Syrc", "SY"),
+    ("lht", "Latn", "VU"),
+    ("lhu", "Latn", "CN"),
+    ("li", "Latn", "NL"),
+    ("lia", "Latn", "SL"),
+    ("lib", "Latn", "PG"),
+    ("lic", "Latn", "CN"),
+    ("lid", "Latn", "PG"),
+    ("lie", "Latn", "CD"),
+    ("lif", "Deva", "NP"),
+    ("lig", "Latn", "GH"),
+    ("lih", "Latn", "PG"),
+    ("lij", "Latn", "IT"),
+    ("lik", "Latn", "CD"),
+    ("lil", "Latn", "CA"),
+    ("lio", "Latn", "ID"),
+    ("lip", "Latn", "GH"),
+    ("liq", "Latn", "ET"),
+    ("lir", "Latn", "LR"),
+    ("lis", "Lisu", "CN"),
+    ("liu", "Latn", "SD"),
+    ("liv", "Latn", "LV"),
+    ("liw", "Latn", "ID"),
+    ("lix", "Latn", "ID"),
+    ("liy", "Latn", "CF"),
+    ("liz", "Latn", "CD"),
+    ("lja", "Latn", "AU"),
+    ("lje", "Latn", "ID"),
+    ("lji", "Latn", "ID"),
+    ("ljl", "Latn", "ID"),
+    ("ljp", "Latn", "ID"),
+    ("ljw", "Latn", "AU"),
+    ("ljx", "Latn", "AU"),
+    ("lka", "Latn", "TL"),
+    ("lkb", "Latn", "KE"),
+    ("lkc", "Latn", "VN"),
+    ("lkd", "Latn", "BR"),
+    ("lke", "Latn", "UG"),
+    ("lkh", "Tibt", "BT"),
+    ("lki", "Arab", "IR"),
+    ("lkj", "Latn", "MY"),
+    ("lkl", "Latn", "PG"),
+    ("lkm", "Latn", "AU"),
+    ("lkn", "Latn", "VU"),
+    ("lko", "Latn", "KE"),
+    ("lkr", "Latn", "SS"),
+    ("lks", "Latn", "KE"),
+    ("lkt", "Latn", "US"),
+    ("lku", "Latn", "AU"),
+    ("lky", "Latn", "SS"),
+    ("lla", "Latn", "NG"),
+    ("llb", "Latn", "MZ"),
+    ("llc", "Latn", "GN"),
+    ("lld", "Latn", "IT"),
+    ("lle", "Latn", "PG"),
+    ("llf", "Latn", "PG"),
+    ("llg", "Latn", "ID"),
+    ("lli", "Latn", "CG"),
+    ("llj", "Latn", "AU"),
+    ("llk", "Latn", "MY"),
+    ("lll", "Latn", "PG"),
+    ("llm", "Latn", "ID"),
+    ("lln", "Latn", "TD"),
+    ("llp", "Latn", "VU"),
+    ("llq", "Latn", "ID"),
+    ("llu", "Latn", "SB"),
+    ("llx", "Latn", "FJ"),
+    ("lma", "Latn", "GN"),
+    ("lmb", "Latn", "VU"),
+    ("lmc", "Latn", "AU"),
+    ("lmd", "Latn", "SD"),
+    ("lme", "Latn", "TD"),
+    ("lmf", "Latn", "ID"),
+    ("lmg", "Latn", "PG"),
+    ("lmh", "Deva", "NP"),
+    ("lmi", "Latn", "CD"),
+    ("lmj", "Latn", "ID"),
+    ("lmk", "Latn", "IN"),
+    ("lml", "Latn", "VU"),
+    ("lmn", "Telu", "IN"),
+    ("lmo", "Latn", "IT"),
+    ("lmp", "Latn", "CM"),
+    ("lmq", "Latn", "ID"),
+    ("lmr", "Latn", "ID"),
+    ("lmu", "Latn", "VU"),
+    ("lmv", "Latn", "FJ"),
+    ("lmw", "Latn", "US"),
+    ("lmx", "Latn", "CM"),
+    ("lmy", "Latn", "ID"),
+    ("ln", "Latn", "CD"),
+    ("lna", "Latn", "CF"),
+    ("lnb", "Latn", "NA"),
+    ("lnd", "Latn", "ID"),
+    ("lng", "Latn", "HU"),
+    ("lnh", "Latn", "MY"),
+    ("lni", "Latn", "PG"),
+    ("lnj", "Latn", "AU"),
+    ("lnl", "Latn", "CF"),
+    ("lnm", "Latn", "PG"),
+    ("lnn", "Latn", "VU"),
+    ("lns", "Latn", "CM"),
+    ("lnu", "Latn", "NG"),
+    ("lnw", "Latn", "AU"),
+    ("lnz", "Latn", "CD"),
+    ("lo", "Laoo", "LA"),
+    ("loa", "Latn", "ID"),
+    ("lob", "Latn", "BF"),
+    ("loc", "Latn", "PH"),
+    ("loe", "Latn", "ID"),
+    ("log", "Latn", "CD"),
+    ("loh", "Latn", "SS"),
+    ("loi", "Latn", "CI"),
+    ("loj", "Latn", "PG"),
+    ("lok", "Latn", "SL"),
+    ("lol", "Latn", "CD"),
+    ("lom", "Latn", "LR"),
+    ("lon", "Latn", "MW"),
+    ("loo", "Latn", "CD"),
+    ("lop", "Latn", "NG"),
+    ("loq", "Latn", "CD"),
+    ("lor", "Latn", "CI"),
+    ("los", "Latn", "PG"),
+    ("lot", "Latn", "SS"),
+    ("lou", "Latn", "US"),
+    ("low", "Latn", "MY"),
+    ("lox", "Latn", "ID"),
+    ("loy", "Deva", "NP"),
+    ("loz", "Latn", "ZM"),
+    ("lpa", "Latn", "VU"),
+    ("lpe", "Latn", "ID"),
+    ("lpn", "Latn", "MM"),
+    ("lpo", "Plrd", "CN"),
+    ("lpx", "Latn", "SS"),
+    ("lqr", "Latn", "SS"),
+    ("lra", "Latn", "MY"),
+    ("lrc", "Arab", "IR"),
+    ("lrg", "Latn", "AU"),
+    ("lri", "Latn", "KE"),
+    ("lrk", "Arab", "PK"),
+    ("lrl", "Arab", "IR"),
+    ("lrm", "Latn", "KE"),
+    ("lrn", "Latn", "ID"),
+    ("lro", "Latn", "SD"),
+    ("lrt", "Latn", "ID"),
+    ("lrv", "Latn", "VU"),
+    ("lrz", "Latn", "VU"),
+    ("lsa", "Arab", "IR"),
+    ("lsd", "Hebr", "IL"),
+    ("lse", "Latn", "CD"),
+    ("lsi", "Latn", "MM"),
+    ("lsm", "Latn", "UG"),
+    ("lsr", "Latn", "PG"),
+    ("lss", "Arab", "PK"),
+    ("lt", "Latn", "LT"),
+    ("ltc", "Hant", "CN"),
+    ("ltg", "Latn", "LV"),
+    ("lth", "Latn", "UG"),
+    ("lti", "Latn", "ID"),
+    ("ltn", "Latn", "BR"),
+    ("lto", "Latn", "KE"),
+    ("lts", "Latn", "KE"),
+    ("ltu", "Latn", "ID"),
+    ("lu", "Latn", "CD"),
+    ("lua", "Latn", "CD"),
+    ("luc", "Latn", "UG"),
+    ("lud", "Latn", "RU"),
+    ("lue", "Latn", "ZM"),
+    ("luf", "Latn", "PG"),
+    ("luh", "Hans", "CN"),
+    ("lui", "Latn", "US"),
+    ("luj", "Latn", "CD"),
+    ("luk", "Tibt", "BT"),
+    ("lul", "Latn", "SS"),
+    ("lum", "Latn", "AO"),
+    ("lun", "Latn", "ZM"),
+    ("luo", "Latn", "KE"),
+    ("lup", "Latn", "GA"),
+    ("luq", "Latn", "CU"),
+    ("lur", "Latn", "ID"),
+    ("lus", "Latn", "IN"),
+    ("lut", "Latn", "US"),
+    ("luu", "Deva", "NP"),
+    ("luv", "Arab", "OM"),
+    ("luw", "Latn", "CM"),
+    ("luy", "Latn", "KE"),
+    ("luz", "Arab", "IR"),
+    ("lv", "Latn", "LV"),
+    ("lva", "Latn", "TL"),
+    ("lvi", "Latn", "LA"),
+    ("lvk", "Latn", "SB"),
+    ("lvl", "Latn", "CD"),
+    ("lvu", "Latn", "ID"),
+    ("lwa", "Latn", "CD"),
+    ("lwe", "Latn", "ID"),
+    ("lwg", "Latn", "KE"),
+    ("lwh", "Latn", "VN"),
+    ("lwl", "Thai", "TH"),
+    ("lwm", "Thai", "CN"),
+    ("lwo", "Latn", "SS"),
+    ("lwt", "Latn", "ID"),
+    ("lww", "Latn", "VU"),
+    ("lxm", "Latn", "PG"),
+    ("lya", "Tibt", "BT"),
+    ("lyn", "Latn", "ZM"),
+    ("lzh", "Hant", "CN"),
+    ("lzl", "Latn", "VU"),
+    ("lzn", "Latn", "MM"),
+    ("lzz", "Latn", "TR"),
+    ("maa", "Latn", "MX"),
+    ("mab", "Latn", "MX"),
+    ("mad", "Latn", "ID"),
+    ("mae", "Latn", "NG"),
+    ("maf", "Latn", "CM"),
+    ("mag", "Deva", "IN"),
+    ("mai", "Deva", "IN"),
+    ("maj", "Latn", "MX"),
+    ("mak", "Latn", "ID"),
+    ("mam", "Latn", "GT"),
+    ("man", "Latn", "GM"),
+    ("maq", "Latn", "MX"),
+    ("mas", "Latn", "KE"),
+    ("mat", "Latn", "MX"),
+    ("mau", "Latn", "MX"),
+    ("mav", "Latn", "BR"),
+    ("maw", "Latn", "GH"),
+    ("max", "Latn", "ID"),
+    ("maz", "Latn", "MX"),
+    ("mba", "Latn", "PH"),
+    ("mbb", "Latn", "PH"),
+    ("mbc", "Latn", "BR"),
+    ("mbd", "Latn", "PH"),
+    ("mbf", "Latn", "SG"),
+    ("mbh", "Latn", "PG"),
+    ("mbi", "Latn", "PH"),
+    ("mbj", "Latn", "BR"),
+    ("mbk", "Latn", "PG"),
+    ("mbl", "Latn", "BR"),
+    ("mbm", "Latn", "CG"),
+    ("mbn", "Latn", "CO"),
+    ("mbo", "Latn", "CM"),
+    ("mbp", "Latn", "CO"),
+    ("mbq", "Latn", "PG"),
+    ("mbr", "Latn", "CO"),
+    ("mbs", "Latn", "PH"),
+    ("mbt", "Latn", "PH"),
+    ("mbu", "Latn", "NG"),
+    ("mbv", "Latn", "GN"),
+    ("mbw", "Latn", "PG"),
+    ("mbx", "Latn", "PG"),
+    ("mby", "Arab", "PK"),
+    ("mbz", "Latn", "MX"),
+    ("mca", "Latn", "PY"),
+    ("mcb", "Latn", "PE"),
+    ("mcc", "Latn", "PG"),
+    ("mcd", "Latn", "PE"),
+    ("mce", "Latn", "MX"),
+    ("mcf", "Latn", "PE"),
+    ("mcg", "Latn", "VE"),
+    ("mch", "Latn", "VE"),
+    ("mci", "Latn", "PG"),
+    ("mcj", "Latn", "NG"),
+    ("mck", "Latn", "AO"),
+    ("mcl", "Latn", "CO"),
+    ("mcm", "Latn", "MY"),
+    ("mcn", "Latn", "TD"),
+    ("mco", "Latn", "MX"),
+    ("mcp", "Latn", "CM"),
+    ("mcq", "Latn", "PG"),
+    ("mcr", "Latn", "PG"),
+    ("mcs", "Latn", "CM"),
+    ("mct", "Latn", "CM"),
+    ("mcu", "Latn", "CM"),
+    ("mcv", "Latn", "PG"),
+    ("mcw", "Latn", "TD"),
+    ("mcx", "Latn", "CF"),
+    ("mcy", "Latn", "PG"),
+    ("mcz", "Latn", "PG"),
+    ("mda", "Latn", "NG"),
+    ("mdb", "Latn", "PG"),
+    ("mdc", "Latn", "PG"),
+    ("mdd", "Latn", "CM"),
+    ("mde", "Arab", "TD"),
+    ("mdf", "Cyrl", "RU"),
+    ("mdg", "Latn", "TD"),
+    ("mdh", "Latn", "PH"),
+    ("mdi", "Latn", "CD"),
+    ("mdj", "Latn", "CD"),
+    ("mdk", "Latn", "CD"),
+    ("mdm", "Latn", "CD"),
+    ("mdn", "Latn", "CF"),
+    ("mdp", "Latn", "CD"),
+    ("mdq", "Latn", "CD"),
+    ("mdr", "Latn", "ID"),
+    ("mds", "Latn", "PG"),
+    ("mdt", "Latn", "CG"),
+    ("mdu", "Latn", "CG"),
+    ("mdv", "Latn", "MX"),
+    ("mdw", "Latn", "CG"),
+    ("mdx", "Ethi", "ET"),
+    ("mdy", "Ethi", "ET"),
+    ("mdz", "Latn", "BR"),
+    ("mea", "Latn", "CM"),
+    ("meb", "Latn", "PG"),
+    ("mec", "Latn", "AU"),
+    ("med", "Latn", "PG"),
+    ("mee", "Latn", "PG"),
+    ("meh", "Latn", "MX"),
+    ("mej", "Latn", "ID"),
+    ("mek", "Latn", "PG"),
+    ("mel", "Latn", "MY"),
+    ("mem", "Latn", "AU"),
+    ("men", "Latn", "SL"),
+    ("meo", "Latn", "MY"),
+    ("mep", "Latn", "AU"),
+    ("meq", "Latn", "CM"),
+    ("mer", "Latn", "KE"),
+    ("mes", "Latn", "TD"),
+    ("met", "Latn", "PG"),
+    ("meu", "Latn", "PG"),
+    ("mev", "Latn", "LR"),
+    ("mew", "Latn", "NG"),
+    ("mey", "Arab", "DZ"),
+    ("mez", "Latn", "US"),
+    ("mfa", "Arab", "TH"),
+    ("mfb", "Latn", "ID"),
+    ("mfc", "Latn", "CD"),
+    ("mfd", "Latn", "CM"),
+    ("mfe", "Latn", "MU"),
+    ("mff", "Latn", "CM"),
+    ("mfg", "Latn", "GN"),
+    ("mfh", "Latn", "CM"),
+    ("mfi", "Arab", "CM"),
+    ("mfj", "Latn", "CM"),
+    ("mfk", "Latn", "CM"),
+    ("mfl", "Latn", "NG"),
+    ("mfm", "Latn", "NG"),
+    ("mfn", "Latn", "NG"),
+    ("mfo", "Latn", "NG"),
+    ("mfp", "Latn", "ID"),
+    ("mfq", "Latn", "TG"),
+    ("mfr", "Latn", "AU"),
+    ("mft", "Latn", "PG"),
+    ("mfu", "Latn", "AO"),
+    ("mfv", "Latn", "SN"),
+    ("mfw", "Latn", "PG"),
+    ("mfx", "Latn", "ET"),
+    ("mfy", "Latn", "MX"),
+    ("mfz", "Latn", "SS"),
+    ("mg", "Latn", "MG"),
+    ("mga", "Latg", "IE"),
+    ("mgb", "Latn", "TD"),
+    ("mgc", "Latn", "SS"),
+    ("mgd", "Latn", "SS"),
+    ("mge", "Latn", "TD"),
+    ("mgf", "Latn", "ID"),
+    ("mgg", "Latn", "CM"),
+    ("mgh", "Latn", "MZ"),
+    ("mgi", "Latn", "NG"),
+    ("mgj", "Latn", "NG"),
+    ("mgk", "Latn", "ID"),
+    ("mgl", "Latn", "PG"),
+    ("mgm", "Latn", "TL"),
+    ("mgn", "Latn", "CF"),
+    ("mgo", "Latn", "CM"),
+    ("mgp", "Deva", "NP"),
+    ("mgq", "Latn", "TZ"),
+    ("mgr", "Latn", "ZM"),
+    ("mgs", "Latn", "TZ"),
+    ("mgt", "Latn", "PG"),
+    ("mgu", "Latn", "PG"),
+    ("mgv", "Latn", "TZ"),
+    ("mgw", "Latn", "TZ"),
+    ("mgy", "Latn", "TZ"),
+    ("mgz", "Latn", "TZ"),
+    ("mh", "Latn", "MH"),
+    ("mhb", "Latn", "GA"),
+    ("mhc", "Latn", "MX"),
+    ("mhd", "Latn", "TZ"),
+    ("mhe", "Latn", "MY"),
+    ("mhf", "Latn", "PG"),
+    ("mhg", "Latn", "AU"),
+    ("mhi", "Latn", "UG"),
+    ("mhj", "Arab", "AF"),
+    ("mhk", "Latn", "CM"),
+    ("mhl", "Latn", "PG"),
+    ("mhm", "Latn", "MZ"),
+    ("mhn", "Latn", "IT"),
+    ("mho", "Latn", "ZM"),
+    ("mhp", "Latn", "ID"),
+    ("mhq", "Latn", "US"),
+    ("mhs", "Latn", "ID"),
+    ("mht", "Latn", "VE"),
+    ("mhu", "Latn", "IN"),
+    ("mhw", "Latn", "BW"),
+    ("mhx", "Latn", "MM"),
+    ("mhy", "Latn", "ID"),
+    ("mhz", "Latn", "ID"),
+    ("mi", "Latn", "NZ"),
+    ("mia", "Latn", "US"),
+    ("mib", "Latn", "MX"),
+    ("mic", "Latn", "CA"),
+    ("mid", "Mand", "IQ"),
+    ("mie", "Latn", "MX"),
+    ("mif", "Latn", "CM"),
+    ("mig", "Latn", "MX"),
+    ("mih", "Latn", "MX"),
+    ("mii", "Latn", "MX"),
+    ("mij", "Latn", "CM"),
+    ("mik", "Latn", "US"),
+    ("mil", "Latn", "MX"),
+    ("mim", "Latn", "MX"),
+    ("min", "Latn", "ID"),
+    ("mio", "Latn", "MX"),
+    ("mip", "Latn", "MX"),
+    ("miq", "Latn", "NI"),
+    ("mir", "Latn", "MX"),
+    ("mit", "Latn", "MX"),
+    ("miu", "Latn", "MX"),
+    ("miw", "Latn", "PG"),
+    ("mix", "Latn", "MX"),
+    ("miy", "Latn", "MX"),
+    ("miz", "Latn", "MX"),
+    ("mjb", "Latn", "TL"),
+    ("mjc", "Latn", "MX"),
+    ("mjd", "Latn", "US"),
+    ("mje", "Latn", "TD"),
+    ("mjg", "Latn", "CN"),
+    ("mjh", "Latn", "TZ"),
+    ("mji", "Latn", "CN"),
+    ("mjj", "Latn", "PG"),
+    ("mjk", "Latn", "PG"),
+    ("mjl", "Deva", "IN"),
+    ("mjm", "Latn", "PG"),
+    ("mjn", "Latn", "PG"),
+    ("mjq", "Mlym", "IN"),
+    ("mjr", "Mlym", "IN"),
+    ("mjs", "Latn", "NG"),
+    ("mjt", "Deva", "IN"),
+    ("mju", "Telu", "IN"),
+    ("mjv", "Mlym", "IN"),
+    ("mjw", "Latn", "IN"),
+    ("mjx", "Latn", "BD"),
+    ("mjy", "Latn", "US"),
+    ("mjz", "Deva", "NP"),
+    ("mk", "Cyrl", "MK"),
+    ("mka", "Latn", "CI"),
+    ("mkb", "Deva", "IN"),
+    ("mkc", "Latn", "PG"),
+    ("mke", "Deva", "IN"),
+    ("mkf", "Latn", "NG"),
+    ("mki", "Arab", "PK"),
+    ("mkj", "Latn", "FM"),
+    ("mkk", "Latn", "CM"),
+    ("mkl", "Latn", "BJ"),
+    ("mkm", "Thai", "TH"),
+    ("mkn", "Latn", "ID"),
+    ("mko", "Latn", "NG"),
+    ("mkp", "Latn", "PG"),
+    ("mkr", "Latn", "PG"),
+    ("mks", "Latn", "MX"),
+    ("mkt", "Latn", "NC"),
+    ("mkv", "Latn", "VU"),
+    ("mkw", "Latn", "CG"),
+    ("mkx", "Latn", "PH"),
+    ("mky", "Latn", "ID"),
+    ("mkz", "Latn", "TL"),
+    ("ml", "Mlym", "IN"),
+    ("mla", "Latn", "VU"),
+    ("mlb", "Latn", "CM"),
+    ("mlc", "Latn", "VN"),
+    ("mle", "Latn", "PG"),
+    ("mlf", "Thai", "LA"),
+    ("mlh", "Latn", "PG"),
+    ("mli", "Latn", "ID"),
+    ("mlj", "Latn", "TD"),
+    ("mlk", "Latn", "KE"),
+    ("mll", "Latn", "VU"),
+    ("mln", "Latn", "SB"),
+    ("mlo", "Latn", "SN"),
+    ("mlp", "Latn", "PG"),
+    ("mlr", "Latn", "CM"),
+    ("mls", "Latn", "SD"),
+    ("mlu", "Latn", "SB"),
+    ("mlv", "Latn", "VU"),
+    ("mlw", "Latn", "CM"),
+    ("mlx", "Latn", "VU"),
+    ("mlz", "Latn", "PH"),
+    ("mma", "Latn", "NG"),
+    ("mmb", "Latn", "ID"),
+    ("mmc", "Latn", "MX"),
+    ("mmd", "Latn", "CN"),
+    ("mme", "Latn", "VU"),
+    ("mmf", "Latn", "NG"),
+    ("mmg", "Latn", "VU"),
+    ("mmh", "Latn", "BR"),
+    ("mmi", "Latn", "PG"),
+    ("mmm", "Latn", "VU"),
+    ("mmn", "Latn", "PH"),
+    ("mmo", "Latn", "PG"),
+    ("mmp", "Latn", "PG"),
+    ("mmq", "Latn", "PG"),
+    ("mmr", "Latn", "CN"),
+    ("mmt", "Latn", "PG"),
+    ("mmu", "Latn", "CM"),
+    ("mmv", "Latn", "BR"),
+    ("mmw", "Latn", "VU"),
+    ("mmx", "Latn", "PG"),
+    ("mmy", "Latn", "TD"),
+    ("mmz", "Latn", "CD"),
+    ("mn", "Cyrl", "MN"),
+    ("mna", "Latn", "PG"),
+    ("mnb", "Latn", "ID"),
+    ("mnc", "Mong", "CN"),
+    ("mnd", "Latn", "BR"),
+    ("mne", "Latn", "TD"),
+    ("mnf", "Latn", "CM"),
+    ("mng", "Latn", "VN"),
+    ("mnh", "Latn", "CD"),
+    ("mni", "Beng", "IN"),
+    ("mnj", "Arab", "AF"),
+    ("mnk", "Latn", "GM"),
+    ("mnl", "Latn", "VU"),
+    ("mnm", "Latn", "PG"),
+    ("mnn", "Latn", "VN"),
+    ("mnp", "Latn", "CN"),
+    ("mnq", "Latn", "MY"),
+    ("mnr", "Latn", "US"),
+    ("mns", "Cyrl", "RU"),
+    ("mnu", "Latn", "ID"),
+    ("mnv", "Latn", "SB"),
+    ("mnw", "Mymr", "MM"),
+    ("mnx", "Latn", "ID"),
+    ("mny", "Latn", "MZ"),
+    ("mnz", "Latn", "ID"),
+    ("mo", "Latn", "RO"),
+    ("moa", "Latn", "CI"),
+    ("moc", "Latn", "AR"),
+    ("mod", "Latn", "US"),
+    ("moe", "Latn", "CA"),
+    ("mog", "Latn", "ID"),
+    ("moh", "Latn", "CA"),
+    ("moi", "Latn", "NG"),
+    ("moj", "Latn", "CG"),
+    ("mok", "Latn", "ID"),
+    ("mom", "Latn", "NI"),
+    ("moo", "Latn", "VN"),
+    ("mop", "Latn", "BZ"),
+    ("moq", "Latn", "ID"),
+    ("mor", "Latn", "SD"),
+    ("mos", "Latn", "BF"),
+    ("mot", "Latn", "CO"),
+    ("mou", "Latn", "TD"),
+    ("mov", "Latn", "US"),
+    ("mow", "Latn", "CG"),
+    ("mox", "Latn", "PG"),
+    ("moy", "Latn", "ET"),
+    ("moz", "Latn", "TD"),
+    ("mpa", "Latn", "TZ"),
+    ("mpb", "Latn", "AU"),
+    ("mpc", "Latn", "AU"),
+    ("mpd", "Latn", "BR"),
+    ("mpe", "Latn", "ET"),
+    ("mpg", "Latn", "TD"),
+    ("mph", "Latn", "AU"),
+    ("mpi", "Latn", "CM"),
+    ("mpj", "Latn", "AU"),
+    ("mpk", "Latn", "TD"),
+    ("mpl", "Latn", "PG"),
+    ("mpm", "Latn", "MX"),
+    ("mpn", "Latn", "PG"),
+    ("mpo", "Latn", "PG"),
+    ("mpp", "Latn", "PG"),
+    ("mpq", "Latn", "BR"),
+    ("mpr", "Latn", "SB"),
+    ("mps", "Latn", "PG"),
+    ("mpt", "Latn", "PG"),
+    ("mpu", "Latn", "BR"),
+    ("mpv", "Latn", "PG"),
+    ("mpw", "Latn", "BR"),
+    ("mpx", "Latn", "PG"),
+    ("mpy", "Latn", "ID"),
+    ("mpz", "Thai", "TH"),
+    ("mqa", "Latn", "ID"),
+    ("mqb", "Latn", "CM"),
+    ("mqc", "Latn", "ID"),
+    ("mqe", "Latn", "PG"),
+    ("mqf", "Latn", "ID"),
+    ("mqg", "Latn", "ID"),
+    ("mqh", "Latn", "MX"),
+    ("mqi", "Latn", "ID"),
+    ("mqj", "Latn", "ID"),
+    ("mqk", "Latn", "PH"),
+    ("mql", "Latn", "BJ"),
+    ("mqm", "Latn", "PF"),
+    ("mqn", "Latn", "ID"),
+    ("mqo", "Latn", "ID"),
+    ("mqp", "Latn", "ID"),
+    ("mqq", "Latn", "MY"),
+    ("mqr", "Latn", "ID"),
+    ("mqs", "Latn", "ID"),
+    ("mqt", "Latn", "TH"),
+    ("mqu", "Latn", "SS"),
+    ("mqv", "Latn", "PG"),
+    ("mqw", "Latn", "PG"),
+    ("mqx", "Latn", "ID"),
+    ("mqy", "Latn", "ID"),
+    ("mqz", "Latn", "PG"),
+    ("mr", "Deva", "IN"),
+    ("mra", "Thai", "TH"),
+    ("mrb", "Latn", "VU"),
+    ("mrc", "Latn", "US"),
+    ("mrd", "Deva", "NP"),
+    ("mrf", "Latn", "ID"),
+    ("mrg", "Latn", "IN"),
+    ("mrh", "Latn", "IN"),
+    ("mrj", "Cyrl", "RU"),
+    ("mrk", "Latn", "NC"),
+    ("mrl", "Latn", "FM"),
+    ("mrm", "Latn", "VU"),
+    ("mrn", "Latn", "SB"),
+    ("mro", "Mroo", "BD"),
+    ("mrp", "Latn", "VU"),
+    ("mrq", "Latn", "PF"),
+    ("mrr", "Deva", "IN"),
+    ("mrs", "Latn", "VU"),
+    ("mrt", "Latn", "NG"),
+    ("mru", "Latn", "CM"),
+    ("mrv", "Latn", "PF"),
+    ("mrw", "Latn", "PH"),
+    ("mrx", "Latn", "ID"),
+    ("mry", "Latn", "PH"),
+    ("mrz", "Latn", "ID"),
+    ("ms", "Latn", "MY"),
+    ("msb", "Latn", "PH"),
+    ("mse", "Latn", "TD"),
+    ("msf", "Latn", "ID"),
+    ("msg", "Latn", "ID"),
+    ("msh", "Latn", "MG"),
+    ("msi", "Latn", "MY"),
+    ("msj", "Latn", "CD"),
+    ("msk", "Latn", "PH"),
+    ("msl", "Latn", "ID"),
+    ("msm", "Latn", "PH"),
+    ("msn", "Latn", "VU"),
+    ("mso", "Latn", "ID"),
+    ("msp", "Latn", "BR"),
+    ("msq", "Latn", "NC"),
+    ("mss", "Latn", "ID"),
+    ("msu", "Latn", "PG"),
+    ("msv", "Latn", "CM"),
+    ("msw", "Latn", "GW"),
+    ("msx", "Latn", "PG"),
+    ("msy", "Latn", "PG"),
+    ("msz", "Latn", "PG"),
+    ("mt", "Latn", "MT"),
+    ("mta", "Latn", "PH"),
+    ("mtb", "Latn", "CI"),
+    ("mtc", "Latn", "PG"),
+    ("mtd", "Latn", "ID"),
+    ("mte", "Latn", "SB"),
+    ("mtf", "Latn", "PG"),
+    ("mtg", "Latn", "ID"),
+    ("mth", "Latn", "ID"),
+    ("mti", "Latn", "PG"),
+    ("mtj", "Latn", "ID"),
+    ("mtk", "Latn", "CM"),
+    ("mtl", "Latn", "NG"),
+    ("mtm", "Cyrl", "RU"),
+    ("mtn", "Latn", "NI"),
+    ("mto", "Latn", "MX"),
+    ("mtp", "Latn", "BO"),
+    ("mtq", "Latn", "VN"),
+    ("mtr", "Deva", "IN"),
+    ("mts", "Latn", "PE"),
+    ("mtt", "Latn", "VU"),
+    ("mtu", "Latn", "MX"),
+    ("mtv", "Latn", "PG"),
+    ("mtw", "Latn", "PH"),
+    ("mtx", "Latn", "MX"),
+    ("mty", "Latn", "PG"),
+    ("mua", "Latn", "CM"),
+    ("mub", "Latn", "TD"),
+    ("muc", "Latn", "CM"),
+    ("mud", "Cyrl", "RU"),
+    ("mue", "Latn", "EC"),
+    ("mug", "Latn", "CM"),
+    ("muh", "Latn", "SS"),
+    ("mui", "Latn", "ID"),
+    ("muj", "Latn", "TD"),
+    ("muk", "Tibt", "NP"),
+    ("mum", "Latn", "PG"),
+    ("muo", "Latn", "CM"),
+    ("muq", "Latn", "CN"),
+    ("mur", "Latn", "SS"),
+    ("mus", "Latn", "US"),
+    ("mut", "Deva", "IN"),
+    ("muu", "Latn", "KE"),
+    ("muv", "Taml", "IN"),
+    ("mux", "Latn", "PG"),
+    ("muy", "Latn", "CM"),
+    ("muz", "Ethi", "ET"),
+    ("mva", "Latn", "PG"),
+    ("mvd", "Latn", "ID"),
+    ("mve", "Arab", "PK"),
+    ("mvf", "Mong", "CN"),
+    ("mvg", "Latn", "MX"),
+    ("mvh", "Latn", "TD"),
+    ("mvk", "Latn", "PG"),
+    ("mvl", "Latn", "AU"),
+    ("mvn", "Latn", "PG"),
+    ("mvo", "Latn", "SB"),
+    ("mvp", "Latn", "ID"),
+    ("mvq", "Latn", "PG"),
+    ("mvr", "Latn", "ID"),
+    ("mvs", "Latn", "ID"),
+    ("mvt", "Latn", "VU"),
+    ("mvu", "Latn", "TD"),
+    ("mvv", "Latn", "MY"),
+    ("mvw", "Latn", "TZ"),
+    ("mvx", "Latn", "ID"),
+    ("mvy", "Arab", "PK"),
+    ("mvz", "Ethi", "ET"),
+    ("mwa", "Latn", "PG"),
+    ("mwb", "Latn", "PG"),
+    ("mwc", "Latn", "PG"),
+    ("mwe", "Latn", "TZ"),
+    ("mwf", "Latn", "AU"),
+    ("mwg", "Latn", "PG"),
+    ("mwh", "Latn", "PG"),
+    ("mwi", "Latn", "VU"),
+    ("mwk", "Latn", "ML"),
+    ("mwl", "Latn", "PT"),
+    ("mwm", "Latn", "TD"),
+    ("mwn", "Latn", "ZM"),
+    ("mwo", "Latn", "VU"),
+    ("mwp", "Latn", "AU"),
+    ("mwq", "Latn", "MM"),
+    ("mwr", "Deva", "IN"),
+    ("mws", "Latn", "KE"),
+    ("mwt", "Mymr", "MM"),
+    ("mwu", "Latn", "SS"),
+    ("mwv", "Latn", "ID"),
+    ("mww", "Hmnp", "US"),
+    ("mwz", "Latn", "CD"),
+    ("mxa", "Latn", "MX"),
+    ("mxb", "Latn", "MX"),
+    ("mxc", "Latn", "ZW"),
+    ("mxd", "Latn", "ID"),
+    ("mxe", "Latn", "VU"),
+    ("mxf", "Latn", "CM"),
+    ("mxg", "Latn", "AO"),
+    ("mxh", "Latn", "CD"),
+    ("mxi", "Latn", "ES"),
+    ("mxj", "Latn", "IN"),
+    ("mxk", "Latn", "PG"),
+    ("mxl", "Latn", "BJ"),
+    ("mxm", "Latn", "PG"),
+    ("mxn", "Latn", "ID"),
+    ("mxo", "Latn", "ZM"),
+    ("mxp", "Latn", "MX"),
+    ("mxq", "Latn", "MX"),
+    ("mxr", "Latn", "MY"),
+    ("mxs", "Latn", "MX"),
+    ("mxt", "Latn", "MX"),
+    ("mxu", "Latn", "CM"),
+    ("mxv", "Latn", "MX"),
+    ("mxw", "Latn", "PG"),
+    ("mxx", "Latn", "CI"),
+    ("mxy", "Latn", "MX"),
+    ("mxz", "Latn", "ID"),
+    ("my", "Mymr", "MM"),
+    ("myb", "Latn", "TD"),
+    ("myc", "Latn", "CD"),
+    ("mye", "Latn", "GA"),
+    ("myf", "Latn", "ET"),
+    ("myg", "Latn", "CM"),
+    ("myh", "Latn", "US"),
+    ("myj", "Latn", "SS"),
+    ("myk", "Latn", "ML"),
+    ("myl", "Latn", "ID"),
+    ("mym", "Ethi", "ET"),
+    ("myp", "Latn", "BR"),
+    ("myr", "Latn", "PE"),
+    ("myu", "Latn", "BR"),
+    ("myv", "Cyrl", "RU"),
+    ("myw", "Latn", "PG"),
+    ("myx", "Latn", "UG"),
+    ("myy", "Latn", "CO"),
+    ("myz", "Mand", "IR"),
+    ("mza", "Latn", "MX"),
+    ("mzb", "Arab", "DZ"),
+    ("mzd", "Latn", "CM"),
+    ("mze", "Latn", "PG"),
+    ("mzh", "Latn", "AR"),
+    ("mzi", "Latn", "MX"),
+    ("mzj", "Latn", "LR"),
+    ("mzk", "Latn", "NG"),
+    ("mzl", "Latn", "MX"),
+    ("mzm", "Latn", "NG"),
+    ("mzn", "Arab", "IR"),
+    ("mzo", "Latn", "BR"),
+    ("mzp", "Latn", "BO"),
+    ("mzq", "Latn", "ID"),
+    ("mzr", "Latn", "BR"),
+    ("mzt", "Latn", "MY"),
+    ("mzu", "Latn", "PG"),
+    ("mzv", "Latn", "CF"),
+    ("mzw", "Latn", "GH"),
+    ("mzx", "Latn", "GY"),
+    ("mzz", "Latn", "PG"),
+    ("na", "Latn", "NR"),
+    ("naa", "Latn", "ID"),
+    ("nab", "Latn", "BR"),
+    ("nac", "Latn", "PG"),
+    ("nae", "Latn", "ID"),
+    ("naf", "Latn", "PG"),
+    ("nag", "Latn", "IN"),
+    ("naj", "Latn", "GN"),
+    ("nak", "Latn", "PG"),
+    ("nal", "Latn", "PG"),
+    ("nam", "Latn", "AU"),
+    ("nan", "Hans", "CN"),
+    ("nao", "Deva", "NP"),
+    ("nap", "Latn", "IT"),
+    ("naq", "Latn", "NA"),
+    ("nar", "Latn", "NG"),
+    ("nas", "Latn", "PG"),
+    ("nat", "Latn", "NG"),
+    ("naw", "Latn", "GH"),
+    ("nax", "Latn", "PG"),
+    ("nay", "Latn", "AU"),
+    ("naz", "Latn", "MX"),
+    ("nb", "Latn", "NO"),
+    ("nba", "Latn", "AO"),
+    ("nbb", "Latn", "NG"),
+    ("nbc", "Latn", "IN"),
+    ("nbd", "Latn", "CD"),
+    ("nbe", "Latn", "IN"),
+    ("nbh", "Latn", "NG"),
+    ("nbi", "Latn", "IN"),
+    ("nbj", "Latn", "AU"),
+    ("nbk", "Latn", "PG"),
+    ("nbm", "Latn", "CF"),
+    ("nbn", "Latn", "ID"),
+    ("nbo", "Latn", "NG"),
+    ("nbp", "Latn", "NG"),
+    ("nbq", "Latn", "ID"),
+    ("nbr", "Latn", "NG"),
+    ("nbt", "Latn", "IN"),
+    ("nbu", "Latn", "IN"),
+    ("nbv", "Latn", "CM"),
+    ("nbw", "Latn", "CD"),
+    ("nby", "Latn", "PG"),
+    ("nca", "Latn", "PG"),
+    ("ncb", "Latn", "IN"),
+    ("ncc", "Latn", "PG"),
+    ("ncd", "Deva", "NP"),
+    ("nce", "Latn", "PG"),
+    ("ncf", "Latn", "PG"),
+    ("ncg", "Latn", "CA"),
+    ("nch", "Latn", "MX"),
+    ("nci", "Latn", "MX"),
+    ("ncj", "Latn", "MX"),
+    ("nck", "Latn", "AU"),
+    ("ncl", "Latn", "MX"),
+    ("ncm", "Latn", "PG"),
+    ("ncn", "Latn", "PG"),
+    ("nco", "Latn", "PG"),
+    ("ncq", "Laoo", "LA"),
+    ("ncr", "Latn", "CM"),
+    ("nct", "Latn", "IN"),
+    ("ncu", "Latn", "GH"),
+    ("ncx", "Latn", "MX"),
+    ("ncz", "Latn", "US"),
+    ("nd", "Latn", "ZW"),
+    ("nda", "Latn", "CG"),
+    ("ndb", "Latn", "CM"),
+    ("ndc", "Latn", "MZ"),
+    ("ndd", "Latn", "NG"),
+    ("ndf", "Cyrl", "RU"),
+    ("ndg", "Latn", "TZ"),
+    ("ndh", "Latn", "TZ"),
+    ("ndi", "Latn", "NG"),
+    ("ndj", "Latn", "TZ"),
+    ("ndk", "Latn", "CD"),
+    ("ndl", "Latn", "CD"),
+    ("ndm", "Latn", "TD"),
+    ("ndn", "Latn", "CG"),
+    ("ndp", "Latn", "UG"),
+    ("ndq", "Latn", "AO"),
+    ("ndr", "Latn", "NG"),
+    ("nds", "Latn", "DE"),
+    ("ndt", "Latn", "CD"),
+    ("ndu", "Latn", "CM"),
+    ("ndv", "Latn", "SN"),
+    ("ndw", "Latn", "CD"),
+    ("ndx", "Latn", "ID"),
+    ("ndy", "Latn", "CF"),
+    ("ndz", "Latn", "SS"),
+    ("ne", "Deva", "NP"),
+    ("nea", "Latn", "ID"),
+    ("neb", "Latn", "CI"),
+    ("nec", "Latn", "ID"),
+    ("ned", "Latn", "NG"),
+    ("nee", "Latn", "NC"),
+    ("neg", "Cyrl", "RU"),
+    ("neh", "Tibt", "BT"),
+    ("nei", "Xsux", "TR"),
+    ("nej", "Latn", "PG"),
+    ("nek", "Latn", "NC"),
+    ("nem", "Latn", "NC"),
+    ("nen", "Latn", "NC"),
+    ("neo", "Latn", "VN"),
+    ("neq", "Latn", "MX"),
+    ("ner", "Latn", "ID"),
+    ("net", "Latn", "PG"),
+    ("neu", "Latn", "001"),
+    ("new", "Deva", "NP"),
+    ("nex", "Latn", "PG"),
+    ("ney", "Latn", "CI"),
+    ("nez", "Latn", "US"),
+    ("nfa", "Latn", "ID"),
+    ("nfd", "Latn", "NG"),
+    ("nfl", "Latn", "SB"),
+    ("nfr", "Latn", "GH"),
+    ("nfu", "Latn", "CM"),
+    ("ng", "Latn", "NA"),
+    ("nga", "Latn", "CD"),
+    ("ngb", "Latn", "CD"),
+    ("ngc", "Latn", "CD"),
+    ("ngd", "Latn", "CF"),
+    ("nge", "Latn", "CM"),
+    ("ngg", "Latn", "CF"),
+    ("ngh", "Latn", "ZA"),
+    ("ngi", "Latn", "NG"),
+    ("ngj", "Latn", "CM"),
+    ("ngk", "Latn", "AU"),
+    ("ngl", "Latn", "MZ"),
+    ("ngm", "Latn", "FM"),
+    ("ngn", "Latn", "CM"),
+    ("ngp", "Latn", "TZ"),
+    ("ngq", "Latn", "TZ"),
+    ("ngr", "Latn", "SB"),
+    ("ngs", "Latn", "NG"),
+    ("ngt", "Laoo", "LA"),
+    ("ngu", "Latn", "MX"),
+    ("ngv", "Latn", "CM"),
+    ("ngw", "Latn", "NG"),
+    ("ngx", "Latn", "NG"),
+    ("ngy", "Latn", "CM"),
+    ("ngz", "Latn", "CG"),
+    ("nha", "Latn", "AU"),
+    ("nhb", "Latn", "CI"),
+    ("nhc", "Latn", "MX"),
+    ("nhd", "Latn", "PY"),
+    ("nhe", "Latn", "MX"),
+    ("nhf", "Latn", "AU"),
+    ("nhg", "Latn", "MX"),
+    ("nhi", "Latn", "MX"),
+    ("nhk", "Latn", "MX"),
+    ("nhm", "Latn", "MX"),
+    ("nhn", "Latn", "MX"),
+    ("nho", "Latn", "PG"),
+    ("nhp", "Latn", "MX"),
+    ("nhq", "Latn", "MX"),
+    ("nhr", "Latn", "BW"),
+    ("nht", "Latn", "MX"),
+    ("nhu", "Latn", "CM"),
+    ("nhv", "Latn", "MX"),
+    ("nhw", "Latn", "MX"),
+    ("nhx", "Latn", "MX"),
+    ("nhy", "Latn", "MX"),
+    ("nhz", "Latn", "MX"),
+    ("nia", "Latn", "ID"),
+    ("nib", "Latn", "PG"),
+    ("nid", "Latn", "AU"),
+    ("nie", "Latn", "TD"),
+    ("nif", "Latn", "PG"),
+    ("nig", "Latn", "AU"),
+    ("nih", "Latn", "TZ"),
+    ("nii", "Latn", "PG"),
+    ("nij", "Latn", "ID"),
+    ("nil", "Latn", "ID"),
+    ("nim", "Latn", "TZ"),
+    ("nin", "Latn", "NG"),
+    ("nio", "Cyrl", "RU"),
+    ("niq", "Latn", "KE"),
+    ("nir", "Latn", "ID"),
+    ("nis", "Latn", "PG"),
+    ("nit", "Telu", "IN"),
+    ("niu", "Latn", "NU"),
+    ("niv", "Cyrl", "RU"),
+    ("niw", "Latn", "PG"),
+    ("nix", "Latn", "CD"),
+    ("niy", "Latn", "CD"),
+    ("niz", "Latn", "PG"),
+    ("nja", "Latn", "NG"),
+    ("njb", "Latn", "IN"),
+    ("njd", "Latn", "TZ"),
+    ("njh", "Latn", "IN"),
+    ("nji", "Latn", "AU"),
+    ("njj", "Latn", "CM"),
+    ("njl", "Latn", "SS"),
+    ("njm", "Latn", "IN"),
+    ("njn", "Latn", "IN"),
+    ("njo", "Latn", "IN"),
+    ("njr", "Latn", "NG"),
+    ("njs", "Latn", "ID"),
+    ("njt", "Latn", "SR"),
+    ("nju", "Latn", "AU"),
+    ("njx", "Latn", "CG"),
+    ("njy", "Latn", "CM"),
+    ("njz", "Latn", "IN"),
+    ("nka", "Latn", "ZM"),
+    ("nkb", "Latn", "IN"),
+    ("nkc", "Latn", "CM"),
+    ("nkd", "Latn", "IN"),
+    ("nke", "Latn", "SB"),
+    ("nkf", "Latn", "IN"),
+    ("nkg", "Latn", "PG"),
+    ("nkh", "Latn", "IN"),
+    ("nki", "Latn", "IN"),
+    ("nkj", "Latn", "ID"),
+    ("nkk", "Latn", "VU"),
+    ("nkm", "Latn", "PG"),
+    ("nkn", "Latn", "AO"),
+    ("nko", "Latn", "GH"),
+    ("nkq", "Latn", "GH"),
+    ("nkr", "Latn", "FM"),
+    ("nks", "Latn", "ID"),
+    ("nkt", "Latn", "TZ"),
+    ("nku", "Latn", "CI"),
+    ("nkv", "Latn", "MW"),
+    ("nkw", "Latn", "CD"),
+    ("nkx", "Latn", "NG"),
+    ("nkz", "Latn", "NG"),
+    ("nl", "Latn", "NL"),
+    ("nla", "Latn", "CM"),
+    ("nlc", "Latn", "ID"),
+    ("nle", "Latn", "KE"),
+    ("nlg", "Latn", "SB"),
+    ("nli", "Arab", "AF"),
+    ("nlj", "Latn", "CD"),
+    ("nlk", "Latn", "ID"),
+    ("nlm", "Arab", "PK"),
+    ("nlo", "Latn", "CD"),
+    ("nlq", "Latn", "MM"),
+    ("nlu", "Latn", "GH"),
+    ("nlv", "Latn", "MX"),
+    ("nlw", "Latn", "AU"),
+    ("nlx", "Deva", "IN"),
+    ("nly", "Latn", "AU"),
+    ("nlz", "Latn", "SB"),
+    ("nma", "Latn", "IN"),
+    ("nmb", "Latn", "VU"),
+    ("nmc", "Latn", "TD"),
+    ("nmd", "Latn", "GA"),
+    ("nme", "Latn", "IN"),
+    ("nmf", "Latn", "IN"),
+    ("nmg", "Latn", "CM"),
+    ("nmh", "Latn", "IN"),
+    ("nmi", "Latn", "NG"),
+    ("nmj", "Latn", "CF"),
+    ("nmk", "Latn", "VU"),
+    ("nml", "Latn", "CM"),
+    ("nmm", "Deva", "NP"),
+    ("nmn", "Latn", "BW"),
+    ("nmo", "Latn", "IN"),
+    ("nmp", "Latn", "AU"),
+    ("nmq", "Latn", "ZW"),
+    ("nmr", "Latn", "CM"),
+    ("nms", "Latn", "VU"),
+    ("nmt", "Latn", "FM"),
+    ("nmu", "Latn", "US"),
+    ("nmv", "Latn", "AU"),
+    ("nmw", "Latn", "PG"),
+    ("nmx", "Latn", "PG"),
+    ("nmz", "Latn", "TG"),
+    ("nn", "Latn", "NO"),
+    ("nna", "Latn", "AU"),
+    ("nnb", "Latn", "CD"),
+    ("nnc", "Latn", "TD"),
+    ("nnd", "Latn", "VU"),
+    ("nne", "Latn", "AO"),
+    ("nnf", "Latn", "PG"),
+    ("nng", "Latn", "IN"),
+    ("nnh", "Latn", "CM"),
+    ("nni", "Latn", "ID"),
+    ("nnj", "Latn", "ET"),
+    ("nnk", "Latn", "PG"),
+    ("nnl", "Latn", "IN"),
+    ("nnm", "Latn", "PG"),
+    ("nnn", "Latn", "TD"),
+    ("nnp", "Wcho", "IN"),
+    ("nnq", "Latn", "TZ"),
+    ("nnr", "Latn", "AU"),
+    ("nnt", "Latn", "US"),
+    ("nnu", "Latn", "GH"),
+    ("nnv", "Latn", "AU"),
+    ("nnw", "Latn", "BF"),
+    ("nny", "Latn", "AU"),
+    ("nnz", "Latn", "CM"),
+    ("no", "Latn", "NO"),
+    ("noa", "Latn", "CO"),
+    ("noc", "Latn", "PG"),
+    ("nod", "Lana", "TH"),
+    ("noe", "Deva", "IN"),
+    ("nof", "Latn", "PG"),
+    ("nog", "Cyrl", "RU"),
+    ("noh", "Latn", "PG"),
+    ("noi", "Deva", "IN"),
+    ("noj", "Latn", "CO"),
+    ("nok", "Latn", "US"),
+    ("non", "Runr", "SE"),
+    ("nop", "Latn", "PG"),
+    ("noq", "Latn", "CD"),
+    ("nos", "Yiii", "CN"),
+    ("not", "Latn", "PE"),
+    ("nou", "Latn", "PG"),
+    ("nov", "Latn", "001"),
+    ("now", "Latn", "TZ"),
+    ("noy", "Latn", "TD"),
+    ("npb", "Tibt", "BT"),
+    ("npg", "Latn", "MM"),
+    ("nph", "Latn", "IN"),
+    ("npl", "Latn", "MX"),
+    ("npn", "Latn", "PG"),
+    ("npo", "Latn", "IN"),
+    ("nps", "Latn", "ID"),
+    ("npu", "Latn", "IN"),
+    ("npx", "Latn", "SB"),
+    ("npy", "Latn", "ID"),
+    ("nqg", "Latn", "BJ"),
+    ("nqk", "Latn", "BJ"),
+    ("nql", "Latn", "AO"),
+    ("nqm", "Latn", "ID"),
+    ("nqn", "Latn", "PG"),
+    ("nqo", "Nkoo", "GN"),
+    ("nqq", "Latn", "MM"),
+    ("nqt", "Latn", "NG"),
+    ("nqy", "Latn", "MM"),
+    ("nr", "Latn", "ZA"),
+    ("nra", "Latn", "GA"),
+    ("nrb", "Latn", "ER"),
+    ("nre", "Latn", "IN"),
+    ("nrf", "Latn", "JE"),
+    ("nrg", "Latn", "VU"),
+    ("nri", "Latn", "IN"),
+    ("nrk", "Latn", "AU"),
+    ("nrl", "Latn", "AU"),
+    ("nrm", "Latn", "MY"),
+    ("nrn", "Runr", "GB"),
+    ("nrp", "Latn", "IT"),
+    ("nru", "Latn", "CN"),
+    ("nrx", "Latn", "AU"),
+    ("nrz", "Latn", "PG"),
+    ("nsa", "Latn", "IN"),
+    ("nsb", "Latn", "ZA"),
+    ("nsc", "Latn", "NG"),
+    ("nsd", "Yiii", "CN"),
+    ("nse", "Latn", "ZM"),
+    ("nsf", "Yiii", "CN"),
+    ("nsg", "Latn", "TZ"),
+    ("nsh", "Latn", "CM"),
+    ("nsk", "Cans", "CA"),
+    ("nsm", "Latn", "IN"),
+    ("nsn", "Latn", "PG"),
+    ("nso", "Latn", "ZA"),
+    ("nsq", "Latn", "US"),
+    ("nss", "Latn", "PG"),
+    ("nst", "Tnsa", "IN"),
+    ("nsu", "Latn", "MX"),
+    ("nsv", "Yiii", "CN"),
+    ("nsw", "Latn", "VU"),
+    ("nsx", "Latn", "AO"),
+    ("nsy", "Latn", "ID"),
+    ("nsz", "Latn", "US"),
+    ("ntd", "Latn", "MY"),
+    ("ntg", "Latn", "AU"),
+    ("nti", "Latn", "BF"),
+    ("ntj", "Latn", "AU"),
+    ("ntk", "Latn", "TZ"),
+    ("ntm", "Latn", "BJ"),
+    ("nto", "Latn", "CD"),
+    ("ntp", "Latn", "MX"),
+    ("ntr", "Latn", "GH"),
+    ("ntu", "Latn", "SB"),
+    ("ntx", "Latn", "MM"),
+    ("nty", "Yiii", "VN"),
+    ("ntz", "Arab", "IR"),
+    ("nua", "Latn", "NC"),
+    ("nuc", "Latn", "BR"),
+    ("nud", "Latn", "PG"),
+    ("nue", "Latn", "CF"),
+    ("nuf", "Latn", "CN"),
+    ("nug", "Latn", "AU"),
+    ("nuh", "Latn", "NG"),
+    ("nui", "Latn", "GQ"),
+    ("nuj", "Latn", "UG"),
+    ("nuk", "Latn", "CA"),
+    ("num", "Latn", "TO"),
+    ("nun", "Latn", "MM"),
+    ("nuo", "Latn", "VN"),
+    ("nup", "Latn", "NG"),
+    ("nuq", "Latn", "PG"),
+    ("nur", "Latn", "PG"),
+    ("nus", "Latn", "SS"),
+    ("nut", "Latn", "VN"),
+    ("nuu", "Latn", "CD"),
+    ("nuv", "Latn", "BF"),
+    ("nuw", "Latn", "FM"),
+    ("nux", "Latn", "PG"),
+    ("nuy", "Latn", "AU"),
+    ("nuz", "Latn", "MX"),
+    ("nv", "Latn", "US"),
+    ("nvh", "Latn", "VU"),
+    ("nvm", "Latn", "PG"),
+    ("nvo", "Latn", "CM"),
+    ("nwb", "Latn", "CI"),
+    ("nwc", "Newa", "NP"),
+    ("nwe", "Latn", "CM"),
+    ("nwg", "Latn", "AU"),
+    ("nwi", "Latn", "VU"),
+    ("nwm", "Latn", "SS"),
+    ("nwo", "Latn", "AU"),
+    ("nwr", "Latn", "PG"),
+    ("nww", "Latn", "TZ"),
+    ("nwx", "Deva", "NP"),
+    ("nxa", "Latn", "TL"),
+    ("nxd", "Latn", "CD"),
+    ("nxe", "Latn", "ID"),
+    ("nxg", "Latn", "ID"),
+    ("nxi", "Latn", "TZ"),
+    ("nxk", "Latn", "MM"),
+    ("nxl", "Latn", "ID"),
+    ("nxn", "Latn", "AU"),
+    ("nxo", "Latn", "GA"),
+    ("nxq", "Latn", "CN"),
+    ("nxr", "Latn", "PG"),
+    ("nxx", "Latn", "ID"),
+    ("ny", "Latn", "MW"),
+    ("nyb", "Latn", "GH"),
+    ("nyc", "Latn", "CD"),
+    ("nyd", "Latn", "KE"),
+    ("nye", "Latn", "AO"),
+    ("nyf", "Latn", "KE"),
+    ("nyg", "Latn", "CD"),
+    ("nyh", "Latn", "AU"),
+    ("nyi", "Latn", "SD"),
+    ("nyj", "Latn", "CD"),
+    ("nyk", "Latn", "AO"),
+    ("nyl", "Thai", "TH"),
+    ("nym", "Latn", "TZ"),
+    ("nyn", "Latn", "UG"),
+    ("nyo", "Latn", "UG"),
+    ("nyp", "Latn", "UG"),
+    ("nyq", "Arab", "IR"),
+    ("nyr", "Latn", "MW"),
+    ("nys", "Latn", "AU"),
+    ("nyt", "Latn", "AU"),
+    ("nyu", "Latn", "MZ"),
+    ("nyv", "Latn", "AU"),
+    ("nyw", "Thai", "TH"),
+    ("nyx", "Latn", "AU"),
+    ("nyy", "Latn", "TZ"),
+    ("nza", "Latn", "CM"),
+    ("nzb", "Latn", "GA"),
+    ("nzd", "Latn", "CD"),
+    ("nzi", "Latn", "GH"),
+    ("nzk", "Latn", "CF"),
+    ("nzm", "Latn", "IN"),
+    ("nzr", "Latn", "NG"),
+    ("nzu", "Latn", "CG"),
+    ("nzy", "Latn", "TD"),
+    ("nzz", "Latn", "ML"),
+    ("oaa", "Cyrl", "RU"),
+    ("oac", "Cyrl", "RU"),
+    ("oar", "Syrc", "SY"),
+    ("oav", "Geor", "GE"),
+    ("obi", "Latn", "US"),
+    ("obk", "Latn", "PH"),
+    ("obl", "Latn", "CM"),
+    ("obm", "Phnx", "JO"),
+    ("obo", "Latn", "PH"),
+    ("obr", "Mymr", "MM"),
+    ("obt", "Latn", "FR"),
+    ("obu", "Latn", "NG"),
+    ("oc", "Latn", "FR"),
+    ("oca", "Latn", "PE"),
+    ("oco", "Latn", "GB"),
+    ("ocu", "Latn", "MX"),
+    ("oda", "Latn", "NG"),
+    ("odk", "Arab", "PK"),
+    ("odt", "Latn", "NL"),
+    ("odu", "Latn", "NG"),
+    ("ofs", "Latn", "NL"),
+    ("ofu", "Latn", "NG"),
+    ("ogb", "Latn", "NG"),
+    ("ogc", "Latn", "NG"),
+    ("ogg", "Latn", "NG"),
+    ("ogo", "Latn", "NG"),
+    ("ogu", "Latn", "NG"),
+    ("oht", "Xsux", "TR"),
+    ("ohu", "Latn", "HU"),
+    ("oia", "Latn", "ID"),
+    ("oie", "Latn", "SS"),
+    ("oin", "Latn", "PG"),
+    ("oj", "Cans", "CA"),
+    ("ojb", "Latn", "CA"),
+    ("ojc", "Latn", "CA"),
+    ("ojs", "Cans", "CA"),
+    ("ojv", "Latn", "SB"),
+    ("ojw", "Latn", "CA"),
+    ("oka", "Latn", "CA"),
+    ("okb", "Latn", "NG"),
+    ("okc", "Latn", "CD"),
+    ("okd", "Latn", "NG"),
+    ("oke", "Latn", "NG"),
+    ("okg", "Latn", "AU"),
+    ("oki", "Latn", "KE"),
+    ("okk", "Latn", "PG"),
+    ("okm", "Hang", "KR"),
+    ("oko", "Hani", "KR"),
+    ("okr", "Latn", "NG"),
+    ("oks", "Latn", "NG"),
+    ("oku", "Latn", "CM"),
+    ("okv", "Latn", "PG"),
+    ("okx", "Latn", "NG"),
+    ("okz", "Khmr", "KH"),
+    ("ola", "Deva", "NP"),
+    ("old", "Latn", "TZ"),
+    ("ole", "Tibt", "BT"),
+    ("olk", "Latn", "AU"),
+    ("olm", "Latn", "NG"),
+    ("olo", "Latn", "RU"),
+    ("olr", "Latn", "VU"),
+    ("olt", "Latn", "LT"),
+    ("olu", "Latn", "AO"),
+    ("om", "Latn", "ET"),
+    ("oma", "Latn", "US"),
+    ("omb", "Latn", "VU"),
+    ("omc", "Latn", "PE"),
+    ("omg", "Latn", "PE"),
+    ("omi", "Latn", "CD"),
+    ("omk", "Cyrl", "RU"),
+    ("oml", "Latn", "CD"),
+    ("omo", "Latn", "PG"),
+    ("omp", "Mtei", "IN"),
+    ("omr", "Modi", "IN"),
+    ("omt", "Latn", "KE"),
+    ("omu", "Latn", "PE"),
+    ("omw", "Latn", "PG"),
+    ("omx", "Mymr", "MM"),
+    ("ona", "Latn", "AR"),
+    ("one", "Latn", "CA"),
+    ("ong", "Latn", "PG"),
+    ("oni", "Latn", "ID"),
+    ("onj", "Latn", "PG"),
+    ("onk", "Latn", "PG"),
+    ("onn", "Latn", "PG"),
+    ("ono", "Latn", "CA"),
+    ("onp", "Latn", "IN"),
+    ("onr", "Latn", "PG"),
+    ("ons", "Latn", "PG"),
+    ("ont", "Latn", "PG"),
+    ("onu", "Latn", "VU"),
+    ("onx", "Latn", "ID"),
+    ("ood", "Latn", "US"),
+    ("oon", "Deva", "IN"),
+    ("oor", "Latn", "ZA"),
+    ("opa", "Latn", "NG"),
+    ("opk", "Latn", "ID"),
+    ("opm", "Latn", "PG"),
+    ("opo", "Latn", "PG"),
+    ("opt", "Latn", "MX"),
+    ("opy", "Latn", "BR"),
+    ("or", "Orya", "IN"),
+    ("ora", "Latn", "SB"),
+    ("orc", "Latn", "KE"),
+    ("ore", "Latn", "PE"),
+    ("org", "Latn", "NG"),
+    ("orn", "Latn", "MY"),
+    ("oro", "Latn", "PG"),
+    ("orr", "Latn", "NG"),
+    ("ors", "Latn", "MY"),
+    ("ort", "Telu", "IN"),
+    ("oru", "Arab", "PK"),
+    ("orv", "Cyrl", "RU"),
+    ("orw", "Latn", "BR"),
+    ("orx", "Latn", "NG"),
+    ("orz", "Latn", "ID"),
+    ("os", "Cyrl", "GE"),
+    ("osa", "Osge", "US"),
+    ("osc", "Ital", "IT"),
+    ("osi", "Java", "ID"),
+    ("oso", "Latn", "NG"),
+    ("osp", "Latn", "ES"),
+    ("ost", "Latn", "CM"),
+    ("osu", "Latn", "PG"),
+    ("osx", "Latn", "DE"),
+    ("ota", "Arab", "TR"),
+    ("otb", "Tibt", "CN"),
+    ("otd", "Latn", "ID"),
+    ("ote", "Latn", "MX"),
+    ("oti", "Latn", "BR"),
+    ("otk", "Orkh", "MN"),
+    ("otl", "Latn", "MX"),
+    ("otm", "Latn", "MX"),
+    ("otn", "Latn", "MX"),
+    ("otq", "Latn", "MX"),
+    ("otr", "Latn", "SD"),
+    ("ots", "Latn", "MX"),
+    ("ott", "Latn", "MX"),
+    ("otu", "Latn", "BR"),
+    ("otw", "Latn", "CA"),
+    ("otx", "Latn", "MX"),
+    ("oty", "Gran", "IN"),
+    ("otz", "Latn", "MX"),
+    ("oub", "Latn", "LR"),
+    ("oue", "Latn", "PG"),
+    ("oui", "Ougr", "CN"),
+    ("oum", "Latn", "PG"),
+    ("ovd", "Latn", "SE"),
+    ("owi", "Latn", "PG"),
+    ("owl", "Latn", "GB"),
+    ("oyb", "Laoo", "LA"),
+    ("oyd", "Latn", "ET"),
+    ("oym", "Latn", "BR"),
+    ("oyy", "Latn", "PG"),
+    ("ozm", "Latn", "CM"),
+    ("pa", "Guru", "IN"),
+    ("pab", "Latn", "BR"),
+    ("pac", "Latn", "VN"),
+    ("pad", "Latn", "BR"),
+    ("pae", "Latn", "CD"),
+    ("paf", "Latn", "BR"),
+    ("pag", "Latn", "PH"),
+    ("pah", "Latn", "BR"),
+    ("pai", "Latn", "NG"),
+    ("pak", "Latn", "BR"),
+    ("pal", "Phli", "IR"),
+    ("pam", "Latn", "PH"),
+    ("pao", "Latn", "US"),
+    ("pap", "Latn", "CW"),
+    ("paq", "Cyrl", "TJ"),
+    ("par", "Latn", "US"),
+    ("pas", "Latn", "ID"),
+    ("pau", "Latn", "PW"),
+    ("pav", "Latn", "BR"),
+    ("paw", "Latn", "US"),
+    ("pax", "Latn", "BR"),
+    ("pay", "Latn", "HN"),
+    ("paz", "Latn", "BR"),
+    ("pbb", "Latn", "CO"),
+    ("pbc", "Latn", "GY"),
+    ("pbe", "Latn", "MX"),
+    ("pbf", "Latn", "MX"),
+    ("pbg", "Latn", "VE"),
+    ("pbh", "Latn", "VE"),
+    ("pbi", "Latn", "CM"),
+    ("pbl", "Latn", "NG"),
+    ("pbm", "Latn", "MX"),
+    ("pbn", "Latn", "NG"),
+    ("pbo", "Latn", "GW"),
+    ("pbp", "Latn", "GN"),
+    ("pbr", "Latn", "TZ"),
+    ("pbs", "Latn", "MX"),
+    ("pbt", "Arab", "AF"),
+    ("pbv", "Latn", "IN"),
+    ("pby", "Latn", "PG"),
+    ("pca", "Latn", "MX"),
+    ("pcb", "Khmr", "KH"),
+    ("pcc", "Latn", "CN"),
+    ("pcd", "Latn", "FR"),
+    ("pce", "Mymr", "MM"),
+    ("pcf", "Mlym", "IN"),
+    ("pcg", "Mlym", "IN"),
+    ("pch", "Deva", "IN"),
+    ("pci", "Deva", "IN"),
+    ("pcj", "Telu", "IN"),
+    ("pck", "Latn", "IN"),
+    ("pcm", "Latn", "NG"),
+    ("pcn", "Latn", "NG"),
+    ("pcp", "Latn", "BO"),
+    ("pcw", "Latn", "NG"),
+    ("pda", "Latn", "PG"),
+    ("pdc", "Latn", "US"),
+    ("pdn", "Latn", "ID"),
+    ("pdo", "Latn", "ID"),
+    ("pdt", "Latn", "CA"),
+    ("pdu", "Latn", "MM"),
+    ("pea", "Latn", "ID"),
+    ("peb", "Latn", "US"),
+    ("ped", "Latn", "PG"),
+    ("pee", "Latn", "ID"),
+    ("peg", "Orya", "IN"),
+    ("pei", "Latn", "MX"),
+    ("pek", "Latn", "PG"),
+    ("pel", "Latn", "ID"),
+    ("pem", "Latn", "CD"),
+    ("peo", "Xpeo", "IR"),
+    ("pep", "Latn", "PG"),
+    ("peq", "Latn", "US"),
+    ("pev", "Latn", "VE"),
+    ("pex", "Latn", "PG"),
+    ("pey", "Latn", "ID"),
+    ("pez", "Latn", "MY"),
+    ("pfa", "Latn", "FM"),
+    ("pfe", "Latn", "CM"),
+    ("pfl", "Latn", "DE"),
+    ("pga", "Latn", "SS"),
+    ("pgd", "Khar", "PK"),
+    ("pgg", "Deva", "IN"),
+    ("pgi", "Latn", "PG"),
+    ("pgk", "Latn", "VU"),
+    ("pgl", "Ogam", "IE"),
+    ("pgn", "Ital", "IT"),
+    ("pgs", "Latn", "NG"),
+    ("pgu", "Latn", "ID"),
+    ("phd", "Deva", "IN"),
+    ("phg", "Latn", "VN"),
+    ("phh", "Latn", "VN"),
+    ("phk", "Mymr", "IN"),
+    ("phl", "Arab", "PK"),
+    ("phm", "Latn", "MZ"),
+    ("phn", "Phnx", "LB"),
+    ("pho", "Laoo", "LA"),
+    ("phr", "Arab", "PK"),
+    ("pht", "Thai", "TH"),
+    ("phu", "Thai", "TH"),
+    ("phv", "Arab", "AF"),
+    ("phw", "Deva", "NP"),
+    ("pi", "Latn", "GB"),
+    ("pia", "Latn", "MX"),
+    ("pib", "Latn", "PE"),
+    ("pic", "Latn", "GA"),
+    ("pid", "Latn", "VE"),
+    ("pif", "Latn", "FM"),
+    ("pig", "Latn", "PE"),
+    ("pih", "Latn", "NF"),
+    ("pij", "Latn", "CO"),
+    ("pil", "Latn", "BJ"),
+    ("pim", "Latn", "US"),
+    ("pin", "Latn", "PG"),
+    ("pio", "Latn", "CO"),
+    ("pip", "Latn", "NG"),
+    ("pir", "Latn", "BR"),
+    ("pis", "Latn", "SB"),
+    ("pit", "Latn", "AU"),
+    ("piu", "Latn", "AU"),
+    ("piv", "Latn", "SB"),
+    ("piw", "Latn", "TZ"),
+    ("pix", "Latn", "PG"),
+    ("piy", "Latn", "NG"),
+    ("piz", "Latn", "NC"),
+    ("pjt", "Latn", "AU"),
+    ("pka", "Brah", "IN"),
+    ("pkb", "Latn", "KE"),
+    ("pkg", "Latn", "PG"),
+    ("pkh", "Latn", "BD"),
+    ("pkn", "Latn", "AU"),
+    ("pko", "Latn", "KE"),
+    ("pkp", "Latn", "CK"),
+    ("pkr", "Mlym", "IN"),
+    ("pku", "Latn", "ID"),
+    ("pl", "Latn", "PL"),
+    ("pla", "Latn", "PG"),
+    ("plb", "Latn", "VU"),
+    ("plc", "Latn", "PH"),
+    ("pld", "Latn", "GB"),
+    ("ple", "Latn", "ID"),
+    ("plg", "Latn", "AR"),
+    ("plh", "Latn", "ID"),
+    ("plk", "Arab", "PK"),
+    ("pll", "Mymr", "MM"),
+    ("pln", "Latn", "CO"),
+    ("plo", "Latn", "MX"),
+    ("plr", "Latn", "CI"),
+    ("pls", "Latn", "MX"),
+    ("plu", "Latn", "BR"),
+    ("plv", "Latn", "PH"),
+    ("plw", "Latn", "PH"),
+    ("plz", "Latn", "MY"),
+    ("pma", "Latn", "VU"),
+    ("pmb", "Latn", "CD"),
+    ("pmd", "Latn", "AU"),
+    ("pme", "Latn", "NC"),
+    ("pmf", "Latn", "ID"),
+    ("pmh", "Brah", "IN"),
+    ("pmi", "Latn", "CN"),
+    ("pmj", "Latn", "CN"),
+    ("pml", "Latn", "TN"),
+    ("pmm", "Latn", "CM"),
+    ("pmn", "Latn", "CM"),
+    ("pmo", "Latn", "ID"),
+    ("pmq", "Latn", "MX"),
+    ("pmr", "Latn", "PG"),
+    ("pms", "Latn", "IT"),
+    ("pmt", "Latn", "PF"),
+    ("pmw", "Latn", "US"),
+    ("pmx", "Latn", "IN"),
+    ("pmy", "Latn", "ID"),
+    ("pmz", "Latn", "MX"),
+    ("pna", "Latn", "MY"),
+    ("pnc", "Latn", "ID"),
+    ("pnd", "Latn", "AO"),
+    ("pne", "Latn", "MY"),
+    ("png", "Latn", "NG"),
+    ("pnh", "Latn", "CK"),
+    ("pni", "Latn", "ID"),
+    ("pnj", "Latn", "AU"),
+    ("pnk", "Latn", "BO"),
+    ("pnl", "Latn", "BF"),
+    ("pnm", "Latn", "MY"),
+    ("pnn", "Latn", "PG"),
+    ("pno", "Latn", "PE"),
+    ("pnp", "Latn", "ID"),
+    ("pnq", "Latn", "BF"),
+    ("pnr", "Latn", "PG"),
+    ("pns", "Latn", "ID"),
+    ("pnt", "Grek", "GR"),
+    ("pnv", "Latn", "AU"),
+    ("pnw", "Latn", "AU"),
+    ("pny", "Latn", "CM"),
+    ("pnz", "Latn", "CF"),
+    ("poc", "Latn", "GT"),
+    ("poe", "Latn", "MX"),
+    ("pof", "Latn", "CD"),
+    ("pog", "Latn", "BR"),
+    ("poh", "Latn", "GT"),
+    ("poi", "Latn", "MX"),
+    ("pok", "Latn", "BR"),
+    ("pom", "Latn", "US"),
+    ("pon", "Latn", "FM"),
+    ("poo", "Latn", "US"),
+    ("pop", "Latn", "NC"),
+    ("poq", "Latn", "MX"),
+    ("pos", "Latn", "MX"),
+    ("pot", "Latn", "US"),
+    ("pov", "Latn", "GW"),
+    ("pow", "Latn", "MX"),
+    ("poy", "Latn", "TZ"),
+    ("ppe", "Latn", "PG"),
+    ("ppi", "Latn", "MX"),
+    ("ppk", "Latn", "ID"),
+    ("ppl", "Latn", "SV"),
+    ("ppm", "Latn", "ID"),
+    ("ppn", "Latn", "PG"),
+    ("ppo", "Latn", "PG"),
+    ("ppp", "Latn", "CD"),
+    ("ppq", "Latn", "PG"),
+    ("pps", "Latn", "MX"),
+    ("ppt", "Latn", "PG"),
+    ("pqa", "Latn", "NG"),
+    ("pqm", "Latn", "CA"),
+    ("prc", "Arab", "AF"),
+    ("prd", "Arab", "IR"),
+    ("pre", "Latn", "ST"),
+    ("prf", "Latn", "PH"),
+    ("prg", "Latn", "PL"),
+    ("prh", "Latn", "PH"),
+    ("pri", "Latn", "NC"),
+    ("prk", "Latn", "MM"),
+    ("prm", "Latn", "PG"),
+    ("pro", "Latn", "FR"),
+    ("prq", "Latn", "PE"),
+    ("prr", "Latn", "BR"),
+    ("prt", "Thai", "TH"),
+    ("pru", "Latn", "ID"),
+    ("prw", "Latn", "PG"),
+    ("prx", "Arab", "IN"),
+    ("ps", "Arab", "AF"),
+    ("psa", "Latn", "ID"),
+    ("pse", "Latn", "ID"),
+    ("psh", "Arab", "AF"),
+    ("psi", "Arab", "AF"),
+    ("psm", "Latn", "BO"),
+    ("psn", "Latn", "ID"),
+    ("psq", "Latn", "PG"),
+    ("pss", "Latn", "PG"),
+    ("pst", "Arab", "PK"),
+    ("psu", "Brah", "IN"),
+    ("psw", "Latn", "VU"),
+    ("pt", "Latn", "BR"),
+    ("pta", "Latn", "PY"),
+    ("pth", "Latn", "BR"),
+    ("pti", "Latn", "AU"),
+    ("ptn", "Latn", "ID"),
+    ("pto", "Latn", "BR"),
+    ("ptp", "Latn", "PG"),
+    ("ptr", "Latn", "VU"),
+    ("ptt", "Latn", "ID"),
+    ("ptu", "Latn", "ID"),
+    ("ptv", "Latn", "VU"),
+    ("pua", "Latn", "MX"),
+    ("pub", "Latn", "IN"),
+    ("puc", "Latn", "ID"),
+    ("pud", "Latn", "ID"),
+    ("pue", "Latn", "AR"),
+    ("puf", "Latn", "ID"),
+    ("pug", "Latn", "BF"),
+    ("pui", "Latn", "CO"),
+    ("puj", "Latn", "ID"),
+    ("pum", "Deva", "NP"),
+    ("puo", "Latn", "VN"),
+    ("pup", "Latn", "PG"),
+    ("puq", "Latn", "BO"),
+    ("pur", "Latn", "BR"),
+    ("put", "Latn", "ID"),
+    ("puu", "Latn", "GA"),
+    ("puw", "Latn", "FM"),
+    ("pux", "Latn", "PG"),
+    ("puy", "Latn", "US"),
+    ("pwa", "Latn", "PG"),
+    ("pwb", "Latn", "NG"),
+    ("pwg", "Latn", "PG"),
+    ("pwm", "Latn", "PH"),
+    ("pwn", "Latn", "TW"),
+    ("pwo", "Mymr", "MM"),
+    ("pwr", "Deva", "IN"),
+    ("pww", "Thai", "TH"),
+    ("pxm", "Latn", "MX"),
+    ("pye", "Latn", "CI"),
+    ("pym", "Latn", "NG"),
+    ("pyn", "Latn", "BR"),
+    ("pyu", "Latn", "TW"),
+    ("pyx", "Mymr", "MM"),
+    ("pyy", "Latn", "MM"),
+    ("pze", "Latn", "NG"),
+    ("pzh", "Latn", "TW"),
+    ("pzn", "Latn", "MM"),
+    ("qu", "Latn", "PE"),
+    ("qua", "Latn", "US"),
+    ("qub", "Latn", "PE"),
+    ("quc", "Latn", "GT"),
+    ("qud", "Latn", "EC"),
+    ("quf", "Latn", "PE"),
+    ("qug", "Latn", "EC"),
+    ("qui", "Latn", "US"),
+    ("quk", "Latn", "PE"),
+    ("qul", "Latn", "BO"),
+    ("qum", "Latn", "GT"),
+    ("qun", "Latn", "US"),
+    ("qup", "Latn", "PE"),
+    ("quq", "Latn", "ES"),
+    ("qur", "Latn", "PE"),
+    ("qus", "Latn", "AR"),
+    ("quv", "Latn", "GT"),
+    ("quw", "Latn", "EC"),
+    ("qux", "Latn", "PE"),
+    ("quy", "Latn", "PE"),
+    ("qva", "Latn", "PE"),
+    ("qvc", "Latn", "PE"),
+    ("qve", "Latn", "PE"),
+    ("qvh", "Latn", "PE"),
+    ("qvi", "Latn", "EC"),
+    ("qvj", "Latn", "EC"),
+    ("qvl", "Latn", "PE"),
+    ("qvm", "Latn", "PE"),
+    ("qvn", "Latn", "PE"),
+    ("qvo", "Latn", "PE"),
+    ("qvp", "Latn", "PE"),
+    ("qvs", "Latn", "PE"),
+    ("qvw", "Latn", "PE"),
+    ("qvz", "Latn", "EC"),
+    ("qwa", "Latn", "PE"),
+    ("qwc", "Latn", "PE"),
+    ("qwh", "Latn", "PE"),
+    ("qwm", "Latn", "HU"),
+    ("qws", "Latn", "PE"),
+    ("qwt", "Latn", "US"),
+    ("qxa", "Latn", "PE"),
+    ("qxc", "Latn", "PE"),
+    ("qxh", "Latn", "PE"),
+    ("qxl", "Latn", "EC"),
+    ("qxn", "Latn", "PE"),
+    ("qxo", "Latn", "PE"),
+    ("qxp", "Latn", "PE"),
+    ("qxq", "Arab", "IR"),
+    ("qxr", "Latn", "EC"),
+    ("qxt", "Latn", "PE"),
+    ("qxu", "Latn", "PE"),
+    ("qxw", "Latn", "PE"),
+    ("qya", "Latn", "001"),
+    ("qyp", "Latn", "US"),
+    ("raa", "Deva", "NP"),
+    ("rab", "Deva", "NP"),
+    ("rac", "Latn", "ID"),
+    ("rad", "Latn", "VN"),
+    ("raf", "Deva", "NP"),
+    ("rag", "Latn", "KE"),
+    ("rah", "Beng", "IN"),
+    ("rai", "Latn", "PG"),
+    ("raj", "Deva", "IN"),
+    ("rak", "Latn", "PG"),
+    ("ram", "Latn", "BR"),
+    ("ran", "Latn", "ID"),
+    ("rao", "Latn", "PG"),
+    ("rap", "Latn", "CL"),
+    ("rar", "Latn", "CK"),
+    ("rav", "Deva", "NP"),
+    ("raw", "Latn", "MM"),
+    ("rax", "Latn", "NG"),
+    ("ray", "Latn", "PF"),
+    ("raz", "Latn", "ID"),
+    ("rbb", "Mymr", "MM"),
+    ("rbk", "Latn", "PH"),
+    ("rbl", "Latn", "PH"),
+    ("rbp", "Latn", "AU"),
+    ("rcf", "Latn", "RE"),
+    ("rdb", "Arab", "IR"),
+    ("rea", "Latn", "PG"),
+    ("reb", "Latn", "ID"),
+    ("ree", "Latn", "MY"),
+    ("reg", "Latn", "TZ"),
+    ("rei", "Orya", "IN"),
+    ("rej", "Latn", "ID"),
+    ("rel", "Latn", "KE"),
+    ("rem", "Latn", "PE"),
+    ("ren", "Latn", "VN"),
+    ("res", "Latn", "NG"),
+    ("ret", "Latn", "ID"),
+    ("rey", "Latn", "BO"),
+    ("rga", "Latn", "VU"),
+    ("rgn", "Latn", "IT"),
+    ("rgr", "Latn", "PE"),
+    ("rgs", "Latn", "VN"),
+    ("rgu", "Latn", "ID"),
+    ("rhg", "Rohg", "MM"),
+    ("rhp", "Latn", "PG"),
+    ("ria", "Latn", "IN"),
+    ("rif", "Latn", "MA"),
+    ("ril", "Latn", "MM"),
+    ("rim", "Latn", "TZ"),
+    ("rin", "Latn", "NG"),
+    ("rir", "Latn", "ID"),
+    ("rit", "Latn", "AU"),
+    ("riu", "Latn", "ID"),
+    ("rjg", "Latn", "ID"),
+    ("rji", "Deva", "NP"),
+    ("rjs", "Deva", "NP"),
+    ("rka", "Khmr", "KH"),
+    ("rkb", "Latn", "BR"),
+    ("rkh", "Latn", "CK"),
+    ("rki", "Mymr", "MM"),
+    ("rkm", "Latn", "BF"),
+    ("rkt", "Beng", "BD"),
+    ("rkw", "Latn", "AU"),
+    ("rm", "Latn", "CH"),
+    ("rma", "Latn", "NI"),
+    ("rmb", "Latn", "AU"),
+    ("rmc", "Latn", "SK"),
+    ("rmd", "Latn", "DK"),
+    ("rme", "Latn", "GB"),
+    ("rmf", "Latn", "FI"),
+    ("rmg", "Latn", "NO"),
+    ("rmh", "Latn", "ID"),
+    ("rmi", "Armn", "AM"),
+    ("rmk", "Latn", "PG"),
+    ("rml", "Latn", "PL"),
+    ("rmm", "Latn", "ID"),
+    ("rmn", "Latn", "RS"),
+    ("rmo", "Latn", "CH"),
+    ("rmp", "Latn", "PG"),
+    ("rmq", "Latn", "ES"),
+    ("rmt", "Arab", "IR"),
+    ("rmu", "Latn", "SE"),
+    ("rmw", "Latn", "GB"),
+    ("rmx", "Latn", "VN"),
+    ("rmz", "Mymr", "IN"),
+    ("rn", "Latn", "BI"),
+    ("rnd", "Latn", "CD"),
+    ("rng", "Latn", "MZ"),
+    ("rnl", "Latn", "IN"),
+    ("rnn", "Latn", "ID"),
+    ("rnr", "Latn", "AU"),
+    ("rnw", "Latn", "TZ"),
+    ("ro", "Latn", "RO"),
+    ("rob", "Latn", "ID"),
+    ("roc", "Latn", "VN"),
+    ("rod", "Latn", "NG"),
+    ("roe", "Latn", "PG"),
+    ("rof", "Latn", "TZ"),
+    ("rog", "Latn", "VN"),
+    ("rol", "Latn", "PH"),
+    ("rom", "Latn", "RO"),
+    ("roo", "Latn", "PG"),
+    ("rop", "Latn", "AU"),
+    ("ror", "Latn", "ID"),
+    ("rou", "Latn", "TD"),
+    ("row", "Latn", "ID"),
+    ("rpn", "Latn", "VU"),
+    ("rpt", "Latn", "PG"),
+    ("rri", "Latn", "SB"),
+    ("rrm", "Latn", "NZ"),
+    ("rro", "Latn", "PG"),
+    ("rrt", "Latn", "AU"),
+    ("rsk", "Cyrl", "RS"),
+    ("rsw", "Latn", "NG"),
+    ("rtc", "Latn", "MM"),
+    ("rth", "Latn", "ID"),
+    ("rtm", "Latn", "FJ"),
+    ("rtw", "Deva", "IN"),
+    ("ru", "Cyrl", "RU"),
+    ("rub", "Latn", "UG"),
+    ("ruc", "Latn", "UG"),
+    ("rue", "Cyrl", "UA"),
+    ("ruf", "Latn", "TZ"),
+    ("rug", "Latn", "SB"),
+    ("rui", "Latn", "TZ"),
+    ("ruk", "Latn", "NG"),
+    ("ruo", "Latn", "HR"),
+    ("rup", "Latn", "RO"),
+    ("ruq", "Latn", "GR"),
+    ("rut", "Cyrl", "RU"),
+    ("ruu", "Latn", "MY"),
+    ("ruy", "Latn", "NG"),
+    ("ruz", "Latn", "NG"),
+    ("rw", "Latn", "RW"),
+    ("rwa", "Latn", "PG"),
+    ("rwk", "Latn", "TZ"),
+    ("rwl", "Latn", "TZ"),
+    ("rwm", "Latn", "UG"),
+    ("rwo", "Latn", "PG"),
+    ("rwr", "Deva", "IN"),
+    ("rxd", "Latn", "AU"),
+    ("rxw", "Latn", "AU"),
+    ("ryu", "Kana", "JP"),
+    ("sa", "Deva", "IN"),
+    ("saa", "Latn", "TD"),
+    ("sab", "Latn", "PA"),
+    ("sac", "Latn", "US"),
+    ("sad", "Latn", "TZ"),
+    ("sae", "Latn", "BR"),
+    ("saf", "Latn", "GH"),
+    ("sah", "Cyrl", "RU"),
+    ("saj", "Latn", "ID"),
+    ("sak", "Latn", "GA"),
+    ("sam", "Samr", "PS"),
+    ("sao", "Latn", "ID"),
+    ("saq", "Latn", "KE"),
+    ("sar", "Latn", "BO"),
+    ("sas", "Latn", "ID"),
+    ("sat", "Olck", "IN"),
+    ("sau", "Latn", "ID"),
+    ("sav", "Latn", "SN"),
+    ("saw", "Latn", "ID"),
+    ("sax", "Latn", "VU"),
+    ("say", "Latn", "NG"),
+    ("saz", "Saur", "IN"),
+    ("sba", "Latn", "TD"),
+    ("sbb", "Latn", "SB"),
+    ("sbc", "Latn", "PG"),
+    ("sbd", "Latn", "BF"),
+    ("sbe", "Latn", "PG"),
+    ("sbg", "Latn", "ID"),
+    ("sbh", "Latn", "PG"),
+    ("sbi", "Latn", "PG"),
+    ("sbj", "Latn", "TD"),
+    ("sbk", "Latn", "TZ"),
+    ("sbl", "Latn", "PH"),
+    ("sbm", "Latn", "TZ"),
+    ("sbn", "Arab", "PK"),
+    ("sbo", "Latn", "MY"),
+    ("sbp", "Latn", "TZ"),
+    ("sbq", "Latn", "PG"),
+    ("sbr", "Latn", "ID"),
+    ("sbs", "Latn", "NA"),
+    ("sbt", "Latn", "ID"),
+    ("sbu", "Tibt", "IN"),
+    ("sbv", "Latn", "IT"),
+    ("sbw", "Latn", "GA"),
+    ("sbx", "Latn", "ID"),
+    ("sby", "Latn", "ZM"),
+    ("sbz", "Latn", "CF"),
+    ("sc", "Latn", "IT"),
+    ("scb", "Latn", "VN"),
+    ("sce", "Latn", "CN"),
+    ("scf", "Latn", "PA"),
+    ("scg", "Latn", "ID"),
+    ("sch", "Latn", "IN"),
+    ("sci", "Latn", "LK"),
+    ("sck", "Deva", "IN"),
+    ("scl", "Arab", "PK"),
+    ("scn", "Latn", "IT"),
+    ("sco", "Latn", "GB"),
+    ("scp", "Deva", "NP"),
+    ("scs", "Latn", "CA"),
+    ("sct", "Laoo", "LA"),
+    ("scu", "Takr", "IN"),
+    ("scv", "Latn", "NG"),
+    ("scw", "Latn", "NG"),
+    ("scx", "Grek", "IT"),
+    ("sd", "Arab", "PK"),
+    ("sda", "Latn", "ID"),
+    ("sdb", "Arab", "IQ"),
+    ("sdc", "Latn", "IT"),
+    ("sde", "Latn", "NG"),
+    ("sdf", "Arab", "IQ"),
+    ("sdg", "Arab", "AF"),
+    ("sdh", "Arab", "IR"),
+    ("sdj", "Latn", "CG"),
+    ("sdk", "Latn", "PG"),
+    ("sdn", "Latn", "IT"),
+    ("sdo", "Latn", "MY"),
+    ("sdq", "Latn", "ID"),
+    ("sdr", "Beng", "BD"),
+    ("sds", "Arab", "TN"),
+    ("sdu", "Latn", "ID"),
+    ("sdx", "Latn", "MY"),
+    ("se", "Latn", "NO"),
+    ("sea", "Latn", "MY"),
+    ("seb", "Latn", "CI"),
+    ("sec", "Latn", "CA"),
+    ("sed", "Latn", "VN"),
+    ("see", "Latn", "US"),
+    ("sef", "Latn", "CI"),
+    ("seg", "Latn", "TZ"),
+    ("seh", "Latn", "MZ"),
+    ("sei", "Latn", "MX"),
+    ("sej", "Latn", "PG"),
+    ("sek", "Latn", "CA"),
+    ("sel", "Cyrl", "RU"),
+    ("sen", "Latn", "BF"),
+    ("seo", "Latn", "PG"),
+    ("sep", "Latn", "BF"),
+    ("seq", "Latn", "BF"),
+    ("ser", "Latn", "US"),
+    ("ses", "Latn", "ML"),
+    ("set", "Latn", "ID"),
+    ("seu", "Latn", "ID"),
+    ("sev", "Latn", "CI"),
+    ("sew", "Latn", "PG"),
+    ("sey", "Latn", "EC"),
+    ("sez", "Latn", "MM"),
+    ("sfe", "Latn", "PH"),
+    ("sfm", "Plrd", "CN"),
+    ("sfw", "Latn", "GH"),
+    ("sg", "Latn", "CF"),
+    ("sga", "Latn", "IE"),
+    ("sgb", "Latn", "PH"),
+    ("sgc", "Latn", "KE"),
+    ("sgd", "Latn", "PH"),
+    ("sge", "Latn", "ID"),
+    ("sgh", "Cyrl", "TJ"),
+    ("sgi", "Latn", "CM"),
+    ("sgj", "Deva", "IN"),
+    ("sgm", "Latn", "KE"),
+    ("sgp", "Latn", "IN"),
+    ("sgr", "Arab", "IR"),
+    ("sgs", "Latn", "LT"),
+    ("sgt", "Tibt", "BT"),
+    ("sgu", "Latn", "ID"),
+    ("sgw", "Ethi", "ET"),
+    ("sgy", "Arab", "AF"),
+    ("sgz", "Latn", "PG"),
+    ("sha", "Latn", "NG"),
+    ("shb", "Latn", "BR"),
+    ("shc", "Latn", "CD"),
+    ("shd", "Arab", "PK"),
+    ("she", "Latn", "ET"),
+    ("shg", "Latn", "BW"),
+    ("shh", "Latn", "US"),
+    ("shi", "Tfng", "MA"),
+    ("shj", "Latn", "SD"),
+    ("shk", "Latn", "SS"),
+    ("shm", "Arab", "IR"),
+    ("shn", "Mymr", "MM"),
+    ("sho", "Latn", "NG"),
+    ("shp", "Latn", "PE"),
+    ("shq", "Latn", "ZM"),
+    ("shr", "Latn", "CD"),
+    ("shs", "Latn", "CA"),
+    ("sht", "Latn", "US"),
+    ("shu", "Arab", "TD"),
+    ("shv", "Arab", "OM"),
+    ("shw", "Latn", "SD"),
+    ("shy", "Latn", "DZ"),
+    ("shz", "Latn", "ML"),
+    ("si", "Sinh", "LK"),
+    ("sia", "Cyrl", "RU"),
+    ("sib", "Latn", "MY"),
+    ("sid", "Latn", "ET"),
+    ("sie", "Latn", "ZM"),
+    ("sif", "Latn", "BF"),
+    ("sig", "Latn", "GH"),
+    ("sih", "Latn", "NC"),
+    ("sii", "Latn", "IN"),
+    ("sij", "Latn", "PG"),
+    ("sik", "Latn", "BR"),
+    ("sil", "Latn", "GH"),
+    ("sim", "Latn", "PG"),
+    ("sip", "Tibt", "IN"),
+    ("siq", "Latn", "PG"),
+    ("sir", "Latn", "NG"),
+    ("sis", "Latn", "US"),
+    ("siu", "Latn", "PG"),
+    ("siv", "Latn", "PG"),
+    ("siw", "Latn", "PG"),
+    ("six", "Latn", "PG"),
+    ("siy", "Arab", "IR"),
+    ("siz", "Arab", "EG"),
+    ("sja", "Latn", "CO"),
+    ("sjb", "Latn", "ID"),
+    ("sjc", "Hans", "CN"),
+    ("sjd", "Cyrl", "RU"),
+    ("sje", "Latn", "SE"),
+    ("sjg", "Latn", "TD"),
+    ("sjl", "Latn", "IN"),
+    ("sjm", "Latn", "PH"),
+    ("sjp", "Deva", "IN"),
+    ("sjr", "Latn", "PG"),
+    ("sjt", "Cyrl", "RU"),
+    ("sju", "Latn", "SE"),
+    ("sjw", "Latn", "US"),
+    ("sk", "Latn", "SK"),
+    ("ska", "Latn", "US"),
+    ("skb", "Thai", "TH"),
+    ("skc", "Latn", "PG"),
+    ("skd", "Latn", "US"),
+    ("ske", "Latn", "VU"),
+    ("skf", "Latn", "BR"),
+    ("skg", "Latn", "MG"),
+    ("skh", "Latn", "ID"),
+    ("ski", "Latn", "ID"),
+    ("skj", "Deva", "NP"),
+    ("skm", "Latn", "PG"),
+    ("skn", "Latn", "PH"),
+    ("sko", "Latn", "ID"),
+    ("skp", "Latn", "MY"),
+    ("skq", "Latn", "BF"),
+    ("skr", "Arab", "PK"),
+    ("sks", "Latn", "PG"),
+    ("skt", "Latn", "CD"),
+    ("sku", "Latn", "VU"),
+    ("skv", "Latn", "ID"),
+    ("skw", "Latn", "GY"),
+    ("skx", "Latn", "ID"),
+    ("sky", "Latn", "SB"),
+    ("skz", "Latn", "ID"),
+    ("sl", "Latn", "SI"),
+    ("slc", "Latn", "CO"),
+    ("sld", "Latn", "BF"),
+    ("slg", "Latn", "ID"),
+    ("slh", "Latn", "US"),
+    ("sli", "Latn", "PL"),
+    ("slj", "Latn", "BR"),
+    ("sll", "Latn", "PG"),
+    ("slm", "Latn", "PH"),
+    ("sln", "Latn", "US"),
+    ("slp", "Latn", "ID"),
+    ("slr", "Latn", "CN"),
+    ("slu", "Latn", "ID"),
+    ("slw", "Latn", "PG"),
+    ("slx", "Latn", "CD"),
+    ("sly", "Latn", "ID"),
+    ("slz", "Latn", "ID"),
+    ("sm", "Latn", "WS"),
+    ("sma", "Latn", "SE"),
+    ("smb", "Latn", "PG"),
+    ("smc", "Latn", "PG"),
+    ("smf", "Latn", "PG"),
+    ("smg", "Latn", "PG"),
+    ("smh", "Yiii", "CN"),
+    ("smj", "Latn", "SE"),
+    ("smk", "Latn", "PH"),
+    ("sml", "Latn", "PH"),
+    ("smn", "Latn", "FI"),
+    ("smp", "Samr", "IL"),
+    ("smq", "Latn", "PG"),
+    ("smr", "Latn", "ID"),
+    ("sms", "Latn", "FI"),
+    ("smt", "Latn", "IN"),
+    ("smu", "Khmr", "KH"),
+    ("smw", "Latn", "ID"),
+    ("smx", "Latn", "CD"),
+    ("smy", "Arab", "IR"),
+    ("smz", "Latn", "PG"),
+    ("sn", "Latn", "ZW"),
+    ("snc", "Latn", "PG"),
+    ("sne", "Latn", "MY"),
+    ("snf", "Latn", "SN"),
+    ("sng", "Latn", "CD"),
+    ("sni", "Latn", "PE"),
+    ("snj", "Latn", "CF"),
+    ("snk", "Latn", "ML"),
+    ("snl", "Latn", "PH"),
+    ("snm", "Latn", "UG"),
+    ("snn", "Latn", "CO"),
+    ("sno", "Latn", "US"),
+    ("snp", "Latn", "PG"),
+    ("snq", "Latn", "GA"),
+    ("snr", "Latn", "PG"),
+    ("sns", "Latn", "VU"),
+    ("snu", "Latn", "ID"),
+    ("snv", "Latn", "MY"),
+    ("snw", "Latn", "GH"),
+    ("snx", "Latn", "PG"),
+    ("sny", "Latn", "PG"),
+    ("snz", "Latn", "PG"),
+    ("so", "Latn", "SO"),
+    ("soa", "Tavt", "TH"),
+    ("sob", "Latn", "ID"),
+    ("soc", "Latn", "CD"),
+    ("sod", "Latn", "CD"),
+    ("soe", "Latn", "CD"),
+    ("sog", "Sogd", "UZ"),
+    ("soi", "Deva", "NP"),
+    ("sok", "Latn", "TD"),
+    ("sol", "Latn", "PG"),
+    ("soo", "Latn", "CD"),
+    ("sop", "Latn", "CD"),
+    ("soq", "Latn", "PG"),
+    ("sor", "Latn", "TD"),
+    ("sos", "Latn", "BF"),
+    ("sou", "Thai", "TH"),
+    ("sov", "Latn", "PW"),
+    ("sow", "Latn", "PG"),
+    ("sox", "Latn", "CM"),
+    ("soy", "Latn", "BJ"),
+    ("soz", "Latn", "TZ"),
+    ("spb", "Latn", "ID"),
+    ("spc", "Latn", "VE"),
+    ("spd", "Latn", "PG"),
+    ("spe", "Latn", "PG"),
+    ("spg", "Latn", "MY"),
+    ("spi", "Latn", "ID"),
+    ("spk", "Latn", "PG"),
+    ("spl", "Latn", "PG"),
+    ("spm", "Latn", "PG"),
+    ("spn", "Latn", "PY"),
+    ("spo", "Latn", "US"),
+    ("spp", "Latn", "ML"),
+    ("spq", "Latn", "PE"),
+    ("spr", "Latn", "ID"),
+    ("sps", "Latn", "PG"),
+    ("spt", "Tibt", "IN"),
+    ("spv", "Orya", "IN"),
+    ("sq", "Latn", "AL"),
+    ("sqa", "Latn", "NG"),
+    ("sqh", "Latn", "NG"),
+    ("sqm", "Latn", "CF"),
+    ("sqo", "Arab", "IR"),
+    ("sqq", "Laoo", "LA"),
+    ("sqt", "Arab", "YE"),
+    ("squ", "Latn", "CA"),
+    ("sr", "Cyrl", "RS"),
+    ("sra", "Latn", "PG"),
+    ("srb", "Sora", "IN"),
+    ("sre", "Latn", "ID"),
+    ("srf", "Latn", "PG"),
+    ("srg", "Latn", "PH"),
+    ("srh", "Arab", "CN"),
+    ("sri", "Latn", "CO"),
+    ("srk", "Latn", "MY"),
+    ("srl", "Latn", "ID"),
+    ("srm", "Latn", "SR"),
+    ("srn", "Latn", "SR"),
+    ("sro", "Latn", "IT"),
+    ("srq", "Latn", "BO"),
+    ("srr", "Latn", "SN"),
+    ("srs", "Latn", "CA"),
+    ("srt", "Latn", "ID"),
+    ("sru", "Latn", "BR"),
+    ("srv", "Latn", "PH"),
+    ("srw", "Latn", "ID"),
+    ("srx", "Deva", "IN"),
+    ("sry", "Latn", "PG"),
+    ("srz", "Arab", "IR"),
+    ("ss", "Latn", "ZA"),
+    ("ssb", "Latn", "PH"),
+    ("ssc", "Latn", "TZ"),
+    ("ssd", "Latn", "PG"),
+    ("sse", "Latn", "PH"),
+    ("ssf", "Latn", "TW"),
+    ("ssg", "Latn", "PG"),
+    ("ssh", "Arab", "AE"),
+    ("ssj", "Latn", "PG"),
+    ("ssl", "Latn", "GH"),
+    ("ssm", "Latn", "MY"),
+    ("ssn", "Latn", "KE"),
+    ("sso", "Latn", "PG"),
+    ("ssq", "Latn", "ID"),
+    ("sss", "Laoo", "LA"),
+    ("sst", "Latn", "PG"),
+    ("ssu", "Latn", "PG"),
+    ("ssv", "Latn", "VU"),
+    ("ssx", "Latn", "PG"),
+    ("ssy", "Latn", "ER"),
+    ("ssz", "Latn", "PG"),
+    ("st", "Latn", "ZA"),
+    ("sta", "Latn", "ZM"),
+    ("stb", "Latn", "PH"),
+    ("ste", "Latn", "ID"),
+    ("stf", "Latn", "PG"),
+    ("stg", "Latn", "VN"),
+    ("sth", "Latn", "IE"),
+    ("sti", "Latn", "VN"),
+    ("stj", "Latn", "BF"),
+    ("stk", "Latn", "PG"),
+    ("stl", "Latn", "NL"),
+    ("stm", "Latn", "PG"),
+    ("stn", "Latn", "SB"),
+    ("sto", "Latn", "CA"),
+    ("stp", "Latn", "MX"),
+    ("stq", "Latn", "DE"),
+    ("str", "Latn", "CA"),
+    ("sts", "Arab", "AF"),
+    ("stt", "Latn", "VN"),
+    ("stu", "Lana", "MM"),
+    ("stv", "Ethi", "ET"),
+    ("stw", "Latn", "FM"),
+    ("sty", "Cyrl", "RU"),
+    ("su", "Latn", "ID"),
+    ("sua", "Latn", "PG"),
+    ("sub", "Latn", "CD"),
+    ("suc", "Latn", "PH"),
+    ("sue", "Latn", "PG"),
+    ("sug", "Latn", "PG"),
+    ("sui", "Latn", "PG"),
+    ("suj", "Latn", "TZ"),
+    ("suk", "Latn", "TZ"),
+    ("suo", "Latn", "PG"),
+    ("suq", "Latn", "ET"),
+    ("sur", "Latn", "NG"),
+    ("sus", "Latn", "GN"),
+    ("sut", "Latn", "NI"),
+    ("suv", "Latn", "IN"),
+    ("suw", "Latn", "TZ"),
+    ("suy", "Latn", "BR"),
+    ("suz", "Deva", "NP"),
+    ("sv", "Latn", "SE"),
+    ("sva", "Geor", "GE"),
+    ("svb", "Latn", "PG"),
+    ("svc", "Latn", "VC"),
+    ("sve", "Latn", "ID"),
+    ("svm", "Latn", "IT"),
+    ("svs", "Latn", "SB"),
+    ("sw", "Latn", "TZ"),
+    ("swb", "Arab", "YT"),
+    ("swf", "Latn", "CD"),
+    ("swg", "Latn", "DE"),
+    ("swi", "Hani", "CN"),
+    ("swj", "Latn", "GA"),
+    ("swk", "Latn", "MW"),
+    ("swm", "Latn", "PG"),
+    ("swo", "Latn", "BR"),
+    ("swp", "Latn", "PG"),
+    ("swq", "Latn", "CM"),
+    ("swr", "Latn", "ID"),
+    ("sws", "Latn", "ID"),
+    ("swt", "Latn", "ID"),
+    ("swu", "Latn", "ID"),
+    ("swv", "Deva", "IN"),
+    ("sww", "Latn", "VU"),
+    ("swx", "Latn", "BR"),
+    ("swy", "Latn", "TD"),
+    ("sxb", "Latn", "KE"),
+    ("sxe", "Latn", "GA"),
+    ("sxn", "Latn", "ID"),
+    ("sxr", "Latn", "TW"),
+    ("sxs", "Latn", "NG"),
+    ("sxu", "Runr", "DE"),
+    ("sxw", "Latn", "BJ"),
+    ("sya", "Latn", "ID"),
+    ("syb", "Latn", "PH"),
+    ("syc", "Syrc", "TR"),
+    ("syi", "Latn", "GA"),
+    ("syk", "Latn", "NG"),
+    ("syl", "Beng", "BD"),
+    ("sym", "Latn", "BF"),
+    ("syn", "Syrc", "IR"),
+    ("syo", "Latn", "KH"),
+    ("syr", "Syrc", "IQ"),
+    ("sys", "Latn", "TD"),
+    ("syw", "Deva", "NP"),
+    ("syx", "Latn", "GA"),
+    ("sza", "Latn", "MY"),
+    ("szb", "Latn", "ID"),
+    ("szc", "Latn", "MY"),
+    ("szg", "Latn", "CD"),
+    ("szl", "Latn", "PL"),
+    ("szn", "Latn", "ID"),
+    ("szp", "Latn", "ID"),
+    ("szv", "Latn", "CM"),
+    ("szw", "Latn", "ID"),
+    ("szy", "Latn", "TW"),
+    ("ta", "Taml", "IN"),
+    ("taa", "Latn", "US"),
+    ("tab", "Cyrl", "RU"),
+    ("tac", "Latn", "MX"),
+    ("tad", "Latn", "ID"),
+    ("tae", "Latn", "BR"),
+    ("taf", "Latn", "BR"),
+    ("tag", "Latn", "SD"),
+    ("taj", "Deva", "NP"),
+    ("tak", "Latn", "NG"),
+    ("tal", "Latn", "NG"),
+    ("tan", "Latn", "NG"),
+    ("tao", "Latn", "TW"),
+    ("tap", "Latn", "CD"),
+    ("taq", "Latn", "ML"),
+    ("tar", "Latn", "MX"),
+    ("tas", "Latn", "VN"),
+    ("tau", "Latn", "US"),
+    ("tav", "Latn", "CO"),
+    ("taw", "Latn", "PG"),
+    ("tax", "Latn", "TD"),
+    ("tay", "Latn", "TW"),
+    ("taz", "Latn", "SD"),
+    ("tba", "Latn", "BR"),
+    ("tbc", "Latn", "PG"),
+    ("tbd", "Latn", "PG"),
+    ("tbe", "Latn", "SB"),
+    ("tbf", "Latn", "PG"),
+    ("tbg", "Latn", "PG"),
+    ("tbh", "Latn", "AU"),
+    ("tbi", "Latn", "SD"),
+    ("tbj", "Latn", "PG"),
+    ("tbk", "Tagb", "PH"),
+    ("tbl", "Latn", "PH"),
+    ("tbm", "Latn", "CD"),
+    ("tbn", "Latn", "CO"),
+    ("tbo", "Latn", "PG"),
+    ("tbp", "Latn", "ID"),
+    ("tbs", "Latn", "PG"),
+    ("tbt", "Latn", "CD"),
+    ("tbu", "Latn", "MX"),
+    ("tbv", "Latn", "PG"),
+    ("tbw", "Latn", "PH"),
+    ("tbx", "Latn", "PG"),
+    ("tby", "Latn", "ID"),
+    ("tbz", "Latn", "BJ"),
+    ("tca", "Latn", "BR"),
+    ("tcb", "Latn", "US"),
+    ("tcc", "Latn", "TZ"),
+    ("tcd", "Latn", "GH"),
+    ("tce", "Latn", "CA"),
+    ("tcf", "Latn", "MX"),
+    ("tcg", "Latn", "ID"),
+    ("tch", "Latn", "TC"),
+    ("tci", "Latn", "PG"),
+    ("tck", "Latn", "GA"),
+    ("tcm", "Latn", "ID"),
+    ("tcn", "Deva", "NP"),
+    ("tco", "Mymr", "MM"),
+    ("tcp", "Latn", "MM"),
+    ("tcq", "Latn", "ID"),
+    ("tcs", "Latn", "AU"),
+    ("tcu", "Latn", "MX"),
+    ("tcw", "Latn", "MX"),
+    ("tcx", "Taml", "IN"),
+    ("tcy", "Knda", "IN"),
+    ("tcz", "Latn", "IN"),
+    ("tda", "Tfng", "NE"),
+    ("tdb", "Deva", "IN"),
+    ("tdc", "Latn", "CO"),
+    ("tdd", "Tale", "CN"),
+    ("tde", "Latn", "ML"),
+    ("tdg", "Deva", "NP"),
+    ("tdh", "Deva", "NP"),
+    ("tdi", "Latn", "ID"),
+    ("tdj", "Latn", "ID"),
+    ("tdk", "Latn", "NG"),
+    ("tdl", "Latn", "NG"),
+    ("tdm", "Latn", "GY"),
+    ("tdn", "Latn", "ID"),
+    ("tdo", "Latn", "NG"),
+    ("tdq", "Latn", "NG"),
+    ("tdr", "Latn", "VN"),
+    ("tds", "Latn", "ID"),
+    ("tdt", "Latn", "TL"),
+    ("tdv", "Latn", "NG"),
+    ("tdx", "Latn", "MG"),
+    ("tdy", "Latn", "PH"),
+    ("te", "Telu", "IN"),
+    ("tea", "Latn", "MY"),
+    ("teb", "Latn", "EC"),
+    ("tec", "Latn", "KE"),
+    ("ted", "Latn", "CI"),
+    ("tee", "Latn", "MX"),
+    ("teg", "Latn", "GA"),
+    ("teh", "Latn", "AR"),
+    ("tei", "Latn", "PG"),
+    ("tek", "Latn", "CD"),
+    ("tem", "Latn", "SL"),
+    ("ten", "Latn", "CO"),
+    ("teo", "Latn", "UG"),
+    ("tep", "Latn", "MX"),
+    ("teq", "Latn", "SD"),
+    ("ter", "Latn", "BR"),
+    ("tes", "Java", "ID"),
+    ("tet", "Latn", "TL"),
+    ("teu", "Latn", "UG"),
+    ("tev", "Latn", "ID"),
+    ("tew", "Latn", "US"),
+    ("tex", "Latn", "SS"),
+    ("tey", "Latn", "SD"),
+    ("tez", "Latn", "NE"),
+    ("tfi", "Latn", "BJ"),
+    ("tfn", "Latn", "US"),
+    ("tfo", "Latn", "ID"),
+    ("tfr", "Latn", "PA"),
+    ("tft", "Latn", "ID"),
+    ("tg", "Cyrl", "TJ"),
+    ("tga", "Latn", "KE"),
+    ("tgb", "Latn", "MY"),
+    ("tgc", "Latn", "PG"),
+    ("tgd", "Latn", "NG"),
+    ("tge", "Deva", "NP"),
+    ("tgf", "Tibt", "BT"),
+    ("tgh", "Latn", "TT"),
+    ("tgi", "Latn", "PG"),
+    ("tgj", "Latn", "IN"),
+    ("tgn", "Latn", "PH"),
+    ("tgo", "Latn", "PG"),
+    ("tgp", "Latn", "VU"),
+    ("tgq", "Latn", "MY"),
+    ("tgs", "Latn", "VU"),
+    ("tgt", "Latn", "PH"),
+    ("tgu", "Latn", "PG"),
+    ("tgv", "Latn", "BR"),
+    ("tgw", "Latn", "CI"),
+    ("tgx", "Latn", "CA"),
+    ("tgy", "Latn", "SS"),
+    ("tgz", "Latn", "AU"),
+    ("th", "Thai", "TH"),
+    ("thd", "Latn", "AU"),
+    ("the", "Deva", "NP"),
+    ("thf", "Deva", "NP"),
+    ("thh", "Latn", "MX"),
+    ("thi", "Tale", "LA"),
+    ("thk", "Latn", "KE"),
+    ("thl", "Deva", "NP"),
+    ("thm", "Thai", "TH"),
+    ("thp", "Latn", "CA"),
+    ("thq", "Deva", "NP"),
+    ("thr", "Deva", "NP"),
+    ("ths", "Deva", "NP"),
+    ("tht", "Latn", "CA"),
+    ("thu", "Latn", "SS"),
+    ("thv", "Latn", "DZ"),
+    ("thy", "Latn", "NG"),
+    ("thz", "Latn", "NE"),
+    ("ti", "Ethi", "ET"),
+    ("tic", "Latn", "SD"),
+    ("tif", "Latn", "PG"),
+    ("tig", "Ethi", "ER"),
+    ("tih", "Latn", "MY"),
+    ("tii", "Latn", "CD"),
+    ("tij", "Deva", "NP"),
+    ("tik", "Latn", "CM"),
+    ("til", "Latn", "US"),
+    ("tim", "Latn", "PG"),
+    ("tin", "Cyrl", "RU"),
+    ("tio", "Latn", "PG"),
+    ("tip", "Latn", "ID"),
+    ("tiq", "Latn", "BF"),
+    ("tis", "Latn", "PH"),
+    ("tit", "Latn", "CO"),
+    ("tiu", "Latn", "PH"),
+    ("tiv", "Latn", "NG"),
+    ("tiw", "Latn", "AU"),
+    ("tix", "Latn", "US"),
+    ("tiy", "Latn", "PH"),
+    ("tja", "Latn", "LR"),
+    ("tjg", "Latn", "ID"),
+    ("tji", "Latn", "CN"),
+    ("tjj", "Latn", "AU"),
+    ("tjl", "Mymr", "MM"),
+    ("tjn", "Latn", "CI"),
+    ("tjo", "Arab", "DZ"),
+    ("tjp", "Latn", "AU"),
+    ("tjs", "Latn", "CN"),
+    ("tju", "Latn", "AU"),
+    ("tjw", "Latn", "AU"),
+    ("tk", "Latn", "TM"),
+    ("tka", "Latn", "BR"),
+    ("tkb", "Deva", "IN"),
+    ("tkd", "Latn", "TL"),
+    ("tke", "Latn", "MZ"),
+    ("tkf", "Latn", "BR"),
+    ("tkg", "Latn", "MG"),
+    ("tkl", "Latn", "TK"),
+    ("tkp", "Latn", "SB"),
+    ("tkq", "Latn", "NG"),
+    ("tkr", "Latn", "AZ"),
+    ("tks", "Arab", "IR"),
+    ("tkt", "Deva", "NP"),
+    ("tku", "Latn", "MX"),
+    ("tkv", "Latn", "PG"),
+    ("tkw", "Latn", "SB"),
+    ("tkx", "Latn", "ID"),
+    ("tkz", "Latn", "VN"),
+    ("tl", "Latn", "PH"),
+    ("tla", "Latn", "MX"),
+    ("tlb", "Latn", "ID"),
+    ("tlc", "Latn", "MX"),
+    ("tld", "Latn", "ID"),
+    ("tlf", "Latn", "PG"),
+    ("tlg", "Latn", "ID"),
+    ("tli", "Latn", "US"),
+    ("tlj", "Latn", "UG"),
+    ("tlk", "Latn", "ID"),
+    ("tll", "Latn", "CD"),
+    ("tlm", "Latn", "VU"),
+    ("tln", "Latn", "ID"),
+    ("tlp", "Latn", "MX"),
+    ("tlq", "Latn", "MM"),
+    ("tlr", "Latn", "SB"),
+    ("tls", "Latn", "VU"),
+    ("tlt", "Latn", "ID"),
+    ("tlu", "Latn", "ID"),
+    ("tlv", "Latn", "ID"),
+    ("tlx", "Latn", "PG"),
+    ("tly", "Latn", "AZ"),
+    ("tma", "Latn", "TD"),
+    ("tmb", "Latn", "VU"),
+    ("tmc", "Latn", "TD"),
+    ("tmd", "Latn", "PG"),
+    ("tme", "Latn", "BR"),
+    ("tmf", "Latn", "PY"),
+    ("tmg", "Latn", "ID"),
+    ("tmh", "Latn", "NE"),
+    ("tmi", "Latn", "VU"),
+    ("tmj", "Latn", "ID"),
+    ("tml", "Latn", "ID"),
+    ("tmm", "Latn", "VN"),
+    ("tmn", "Latn", "ID"),
+    ("tmo", "Latn", "MY"),
+    ("tmq", "Latn", "PG"),
+    ("tmr", "Syrc", "IL"),
+    ("tmt", "Latn", "VU"),
+    ("tmu", "Latn", "ID"),
+    ("tmv", "Latn", "CD"),
+    ("tmw", "Latn", "MY"),
+    ("tmy", "Latn", "PG"),
+    ("tmz", "Latn", "VE"),
+    ("tn", "Latn", "ZA"),
+    ("tna", "Latn", "BO"),
+    ("tnb", "Latn", "CO"),
+    ("tnc", "Latn", "CO"),
+    ("tnd", "Latn", "CO"),
+    ("tng", "Latn", "TD"),
+    ("tnh", "Latn", "PG"),
+    ("tni", "Latn", "ID"),
+    ("tnk", "Latn", "VU"),
+    ("tnl", "Latn", "VU"),
+    ("tnm", "Latn", "ID"),
+    ("tnn", "Latn", "VU"),
+    ("tno", "Latn", "BO"),
+    ("tnp", "Latn", "VU"),
+    ("tnq", "Latn", "PR"),
+    ("tnr", "Latn", "SN"),
+    ("tns", "Latn", "PG"),
+    ("tnt", "Latn", "ID"),
+    ("tnv", "Cakm", "BD"),
+    ("tnw", "Latn", "ID"),
+    ("tnx", "Latn", "SB"),
+    ("tny", "Latn", "TZ"),
+    ("to", "Latn", "TO"),
+    ("tob", "Latn", "AR"),
+    ("toc", "Latn", "MX"),
+    ("tod", "Latn", "GN"),
+    ("tof", "Latn", "PG"),
+    ("tog", "Latn", "MW"),
+    ("toh", "Latn", "MZ"),
+    ("toi", "Latn", "ZM"),
+    ("toj", "Latn", "MX"),
+    ("tok", "Latn", "001"),
+    ("tol", "Latn", "US"),
+    ("tom", "Latn", "ID"),
+    ("too", "Latn", "MX"),
+    ("top", "Latn", "MX"),
+    ("toq", "Latn", "SS"),
+    ("tor", "Latn", "CD"),
+    ("tos", "Latn", "MX"),
+    ("tou", "Latn", "VN"),
+    ("tov", "Arab", "IR"),
+    ("tow", "Latn", "US"),
+    ("tox", "Latn", "PW"),
+    ("toy", "Latn", "ID"),
+    ("toz", "Latn", "CM"),
+    ("tpa", "Latn", "PG"),
+    ("tpc", "Latn", "MX"),
+    ("tpe", "Latn", "BD"),
+    ("tpf", "Latn", "ID"),
+    ("tpg", "Latn", "ID"),
+    ("tpi", "Latn", "PG"),
+    ("tpj", "Latn", "PY"),
+    ("tpk", "Latn", "BR"),
+    ("tpl", "Latn", "MX"),
+    ("tpm", "Latn", "GH"),
+    ("tpn", "Latn", "BR"),
+    ("tpp", "Latn", "MX"),
+    ("tpr", "Latn", "BR"),
+    ("tpt", "Latn", "MX"),
+    ("tpu", "Khmr", "KH"),
+    ("tpv", "Latn", "MP"),
+    ("tpx", "Latn", "MX"),
+    ("tpy", "Latn", "BR"),
+    ("tpz", "Latn", "PG"),
+    ("tqb", "Latn", "BR"),
+    ("tql", "Latn", "VU"),
+    ("tqm", "Latn", "PG"),
+    ("tqn", "Latn", "US"),
+    ("tqo", "Latn", "PG"),
+    ("tqp", "Latn", "PG"),
+    ("tqt", "Latn", "MX"),
+    ("tqu", "Latn", "SB"),
+    ("tqw", "Latn", "US"),
+    ("tr", "Latn", "TR"),
+    ("tra", "Arab", "AF"),
+    ("trb", "Latn", "PG"),
+    ("trc", "Latn", "MX"),
+    ("tre", "Latn", "ID"),
+    ("trf", "Latn", "TT"),
+    ("trg", "Hebr", "IL"),
+    ("trh", "Latn", "PG"),
+    ("tri", "Latn", "SR"),
+    ("trj", "Latn", "TD"),
+    ("trl", "Latn", "GB"),
+    ("trm", "Arab", "AF"),
+    ("trn", "Latn", "BO"),
+    ("tro", "Latn", "IN"),
+    ("trp", "Latn", "IN"),
+    ("trq", "Latn", "MX"),
+    ("trr", "Latn", "PE"),
+    ("trs", "Latn", "MX"),
+    ("trt", "Latn", "ID"),
+    ("tru", "Latn", "TR"),
+    ("trv", "Latn", "TW"),
+    ("trw", "Arab", "PK"),
+    ("trx", "Latn", "MY"),
+    ("try", "Latn", "IN"),
+    ("trz", "Latn", "BR"),
+    ("ts", "Latn", "ZA"),
+    ("tsa", "Latn", "CG"),
+    ("tsb", "Latn", "ET"),
+    ("tsc", "Latn", "MZ"),
+    ("tsd", "Grek", "GR"),
+    ("tsg", "Latn", "PH"),
+    ("tsh", "Latn", "CM"),
+    ("tsi", "Latn", "CA"),
+    ("tsj", "Tibt", "BT"),
+    ("tsl", "Latn", "VN"),
+    ("tsp", "Latn", "BF"),
+    ("tsr", "Latn", "VU"),
+    ("tst", "Latn", "ML"),
+    ("tsu", "Latn", "TW"),
+    ("tsv", "Latn", "GA"),
+    ("tsw", "Latn", "NG"),
+    ("tsx", "Latn", "PG"),
+    ("tsz", "Latn", "MX"),
+    ("tt", "Cyrl", "RU"),
+    ("ttb", "Latn", "NG"),
+    ("ttc", "Latn", "GT"),
+    ("ttd", "Latn", "PG"),
+    ("tte", "Latn", "PG"),
+    ("ttf", "Latn", "CM"),
+    ("tth", "Laoo", "LA"),
+    ("tti", "Latn", "ID"),
+    ("ttj", "Latn", "UG"),
+    ("ttk", "Latn", "CO"),
+    ("ttl", "Latn", "ZM"),
+    ("ttm", "Latn", "CA"),
+    ("ttn", "Latn", "ID"),
+    ("tto", "Laoo", "LA"),
+    ("ttp", "Latn", "ID"),
+    ("ttr", "Latn", "NG"),
+    ("tts", "Thai", "TH"),
+    ("ttt", "Latn", "AZ"),
+    ("ttu", "Latn", "PG"),
+    ("ttv", "Latn", "PG"),
+    ("ttw", "Latn", "MY"),
+    ("tty", "Latn", "ID"),
+    ("ttz", "Deva", "NP"),
+    ("tua", "Latn", "PG"),
+    ("tub", "Latn", "US"),
+    ("tuc", "Latn", "PG"),
+    ("tud", "Latn", "BR"),
+    ("tue", "Latn", "CO"),
+    ("tuf", "Latn", "CO"),
+    ("tug", "Latn", "TD"),
+    ("tuh", "Latn", "PG"),
+    ("tui", "Latn", "CM"),
+    ("tuj", "Latn", "ID"),
+    ("tul", "Latn", "NG"),
+    ("tum", "Latn", "MW"),
+    ("tun", "Latn", "US"),
+    ("tuo", "Latn", "BR"),
+    ("tuq", "Latn", "TD"),
+    ("tus", "Latn", "CA"),
+    ("tuu", "Latn", "US"),
+    ("tuv", "Latn", "KE"),
+    ("tux", "Latn", "BR"),
+    ("tuy", "Latn", "KE"),
+    ("tuz", "Latn", "BF"),
+    ("tva", "Latn", "SB"),
+    ("tvd", "Latn", "NG"),
+    ("tve", "Latn", "ID"),
+    ("tvi", "Latn", "NG"),
+    ("tvk", "Latn", "VU"),
+    ("tvl", "Latn", "TV"),
+    ("tvm", "Latn", "ID"),
+    ("tvn", "Mymr", "MM"),
+    ("tvo", "Latn", "ID"),
+    ("tvs", "Latn", "KE"),
+    ("tvt", "Latn", "IN"),
+    ("tvu", "Latn", "CM"),
+    ("tvw", "Latn", "ID"),
+    ("tvx", "Latn", "TW"),
+    ("twa", "Latn", "US"),
+    ("twb", "Latn", "PH"),
+    ("twd", "Latn", "NL"),
+    ("twe", "Latn", "ID"),
+    ("twf", "Latn", "US"),
+    ("twg", "Latn", "ID"),
+    ("twh", "Latn", "VN"),
+    ("twl", "Latn", "MZ"),
+    ("twm", "Tibt", "IN"),
+    ("twn", "Latn", "CM"),
+    ("two", "Latn", "BW"),
+    ("twp", "Latn", "PG"),
+    ("twq", "Latn", "NE"),
+    ("twr", "Latn", "MX"),
+    ("twt", "Latn", "BR"),
+    ("twu", "Latn", "ID"),
+    ("tww", "Latn", "PG"),
+    ("twx", "Latn", "MZ"),
+    ("twy", "Latn", "ID"),
+    ("txa", "Latn", "MY"),
+    ("txe", "Latn", "ID"),
+    ("txg", "Tang", "CN"),
+    ("txi", "Latn", "BR"),
+    ("txj", "Latn", "NG"),
+    ("txm", "Latn", "ID"),
+    ("txn", "Latn", "ID"),
+    ("txo", "Toto", "IN"),
+    ("txq", "Latn", "ID"),
+    ("txs", "Latn", "ID"),
+    ("txt", "Latn", "ID"),
+    ("txu", "Latn", "BR"),
+    ("txx", "Latn", "MY"),
+    ("txy", "Latn", "MG"),
+    ("ty", "Latn", "PF"),
+    ("tya", "Latn", "PG"),
+    ("tye", "Latn", "NG"),
+    ("tyh", "Latn", "VN"),
+    ("tyi", "Latn", "CG"),
+    ("tyj", "Latn", "VN"),
+    ("tyl", "Latn", "VN"),
+    ("tyn", "Latn", "ID"),
+    ("typ", "Latn", "AU"),
+    ("tyr", "Tavt", "VN"),
+    ("tys", "Latn", "VN"),
+    ("tyt", "Latn", "VN"),
+    ("tyu", "Latn", "BW"),
+    ("tyv", "Cyrl", "RU"),
+    ("tyx", "Latn", "CG"),
+    ("tyy", "Latn", "NG"),
+    ("tyz", "Latn", "VN"),
+    ("tzh", "Latn", "MX"),
+    ("tzj", "Latn", "GT"),
+    ("tzl", "Latn", "001"),
+    ("tzm", "Latn", "MA"),
+    ("tzn", "Latn", "ID"),
+    ("tzo", "Latn", "MX"),
+    ("tzx", "Latn", "PG"),
+    ("uam", "Latn", "BR"),
+    ("uar", "Latn", "PG"),
+    ("uba", "Latn", "NG"),
+    ("ubi", "Latn", "TD"),
+    ("ubl", "Latn", "PH"),
+    ("ubr", "Latn", "PG"),
+    ("ubu", "Latn", "PG"),
+    ("uby", "Latn", "TR"),
+    ("uda", "Latn", "NG"),
+    ("ude", "Cyrl", "RU"),
+    ("udg", "Mlym", "IN"),
+    ("udi", "Cyrl", "RU"),
+    ("udj", "Latn", "ID"),
+    ("udl", "Latn", "CM"),
+    ("udm", "Cyrl", "RU"),
+    ("udu", "Latn", "SD"),
+    ("ues", "Latn", "ID"),
+    ("ufi", "Latn", "PG"),
+    ("ug", "Arab", "CN"),
+    ("uga", "Ugar", "SY"),
+    ("ugb", "Latn", "AU"),
+    ("uge", "Latn", "SB"),
+    ("ugh", "Cyrl", "RU"),
+    ("ugo", "Thai", "TH"),
+    ("uha", "Latn", "NG"),
+    ("uhn", "Latn", "ID"),
+    ("uis", "Latn", "PG"),
+    ("uiv", "Latn", "CM"),
+    ("uji", "Latn", "NG"),
+    ("uk", "Cyrl", "UA"),
+    ("uka", "Latn", "ID"),
+    ("ukg", "Latn", "PG"),
+    ("ukh", "Latn", "CF"),
+    ("uki", "Orya", "IN"),
+    ("ukk", "Latn", "MM"),
+    ("ukp", "Latn", "NG"),
+    ("ukq", "Latn", "NG"),
+    ("uku", "Latn", "NG"),
+    ("ukv", "Latn", "SS"),
+    ("ukw", "Latn", "NG"),
+    ("uky", "Latn", "AU"),
+    ("ula", "Latn", "NG"),
+    ("ulb", "Latn", "NG"),
+    ("ulc", "Cyrl", "RU"),
+    ("ule", "Latn", "AR"),
+    ("ulf", "Latn", "ID"),
+    ("uli", "Latn", "FM"),
+    ("ulk", "Latn", "AU"),
+    ("ulm", "Latn", "ID"),
+    ("uln", "Latn", "PG"),
+    ("ulu", "Latn", "ID"),
+    ("ulw", "Latn", "NI"),
+    ("uly", "Latn", "NG"),
+    ("uma", "Latn", "US"),
+    ("umb", "Latn", "AO"),
+    ("umd", "Latn", "AU"),
+    ("umg", "Latn", "AU"),
+    ("umi", "Latn", "MY"),
+    ("umm", "Latn", "NG"),
+    ("umn", "Latn", "MM"),
+    ("umo", "Latn", "BR"),
+    ("ump", "Latn", "AU"),
+    ("umr", "Latn", "AU"),
+    ("ums", "Latn", "ID"),
+    ("una", "Latn", "PG"),
+    ("une", "Latn", "NG"),
+    ("ung", "Latn", "AU"),
+    ("uni", "Latn", "PG"),
+    ("unk", "Latn", "BR"),
+    ("unm", "Latn", "US"),
+    ("unn", "Latn", "AU"),
+    ("unr", "Beng", "IN"),
+    ("unu", "Latn", "PG"),
+    ("unx", "Beng", "IN"),
+    ("unz", "Latn", "ID"),
+    ("uon", "Latn", "TW"),
+    ("upi", "Latn", "PG"),
+    ("upv", "Latn", "VU"),
+    ("ur", "Arab", "PK"),
+    ("ura", "Latn", "PE"),
+    ("urb", "Latn", "BR"),
+    ("urc", "Latn", "AU"),
+    ("ure", "Latn", "BO"),
+    ("urf", "Latn", "AU"),
+    ("urg", "Latn", "PG"),
+    ("urh", "Latn", "NG"),
+    ("uri", "Latn", "PG"),
+    ("urk", "Thai", "TH"),
+    ("urm", "Latn", "PG"),
+    ("urn", "Latn", "ID"),
+    ("uro", "Latn", "PG"),
+    ("urp", "Latn", "BR"),
+    ("urr", "Latn", "VU"),
+    ("urt", "Latn", "PG"),
+    ("uru", "Latn", "BR"),
+    ("urv", "Latn", "PG"),
+    ("urw", "Latn", "PG"),
+    ("urx", "Latn", "PG"),
+    ("ury", "Latn", "ID"),
+    ("urz", "Latn", "BR"),
+    ("usa", "Latn", "PG"),
+    ("ush", "Arab", "PK"),
+    ("usi", "Latn", "BD"),
+    ("usk", "Latn", "CM"),
+    ("usp", "Latn", "GT"),
+    ("uss", "Latn", "NG"),
+    ("usu", "Latn", "PG"),
+    ("uta", "Latn", "NG"),
+    ("ute", "Latn", "US"),
+    ("uth", "Latn", "NG"),
+    ("utp", "Latn", "SB"),
+    ("utr", "Latn", "NG"),
+    ("utu", "Latn", "PG"),
+    ("uum", "Grek", "GE"),
+    ("uur", "Latn", "VU"),
+    ("uve", "Latn", "NC"),
+    ("uvh", "Latn", "PG"),
+    ("uvl", "Latn", "PG"),
+    ("uwa", "Latn", "AU"),
+    ("uya", "Latn", "NG"),
+    ("uz", "Latn", "UZ"),
+    ("uzs", "Arab", "AF"),
+    ("vaa", "Taml", "IN"),
+    ("vae", "Latn", "CF"),
+    ("vaf", "Arab", "IR"),
+    ("vag", "Latn", "GH"),
+    ("vah", "Deva", "IN"),
+    ("vai", "Vaii", "LR"),
+    ("vaj", "Latn", "NA"),
+    ("val", "Latn", "PG"),
+    ("vam", "Latn", "PG"),
+    ("van", "Latn", "PG"),
+    ("vao", "Latn", "VU"),
+    ("vap", "Latn", "IN"),
+    ("var", "Latn", "MX"),
+    ("vas", "Deva", "IN"),
+    ("vau", "Latn", "CD"),
+    ("vav", "Deva", "IN"),
+    ("vay", "Deva", "NP"),
+    ("vbb", "Latn", "ID"),
+    ("vbk", "Latn", "PH"),
+    ("ve", "Latn", "ZA"),
+    ("vec", "Latn", "IT"),
+    ("vem", "Latn", "NG"),
+    ("veo", "Latn", "US"),
+    ("vep", "Latn", "RU"),
+    ("ver", "Latn", "NG"),
+    ("vgr", "Arab", "PK"),
+    ("vi", "Latn", "VN"),
+    ("vic", "Latn", "SX"),
+    ("vid", "Latn", "TZ"),
+    ("vif", "Latn", "CG"),
+    ("vig", "Latn", "BF"),
+    ("vil", "Latn", "AR"),
+    ("vin", "Latn", "TZ"),
+    ("vit", "Latn", "NG"),
+    ("viv", "Latn", "PG"),
+    ("vjk", "Deva", "IN"),
+    ("vka", "Latn", "AU"),
+    ("vkj", "Latn", "TD"),
+    ("vkk", "Latn", "ID"),
+    ("vkl", "Latn", "ID"),
+    ("vkm", "Latn", "BR"),
+    ("vkn", "Latn", "NG"),
+    ("vko", "Latn", "ID"),
+    ("vkp", "Latn", "IN"),
+    ("vkt", "Latn", "ID"),
+    ("vku", "Latn", "AU"),
+    ("vkz", "Latn", "NG"),
+    ("vlp", "Latn", "VU"),
+    ("vls", "Latn", "BE"),
+    ("vma", "Latn", "AU"),
+    ("vmb", "Latn", "AU"),
+    ("vmc", "Latn", "MX"),
+    ("vmd", "Knda", "IN"),
+    ("vme", "Latn", "ID"),
+    ("vmf", "Latn", "DE"),
+    ("vmg", "Latn", "PG"),
+    ("vmh", "Arab", "IR"),
+    ("vmi", "Latn", "AU"),
+    ("vmj", "Latn", "MX"),
+    ("vmk", "Latn", "MZ"),
+    ("vml", "Latn", "AU"),
+    ("vmm", "Latn", "MX"),
+    ("vmp", "Latn", "MX"),
+    ("vmq", "Latn", "MX"),
+    ("vmr", "Latn", "MZ"),
+    ("vms", "Latn", "ID"),
+    ("vmu", "Latn", "AU"),
+    ("vmw", "Latn", "MZ"),
+    ("vmx", "Latn", "MX"),
+    ("vmy", "Latn", "MX"),
+    ("vmz", "Latn", "MX"),
+    ("vnk", "Latn", "SB"),
+    ("vnm", "Latn", "VU"),
+    ("vnp", "Latn", "VU"),
+    ("vo", "Latn", "001"),
+    ("vor", "Latn", "NG"),
+    ("vot", "Latn", "RU"),
+    ("vra", "Latn", "VU"),
+    ("vro", "Latn", "EE"),
+    ("vrs", "Latn", "SB"),
+    ("vrt", "Latn", "VU"),
+    ("vto", "Latn", "ID"),
+    ("vum", "Latn", "GA"),
+    ("vun", "Latn", "TZ"),
+    ("vut", "Latn", "CM"),
+    ("vwa", "Latn", "CN"),
+    ("wa", "Latn", "BE"),
+    ("waa", "Latn", "US"),
+    ("wab", "Latn", "PG"),
+    ("wac", "Latn", "US"),
+    ("wad", "Latn", "ID"),
+    ("wae", "Latn", "CH"),
+    ("waf", "Latn", "BR"),
+    ("wag", "Latn", "PG"),
+    ("wah", "Latn", "ID"),
+    ("wai", "Latn", "ID"),
+    ("waj", "Latn", "PG"),
+    ("wal", "Ethi", "ET"),
+    ("wam", "Latn", "US"),
+    ("wan", "Latn", "CI"),
+    ("wap", "Latn", "GY"),
+    ("waq", "Latn", "AU"),
+    ("war", "Latn", "PH"),
+    ("was", "Latn", "US"),
+    ("wat", "Latn", "PG"),
+    ("wau", "Latn", "BR"),
+    ("wav", "Latn", "NG"),
+    ("waw", "Latn", "BR"),
+    ("wax", "Latn", "PG"),
+    ("way", "Latn", "SR"),
+    ("waz", "Latn", "PG"),
+    ("wba", "Latn", "VE"),
+    ("wbb", "Latn", "ID"),
+    ("wbe", "Latn", "ID"),
+    ("wbf", "Latn", "BF"),
+    ("wbh", "Latn", "TZ"),
+    ("wbi", "Latn", "TZ"),
+    ("wbj", "Latn", "TZ"),
+    ("wbk", "Arab", "AF"),
+    ("wbl", "Latn", "PK"),
+    ("wbm", "Latn", "CN"),
+    ("wbp", "Latn", "AU"),
+    ("wbq", "Telu", "IN"),
+    ("wbr", "Deva", "IN"),
+    ("wbt", "Latn", "AU"),
+    ("wbv", "Latn", "AU"),
+    ("wbw", "Latn", "ID"),
+    ("wca", "Latn", "BR"),
+    ("wci", "Latn", "TG"),
+    ("wdd", "Latn", "GA"),
+    ("wdg", "Latn", "PG"),
+    ("wdj", "Latn", "AU"),
+    ("wdk", "Latn", "AU"),
+    ("wdt", "Latn", "CA"),
+    ("wdu", "Latn", "AU"),
+    ("wdy", "Latn", "AU"),
+    ("wec", "Latn", "CI"),
+    ("wed", "Latn", "PG"),
+    ("weg", "Latn", "AU"),
+    ("weh", "Latn", "CM"),
+    ("wei", "Latn", "PG"),
+    ("wem", "Latn", "BJ"),
+    ("weo", "Latn", "ID"),
+    ("wep", "Latn", "DE"),
+    ("wer", "Latn", "PG"),
+    ("wes", "Latn", "CM"),
+    ("wet", "Latn", "ID"),
+    ("weu", "Latn", "MM"),
+    ("wew", "Latn", "ID"),
+    ("wfg", "Latn", "ID"),
+    ("wga", "Latn", "AU"),
+    ("wgb", "Latn", "PG"),
+    ("wgg", "Latn", "AU"),
+    ("wgi", "Latn", "PG"),
+    ("wgo", "Latn", "ID"),
+    ("wgu", "Latn", "AU"),
+    ("wgy", "Latn", "AU"),
+    ("wha", "Latn", "ID"),
+    ("whg", "Latn", "PG"),
+    ("whk", "Latn", "ID"),
+    ("whu", "Latn", "ID"),
+    ("wib", "Latn", "BF"),
+    ("wic", "Latn", "US"),
+    ("wie", "Latn", "AU"),
+    ("wif", "Latn", "AU"),
+    ("wig", "Latn", "AU"),
+    ("wih", "Latn", "AU"),
+    ("wii", "Latn", "PG"),
+    ("wij", "Latn", "AU"),
+    ("wik", "Latn", "AU"),
+    ("wil", "Latn", "AU"),
+    ("wim", "Latn", "AU"),
+    ("win", "Latn", "US"),
+    ("wir", "Latn", "BR"),
+    ("wiu", "Latn", "PG"),
+    ("wiv", "Latn", "PG"),
+    ("wiy", "Latn", "US"),
+    ("wja", "Latn", "NG"),
+    ("wji", "Latn", "NG"),
+    ("wka", "Latn", "TZ"),
+    ("wkd", "Latn", "ID"),
+    ("wkr", "Latn", "AU"),
+    ("wkw", "Latn", "AU"),
+    ("wky", "Latn", "AU"),
+    ("wla", "Latn", "PG"),
+    ("wle", "Ethi", "ET"),
+    ("wlg", "Latn", "AU"),
+    ("wlh", "Latn", "TL"),
+    ("wli", "Latn", "ID"),
+    ("wlm", "Latn", "GB"),
+    ("wlo", "Arab", "ID"),
+    ("wlr", "Latn", "VU"),
+    ("wls", "Latn", "WF"),
+    ("wlu", "Latn", "AU"),
+    ("wlv", "Latn", "AR"),
+    ("wlw", "Latn", "ID"),
+    ("wlx", "Latn", "GH"),
+    ("wma", "Latn", "NG"),
+    ("wmb", "Latn", "AU"),
+    ("wmc", "Latn", "PG"),
+    ("wmd", "Latn", "BR"),
+    ("wme", "Deva", "NP"),
+    ("wmh", "Latn", "TL"),
+    ("wmi", "Latn", "AU"),
+    ("wmm", "Latn", "ID"),
+    ("wmn", "Latn", "NC"),
+    ("wmo", "Latn", "PG"),
+    ("wms", "Latn", "ID"),
+    ("wmt", "Latn", "AU"),
+    ("wmw", "Latn", "MZ"),
+    ("wmx", "Latn", "PG"),
+    ("wnb", "Latn", "PG"),
+    ("wnc", "Latn", "PG"),
+    ("wnd", "Latn", "AU"),
+    ("wne", "Arab", "PK"),
+    ("wng", "Latn", "ID"),
+    ("wni", "Arab", "KM"),
+    ("wnk", "Latn", "ID"),
+    ("wnm", "Latn", "AU"),
+    ("wnn", "Latn", "AU"),
+    ("wno", "Latn", "ID"),
+    ("wnp", "Latn", "PG"),
+    ("wnu", "Latn", "PG"),
+    ("wnw", "Latn", "US"),
+    ("wny", "Latn", "AU"),
+    ("wo", "Latn", "SN"),
+    ("woa", "Latn", "AU"),
+    ("wob", "Latn", "CI"),
+    ("woc", "Latn", "PG"),
+    ("wod", "Latn", "ID"),
+    ("woe", "Latn", "FM"),
+    ("wof", "Latn", "GM"),
+    ("wog", "Latn", "PG"),
+    ("woi", "Latn", "ID"),
+    ("wok", "Latn", "CM"),
+    ("wom", "Latn", "NG"),
+    ("won", "Latn", "CD"),
+    ("woo", "Latn", "ID"),
+    ("wor", "Latn", "ID"),
+    ("wos", "Latn", "PG"),
+    ("wow", "Latn", "ID"),
+    ("wpc", "Latn", "VE"),
+    ("wrb", "Latn", "AU"),
+    ("wrg", "Latn", "AU"),
+    ("wrh", "Latn", "AU"),
+    ("wri", "Latn", "AU"),
+    ("wrk", "Latn", "AU"),
+    ("wrl", "Latn", "AU"),
+    ("wrm", "Latn", "AU"),
+    ("wro", "Latn", "AU"),
+    ("wrp", "Latn", "ID"),
+    ("wrr", "Latn", "AU"),
+    ("wrs", "Latn", "PG"),
+    ("wru", "Latn", "ID"),
+    ("wrv", "Latn", "PG"),
+    ("wrw", "Latn", "AU"),
+    ("wrx", "Latn", "ID"),
+    ("wrz", "Latn", "AU"),
+    ("wsa", "Latn", "ID"),
+    ("wsg", "Gong", "IN"),
+    ("wsi", "Latn", "VU"),
+    ("wsk", "Latn", "PG"),
+    ("wsr", "Latn", "PG"),
+    ("wss", "Latn", "GH"),
+    ("wsu", "Latn", "BR"),
+    ("wsv", "Arab", "AF"),
+    ("wtb", "Latn", "TZ"),
+    ("wtf", "Latn", "PG"),
+    ("wth", "Latn", "AU"),
+    ("wti", "Latn", "ET"),
+    ("wtk", "Latn", "PG"),
+    ("wtm", "Deva", "IN"),
+    ("wtw", "Latn", "ID"),
+    ("wua", "Latn", "AU"),
+    ("wub", "Latn", "AU"),
+    ("wud", "Latn", "TG"),
+    ("wul", "Latn", "ID"),
+    ("wum", "Latn", "GA"),
+    ("wun", "Latn", "TZ"),
+    ("wur", "Latn", "AU"),
+    ("wut", "Latn", "PG"),
+    ("wuu", "Hans", "CN"),
+    ("wuv", "Latn", "PG"),
+    ("wux", "Latn", "AU"),
+    ("wuy", "Latn", "ID"),
+    ("wwa", "Latn", "BJ"),
+    ("wwb", "Latn", "AU"),
+    ("wwo", "Latn", "VU"),
+    ("wwr", "Latn", "AU"),
+    ("www", "Latn", "CM"),
+    ("wxw", "Latn", "AU"),
+    ("wyb", "Latn", "AU"),
+    ("wyi", "Latn", "AU"),
+    ("wym", "Latn", "PL"),
+    ("wyn", "Latn", "US"),
+    ("wyr", "Latn", "BR"),
+    ("wyy", "Latn", "FJ"),
+    ("xaa", "Latn", "ES"),
+    ("xab", "Latn", "NG"),
+    ("xag", "Aghb", "AZ"),
+    ("xai", "Latn", "BR"),
+    ("xaj", "Latn", "BR"),
+    ("xak", "Latn", "VE"),
+    ("xal", "Cyrl", "RU"),
+    ("xam", "Latn", "ZA"),
+    ("xan", "Ethi", "ET"),
+    ("xao", "Latn", "VN"),
+    ("xar", "Latn", "PG"),
+    ("xas", "Cyrl", "RU"),
+    ("xat", "Latn", "BR"),
+    ("xau", "Latn", "ID"),
+    ("xav", "Latn", "BR"),
+    ("xaw", "Latn", "US"),
+    ("xay", "Latn", "ID"),
+    ("xbb", "Latn", "AU"),
+    ("xbd", "Latn", "AU"),
+    ("xbe", "Latn", "AU"),
+    ("xbg", "Latn", "AU"),
+    ("xbi", "Latn", "PG"),
+    ("xbj", "Latn", "AU"),
+    ("xbm", "Latn", "FR"),
+    ("xbn", "Latn", "MY"),
+    ("xbp", "Latn", "AU"),
+    ("xbr", "Latn", "ID"),
+    ("xbw", "Latn", "BR"),
+    ("xby", "Latn", "AU"),
+    ("xch", "Latn", "US"),
+    ("xco", "Chrs", "UZ"),
+    ("xcr", "Cari", "TR"),
+    ("xda", "Latn", "AU"),
+    ("xdk", "Latn", "AU"),
+    ("xdo", "Latn", "AO"),
+    ("xdq", "Cyrl", "RU"),
+    ("xdy", "Latn", "ID"),
+    ("xed", "Latn", "CM"),
+    ("xeg", "Latn", "ZA"),
+    ("xem", "Latn", "ID"),
+    ("xer", "Latn", "BR"),
+    ("xes", "Latn", "PG"),
+    ("xet", "Latn", "BR"),
+    ("xeu", "Latn", "PG"),
+    ("xgb", "Latn", "CI"),
+    ("xgd", "Latn", "AU"),
+    ("xgg", "Latn", "AU"),
+    ("xgi", "Latn", "AU"),
+    ("xgm", "Latn", "AU"),
+    ("xgu", "Latn", "AU"),
+    ("xgw", "Latn", "AU"),
+    ("xh", "Latn", "ZA"),
+    ("xhe", "Arab", "PK"),
+    ("xhm", "Khmr", "KH"),
+    ("xhv", "Latn", "VN"),
+    ("xii", "Latn", "ZA"),
+    ("xin", "Latn", "GT"),
+    ("xir", "Latn", "BR"),
+    ("xis", "Orya", "IN"),
+    ("xiy", "Latn", "BR"),
+    ("xjb", "Latn", "AU"),
+    ("xjt", "Latn", "AU"),
+    ("xka", "Arab", "PK"),
+    ("xkb", "Latn", "BJ"),
+    ("xkc", "Arab", "IR"),
+    ("xkd", "Latn", "ID"),
+    ("xke", "Latn", "ID"),
+    ("xkf", "Tibt", "BT"),
+    ("xkg", "Latn", "ML"),
+    ("xkj", "Arab", "IR"),
+    ("xkl", "Latn", "ID"),
+    ("xkn", "Latn", "ID"),
+    ("xkp", "Arab", "IR"),
+    ("xkq", "Latn", "ID"),
+    ("xkr", "Latn", "BR"),
+    ("xks", "Latn", "ID"),
+    ("xkt", "Latn", "GH"),
+    ("xku", "Latn", "CG"),
+    ("xkv", "Latn", "BW"),
+    ("xkw", "Latn", "ID"),
+    ("xkx", "Latn", "PG"),
+    ("xky", "Latn", "MY"),
+    ("xkz", "Latn", "BT"),
+    ("xla", "Latn", "PG"),
+    ("xlc", "Lyci", "TR"),
+    ("xld", "Lydi", "TR"),
+    ("xly", "Elym", "IR"),
+    ("xma", "Latn", "SO"),
+    ("xmb", "Latn", "CM"),
+    ("xmc", "Latn", "MZ"),
+    ("xmd", "Latn", "CM"),
+    ("xmf", "Geor", "GE"),
+    ("xmg", "Latn", "CM"),
+    ("xmh", "Latn", "AU"),
+    ("xmj", "Latn", "CM"),
+    ("xmm", "Latn", "ID"),
+    ("xmn", "Mani", "CN"),
+    ("xmo", "Latn", "BR"),
+    ("xmp", "Latn", "AU"),
+    ("xmq", "Latn", "AU"),
+    ("xmr", "Merc", "SD"),
+    ("xmt", "Latn", "ID"),
+    ("xmu", "Latn", "AU"),
+    ("xmv", "Latn", "MG"),
+    ("xmw", "Latn", "MG"),
+    ("xmx", "Latn", "ID"),
+    ("xmy", "Latn", "AU"),
+    ("xmz", "Latn", "ID"),
+    ("xna", "Narb", "SA"),
+    ("xnb", "Latn", "TW"),
+    ("xni", "Latn", "AU"),
+    ("xnj", "Latn", "TZ"),
+    ("xnk", "Latn", "AU"),
+    ("xnm", "Latn", "AU"),
+    ("xnn", "Latn", "PH"),
+    ("xnq", "Latn", "MZ"),
+    ("xnr", "Deva", "IN"),
+    ("xnt", "Latn", "US"),
+    ("xnu", "Latn", "AU"),
+    ("xny", "Latn", "AU"),
+    ("xnz", "Latn", "EG"),
+    ("xoc", "Latn", "NG"),
+    ("xod", "Latn", "ID"),
+    ("xog", "Latn", "UG"),
+    ("xoi", "Latn", "PG"),
+    ("xok", "Latn", "BR"),
+    ("xom", "Latn", "SD"),
+    ("xon", "Latn", "GH"),
+    ("xoo", "Latn", "BR"),
+    ("xop", "Latn", "PG"),
+    ("xor", "Latn", "BR"),
+    ("xow", "Latn", "PG"),
+    ("xpa", "Latn", "AU"),
+    ("xpb", "Latn", "AU"),
+    ("xpd", "Latn", "AU"),
+    ("xpf", "Latn", "AU"),
+    ("xpg", "Grek", "TR"),
+    ("xph", "Latn", "AU"),
+    ("xpi", "Ogam", "GB"),
+    ("xpj", "Latn", "AU"),
+    ("xpk", "Latn", "BR"),
+    ("xpl", "Latn", "AU"),
+    ("xpm", "Cyrl", "RU"),
+    ("xpn", "Latn", "BR"),
+    ("xpo", "Latn", "MX"),
+    ("xpq", "Latn", "US"),
+    ("xpr", "Prti", "IR"),
+    ("xpt", "Latn", "AU"),
+    ("xpv", "Latn", "AU"),
+    ("xpw", "Latn", "AU"),
+    ("xpx", "Latn", "AU"),
+    ("xpz", "Latn", "AU"),
+    ("xra", "Latn", "BR"),
+    ("xrb", "Latn", "BF"),
+    ("xrd", "Latn", "AU"),
+    ("xre", "Latn", "BR"),
+    ("xrg", "Latn", "AU"),
+    ("xri", "Latn", "BR"),
+    ("xrm", "Cyrl", "RU"),
+    ("xrn", "Cyrl", "RU"),
+    ("xrr", "Latn", "IT"),
+    ("xru", "Latn", "AU"),
+    ("xrw", "Latn", "PG"),
+    ("xsa", "Sarb", "YE"),
+    ("xsb", "Latn", "PH"),
+    ("xsd", "Sidt", "TR"),
+    ("xse", "Latn", "ID"),
+    ("xsh", "Latn", "NG"),
+    ("xsi", "Latn", "PG"),
+    ("xsm", "Latn", "GH"),
+    ("xsn", "Latn", "NG"),
+    ("xsp", "Latn", "PG"),
+    ("xsq", "Latn", "MZ"),
+    ("xsr", "Deva", "NP"),
+    ("xsu", "Latn", "VE"),
+    ("xsy", "Latn", "TW"),
+    ("xta", "Latn", "MX"),
+    ("xtb", "Latn", "MX"),
+    ("xtc", "Latn", "SD"),
+    ("xtd", "Latn", "MX"),
+    ("xte", "Latn", "ID"),
+    ("xth", "Latn", "AU"),
+    ("xti", "Latn", "MX"),
+    ("xtj", "Latn", "MX"),
+    ("xtl", "Latn", "MX"),
+    ("xtm", "Latn", "MX"),
+    ("xtn", "Latn", "MX"),
+    ("xtp", "Latn", "MX"),
+    ("xtq", "Brah", "IR"),
+    ("xts", "Latn", "MX"),
+    ("xtt", "Latn", "MX"),
+    ("xtu", "Latn", "MX"),
+    ("xtv", "Latn", "AU"),
+    ("xtw", "Latn", "BR"),
+    ("xty", "Latn", "MX"),
+    ("xub", "Taml", "IN"),
+    ("xud", "Latn", "AU"),
+    ("xuj", "Taml", "IN"),
+    ("xul", "Latn", "AU"),
+    ("xum", "Latn", "IT"),
+    ("xun", "Latn", "AU"),
+    ("xuo", "Latn", "TD"),
+    ("xut", "Latn", "AU"),
+    ("xuu", "Latn", "NA"),
+    ("xve", "Ital", "IT"),
+    ("xvi", "Arab", "AF"),
+    ("xvn", "Latn", "ES"),
+    ("xvo", "Latn", "IT"),
+    ("xvs", "Latn", "IT"),
+    ("xwa", "Latn", "BR"),
+    ("xwd", "Latn", "AU"),
+    ("xwe", "Latn", "BJ"),
+    ("xwj", "Latn", "AU"),
+    ("xwk", "Latn", "AU"),
+    ("xwl", "Latn", "BJ"),
+    ("xwo", "Cyrl", "RU"),
+    ("xwr", "Latn", "ID"),
+    ("xwt", "Latn", "AU"),
+    ("xww", "Latn", "AU"),
+    ("xxb", "Latn", "GH"),
+    ("xxk", "Latn", "ID"),
+    ("xxm", "Latn", "AU"),
+    ("xxr", "Latn", "BR"),
+    ("xxt", "Latn", "ID"),
+    ("xya", "Latn", "AU"),
+    ("xyb", "Latn", "AU"),
+    ("xyj", "Latn", "AU"),
+    ("xyk", "Latn", "AU"),
+    ("xyl", "Latn", "BR"),
+    ("xyt", "Latn", "AU"),
+    ("xyy", "Latn", "AU"),
+    ("xzh", "Marc", "CN"),
+    ("xzp", "Latn", "MX"),
+    ("yaa", "Latn", "PE"),
+    ("yab", "Latn", "BR"),
+    ("yac", "Latn", "ID"),
+    ("yad", "Latn", "PE"),
+    ("yae", "Latn", "VE"),
+    ("yaf", "Latn", "CD"),
+    ("yag", "Latn", "CL"),
+    ("yah", "Latn", "TJ"),
+    ("yai", "Cyrl", "TJ"),
+    ("yaj", "Latn", "CF"),
+    ("yak", "Latn", "US"),
+    ("yal", "Latn", "GN"),
+    ("yam", "Latn", "CM"),
+    ("yan", "Latn", "NI"),
+    ("yao", "Latn", "MZ"),
+    ("yap", "Latn", "FM"),
+    ("yaq", "Latn", "MX"),
+    ("yar", "Latn", "VE"),
+    ("yas", "Latn", "CM"),
+    ("yat", "Latn", "CM"),
+    ("yau", "Latn", "VE"),
+    ("yav", "Latn", "CM"),
+    ("yaw", "Latn", "BR"),
+    ("yax", "Latn", "AO"),
+    ("yay", "Latn", "NG"),
+    ("yaz", "Latn", "NG"),
+    ("yba", "Latn", "NG"),
+    ("ybb", "Latn", "CM"),
+    ("ybe", "Latn", "CN"),
+    ("ybh", "Deva", "NP"),
+    ("ybi", "Deva", "NP"),
+    ("ybj", "Latn", "NG"),
+    ("ybl", "Latn", "NG"),
+    ("ybm", "Latn", "PG"),
+    ("ybn", "Latn", "BR"),
+    ("ybo", "Latn", "PG"),
+    ("ybx", "Latn", "PG"),
+    ("yby", "Latn", "PG"),
+    ("ycl", "Latn", "CN"),
+    ("ycn", "Latn", "CO"),
+    ("ycr", "Latn", "TW"),
+    ("yda", "Latn", "AU"),
+    ("yde", "Latn", "PG"),
+    ("ydg", "Arab", "PK"),
+    ("ydk", "Latn", "PG"),
+    ("yea", "Mlym", "IN"),
+    ("yec", "Latn", "DE"),
+    ("yee", "Latn", "PG"),
+    ("yei", "Latn", "CM"),
+    ("yej", "Grek", "GR"),
+    ("yel", "Latn", "CD"),
+    ("yer", "Latn", "NG"),
+    ("yes", "Latn", "NG"),
+    ("yet", "Latn", "ID"),
+    ("yeu", "Telu", "IN"),
+    ("yev", "Latn", "PG"),
+    ("yey", "Latn", "BW"),
+    ("yga", "Latn", "AU"),
+    ("ygi", "Latn", "AU"),
+    ("ygl", "Latn", "PG"),
+    ("ygm", "Latn", "PG"),
+    ("ygp", "Plrd", "CN"),
+    ("ygr", "Latn", "PG"),
+    ("ygu", "Latn", "AU"),
+    ("ygw", "Latn", "PG"),
+    ("yhd", "Hebr", "IL"),
+    ("yi", "Hebr", "UA"),
+    ("yia", "Latn", "AU"),
+    ("yig", "Yiii", "CN"),
+    ("yih", "Hebr", "DE"),
+    ("yii", "Latn", "AU"),
+    ("yij", "Latn", "AU"),
+    ("yil", "Latn", "AU"),
+    ("yim", "Latn", "IN"),
+    ("yir", "Latn", "ID"),
+    ("yis", "Latn", "PG"),
+    ("yiv", "Yiii", "CN"),
+    ("yka", "Latn", "PH"),
+    ("ykg", "Cyrl", "RU"),
+    ("ykh", "Cyrl", "MN"),
+    ("yki", "Latn", "ID"),
+    ("ykk", "Latn", "PG"),
+    ("ykm", "Latn", "PG"),
+    ("yko", "Latn", "CM"),
+    ("ykr", "Latn", "PG"),
+    ("yky", "Latn", "CF"),
+    ("yla", "Latn", "PG"),
+    ("ylb", "Latn", "PG"),
+    ("yle", "Latn", "PG"),
+    ("ylg", "Latn", "PG"),
+    ("yli", "Latn", "ID"),
+    ("yll", "Latn", "PG"),
+    ("ylr", "Latn", "AU"),
+    ("ylu", "Latn", "PG"),
+    ("yly", "Latn", "NC"),
+    ("ymb", "Latn", "PG"),
+    ("yme", "Latn", "PE"),
+    ("ymg", "Latn", "CD"),
+    ("ymk", "Latn", "MZ"),
+    ("yml", "Latn", "PG"),
+    ("ymm", "Latn", "SO"),
+    ("ymn", "Latn", "ID"),
+    ("ymo", "Latn", "PG"),
+    ("ymp", "Latn", "PG"),
+    ("yna", "Plrd", "CN"),
+    ("ynb", "Latn", "PG"),
+    ("ynd", "Latn", "AU"),
+    ("yng", "Latn", "CD"),
+    ("ynk", "Cyrl", "RU"),
+    ("ynl", "Latn", "PG"),
+    ("ynq", "Latn", "NG"),
+    ("yns", "Latn", "CD"),
+    ("ynu", "Latn", "CO"),
+    ("yo", "Latn", "NG"),
+    ("yob", "Latn", "PG"),
+    ("yog", "Latn", "PH"),
+    ("yoi", "Jpan", "JP"),
+    ("yok", "Latn", "US"),
+    ("yol", "Latn", "IE"),
+    ("yom", "Latn", "CD"),
+    ("yon", "Latn", "PG"),
+    ("yot", "Latn", "NG"),
+    ("yoy", "Thai", "TH"),
+    ("yra", "Latn", "PG"),
+    ("yrb", "Latn", "PG"),
+    ("yre", "Latn", "CI"),
+    ("yrk", "Cyrl", "RU"),
+    ("yrl", "Latn", "BR"),
+    ("yrm", "Latn", "AU"),
+    ("yro", "Latn", "BR"),
+    ("yrs", "Latn", "ID"),
+    ("yrw", "Latn", "PG"),
+    ("yry", "Latn", "AU"),
+    ("ysd", "Yiii", "CN"),
+    ("ysn", "Yiii", "CN"),
+    ("ysp", "Yiii", "CN"),
+    ("ysr", "Cyrl", "RU"),
+    ("yss", "Latn", "PG"),
+    ("ysy", "Plrd", "CN"),
+    ("ytw", "Latn", "PG"),
+    ("yty", "Latn", "AU"),
+    ("yua", "Latn", "MX"),
+    ("yub", "Latn", "AU"),
+    ("yuc", "Latn", "US"),
+    ("yud", "Hebr", "IL"),
+    ("yue", "Hant", "HK"),
+    ("yuf", "Latn", "US"),
+    ("yug", "Cyrl", "RU"),
+    ("yui", "Latn", "CO"),
+    ("yuj", "Latn", "PG"),
+    ("yul", "Latn", "CF"),
+    ("yum", "Latn", "US"),
+    ("yun", "Latn", "NG"),
+    ("yup", "Latn", "CO"),
+    ("yuq", "Latn", "BO"),
+    ("yur", "Latn", "US"),
+    ("yut", "Latn", "PG"),
+    ("yuw", "Latn", "PG"),
+    ("yux", "Cyrl", "RU"),
+    ("yuz", "Latn", "BO"),
+    ("yva", "Latn", "ID"),
+    ("yvt", "Latn", "VE"),
+    ("ywa", "Latn", "PG"),
+    ("ywg", "Latn", "AU"),
+    ("ywn", "Latn", "BR"),
+    ("ywq", "Plrd", "CN"),
+    ("ywr", "Latn", "AU"),
+    ("ywu", "Plrd", "CN"),
+    ("yww", "Latn", "AU"),
+    ("yxa", "Latn", "AU"),
+    ("yxg", "Latn", "AU"),
+    ("yxl", "Latn", "AU"),
+    ("yxm", "Latn", "AU"),
+    ("yxu", "Latn", "AU"),
+    ("yxy", "Latn", "AU"),
+    ("yyr", "Latn", "AU"),
+    ("yyu", "Latn", "PG"),
+    ("za", "Latn", "CN"),
+    ("zaa", "Latn", "MX"),
+    ("zab", "Latn", "MX"),
+    ("zac", "Latn", "MX"),
+    ("zad", "Latn", "MX"),
+    ("zae", "Latn", "MX"),
+    ("zaf", "Latn", "MX"),
+    ("zag", "Latn", "SD"),
+    ("zah", "Latn", "NG"),
+    ("zaj", "Latn", "TZ"),
+    ("zak", "Latn", "TZ"),
+    ("zam", "Latn", "MX"),
+    ("zao", "Latn", "MX"),
+    ("zap", "Latn", "MX"),
+    ("zaq", "Latn", "MX"),
+    ("zar", "Latn", "MX"),
+    ("zas", "Latn", "MX"),
+    ("zat", "Latn", "MX"),
+    ("zau", "Tibt", "IN"),
+    ("zav", "Latn", "MX"),
+    ("zaw", "Latn", "MX"),
+    ("zax", "Latn", "MX"),
+    ("zay", "Latn", "ET"),
+    ("zaz", "Latn", "NG"),
+    ("zba", "Arab", "001"),
+    ("zbc", "Latn", "MY"),
+    ("zbe", "Latn", "MY"),
+    ("zbt", "Latn", "ID"),
+    ("zbu", "Latn", "NG"),
+    ("zbw", "Latn", "MY"),
+    ("zca", "Latn", "MX"),
+    ("zch", "Hani", "CN"),
+    ("zdj", "Arab", "KM"),
+    ("zea", "Latn", "NL"),
+    ("zeg", "Latn", "PG"),
+    ("zeh", "Hani", "CN"),
+    ("zem", "Latn", "NG"),
+    ("zen", "Tfng", "MR"),
+    ("zga", "Latn", "TZ"),
+    ("zgb", "Hani", "CN"),
+    ("zgh", "Tfng", "MA"),
+    ("zgm", "Hani", "CN"),
+    ("zgn", "Hani", "CN"),
+    ("zgr", "Latn", "PG"),
+    ("zh", "Hans", "CN"),
+    ("zhd", "Hani", "CN"),
+    ("zhi", "Latn", "NG"),
+    ("zhn", "Latn", "CN"),
+    ("zhw", "Latn", "CM"),
+    ("zia", "Latn", "PG"),
+    ("zik", "Latn", "PG"),
+    ("zil", "Latn", "GN"),
+    ("zim", "Latn", "TD"),
+    ("zin", "Latn", "TZ"),
+    ("ziw", "Latn", "TZ"),
+    ("ziz", "Latn", "NG"),
+    ("zka", "Latn", "ID"),
+    ("zkd", "Latn", "MM"),
+    ("zko", "Cyrl", "RU"),
+    ("zkp", "Latn", "BR"),
+    ("zkt", "Kits", "CN"),
+    ("zku", "Latn", "AU"),
+    ("zkz", "Cyrl", "RU"),
+    ("zla", "Latn", "CD"),
+    ("zlj", "Hani", "CN"),
+    ("zlm", "Latn", "MY"),
+    ("zln", "Hani", "CN"),
+    ("zlq", "Hani", "CN"),
+    ("zlu", "Latn", "NG"),
+    ("zma", "Latn", "AU"),
+    ("zmb", "Latn", "CD"),
+    ("zmc", "Latn", "AU"),
+    ("zmd", "Latn", "AU"),
+    ("zme", "Latn", "AU"),
+    ("zmf", "Latn", "CD"),
+    ("zmg", "Latn", "AU"),
+    ("zmh", "Latn", "PG"),
+    ("zmi", "Latn", "MY"),
+    ("zmj", "Latn", "AU"),
+    ("zmk", "Latn", "AU"),
+    ("zml", "Latn", "AU"),
+    ("zmm", "Latn", "AU"),
+    ("zmn", "Latn", "GA"),
+    ("zmo", "Latn", "SD"),
+    ("zmp", "Latn", "CD"),
+    ("zmq", "Latn", "CD"),
+    ("zmr", "Latn", "AU"),
+    ("zms", "Latn", "CD"),
+    ("zmt", "Latn", "AU"),
+    ("zmu", "Latn", "AU"),
+    ("zmv", "Latn", "AU"),
+    ("zmw", "Latn", "CD"),
+    ("zmx", "Latn", "CG"),
+    ("zmy", "Latn", "AU"),
+    ("zmz", "Latn", "CD"),
+    ("zna", "Latn", "TD"),
+    ("zne", "Latn", "CD"),
+    ("zng", "Latn", "VN"),
+    ("znk", "Latn", "AU"),
+    ("zns", "Latn", "NG"),
+    ("zoc", "Latn", "MX"),
+    ("zoh", "Latn", "MX"),
+    ("zom", "Latn", "IN"),
+    ("zoo", "Latn", "MX"),
+    ("zoq", "Latn", "MX"),
+    ("zor", "Latn", "MX"),
+    ("zos", "Latn", "MX"),
+    ("zpa", "Latn", "MX"),
+    ("zpb", "Latn", "MX"),
+    ("zpc", "Latn", "MX"),
+    ("zpd", "Latn", "MX"),
+    ("zpe", "Latn", "MX"),
+    ("zpf", "Latn", "MX"),
+    ("zpg", "Latn", "MX"),
+    ("zph", "Latn", "MX"),
+    ("zpi", "Latn", "MX"),
+    ("zpj", "Latn", "MX"),
+    ("zpk", "Latn", "MX"),
+    ("zpl", "Latn", "MX"),
+    ("zpm", "Latn", "MX"),
+    ("zpn", "Latn", "MX"),
+    ("zpo", "Latn", "MX"),
+    ("zpp", "Latn", "MX"),
+    ("zpq", "Latn", "MX"),
+    ("zpr", "Latn", "MX"),
+    ("zps", "Latn", "MX"),
+    ("zpt", "Latn", "MX"),
+    ("zpu", "Latn", "MX"),
+    ("zpv", "Latn", "MX"),
+    ("zpw", "Latn", "MX"),
+    ("zpx", "Latn", "MX"),
+    ("zpy", "Latn", "MX"),
+    ("zpz", "Latn", "MX"),
+    ("zqe", "Hani", "CN"),
+    ("zrg", "Orya", "IN"),
+    ("zrn", "Latn", "TD"),
+    ("zro", "Latn", "EC"),
+    ("zrp", "Hebr", "FR"),
+    ("zrs", "Latn", "ID"),
+    ("zsa", "Latn", "PG"),
+    ("zsr", "Latn", "MX"),
+    ("zsu", "Latn", "PG"),
+    ("zte", "Latn", "MX"),
+    ("ztg", "Latn", "MX"),
+    ("ztl", "Latn", "MX"),
+    ("ztm", "Latn", "MX"),
+    ("ztn", "Latn", "MX"),
+    ("ztp", "Latn", "MX"),
+    ("ztq", "Latn", "MX"),
+    ("zts", "Latn", "MX"),
+    ("ztt", "Latn", "MX"),
+    ("ztu", "Latn", "MX"),
+    ("ztx", "Latn", "MX"),
+    ("zty", "Latn", "MX"),
+    ("zu", "Latn", "ZA"),
+    ("zuh", "Latn", "PG"),
+    ("zum", "Arab", "OM"),
+    ("zun", "Latn", "US"),
+    ("zuy", "Latn", "CM"),
+    ("zwa", "Ethi", "ET"),
+    ("zyg", "Hani", "CN"),
+    ("zyj", "Latn", "CN"),
+    ("zyn", "Hani", "CN"),
+    ("zyp", "Latn", "MM"),
+    ("zza", "Latn", "TR"),
+    ("zzj", "Hani", "CN"),
+];
+
+/// Likely scripts of languages in regions, which differ from the ones of the
+/// languages.
+pub(super) const REGIONS: &[(&str, &str, &str)] = &[
+    ("abq", "TR", "Latn"),
+    ("az", "IQ", "Arab"),
+    ("az", "IR", "Arab"),
+    ("az", "RU", "Cyrl"),
+    ("ha", "CM", "Arab"),
+    ("ha", "SD", "Arab"),
+    ("hak", "TW", "Hant"),
+    ("kk", "AF", "Arab"),
+    ("kk", "CN", "Arab"),
+    ("kk", "IR", "Arab"),
+    ("kk", "MN", "Arab"),
+    ("ku", "AM", "Cyrl"),
+    ("ku", "AZ", "Cyrl"),
+    ("ku", "GE", "Cyrl"),
+    ("ku", "IQ", "Arab"),
+    ("ku", "IR", "Arab"),
+    ("ku", "LB", "Arab"),
+    ("ku", "TM", "Cyrl"),
+    ("ky", "CN", "Arab"),
+    ("ky", "TR", "Latn"),
+    ("lzz", "GE", "Geor"),
+    ("mey", "SN", "Latn"),
+    ("mn", "CN", "Mong"),
+    ("ms", "CC", "Arab"),
+    ("nan", "MO", "Hant"),
+    ("nan", "TW", "Hant"),
+    ("pa", "PK", "Arab"),
+    ("pi", "IN", "Deva"),
+    ("pi", "LK", "Sinh"),
+    ("pi", "MM", "Mymr"),
+    ("pi", "TH", "Thai"),
+    ("pnt", "RU", "Cyrl"),
+    ("pnt", "TR", "Latn"),
+    ("sd", "IN", "Deva"),
+    ("sr", "ME", "Latn"),
+    ("sr", "RO", "Latn"),
+    ("sr", "TR", "Latn"),
+    ("stu", "CN", "Tale"),
+    ("tg", "PK", "Arab"),
+    ("ug", "KZ", "Cyrl"),
+    ("ug", "MN", "Cyrl"),
+    ("unr", "NP", "Deva"),
+    ("uz", "AF", "Arab"),
+    ("uz", "CN", "Cyrl"),
+    ("yue", "CN", "Hans"),
+    ("zh", "AU", "Hant"),
+    ("zh", "BN", "Hant"),
+    ("zh", "GB", "Hant"),
+    ("zh", "GF", "Hant"),
+    ("zh", "HK", "Hant"),
+    ("zh", "ID", "Hant"),
+    ("zh", "MO", "Hant"),
+    ("zh", "PA", "Hant"),
+    ("zh", "PF", "Hant"),
+    ("zh", "PH", "Hant"),
+    ("zh", "SR", "Hant"),
+    ("zh", "TH", "Hant"),
+    ("zh", "TW", "Hant"),
+    ("zh", "US", "Hant"),
+    ("zh", "VN", "Hant"),
+];
+
+/// Likely regions of languages in scripts, which differ from the ones of the
+/// languages.
+pub(super) const SCRIPTS: &[(&str, &str, &str)] = &[
+    ("abq", "Latn", "TR"),
+    ("arc", "Hatr", "IQ"),
+    ("arc", "Nbat", "JO"),
+    ("arc", "Palm", "SY"),
+    ("az", "Arab", "IR"),
+    ("bap", "Krai", "IN"),
+    ("cu", "Glag", "BG"),
+    ("en", "Shaw", "GB"),
+    ("ff", "Adlm", "GN"),
+    ("hak", "Hant", "TW"),
+    ("hnj", "Hmng", "LA"),
+    ("kk", "Arab", "CN"),
+    ("ku", "Arab", "IQ"),
+    ("ku", "Cyrl", "AM"),
+    ("ku", "Yezi", "GE"),
+    ("ky", "Arab", "CN"),
+    ("ky", "Latn", "TR"),
+    ("lif", "Limb", "IN"),
+    ("lzz", "Geor", "GE"),
+    ("man", "Nkoo", "GN"),
+    ("mey", "Latn", "SN"),
+    ("mn", "Mong", "CN"),
+    ("nan", "Hant", "TW"),
+    ("pa", "Arab", "PK"),
+    ("pal", "Phlp", "CN"),
+    ("pi", "Deva", "IN"),
+    ("pi", "Mymr", "MM"),
+    ("pi", "Sinh", "LK"),
+    ("pi", "Thai", "TH"),
+    ("pnt", "Cyrl", "RU"),
+    ("pnt", "Latn", "TR"),
+    ("sd", "Deva", "IN"),
+    ("sd", "Khoj", "IN"),
+    ("sd", "Sind", "IN"),
+    ("stu", "Tale", "CN"),
+    ("tg", "Arab", "PK"),
+    ("ug", "Cyrl", "KZ"),
+    ("unr", "Deva", "NP"),
+    ("uz", "Arab", "AF"),
+    ("yue", "Hans", "CN"),
+    ("zh", "Bopo", "TW"),
+    ("zh", "Hanb", "TW"),
+    ("zh", "Hant", "TW"),
+];
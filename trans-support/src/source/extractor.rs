@@ -1,11 +1,32 @@
-use std::{collections::HashSet, fs::File, io::Read, path::Path};
-
-use proc_macro2::{TokenStream as TokenStream2, TokenTree};
+use std::{
+    collections::{BTreeSet, HashMap},
+    fs::File,
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+use proc_macro2::{Spacing, TokenStream as TokenStream2, TokenTree};
 use quote::ToTokens;
 
+use super::placeholder_names;
+
+/// Translation keys extracted from source.
+pub type Contents = HashMap<String, Content>;
+
+/// A translation key extracted from source.
+#[derive(Debug, Default, Clone)]
+pub struct Content {
+    /// Names of its arguments.
+    pub names: BTreeSet<String>,
+    /// Files and lines where it is used.
+    pub references: BTreeSet<(PathBuf, usize)>,
+    /// Whether it is translated by `tr_plural!`.
+    pub plural: bool,
+}
+
 pub fn extract(
     path: impl AsRef<Path>,
-    contents: &mut HashSet<String>,
+    contents: &mut Contents,
     locale: &mut String,
 ) -> crate::Result<()> {
     let mut file = File::open(path.as_ref())?;
@@ -15,20 +36,21 @@ pub fn extract(
 
     let stream = syn::parse_file(&source)?.into_token_stream();
 
-    extract_inner(stream, contents, locale)
+    extract_inner(stream, path.as_ref(), contents, locale)
         .map_err(|e| format!("{}:{}", path.as_ref().display(), e).into())
 }
 
 fn extract_inner(
     stream: TokenStream2,
-    contents: &mut HashSet<String>,
+    path: &Path,
+    contents: &mut Contents,
     locale: &mut String,
 ) -> crate::Result<()> {
     let mut stream = stream.into_iter().peekable();
 
     while let Some(token) = stream.next() {
         match token {
-            TokenTree::Group(g) => extract_inner(g.stream(), contents, locale)?,
+            TokenTree::Group(g) => extract_inner(g.stream(), path, contents, locale)?,
             TokenTree::Ident(id) => {
                 // is macro
                 match stream.peek() {
@@ -41,8 +63,12 @@ fn extract_inner(
                         extract_init(g.stream(), locale)?;
                         stream.next();
                     }
-                    ("tr", Some(TokenTree::Group(g))) => {
-                        extract_tr(g.stream(), contents)?;
+                    ("tr" | "try_tr", Some(TokenTree::Group(g))) => {
+                        extract_tr(g.stream(), path, contents)?;
+                        stream.next();
+                    }
+                    ("tr_plural", Some(TokenTree::Group(g))) => {
+                        extract_tr_plural(g.stream(), path, contents)?;
                         stream.next();
                     }
                     _ => {}
@@ -83,7 +109,11 @@ fn extract_init(stream: TokenStream2, locale: &mut String) -> crate::Result<()>
     Ok(())
 }
 
-fn extract_tr(stream: TokenStream2, contents: &mut HashSet<String>) -> crate::Result<()> {
+fn extract_tr<'c>(
+    stream: TokenStream2,
+    path: &Path,
+    contents: &'c mut Contents,
+) -> crate::Result<&'c mut Content> {
     let mut stream = stream.into_iter().peekable();
 
     let prefix = match stream.peek() {
@@ -117,17 +147,19 @@ fn extract_tr(stream: TokenStream2, contents: &mut HashSet<String>) -> crate::Re
         _ => String::new(),
     };
 
-    let content = match stream.next() {
+    let (content, line) = match stream.next() {
         Some(TokenTree::Literal(literal)) => {
             let pos = literal.span().start();
-            syn::parse2::<syn::LitStr>(literal.into_token_stream())
+            let content = syn::parse2::<syn::LitStr>(literal.into_token_stream())
                 .map_err(|e| {
                     format!(
                         "{}:{} Invalid translation content: {}",
                         pos.line, pos.column, e
                     )
                 })?
-                .value()
+                .value();
+
+            (content, pos.line)
         }
         Some(token) => {
             let pos = token.span().start();
@@ -136,15 +168,48 @@ fn extract_tr(stream: TokenStream2, contents: &mut HashSet<String>) -> crate::Re
         _ => return Err(" Unexpected termination while parsing content".into()),
     };
 
-    contents.insert(format!("{} {}", prefix, content));
+    let mut names = placeholder_names(&content)
+        .into_iter()
+        .collect::<BTreeSet<_>>();
 
-    Ok(())
+    // named arguments, like `name = value`
+    let stream = stream.collect::<Vec<_>>();
+    for args in stream.windows(3) {
+        if let [TokenTree::Punct(comma), TokenTree::Ident(name), TokenTree::Punct(eq)] = args {
+            if comma.as_char() == ',' && eq.as_char() == '=' && eq.spacing() == Spacing::Alone {
+                names.insert(name.to_string());
+            }
+        }
+    }
+
+    let content = contents
+        .entry(format!("{} {}", prefix, content))
+        .or_default();
+
+    content.names.extend(names);
+    content.references.insert((path.to_path_buf(), line));
+
+    Ok(content)
+}
+
+fn extract_tr_plural(stream: TokenStream2, path: &Path, contents: &mut Contents) -> crate::Result<()> {
+    let mut stream = stream.into_iter();
+
+    // skip the count
+    for token in stream.by_ref() {
+        if let TokenTree::Punct(p) = &token {
+            if p.as_char() == ',' {
+                extract_tr(stream.collect(), path, contents)?.plural = true;
+                return Ok(());
+            }
+        }
+    }
+
+    Err(" Unexpected termination while parsing count".into())
 }
 
 #[cfg(test)]
 mod tests {
-    use std::collections::HashSet;
-
     use quote::ToTokens;
 
     use crate::source::extractor::extract_inner;
@@ -154,10 +219,10 @@ mod tests {
         let source = include_str!("../../../example/src/main.rs");
 
         let stream = syn::parse_file(source).unwrap().into_token_stream();
-        let mut contents = HashSet::new();
+        let mut contents = Default::default();
         let mut locale = String::new();
 
-        extract_inner(stream, &mut contents, &mut locale).unwrap();
+        extract_inner(stream, "src/main.rs".as_ref(), &mut contents, &mut locale).unwrap();
 
         println!("contents: {:#?}", contents);
         println!("locale: {}", locale);
@@ -0,0 +1,193 @@
+/// A part of a text split by [`segments`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Segment {
+    /// A literal text, where `{{` and `}}` are unescaped.
+    Text(String),
+    /// A placeholder like `{name:>8}`, positional ones are named by their
+    /// indexes, or `count` in plural forms.
+    Placeholder { name: String, spec: String },
+    /// An ICU MessageFormat argument without braces, like
+    /// `count, plural, one {# file} other {# files}`.
+    Message(String),
+}
+
+/// Splits `text` into texts, placeholders and ICU MessageFormat arguments.
+pub fn segments(text: &str, plural: bool) -> Vec<Segment> {
+    let mut ret = Vec::new();
+    let mut buffer = String::new();
+    let mut chars = text.chars().peekable();
+    let mut position = 0;
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' | '}' if chars.peek() == Some(&c) => {
+                chars.next();
+                buffer.push(c);
+            }
+            '{' => {
+                let mut depth = 1;
+                let placeholder = chars
+                    .by_ref()
+                    .take_while(|c| {
+                        match c {
+                            '{' => depth += 1,
+                            '}' => depth -= 1,
+                            _ => {}
+                        }
+                        depth > 0
+                    })
+                    .collect::<String>();
+
+                if !buffer.is_empty() {
+                    ret.push(Segment::Text(std::mem::take(&mut buffer)));
+                }
+
+                // the first separator of ICU arguments is `,`, and the one of
+                // format specs is `:`, like `{name:,>8}`
+                if placeholder.find([',', ':']).map(|i| &placeholder[i..i + 1]) == Some(",") {
+                    ret.push(Segment::Message(placeholder));
+                    continue;
+                }
+
+                let (name, spec) = placeholder.split_once(':').unwrap_or((&placeholder, ""));
+                let name = match name.trim() {
+                    "" if plural => "count".to_string(),
+                    "" => {
+                        position += 1;
+                        (position - 1).to_string()
+                    }
+                    name => name.to_string(),
+                };
+
+                ret.push(Segment::Placeholder {
+                    name,
+                    spec: spec.to_string(),
+                });
+            }
+            c => buffer.push(c),
+        }
+    }
+
+    if !buffer.is_empty() {
+        ret.push(Segment::Text(buffer));
+    }
+
+    ret
+}
+
+/// Returns names of named placeholders in `content`, like `name` in
+/// `"Hello, {name}"`.
+pub fn placeholder_names(content: &str) -> Vec<String> {
+    let mut names = Vec::<String>::new();
+    let mut push = |name: &str| {
+        if is_identifier(name) && !names.iter().any(|n| n == name) {
+            names.push(name.to_string());
+        }
+    };
+
+    for segment in segments(content, false) {
+        match segment {
+            Segment::Text(_) => {}
+            Segment::Placeholder { name, .. } => push(&name),
+            // ICU MessageFormat arguments, like `{count, plural, one {...} other {...}}`
+            Segment::Message(message) => {
+                let mut parts = message.splitn(3, ',');
+                push(parts.next().unwrap_or_default().trim());
+
+                for name in sub_messages(parts.nth(1).unwrap_or_default()).flat_map(placeholder_names) {
+                    push(&name);
+                }
+            }
+        }
+    }
+
+    names
+}
+
+/// Returns sub-messages in the options of an ICU MessageFormat argument.
+fn sub_messages(options: &str) -> impl Iterator<Item = &str> {
+    let mut depth = 0;
+    let mut start = 0;
+
+    options.char_indices().filter_map(move |(i, c)| {
+        match c {
+            '{' if depth == 0 => {
+                depth += 1;
+                start = i + 1;
+            }
+            '{' => depth += 1,
+            '}' if depth == 1 => {
+                depth -= 1;
+                return Some(&options[start..i]);
+            }
+            '}' => depth -= 1,
+            _ => {}
+        }
+
+        None
+    })
+}
+
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+
+    match chars.next() {
+        Some(c) if c.is_alphabetic() || c == '_' => {
+            name != "_" && chars.all(|c| c.is_alphanumeric() || c == '_')
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Segment;
+
+    #[test]
+    fn test_segments() {
+        assert_eq!(
+            vec![
+                Segment::Text("{literal} ".to_string()),
+                Segment::Placeholder {
+                    name: "0".to_string(),
+                    spec: String::new()
+                },
+                Segment::Text(" ".to_string()),
+                Segment::Placeholder {
+                    name: "name".to_string(),
+                    spec: ",>8".to_string()
+                },
+                Segment::Placeholder {
+                    name: "1".to_string(),
+                    spec: ".2".to_string()
+                },
+                Segment::Message("count, plural, other {# {}}".to_string()),
+            ],
+            super::segments("{{literal}} {} {name:,>8}{:.2}{count, plural, other {# {}}}", false)
+        );
+        assert_eq!(
+            vec![
+                Segment::Placeholder {
+                    name: "count".to_string(),
+                    spec: String::new()
+                },
+                Segment::Text(" files".to_string()),
+            ],
+            super::segments("{} files", true)
+        );
+    }
+
+    #[test]
+    fn test_placeholder_names() {
+        assert_eq!(
+            vec!["name", "count"],
+            super::placeholder_names("{{literal}} {} {0} {name}, {count} {name} {_}")
+        );
+        assert_eq!(
+            vec!["gender", "count", "name"],
+            super::placeholder_names(
+                "{gender, select, female {{count, plural, one {{name}: #} other {#}}} other {}}"
+            )
+        );
+    }
+}
@@ -0,0 +1,160 @@
+//! Android string resources of `res/values/strings.xml`.
+//!
+//! Prefixes are namespaces of resource names like `pua.hello`, and plural
+//! forms are `<plurals>` resources.
+
+use std::collections::{BTreeMap, HashMap};
+
+use super::{escape_xml, printf_format, split_key, Translation};
+
+/// Returns a resource name of `s`, which has only lowercase ASCII
+/// alphanumerics and underscores, other letters are named by their code
+/// points.
+pub(crate) fn identifier(s: &str) -> String {
+    let name = s
+        .chars()
+        .map(|c| match c {
+            c if c.is_ascii_alphanumeric() => c.to_ascii_lowercase().to_string(),
+            c if c.is_alphanumeric() => format!("_u{:x}_", c as u32),
+            _ => "_".to_string(),
+        })
+        .collect::<String>();
+    let name = name
+        .split('_')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("_");
+
+    match name.starts_with(|c: char| c.is_ascii_digit()) || name.is_empty() {
+        true => format!("_{}", name),
+        false => name,
+    }
+}
+
+/// Returns the resource name of a key, like `pua.hello` of
+/// `".pua Hello, {}"`.
+fn resource_name(prefix: &str, content: &str) -> String {
+    prefix
+        .split('.')
+        .filter(|part| !part.is_empty())
+        .map(identifier)
+        .chain([identifier(content)])
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+/// Escapes `s` for string resources, which are also XML texts.
+fn escape_android(s: &str) -> String {
+    let escaped = s
+        .replace('\\', "\\\\")
+        .replace('\'', "\\'")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n");
+
+    // references to resources and attributes start with `@` or `?`
+    match escaped.starts_with(['@', '?']) {
+        true => escape_xml(&format!("\\{}", escaped)),
+        false => escape_xml(&escaped),
+    }
+}
+
+/// Generates a `strings.xml` file of `translations`, where placeholders are
+/// rewritten to format specifiers like `%1$s`.
+pub fn generate_android_strings(
+    translations: &HashMap<String, Translation>,
+) -> crate::Result<String> {
+    let mut resources = BTreeMap::new();
+
+    for (key, translation) in translations {
+        let (prefix, content) = split_key(key)?;
+        let name = resource_name(prefix, content);
+
+        if let Some((other, _, _)) = resources.get(&name) {
+            let (a, b) = match *other < key {
+                true => (*other, key),
+                false => (key, *other),
+            };
+
+            return Err(format!("`{}` and `{}` have the same resource name `{}`", a, b, name).into());
+        }
+
+        resources.insert(name, (key, content, translation));
+    }
+
+    let mut document = String::from("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<resources>\n");
+
+    for (name, (_, content, translation)) in resources {
+        match translation {
+            Translation::Text(text) => document.push_str(&format!(
+                "    <string name=\"{}\">{}</string>\n",
+                name,
+                escape_android(&printf_format(content, text, false, "s"))
+            )),
+            Translation::Plural(forms) => {
+                document.push_str(&format!("    <plurals name=\"{}\">\n", name));
+
+                for (category, form) in forms {
+                    document.push_str(&format!(
+                        "        <item quantity=\"{}\">{}</item>\n",
+                        category,
+                        escape_android(&printf_format(content, form, true, "s"))
+                    ));
+                }
+
+                document.push_str("    </plurals>\n");
+            }
+        }
+    }
+
+    document.push_str("</resources>\n");
+
+    Ok(document)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::translation::{fixture, Translation};
+
+    #[test]
+    fn resource_name_test() {
+        assert_eq!("hello", super::resource_name("", "Hello, {}"));
+        assert_eq!("pua.hello", super::resource_name(".pua", "Hello, {}"));
+        assert_eq!("a.b._0_sent_1_a_message", super::resource_name(".a.b", "{0} sent {1} a message"));
+        assert_eq!("u4f60_u597d_name", super::resource_name("", "你好，{name}"));
+    }
+
+    #[test]
+    fn generate_android_strings_test() {
+        let mut translations = fixture();
+        translations.insert(".pua Hello, {}".to_string(), "@要到年底了，{}'s".into());
+
+        assert_eq!(
+            super::generate_android_strings(&translations).unwrap(),
+            r#"<?xml version="1.0" encoding="utf-8"?>
+<resources>
+    <plurals name="files">
+        <item quantity="one">%1$d файл</item>
+        <item quantity="other">%1$d файлов</item>
+    </plurals>
+    <string name="hello">你好，\&quot;%1$s\&quot;</string>
+    <string name="pua.hello">\@要到年底了，%1$s\'s</string>
+</resources>
+"#
+        );
+
+        let conflict = [
+            (" Hello".to_string(), "你好".into()),
+            (" hello!".to_string(), "你好！".into()),
+        ]
+        .into_iter()
+        .collect::<HashMap<String, Translation>>();
+        assert_eq!(
+            "` Hello` and ` hello!` have the same resource name `hello`",
+            super::generate_android_strings(&conflict)
+                .unwrap_err()
+                .to_string()
+        );
+    }
+}
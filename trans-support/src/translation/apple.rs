@@ -0,0 +1,193 @@
+//! Apple string resources of `Localizable.strings` and
+//! `Localizable.stringsdict`.
+//!
+//! Keys are source texts in namespaces of their prefixes like
+//! `pua.Hello, {}`, and plural forms are in stringsdict files.
+
+use std::collections::{BTreeMap, HashMap};
+
+use super::{escape_xml, printf_count, printf_format, split_key, PluralCategory, Translation};
+
+/// Returns translations of `translations` sorted by their Apple keys, with
+/// their source texts, keys of more than one text are rejected.
+fn entries(
+    translations: &HashMap<String, Translation>,
+) -> crate::Result<BTreeMap<String, (&str, &Translation)>> {
+    // Apple key => (key, entry)
+    let mut ret = BTreeMap::<String, (&str, (&str, &Translation))>::new();
+
+    for (key, translation) in translations {
+        let (prefix, content) = split_key(key)?;
+        let apple_key = prefix
+            .split('.')
+            .filter(|part| !part.is_empty())
+            .chain([content])
+            .collect::<Vec<_>>()
+            .join(".");
+
+        if let Some((other, _)) = ret.get(&apple_key) {
+            let (a, b) = match *other < key.as_str() {
+                true => (*other, key.as_str()),
+                false => (key.as_str(), *other),
+            };
+
+            return Err(format!("`{}` and `{}` have the same key `{}`", a, b, apple_key).into());
+        }
+
+        ret.insert(apple_key, (key.as_str(), (content, translation)));
+    }
+
+    Ok(ret.into_iter().map(|(k, (_, entry))| (k, entry)).collect())
+}
+
+fn escape_strings(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Generates a `Localizable.strings` file of texts of `translations`, where
+/// placeholders are rewritten to format specifiers like `%1$@`.
+pub fn generate_apple_strings(translations: &HashMap<String, Translation>) -> crate::Result<String> {
+    let mut document = String::new();
+
+    for (key, (content, translation)) in entries(translations)? {
+        if let Translation::Text(text) = translation {
+            document.push_str(&format!(
+                "\"{}\" = \"{}\";\n",
+                escape_strings(&key),
+                escape_strings(&printf_format(content, text, false, "@"))
+            ));
+        }
+    }
+
+    Ok(document)
+}
+
+/// Generates a `Localizable.stringsdict` file of plural forms of
+/// `translations`, where the count is the variable `count` of its positional
+/// argument.
+pub fn generate_apple_stringsdict(
+    translations: &HashMap<String, Translation>,
+) -> crate::Result<String> {
+    let mut document = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <!DOCTYPE plist PUBLIC \"-//Apple//DTD PLIST 1.0//EN\" \
+         \"http://www.apple.com/DTDs/PropertyList-1.0.dtd\">\n\
+         <plist version=\"1.0\">\n\
+         <dict>\n",
+    );
+    let mut line = |depth: usize, line: &str| {
+        document.push_str(&"  ".repeat(depth));
+        document.push_str(line);
+        document.push('\n');
+    };
+
+    for (key, (content, translation)) in entries(translations)? {
+        let Translation::Plural(forms) = translation else {
+            continue;
+        };
+
+        line(1, &format!("<key>{}</key>", escape_xml(&key)));
+        line(1, "<dict>");
+        line(2, "<key>NSStringLocalizedFormatKey</key>");
+        line(
+            2,
+            &format!("<string>%{}$#@count@</string>", printf_count(content)),
+        );
+        line(2, "<key>count</key>");
+        line(2, "<dict>");
+        line(3, "<key>NSStringFormatSpecTypeKey</key>");
+        line(3, "<string>NSStringPluralRuleType</string>");
+        line(3, "<key>NSStringFormatValueTypeKey</key>");
+        line(3, "<string>d</string>");
+
+        for (category, form) in forms {
+            line(3, &format!("<key>{}</key>", category));
+            line(
+                3,
+                &format!(
+                    "<string>{}</string>",
+                    escape_xml(&printf_format(content, form, true, "@"))
+                ),
+            );
+        }
+
+        // `other` is required
+        if !forms.contains_key(&PluralCategory::Other) {
+            line(3, "<key>other</key>");
+            line(3, "<string></string>");
+        }
+
+        line(2, "</dict>");
+        line(1, "</dict>");
+    }
+
+    document.push_str("</dict>\n</plist>\n");
+
+    Ok(document)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::translation::{fixture, PluralCategory, Translation};
+
+    #[test]
+    fn generate_apple_strings_test() {
+        assert_eq!(
+            super::generate_apple_strings(&fixture()).unwrap(),
+            "\"Hello, {}\" = \"你好，\\\"%1$@\\\"\";\n\
+             \"pua.Hello, {}\" = \"要到年底了，%1$@\";\n"
+        );
+    }
+
+    #[test]
+    fn generate_apple_stringsdict_test() {
+        let mut translations = fixture();
+        translations.insert(
+            " {name} sent {} messages".to_string(),
+            Translation::Plural(
+                [(PluralCategory::Other, "{} 条来自 {name} 的消息".to_string())]
+                    .into_iter()
+                    .collect(),
+            ),
+        );
+        let stringsdict = super::generate_apple_stringsdict(&translations).unwrap();
+
+        assert!(stringsdict.contains(
+            "  <key>{} files</key>\n  <dict>\n    <key>NSStringLocalizedFormatKey</key>\n    \
+             <string>%1$#@count@</string>\n"
+        ));
+        assert!(stringsdict.contains(
+            "      <key>one</key>\n      <string>%1$d файл</string>\n      \
+             <key>other</key>\n      <string>%1$d файлов</string>\n"
+        ));
+        assert!(stringsdict.contains(
+            "  <key>{name} sent {} messages</key>\n  <dict>\n    \
+             <key>NSStringLocalizedFormatKey</key>\n    <string>%2$#@count@</string>\n"
+        ));
+        assert!(stringsdict.contains("<string>%2$d 条来自 %1$@ 的消息</string>"));
+        assert!(!stringsdict.contains("Hello"));
+        assert!(crate::translation::parse_xml(&stringsdict).is_ok());
+    }
+
+    #[test]
+    fn entries_test() {
+        let conflict = [
+            (" pua.Hello, {}".to_string(), "你好".into()),
+            (".pua Hello, {}".to_string(), "要到年底了".into()),
+        ]
+        .into_iter()
+        .collect::<HashMap<String, Translation>>();
+
+        assert_eq!(
+            "` pua.Hello, {}` and `.pua Hello, {}` have the same key `pua.Hello, {}`",
+            super::generate_apple_strings(&conflict)
+                .unwrap_err()
+                .to_string()
+        );
+        assert!(super::generate_apple_stringsdict(&conflict).is_err());
+    }
+}
@@ -0,0 +1,401 @@
+//! Flutter ARB (Application Resource Bundle) translation files.
+//!
+//! Messages are named by Dart identifiers of their keys like `puaHello`, with
+//! the source text as `x-source` and the prefix as `context` of their
+//! metadata. Messages without `x-source` are named by their contents. Plural
+//! forms are a `plural` argument of the count, where `#` is the count.
+//! The metadata of messages is ignored by translating.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use serde_json::{Map, Value};
+
+use super::{
+    closing, generate_json, identifier, parse_json, split_key, Comments, PluralCategory, Translation,
+};
+
+/// Metadata of an ARB message, which is the `@<name>` entry beside it.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ArbMetadata(Map<String, Value>);
+
+impl ArbMetadata {
+    /// Returns the description of the message.
+    pub fn description(&self) -> Option<&str> {
+        self.0.get("description")?.as_str()
+    }
+
+    /// Returns the names of the declared placeholders of the message.
+    pub fn placeholders(&self) -> Vec<&str> {
+        self.0
+            .get("placeholders")
+            .and_then(|p| p.as_object())
+            .map(|p| p.keys().map(|k| k.as_str()).collect())
+            .unwrap_or_default()
+    }
+}
+
+/// An ARB message to generate.
+pub struct ArbMessage<'a> {
+    /// Key of the message, like `".pua Hello, {}"`.
+    pub key: &'a str,
+    /// Named placeholders of the message, which are declared in the metadata
+    /// if they are not.
+    pub placeholders: Vec<&'a str>,
+    pub metadata: Option<&'a ArbMetadata>,
+    /// The message is skipped if it is not translated.
+    pub translation: Option<&'a Translation>,
+}
+
+fn parse_arb(source: &str) -> crate::Result<Map<String, Value>> {
+    match parse_json(source)?.0 {
+        Value::Object(object) => Ok(object),
+        _ => Err("an ARB file must be an object".into()),
+    }
+}
+
+/// Returns the translation key of the message `name` with `metadata`.
+fn key_of(name: &str, metadata: Option<&Value>) -> String {
+    let field = |field| {
+        metadata
+            .and_then(|m| m.get(field))
+            .and_then(|c| c.as_str())
+            .filter(|c| !c.is_empty())
+    };
+
+    match (field("context"), field("x-source")) {
+        (Some(context), Some(source)) => format!(".{} {}", context, source),
+        (None, Some(source)) => format!(" {}", source),
+        (Some(context), None) => {
+            let content = name
+                .strip_prefix(context)
+                .and_then(|n| n.strip_prefix('.'))
+                .unwrap_or(name);
+
+            format!(".{} {}", context, content)
+        }
+        (None, None) => format!(" {}", name),
+    }
+}
+
+/// Returns the Dart identifier naming the message of a key, like `puaHello`
+/// of `".pua Hello, {}"`.
+fn message_name(prefix: &str, content: &str) -> String {
+    let name = prefix
+        .split('.')
+        .chain([content])
+        .flat_map(|part| identifier(part).split('_').map(str::to_string).collect::<Vec<_>>())
+        .filter(|word| !word.is_empty())
+        .enumerate()
+        .map(|(i, word)| match i {
+            0 => word,
+            _ => word[..1].to_uppercase() + &word[1..],
+        })
+        .collect::<String>();
+
+    match name.starts_with(|c: char| c.is_ascii_lowercase()) {
+        true => name,
+        false => format!("message{}", name),
+    }
+}
+
+/// Returns plural forms of a message which is only a `plural` argument of
+/// CLDR plural categories, like `{count, plural, one {# file} other {# files}}`.
+fn plural_forms(message: &str) -> Option<BTreeMap<PluralCategory, String>> {
+    let argument = message.strip_prefix('{')?;
+    let end = closing(argument).filter(|end| end + 1 == argument.len())?;
+
+    let mut parts = argument[..end].splitn(3, ',').skip(1);
+    let (kind, mut options) = (parts.next()?, parts.next()?);
+    if kind.trim() != "plural" {
+        return None;
+    }
+
+    let mut forms = BTreeMap::new();
+
+    loop {
+        options = options.trim_start();
+        if options.is_empty() {
+            break;
+        }
+
+        let i = options.find(|c: char| c.is_whitespace() || c == '{')?;
+        let (selector, rest) = options.split_at(i);
+        let form = rest.trim_start().strip_prefix('{')?;
+        let end = closing(form)?;
+
+        forms.insert(selector.parse().ok()?, form[..end].replace('#', "{}"));
+        options = &form[end + 1..];
+    }
+
+    forms.contains_key(&PluralCategory::Other).then_some(forms)
+}
+
+pub(crate) fn extract_arb(source: &str) -> crate::Result<HashMap<String, Translation>> {
+    let object = parse_arb(source)?;
+    let mut ret = HashMap::new();
+
+    for (name, value) in object.iter().filter(|(name, _)| !name.starts_with('@')) {
+        let text = value
+            .as_str()
+            .ok_or_else(|| format!("message `{}` is not a string", name))?;
+        let translation = match plural_forms(text) {
+            Some(forms) => Translation::Plural(forms),
+            None => text.to_string().into(),
+        };
+
+        ret.insert(key_of(name, object.get(&format!("@{}", name))), translation);
+    }
+
+    Ok(ret)
+}
+
+/// Returns the locale of an ARB file declared by `@@locale`.
+pub fn arb_locale(source: &str) -> Option<String> {
+    parse_arb(source)
+        .ok()?
+        .get("@@locale")?
+        .as_str()
+        .map(|l| l.to_string())
+}
+
+/// Returns the metadata of messages in an ARB file, keyed by their
+/// translation keys.
+pub fn arb_metadata(source: &str) -> crate::Result<HashMap<String, ArbMetadata>> {
+    let object = parse_arb(source)?;
+
+    Ok(object
+        .iter()
+        .filter(|(name, _)| !name.starts_with('@'))
+        .filter_map(|(name, _)| {
+            let metadata = object.get(&format!("@{}", name))?;
+
+            Some((
+                key_of(name, Some(metadata)),
+                ArbMetadata(metadata.as_object()?.clone()),
+            ))
+        })
+        .collect())
+}
+
+/// Returns an ICU MessageFormat message of `translation`, the count of plural
+/// forms is named `count`.
+fn message_of(translation: &Translation) -> String {
+    match translation {
+        Translation::Text(text) => text.clone(),
+        Translation::Plural(forms) => {
+            // `{}` of plural forms is the count
+            let forms = forms
+                .iter()
+                .map(|(category, form)| format!("{} {{{}}}", category, form.replace("{}", "#")))
+                .collect::<Vec<_>>();
+
+            format!("{{count, plural, {}}}", forms.join(" "))
+        }
+    }
+}
+
+/// Generates an ARB file of `locale`, which is declared by `@@locale`.
+pub fn generate_arb(locale: Option<&str>, messages: &[ArbMessage]) -> crate::Result<String> {
+    let mut entries = Vec::new();
+
+    if let Some(locale) = locale {
+        entries.push(("@@locale".to_string(), Value::String(locale.to_string())));
+    }
+
+    let mut names = HashSet::new();
+
+    for message in messages {
+        let Some(translation) = message.translation else {
+            continue;
+        };
+
+        let (prefix, content) = split_key(message.key)?;
+
+        // messages with the same identifier are numbered
+        let name = message_name(prefix, content);
+        let name = (1..)
+            .map(|i| match i {
+                1 => name.clone(),
+                i => format!("{}{}", name, i),
+            })
+            .find(|name| names.insert(name.clone()))
+            .unwrap_or(name);
+
+        let mut metadata = message.metadata.cloned().unwrap_or_default().0;
+
+        metadata.remove("x-source");
+        if name != content {
+            metadata.insert("x-source".to_string(), Value::String(content.to_string()));
+        }
+        if !prefix.is_empty() {
+            metadata.insert("context".to_string(), Value::String(prefix[1..].to_string()));
+        }
+        if !message.placeholders.is_empty() {
+            let placeholders = metadata
+                .entry("placeholders")
+                .or_insert_with(|| Value::Object(Map::new()));
+
+            if let Some(placeholders) = placeholders.as_object_mut() {
+                for placeholder in &message.placeholders {
+                    placeholders
+                        .entry(placeholder.to_string())
+                        .or_insert_with(|| Value::Object(Map::new()));
+                }
+            }
+        }
+
+        entries.push((name.clone(), Value::String(message_of(translation))));
+        if !metadata.is_empty() {
+            entries.push((format!("@{}", name), Value::Object(metadata)));
+        }
+    }
+
+    // entries are written in order, with messages followed by their metadata
+    let mut document = String::from("{");
+
+    for (i, (name, value)) in entries.iter().enumerate() {
+        let value = generate_json(value, &Comments::default()).replace('\n', "\n  ");
+
+        document.push_str(&format!("\n  {}: {}", Value::String(name.clone()), value));
+        if i + 1 < entries.len() {
+            document.push(',');
+        }
+    }
+
+    document.push_str("\n}");
+    Ok(document)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::translation::{fixture, Translation};
+
+    const ARB: &str = r#"{
+  "@@locale": "zh-CN",
+  "Hello, {name}": "你好，{name}",
+  "@Hello, {name}": {
+    "description": "Greeting on the home page",
+    "placeholders": {
+      "name": { "example": "ho-229" }
+    }
+  },
+  "pua.Hello, {}": "要到年底了",
+  "@pua.Hello, {}": { "context": "pua" },
+  "emails": "{count, plural, =0 {没有邮件} other {{count} 封邮件}}"
+}"#;
+
+    #[test]
+    fn extract_arb_test() {
+        assert_eq!(
+            super::extract_arb(ARB).unwrap(),
+            [
+                (" Hello, {name}", "你好，{name}"),
+                (".pua Hello, {}", "要到年底了"),
+                (" emails", "{count, plural, =0 {没有邮件} other {{count} 封邮件}}"),
+            ]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v.into()))
+            .collect::<HashMap<String, Translation>>()
+        );
+
+        assert_eq!(Some("zh-CN".to_string()), super::arb_locale(ARB));
+
+        let metadata = super::arb_metadata(ARB).unwrap();
+        let hello = &metadata[" Hello, {name}"];
+        assert_eq!(Some("Greeting on the home page"), hello.description());
+        assert_eq!(vec!["name"], hello.placeholders());
+        assert!(metadata.contains_key(".pua Hello, {}"));
+        assert!(!metadata.contains_key(" emails"));
+    }
+
+    #[test]
+    fn generate_arb_test() {
+        let metadata = super::arb_metadata(ARB).unwrap();
+        let translations = fixture();
+        let hello = "你好，{name}".into();
+        let pua = &translations[".pua Hello, {}"];
+        let files = &translations[" {} files"];
+
+        let messages = [
+            super::ArbMessage {
+                key: " Hello, {name}",
+                placeholders: vec!["name"],
+                metadata: metadata.get(" Hello, {name}"),
+                translation: Some(&hello),
+            },
+            super::ArbMessage {
+                key: " Goodbye, {name}",
+                placeholders: vec!["name"],
+                metadata: None,
+                translation: None,
+            },
+            super::ArbMessage {
+                key: ".pua Hello, {}",
+                placeholders: vec![],
+                metadata: None,
+                translation: Some(pua),
+            },
+            super::ArbMessage {
+                key: " {} files",
+                placeholders: vec![],
+                metadata: None,
+                translation: Some(files),
+            },
+        ];
+
+        let arb = super::generate_arb(Some("zh-CN"), &messages).unwrap();
+        assert_eq!(
+            arb,
+            r#"{
+  "@@locale": "zh-CN",
+  "helloName": "你好，{name}",
+  "@helloName": {
+    "description": "Greeting on the home page",
+    "placeholders": {
+      "name": {
+        "example": "ho-229"
+      }
+    },
+    "x-source": "Hello, {name}"
+  },
+  "puaHello": "要到年底了，{}",
+  "@puaHello": {
+    "context": "pua",
+    "x-source": "Hello, {}"
+  },
+  "files": "{count, plural, one {# файл} other {# файлов}}",
+  "@files": {
+    "x-source": "{} files"
+  }
+}"#
+        );
+
+        let extracted = super::extract_arb(&arb).unwrap();
+        assert_eq!(Some(&hello), extracted.get(" Hello, {name}"));
+        assert_eq!(Some(pua), extracted.get(".pua Hello, {}"));
+        assert_eq!(Some(files), extracted.get(" {} files"));
+    }
+
+    #[test]
+    fn message_name_test() {
+        assert_eq!("hello", super::message_name("", "Hello, {}"));
+        assert_eq!("puaHello", super::message_name(".pua", "Hello, {}"));
+        assert_eq!("aBSent1AMessage", super::message_name(".a.b", "sent {1} a message"));
+        assert_eq!("message0Sent", super::message_name("", "{0} sent"));
+        assert_eq!("u4f60U597dName", super::message_name("", "你好，{name}"));
+
+        let (hello, hello2) = ("你好".into(), "你好！".into());
+        let messages = [(" Hello", &hello), (" hello!", &hello2)].map(|(key, translation)| {
+            super::ArbMessage {
+                key,
+                placeholders: vec![],
+                metadata: None,
+                translation: Some(translation),
+            }
+        });
+        let extracted = super::extract_arb(&super::generate_arb(None, &messages).unwrap()).unwrap();
+        assert_eq!(Some(&hello2), extracted.get(" hello!"));
+    }
+}
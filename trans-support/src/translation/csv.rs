@@ -0,0 +1,100 @@
+//! CSV files of RFC 4180 for spreadsheets.
+
+/// Generates a CSV file of `rows`, fields are quoted if needed.
+pub fn generate_csv(rows: &[Vec<String>]) -> String {
+    let mut document = String::new();
+
+    for row in rows {
+        let fields = row
+            .iter()
+            .map(|field| match field.contains([',', '"', '\n', '\r']) {
+                true => format!("\"{}\"", field.replace('"', "\"\"")),
+                false => field.clone(),
+            })
+            .collect::<Vec<_>>();
+
+        document.push_str(&fields.join(","));
+        document.push_str("\r\n");
+    }
+
+    document
+}
+
+/// Parses rows of a CSV file, empty lines are skipped.
+pub fn parse_csv(source: &str) -> crate::Result<Vec<Vec<String>>> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut chars = source.trim_start_matches('\u{feff}').chars().peekable();
+    let mut line = 1;
+
+    loop {
+        match chars.next() {
+            Some('"') if field.is_empty() => loop {
+                match chars.next() {
+                    Some('"') if chars.peek() == Some(&'"') => {
+                        chars.next();
+                        field.push('"');
+                    }
+                    Some('"') => break,
+                    Some(c) => {
+                        if c == '\n' {
+                            line += 1;
+                        }
+                        field.push(c);
+                    }
+                    None => return Err(format!("unterminated quoted field at line {}", line).into()),
+                }
+            },
+            Some(',') => row.push(std::mem::take(&mut field)),
+            Some('\r') if chars.peek() == Some(&'\n') => {}
+            c @ (Some('\n') | None) => {
+                if !row.is_empty() || !field.is_empty() {
+                    row.push(std::mem::take(&mut field));
+                    rows.push(std::mem::take(&mut row));
+                }
+
+                match c {
+                    Some(_) => line += 1,
+                    None => return Ok(rows),
+                }
+            }
+            Some(c) => field.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn csv_test() {
+        let rows = vec![
+            vec!["prefix".to_string(), "source".to_string(), "zh-CN".to_string()],
+            vec![
+                "pua".to_string(),
+                "Hello, {}".to_string(),
+                "你好，\"{}\"".to_string(),
+            ],
+            vec![
+                String::new(),
+                "{} files".to_string(),
+                "one: {} 个文件\nother: {} 个文件".to_string(),
+            ],
+        ];
+
+        let csv = super::generate_csv(&rows);
+        assert_eq!(
+            csv,
+            "prefix,source,zh-CN\r\n\
+             pua,\"Hello, {}\",\"你好，\"\"{}\"\"\"\r\n\
+             ,{} files,\"one: {} 个文件\nother: {} 个文件\"\r\n"
+        );
+        assert_eq!(rows, super::parse_csv(&csv).unwrap());
+
+        assert_eq!(
+            vec![vec!["a".to_string(), String::new()], vec!["b".to_string()]],
+            super::parse_csv("a,\n\nb").unwrap()
+        );
+        assert!(super::parse_csv("\"a").is_err());
+    }
+}
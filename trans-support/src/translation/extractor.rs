@@ -1,39 +1,79 @@
-use std::{collections::HashMap, fs::File, io::Read, path::Path};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs,
+    path::Path,
+};
 
 use serde_json::Value;
 
-pub fn extract(path: impl AsRef<Path>) -> crate::Result<HashMap<String, String>> {
-    let mut content = String::new();
+use super::{
+    extract_arb, extract_fluent, extract_mo, extract_po, parse_json, parse_toml, parse_yaml,
+    PluralCategory, Translation,
+};
 
-    File::open(path.as_ref())?.read_to_string(&mut content)?;
+pub fn extract(path: impl AsRef<Path>) -> crate::Result<HashMap<String, Translation>> {
+    let content = fs::read(path.as_ref())?;
 
-    let root = serde_json::from_str::<Value>(&content)?;
+    let translations = match path.as_ref().extension().and_then(|ext| ext.to_str()) {
+        Some("mo") => extract_mo(&content)?,
+        Some("po") => extract_po(std::str::from_utf8(&content)?)?,
+        Some("arb") => extract_arb(std::str::from_utf8(&content)?)?,
+        Some("ftl") => extract_fluent(std::str::from_utf8(&content)?)?,
+        Some("yaml" | "yml") => {
+            extract_value(String::new(), parse_yaml(std::str::from_utf8(&content)?)?)
+        }
+        Some("toml") => extract_value(String::new(), parse_toml(std::str::from_utf8(&content)?)?),
+        _ => extract_value(String::new(), parse_json(std::str::from_utf8(&content)?)?.0),
+    };
+
+    for (key, translation) in &translations {
+        translation
+            .check()
+            .map_err(|e| format!("invalid translation of `{}`: {}", key.trim(), e))?;
+    }
+
+    Ok(translations)
+}
+
+/// Returns plural forms if `value` is an object whose keys are all plural
+/// categories including `other`, and whose values are all strings.
+fn plural_forms(value: &Value) -> Option<BTreeMap<PluralCategory, String>> {
+    let forms = value
+        .as_object()?
+        .iter()
+        .map(|(k, v)| Some((k.parse().ok()?, v.as_str()?.to_string())))
+        .collect::<Option<BTreeMap<_, _>>>()?;
 
-    Ok(extract_value(String::new(), root))
+    forms.contains_key(&PluralCategory::Other).then_some(forms)
 }
 
-fn extract_value(prefix: String, object: Value) -> HashMap<String, String> {
+fn extract_value(prefix: String, object: Value) -> HashMap<String, Translation> {
     let mut ret = HashMap::new();
 
     macro_rules! gen_prefix {
         ($prefix:expr, $current:expr, $value:expr) => {
-            match $value.is_object() || $value.is_array() {
+            match ($value.is_object() || $value.is_array()) && plural_forms(&$value).is_none() {
                 true => format!("{}.{}", $prefix, $current),
                 false => format!("{} {}", $prefix, $current),
             }
         };
     }
 
+    if let Some(forms) = plural_forms(&object) {
+        ret.insert(prefix, Translation::Plural(forms));
+        return ret;
+    }
+
     match object {
         Value::Null => {}
         Value::Bool(b) => {
-            ret.insert(prefix, b.to_string());
+            ret.insert(prefix, b.to_string().into());
         }
         Value::Number(n) => {
-            ret.insert(prefix, n.to_string());
+            ret.insert(prefix, n.to_string().into());
         }
         Value::String(s) => {
-            ret.insert(prefix, s);
+            ret.insert(prefix, s.into());
         }
         Value::Array(arr) => arr.into_iter().enumerate().for_each(|(i, v)| {
             ret.extend(extract_value(gen_prefix!(prefix, i, v), v));
@@ -48,6 +88,8 @@ fn extract_value(prefix: String, object: Value) -> HashMap<String, String> {
 
 #[cfg(test)]
 mod tests {
+    use crate::translation::{PluralCategory, Translation};
+
     #[test]
     fn extract_value_test() {
         let json = serde_json::json!({
@@ -68,7 +110,43 @@ mod tests {
                 (".evil {} is typing", "{} 正在女装")
             ]
             .into_iter()
-            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .map(|(k, v)| (k.to_string(), v.into()))
+            .collect()
+        )
+    }
+
+    #[test]
+    fn extract_plural_test() {
+        let json = serde_json::json!({
+            "{} files": {
+                "one": "{} файл",
+                "few": "{} файла",
+                "other": "{} файлов"
+            },
+            "other": {
+                "one": "{} other"
+            }
+        });
+
+        assert_eq!(
+            super::extract_value(String::new(), json),
+            [
+                (
+                    " {} files".to_string(),
+                    Translation::Plural(
+                        [
+                            (PluralCategory::One, "{} файл"),
+                            (PluralCategory::Few, "{} файла"),
+                            (PluralCategory::Other, "{} файлов")
+                        ]
+                        .into_iter()
+                        .map(|(c, v)| (c, v.to_string()))
+                        .collect()
+                    )
+                ),
+                (".other one".to_string(), "{} other".into())
+            ]
+            .into_iter()
             .collect()
         )
     }
@@ -0,0 +1,1067 @@
+//! [Project Fluent](https://projectfluent.org) catalogs.
+//!
+//! Messages are keyed by their identifiers without a prefix, and attributes
+//! are keyed by their names with the message as the prefix, e.g. `.title` of
+//! `login` is looked up by `tr!([".login"] "title")`. Terms and message
+//! references are inlined, variables become named placeholders, and selectors
+//! become ICU MessageFormat `plural` or `select` arguments.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    ops::Range,
+};
+
+use super::Translation;
+
+type Pattern = Vec<Element>;
+
+enum Element {
+    Text(String),
+    /// Indentation of a continuation line, the common indentation is removed
+    /// after parsing.
+    Indent(usize),
+    Placeable(Expression),
+}
+
+enum Expression {
+    String(String),
+    Number(String),
+    Variable(String),
+    Message {
+        id: String,
+        attribute: Option<String>,
+    },
+    Term {
+        id: String,
+        attribute: Option<String>,
+        args: Vec<(String, Expression)>,
+    },
+    Function {
+        args: Vec<Expression>,
+    },
+    Select {
+        selector: Box<Expression>,
+        variants: Vec<Variant>,
+    },
+}
+
+struct Variant {
+    key: String,
+    default: bool,
+    value: Pattern,
+}
+
+struct Entry {
+    id: String,
+    term: bool,
+    value: Option<Pattern>,
+    attributes: Vec<(String, Pattern)>,
+    /// Source of the entry with its comment.
+    span: Range<usize>,
+    /// Source of the value after `=`.
+    value_span: Range<usize>,
+    /// Sources of the attributes from the end of the previous line.
+    attribute_spans: Vec<Range<usize>>,
+}
+
+struct Parser<'s> {
+    src: &'s str,
+    pos: usize,
+}
+
+impl<'s> Parser<'s> {
+    fn peek(&self) -> Option<char> {
+        self.src[self.pos..].chars().next()
+    }
+
+    fn rest(&self) -> &'s str {
+        &self.src[self.pos..]
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        let matched = self.peek() == Some(c);
+        if matched {
+            self.pos += c.len_utf8();
+        }
+
+        matched
+    }
+
+    fn error(&self, message: &str) -> String {
+        let line = self.src[..self.pos].matches('\n').count() + 1;
+        format!("{} at line {}", message, line)
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), String> {
+        match self.eat(c) {
+            true => Ok(()),
+            false => Err(self.error(&format!("expected `{}`", c))),
+        }
+    }
+
+    fn skip_inline_blank(&mut self) {
+        while self.eat(' ') {}
+    }
+
+    fn skip_blank(&mut self) {
+        while self.eat(' ') || self.eat('\n') || self.eat('\r') {}
+    }
+
+    fn skip_line(&mut self) {
+        self.pos = self.rest().find('\n').map_or(self.src.len(), |i| self.pos + i + 1);
+    }
+
+    fn take_while(&mut self, f: impl Fn(char) -> bool) -> &'s str {
+        let rest = self.rest();
+        let end = rest.find(|c| !f(c)).unwrap_or(rest.len());
+        self.pos += end;
+
+        &rest[..end]
+    }
+
+    fn identifier(&mut self) -> Result<String, String> {
+        match self.peek() {
+            Some(c) if c.is_ascii_alphabetic() => Ok(self
+                .take_while(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+                .to_string()),
+            _ => Err(self.error("expected an identifier")),
+        }
+    }
+
+    fn resource(&mut self) -> Result<Vec<Entry>, String> {
+        let mut entries = Vec::new();
+        let mut comment = None;
+
+        while let Some(c) = self.peek() {
+            match c {
+                '\n' | '\r' => {
+                    self.pos += 1;
+                    comment = None;
+                }
+                '#' => {
+                    // group and resource comments are not attached to entries
+                    comment = match self.rest().starts_with("##") {
+                        true => None,
+                        false => comment.or(Some(self.pos)),
+                    };
+                    self.skip_line();
+                }
+                ' ' if self.rest().trim_start_matches(' ').starts_with(['\n', '\r']) => {
+                    self.skip_line();
+                }
+                _ => {
+                    let start = comment.take().unwrap_or(self.pos);
+                    let mut entry = self.entry()?;
+                    entry.span = start..self.pos;
+                    entries.push(entry);
+                }
+            }
+        }
+
+        Ok(entries)
+    }
+
+    fn entry(&mut self) -> Result<Entry, String> {
+        let term = self.eat('-');
+        let id = self.identifier()?;
+
+        self.skip_inline_blank();
+        self.expect('=')?;
+
+        let value_start = self.pos;
+        let value = self.pattern()?;
+        let value_span = value_start..self.pos;
+        let mut attributes = Vec::new();
+        let mut attribute_spans = Vec::new();
+
+        loop {
+            let start = self.pos;
+            self.skip_blank();
+
+            let indented = self.src[start..self.pos].ends_with(' ');
+            if !(indented && self.eat('.')) {
+                self.pos = start;
+                break;
+            }
+
+            let name = self.identifier()?;
+            self.skip_inline_blank();
+            self.expect('=')?;
+
+            match self.pattern()? {
+                Some(value) => attributes.push((name, value)),
+                None => return Err(self.error(&format!("missing value of `.{}`", name))),
+            }
+            attribute_spans.push(start..self.pos);
+        }
+
+        if value.is_none() && (term || attributes.is_empty()) {
+            return Err(self.error(&format!("missing value of `{}`", id)));
+        }
+
+        match self.peek() {
+            None => {}
+            Some('\n' | '\r') => self.skip_line(),
+            Some(_) => return Err(self.error("unexpected character")),
+        }
+
+        Ok(Entry {
+            id,
+            term,
+            value,
+            attributes,
+            span: 0..0,
+            value_span,
+            attribute_spans,
+        })
+    }
+
+    /// Parses a pattern until a line which is not its continuation, or a `}`.
+    fn pattern(&mut self) -> Result<Option<Pattern>, String> {
+        let mut pattern = Pattern::new();
+
+        self.skip_inline_blank();
+
+        loop {
+            match self.peek() {
+                None | Some('}') => break,
+                Some('{') => {
+                    self.pos += 1;
+                    pattern.push(Element::Placeable(self.placeable()?));
+                }
+                Some('\n' | '\r') => {
+                    let start = self.pos;
+                    let lines = self.take_while(|c| matches!(c, ' ' | '\n' | '\r'));
+                    let indent = lines.len() - lines.rfind('\n').map_or(0, |i| i + 1);
+                    let rest = self.rest();
+
+                    if indent == 0
+                        || rest.is_empty()
+                        || rest.starts_with(['[', '.', '}'])
+                        || rest.starts_with("*[")
+                    {
+                        self.pos = start;
+                        break;
+                    }
+
+                    // a pattern may start on the next line of `=`
+                    if !pattern.is_empty() {
+                        let newlines = lines.matches('\n').count();
+                        pattern.push(Element::Text("\n".repeat(newlines)));
+                    }
+
+                    pattern.push(Element::Indent(indent));
+                }
+                Some(_) => {
+                    let text = self.take_while(|c| !matches!(c, '{' | '}' | '\n' | '\r'));
+                    pattern.push(Element::Text(text.to_string()));
+                }
+            }
+        }
+
+        Ok(dedent(pattern))
+    }
+
+    /// Parses a placeable after its `{`.
+    fn placeable(&mut self) -> Result<Expression, String> {
+        self.skip_blank();
+
+        let expression = match self.eat('{') {
+            true => self.placeable()?,
+            false => self.inline_expression()?,
+        };
+
+        self.skip_blank();
+
+        if self.rest().starts_with("->") {
+            self.pos += 2;
+
+            let variants = self.variants()?;
+            self.skip_blank();
+            self.expect('}')?;
+
+            return Ok(Expression::Select {
+                selector: Box::new(expression),
+                variants,
+            });
+        }
+
+        self.expect('}')?;
+
+        Ok(expression)
+    }
+
+    fn inline_expression(&mut self) -> Result<Expression, String> {
+        let rest = self.rest();
+
+        match self.peek() {
+            Some('"') => {
+                self.pos += 1;
+                self.string()
+            }
+            Some('0'..='9') => Ok(Expression::Number(self.number())),
+            Some('-') if rest[1..].starts_with(|c: char| c.is_ascii_digit()) => {
+                Ok(Expression::Number(self.number()))
+            }
+            Some('$') => {
+                self.pos += 1;
+                Ok(Expression::Variable(self.identifier()?))
+            }
+            Some('-') => {
+                self.pos += 1;
+                let id = self.identifier()?;
+                let attribute = self.attribute_accessor()?;
+                let args = match self.peek() {
+                    Some('(') => self.call_arguments()?.1,
+                    _ => Vec::new(),
+                };
+
+                Ok(Expression::Term {
+                    id,
+                    attribute,
+                    args,
+                })
+            }
+            Some(_) => {
+                let id = self.identifier()?;
+
+                if self.peek() == Some('(') {
+                    return Ok(Expression::Function {
+                        args: self.call_arguments()?.0,
+                    });
+                }
+
+                Ok(Expression::Message {
+                    id,
+                    attribute: self.attribute_accessor()?,
+                })
+            }
+            None => Err(self.error("expected an expression")),
+        }
+    }
+
+    fn string(&mut self) -> Result<Expression, String> {
+        let mut ret = String::new();
+
+        loop {
+            match self.peek() {
+                Some('"') => {
+                    self.pos += 1;
+                    return Ok(Expression::String(ret));
+                }
+                Some('\\') => {
+                    self.pos += 1;
+
+                    match self.peek() {
+                        Some(c @ ('"' | '\\')) => {
+                            self.pos += 1;
+                            ret.push(c);
+                        }
+                        Some(c @ ('u' | 'U')) => {
+                            self.pos += 1;
+                            let len = if c == 'u' { 4 } else { 6 };
+                            let hex = self.rest().get(..len).unwrap_or_default();
+                            let c = u32::from_str_radix(hex, 16)
+                                .ok()
+                                .and_then(char::from_u32)
+                                .ok_or_else(|| self.error("invalid unicode escape"))?;
+
+                            self.pos += len;
+                            ret.push(c);
+                        }
+                        _ => return Err(self.error("invalid escape")),
+                    }
+                }
+                None | Some('\n' | '\r') => return Err(self.error("unterminated string")),
+                Some(c) => {
+                    self.pos += c.len_utf8();
+                    ret.push(c);
+                }
+            }
+        }
+    }
+
+    fn number(&mut self) -> String {
+        let start = self.pos;
+        self.eat('-');
+        self.take_while(|c| c.is_ascii_digit());
+
+        if self.eat('.') {
+            self.take_while(|c| c.is_ascii_digit());
+        }
+
+        self.src[start..self.pos].to_string()
+    }
+
+    fn attribute_accessor(&mut self) -> Result<Option<String>, String> {
+        match self.eat('.') {
+            true => self.identifier().map(Some),
+            false => Ok(None),
+        }
+    }
+
+    /// Parses positional and named arguments of a call.
+    #[allow(clippy::type_complexity)]
+    fn call_arguments(&mut self) -> Result<(Vec<Expression>, Vec<(String, Expression)>), String> {
+        let mut positional = Vec::new();
+        let mut named = Vec::new();
+
+        self.expect('(')?;
+
+        loop {
+            self.skip_blank();
+
+            if self.eat(')') {
+                break;
+            }
+
+            let argument = self.inline_expression()?;
+            self.skip_blank();
+
+            match argument {
+                Expression::Message {
+                    id,
+                    attribute: None,
+                } if self.eat(':') => {
+                    self.skip_blank();
+                    named.push((id, self.inline_expression()?));
+                    self.skip_blank();
+                }
+                argument => positional.push(argument),
+            }
+
+            if !self.eat(',') {
+                self.skip_blank();
+                self.expect(')')?;
+                break;
+            }
+        }
+
+        Ok((positional, named))
+    }
+
+    fn variants(&mut self) -> Result<Vec<Variant>, String> {
+        let mut variants = Vec::new();
+
+        loop {
+            self.skip_blank();
+
+            let default = self.eat('*');
+            if !self.eat('[') {
+                match default {
+                    true => return Err(self.error("expected `[`")),
+                    false => break,
+                }
+            }
+
+            self.skip_blank();
+            let key = self
+                .take_while(|c| !matches!(c, ' ' | ']' | '\n' | '\r'))
+                .to_string();
+            self.skip_blank();
+            self.expect(']')?;
+
+            variants.push(Variant {
+                key,
+                default,
+                value: self.pattern()?.unwrap_or_default(),
+            });
+        }
+
+        match variants.iter().filter(|v| v.default).count() {
+            1 => Ok(variants),
+            _ => Err(self.error("expected exactly one default variant")),
+        }
+    }
+}
+
+/// Removes the common indentation of continuation lines and trailing blanks.
+fn dedent(pattern: Pattern) -> Option<Pattern> {
+    let common = pattern
+        .iter()
+        .filter_map(|e| match e {
+            Element::Indent(indent) => Some(*indent),
+            _ => None,
+        })
+        .min()
+        .unwrap_or_default();
+
+    let mut pattern = pattern
+        .into_iter()
+        .map(|e| match e {
+            Element::Indent(indent) => Element::Text(" ".repeat(indent - common)),
+            e => e,
+        })
+        .collect::<Pattern>();
+
+    while let Some(Element::Text(text)) = pattern.last_mut() {
+        let trimmed = text.trim_end().len();
+        text.truncate(trimmed);
+
+        match text.is_empty() {
+            true => pattern.pop(),
+            false => break,
+        };
+    }
+
+    (!pattern.is_empty()).then_some(pattern)
+}
+
+const PLURAL_CATEGORIES: [&str; 6] = ["zero", "one", "two", "few", "many", "other"];
+
+/// Converts Fluent patterns into r18 templates.
+struct Converter<'r> {
+    messages: HashMap<&'r str, &'r Entry>,
+    terms: HashMap<&'r str, &'r Entry>,
+}
+
+/// The context of converting a pattern.
+#[derive(Clone, Copy)]
+struct Context<'a> {
+    /// Whether in a sub-message of an ICU argument.
+    nested: bool,
+    /// Arguments of the term being inlined.
+    term_args: Option<&'a HashMap<String, String>>,
+    depth: usize,
+}
+
+fn escape(text: &str, nested: bool) -> String {
+    let mut ret = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match (c, nested) {
+            ('{', false) => ret.push_str("{{"),
+            ('}', false) => ret.push_str("}}"),
+            ('\'', true) => ret.push_str("''"),
+            ('{' | '}' | '#', true) => {
+                ret.push('\'');
+                ret.push(c);
+                ret.push('\'');
+            }
+            _ => ret.push(c),
+        }
+    }
+
+    ret
+}
+
+impl<'r> Converter<'r> {
+    fn new(entries: &'r [Entry]) -> Self {
+        let mut messages = HashMap::new();
+        let mut terms = HashMap::new();
+
+        for entry in entries {
+            match entry.term {
+                true => terms.insert(entry.id.as_str(), entry),
+                false => messages.insert(entry.id.as_str(), entry),
+            };
+        }
+
+        Self { messages, terms }
+    }
+
+    fn pattern(&self, pattern: &Pattern, ctx: Context) -> Result<String, String> {
+        if ctx.depth > 16 {
+            return Err("too deep or cyclic references".into());
+        }
+
+        pattern
+            .iter()
+            .map(|element| match element {
+                Element::Text(text) => Ok(escape(text, ctx.nested)),
+                Element::Indent(_) => Ok(String::new()),
+                Element::Placeable(expression) => self.expression(expression, ctx),
+            })
+            .collect()
+    }
+
+    fn reference(
+        &self,
+        entries: &HashMap<&'r str, &'r Entry>,
+        id: &str,
+        attribute: Option<&String>,
+    ) -> Result<&'r Pattern, String> {
+        let entry = entries
+            .get(id)
+            .ok_or_else(|| format!("unknown reference `{}`", id))?;
+
+        match attribute {
+            Some(attribute) => entry
+                .attributes
+                .iter()
+                .find(|(name, _)| name == attribute)
+                .map(|(_, value)| value),
+            None => entry.value.as_ref(),
+        }
+        .ok_or_else(|| format!("unknown reference `{}.{}`", id, attribute.unwrap_or(&String::new())))
+    }
+
+    /// Returns the value of `expression` if it is known at compile time.
+    fn literal(&self, expression: &Expression, ctx: Context) -> Option<String> {
+        match expression {
+            Expression::String(s) | Expression::Number(s) => Some(s.clone()),
+            Expression::Variable(name) => ctx.term_args?.get(name).cloned(),
+            Expression::Term {
+                id,
+                attribute: Some(attribute),
+                ..
+            } => {
+                let pattern = self.reference(&self.terms, id, Some(attribute)).ok()?;
+
+                match pattern.as_slice() {
+                    [Element::Text(text)] => Some(text.clone()),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    fn expression(&self, expression: &Expression, ctx: Context) -> Result<String, String> {
+        let deeper = Context {
+            depth: ctx.depth + 1,
+            ..ctx
+        };
+
+        match expression {
+            Expression::String(s) | Expression::Number(s) => Ok(escape(s, ctx.nested)),
+            Expression::Variable(name) => match ctx.term_args {
+                Some(args) => Ok(escape(args.get(name).map_or("", |v| v.as_str()), ctx.nested)),
+                None => Ok(format!("{{{}}}", name.replace('-', "_"))),
+            },
+            Expression::Message { id, attribute } => self.pattern(
+                self.reference(&self.messages, id, attribute.as_ref())?,
+                Context {
+                    term_args: None,
+                    ..deeper
+                },
+            ),
+            Expression::Term {
+                id,
+                attribute,
+                args,
+            } => {
+                let args = args
+                    .iter()
+                    .filter_map(|(name, value)| Some((name.clone(), self.literal(value, ctx)?)))
+                    .collect();
+
+                self.pattern(
+                    self.reference(&self.terms, id, attribute.as_ref())?,
+                    Context {
+                        term_args: Some(&args),
+                        ..deeper
+                    },
+                )
+            }
+            Expression::Function { args } => match args.first() {
+                Some(arg) => self.expression(arg, ctx),
+                None => Err("missing argument of function".into()),
+            },
+            Expression::Select { selector, variants } => {
+                self.select(selector, variants, ctx, deeper)
+            }
+        }
+    }
+
+    fn select(
+        &self,
+        selector: &Expression,
+        variants: &[Variant],
+        ctx: Context,
+        deeper: Context,
+    ) -> Result<String, String> {
+        let default = variants.iter().find(|v| v.default).map(|v| &v.value);
+        let default = default.ok_or("missing default variant")?;
+
+        if let Some(value) = self.literal(selector, ctx) {
+            let variant = variants
+                .iter()
+                .find(|v| v.key == value)
+                .map_or(default, |v| &v.value);
+
+            return self.pattern(variant, deeper);
+        }
+
+        // variables of terms are only known at compile time
+        if ctx.term_args.is_some() {
+            return self.pattern(default, deeper);
+        }
+
+        let name = match selector {
+            Expression::Variable(name) => name,
+            Expression::Function { args } => match args.first() {
+                Some(Expression::Variable(name)) => name,
+                _ => return Err("unsupported selector".into()),
+            },
+            _ => return Err("unsupported selector".into()),
+        };
+
+        let plural = variants.iter().all(|v| {
+            PLURAL_CATEGORIES.contains(&v.key.as_str()) || v.key.parse::<f64>().is_ok()
+        });
+        let nested = Context {
+            nested: true,
+            ..deeper
+        };
+
+        let mut options = BTreeMap::new();
+        let mut ret = format!(
+            "{{{}, {},",
+            name.replace('-', "_"),
+            if plural { "plural" } else { "select" }
+        );
+
+        for variant in variants {
+            let key = match plural && variant.key.parse::<f64>().is_ok() {
+                true => format!("={}", variant.key),
+                false => variant.key.clone(),
+            };
+
+            options.insert(key, self.pattern(&variant.value, nested)?);
+        }
+
+        if !options.contains_key("other") {
+            options.insert("other".into(), self.pattern(default, nested)?);
+        }
+
+        for (key, message) in options {
+            ret.push_str(&format!(" {} {{{}}}", key, message));
+        }
+
+        ret.push('}');
+
+        Ok(ret)
+    }
+}
+
+fn parse(source: &str) -> Result<Vec<Entry>, String> {
+    Parser { src: source, pos: 0 }.resource()
+}
+
+/// Extracts translations from the source of a `.ftl` file.
+pub(crate) fn extract_fluent(source: &str) -> crate::Result<HashMap<String, Translation>> {
+    let entries = parse(source)?;
+    let converter = Converter::new(&entries);
+    let ctx = Context {
+        nested: false,
+        term_args: None,
+        depth: 0,
+    };
+
+    let mut ret = HashMap::new();
+
+    for entry in entries.iter().filter(|e| !e.term) {
+        let error = |e: String| format!("invalid message `{}`: {}", entry.id, e);
+
+        if let Some(value) = &entry.value {
+            let value = converter.pattern(value, ctx).map_err(error)?;
+            ret.insert(format!(" {}", entry.id), value.into());
+        }
+
+        for (name, value) in &entry.attributes {
+            let value = converter.pattern(value, ctx).map_err(error)?;
+            ret.insert(format!(".{} {}", entry.id, name), value.into());
+        }
+    }
+
+    Ok(ret)
+}
+
+/// Converts a template into a Fluent pattern, placeholders become variables.
+fn fluent_pattern(template: &str) -> String {
+    let mut ret = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(i) = rest.find(['{', '}']) {
+        ret.push_str(&rest[..i]);
+
+        let brace = &rest[i..i + 1];
+        let tail = &rest[i + 1..];
+
+        if let Some(tail) = tail.strip_prefix(brace) {
+            ret.push_str(&format!("{{ \"{}\" }}", brace));
+            rest = tail;
+            continue;
+        }
+
+        match tail.find('}').filter(|_| brace == "{") {
+            Some(end) => {
+                let name = tail[..end].split(':').next().unwrap_or_default();
+                ret.push_str(&format!("{{ ${} }}", name));
+                rest = &tail[end + 1..];
+            }
+            None => {
+                ret.push_str(&format!("{{ \"{}\" }}", brace));
+                rest = tail;
+            }
+        }
+    }
+
+    ret.push_str(rest);
+    ret.replace('\n', "\n    ")
+}
+
+/// Converts a translation into a Fluent pattern, plural forms are not
+/// supported and the last one is used.
+fn fluent_translation(translation: Translation) -> String {
+    let text = match translation {
+        Translation::Text(text) => text,
+        Translation::Plural(mut forms) => forms.pop_last().map(|(_, v)| v).unwrap_or_default(),
+    };
+
+    fluent_pattern(&text)
+}
+
+/// Returns the source of `entry`, whose value and attributes are replaced by
+/// the `changed` patterns of their keys.
+fn rewrite_entry(source: &str, entry: &Entry, changed: &HashMap<String, String>) -> String {
+    let mut ret = source[entry.span.start..entry.value_span.start].to_string();
+
+    match changed.get(&format!(" {}", entry.id)) {
+        Some(value) => ret.push_str(&format!(" {}", value)),
+        None => ret.push_str(&source[entry.value_span.clone()]),
+    }
+
+    for ((name, _), span) in entry.attributes.iter().zip(&entry.attribute_spans) {
+        match changed.get(&format!(".{} {}", entry.id, name)) {
+            Some(value) => ret.push_str(&format!("\n    .{} = {}", name, value)),
+            None => ret.push_str(&source[span.clone()]),
+        }
+    }
+
+    ret
+}
+
+fn is_identifier(id: &str) -> bool {
+    id.starts_with(|c: char| c.is_ascii_alphabetic())
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+/// Generates a `.ftl` document of `translation`, entries of `source` are kept
+/// if any of their keys are in `translation`, where values and attributes are
+/// rewritten only if their translations are changed, and the others are
+/// appended.
+pub(crate) fn generate_fluent(
+    source: &str,
+    mut translation: HashMap<String, Translation>,
+) -> crate::Result<String> {
+    let entries = parse(source)?;
+    let extracted = extract_fluent(source)?;
+    let mut document = String::new();
+
+    // message => (value, attributes)
+    let mut appended = BTreeMap::<String, (Option<String>, BTreeMap<String, String>)>::new();
+    let mut invalid = Vec::new();
+
+    // messages whose value or attributes are still translated, with the
+    // patterns of changed ones
+    let mut kept = HashMap::<&str, HashMap<String, String>>::new();
+
+    for entry in entries.iter().filter(|e| !e.term) {
+        let keys = entry
+            .value
+            .iter()
+            .map(|_| format!(" {}", entry.id))
+            .chain(
+                (entry.attributes.iter()).map(|(name, _)| format!(".{} {}", entry.id, name)),
+            );
+
+        for key in keys {
+            let Some(value) = translation.remove(&key) else {
+                continue;
+            };
+            let changed = kept.entry(entry.id.as_str()).or_default();
+
+            if extracted.get(&key) != Some(&value) {
+                changed.insert(key, fluent_translation(value));
+            }
+        }
+    }
+
+    for (key, value) in translation {
+        let text = fluent_translation(value);
+
+        match key.split_once(' ') {
+            Some(("", id)) if is_identifier(id) => appended.entry(id.to_string()).or_default().0 = Some(text),
+            Some((prefix, name)) if is_identifier(name) && is_identifier(&prefix[1..]) => {
+                appended
+                    .entry(prefix[1..].to_string())
+                    .or_default()
+                    .1
+                    .insert(name.to_string(), text);
+            }
+            _ => invalid.push(key),
+        }
+    }
+
+    let write_attributes = |document: &mut String, attributes: &BTreeMap<String, String>| {
+        for (name, value) in attributes {
+            document.push_str(&format!("    .{} = {}\n", name, value));
+        }
+    };
+
+    for entry in &entries {
+        let changed = match entry.term {
+            true => None,
+            false => match kept.get(entry.id.as_str()) {
+                Some(changed) => Some(changed),
+                None => continue,
+            },
+        };
+
+        if !document.is_empty() {
+            document.push('\n');
+        }
+
+        match changed.filter(|changed| !changed.is_empty()) {
+            Some(changed) => document.push_str(rewrite_entry(source, entry, changed).trim_end()),
+            None => document.push_str(source[entry.span.clone()].trim_end()),
+        }
+        document.push('\n');
+
+        if let Some((None, attributes)) = appended.get(&entry.id).filter(|_| !entry.term) {
+            write_attributes(&mut document, attributes);
+            appended.remove(&entry.id);
+        }
+    }
+
+    for (id, (value, attributes)) in appended {
+        if !document.is_empty() {
+            document.push('\n');
+        }
+
+        document.push_str(&format!("{} = {}\n", id, value.unwrap_or_default()));
+        write_attributes(&mut document, &attributes);
+    }
+
+    if !invalid.is_empty() {
+        invalid.sort();
+        document.push_str("\n# Keys below are not valid Fluent identifiers:\n");
+
+        for key in invalid {
+            document.push_str(&format!("# {}\n", key.trim()));
+        }
+    }
+
+    Ok(document)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::translation::Translation;
+
+    const SOURCE: &str = r#"## Login
+
+-brand = { $case ->
+    [genitive] r18's
+   *[nominative] r18
+}
+
+# A greeting
+hello = Hello, { $user-name }!
+    Welcome to { -brand(case: "genitive") } { "{" }site{ "}" }.
+
+emails = { $count ->
+        [0] No e-mails
+        [one] { $count } e-mail from { hello }
+       *[other] { $count } e-mails {"{"}'#'{"}"}
+    }
+
+login =
+    .title = { -brand } login
+gender = { $gender ->
+    [female] She
+   *[male] He
+}
+"#;
+
+    #[test]
+    fn extract_fluent_test() {
+        assert_eq!(
+            super::extract_fluent(SOURCE).unwrap(),
+            [
+                (" hello", "Hello, {user_name}!\nWelcome to r18's {{site}}."),
+                (
+                    " emails",
+                    "{count, plural, =0 {No e-mails} one {{count} e-mail from \
+                     Hello, {user_name}!\nWelcome to r18''s '{'site'}'.} \
+                     other {{count} e-mails '{''''#''''}'}}"
+                ),
+                (".login title", "r18 login"),
+                (" gender", "{gender, select, female {She} male {He} other {He}}"),
+            ]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v.into()))
+            .collect()
+        );
+
+        assert!(super::extract_fluent("a = { b }\nb = { a }").is_err());
+        assert!(super::extract_fluent("a = { $b ->\n [x] X\n}").is_err());
+        assert!(super::extract_fluent("a = {").is_err());
+    }
+
+    #[test]
+    fn generate_fluent_test() {
+        let translation = [
+            (" hello", "Hello, {user_name}!\nWelcome to r18's {{site}}."),
+            (".login title", "Sign in"),
+            (".login placeholder", "[TODO] {name}"),
+            (" {} files", "[TODO]"),
+            (" new", "[TODO] {{new}}"),
+        ]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v.into()))
+        .collect::<HashMap<String, Translation>>();
+
+        assert_eq!(
+            super::generate_fluent(SOURCE, translation).unwrap(),
+            r#"-brand = { $case ->
+    [genitive] r18's
+   *[nominative] r18
+}
+
+# A greeting
+hello = Hello, { $user-name }!
+    Welcome to { -brand(case: "genitive") } { "{" }site{ "}" }.
+
+login =
+    .title = Sign in
+    .placeholder = [TODO] { $name }
+
+new = [TODO] { "{" }new{ "}" }
+
+# Keys below are not valid Fluent identifiers:
+# {} files
+"#
+        );
+
+        // changed messages are rewritten, and their comments are kept
+        let translation = [
+            (" hello", "Hi, {user_name}!"),
+            (".login title", "r18 login"),
+        ]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v.into()))
+        .collect::<HashMap<String, Translation>>();
+
+        assert_eq!(
+            super::generate_fluent(SOURCE, translation).unwrap(),
+            r#"-brand = { $case ->
+    [genitive] r18's
+   *[nominative] r18
+}
+
+# A greeting
+hello = Hi, { $user_name }!
+
+login =
+    .title = { -brand } login
+"#
+        );
+    }
+}
@@ -1,13 +1,23 @@
-use std::{collections::HashMap, fs::File, io::Write, path::Path};
+use std::{
+    collections::HashMap,
+    fs::{self, File},
+    io::Write,
+    path::Path,
+};
 
 use serde_json::{Map, Value};
 
+use super::{
+    arb_locale, arb_metadata, generate_arb, generate_fluent, generate_json, generate_toml,
+    generate_yaml, parse_json, split_key, ArbMessage, Translation,
+};
+
+/// Writes `translation` to `TODO.<filename>` next to `old_path`, in the
+/// format of `old_path`.
 pub fn generate(
     old_path: impl AsRef<Path>,
-    translation: HashMap<String, String>,
+    translation: HashMap<String, Translation>,
 ) -> crate::Result<()> {
-    let document = generate_inner(translation)?;
-
     let todo_path = old_path.as_ref().with_file_name(format!(
         "TODO.{}",
         old_path
@@ -18,26 +28,75 @@ pub fn generate(
             .unwrap_or_default()
     ));
 
-    let mut todo_file =
-        File::create(todo_path).map_err(|e| format!("Failed to create todo file: {}", e))?;
+    let document = generate_document(old_path.as_ref(), translation)?;
 
-    writeln!(
-        todo_file,
-        "{}",
-        serde_json::to_string_pretty(&document).unwrap()
-    )
-    .map_err(|e| format!("Failed to write todo file: {}", e))?;
+    write_document(todo_path, &document).map_err(|e| format!("Failed to write todo file: {}", e))?;
 
     Ok(())
 }
 
-fn generate_inner(translation: HashMap<String, String>) -> crate::Result<Value> {
+/// Writes `translation` to `path` in its format, replacing its translations.
+pub fn write(path: impl AsRef<Path>, translation: HashMap<String, Translation>) -> crate::Result<()> {
+    let document = generate_document(path.as_ref(), translation)?;
+
+    write_document(path.as_ref(), &document)
+        .map_err(|e| format!("Failed to write {}: {}", path.as_ref().display(), e))?;
+
+    Ok(())
+}
+
+fn generate_document(
+    path: &Path,
+    translation: HashMap<String, Translation>,
+) -> crate::Result<String> {
+    Ok(match path.extension().and_then(|ext| ext.to_str()) {
+        Some("ftl") => generate_fluent(
+            &fs::read_to_string(path).unwrap_or_default(),
+            translation,
+        )?,
+        Some("arb") => {
+            // the locale and metadata of the previous file are kept
+            let source = fs::read_to_string(path).unwrap_or_default();
+            let metadata = arb_metadata(&source).unwrap_or_default();
+
+            let mut keys = translation.keys().collect::<Vec<_>>();
+            keys.sort();
+
+            let messages = keys
+                .into_iter()
+                .map(|key| ArbMessage {
+                    key,
+                    placeholders: Vec::new(),
+                    metadata: metadata.get(key),
+                    translation: translation.get(key),
+                })
+                .collect::<Vec<_>>();
+
+            generate_arb(arb_locale(&source).as_deref(), &messages)?
+        }
+        Some("yaml" | "yml") => generate_yaml(&generate_inner(translation)?),
+        Some("toml") => generate_toml(&generate_inner(translation)?),
+        _ => {
+            // comments of the previous file are kept
+            let (_, comments) = fs::read_to_string(path)
+                .ok()
+                .and_then(|source| parse_json(&source).ok())
+                .unwrap_or_default();
+
+            generate_json(&generate_inner(translation)?, &comments)
+        }
+    })
+}
+
+fn write_document(path: impl AsRef<Path>, document: &str) -> std::io::Result<()> {
+    writeln!(File::create(path)?, "{}", document.trim_end())
+}
+
+fn generate_inner(translation: HashMap<String, Translation>) -> crate::Result<Value> {
     let mut document = Map::new().into();
 
     for (key, value) in translation {
-        let (prefix, content) = key
-            .split_once(' ')
-            .ok_or("Can not find whitespace in key")?;
+        let (prefix, content) = split_key(&key)?;
         let mut level = prefix
             .split('.')
             .filter(|l| !l.is_empty())
@@ -50,10 +109,23 @@ fn generate_inner(translation: HashMap<String, String>) -> crate::Result<Value>
     Ok(document)
 }
 
-fn generate_value<'a>(mut level: impl Iterator<Item = &'a str>, parent: &mut Value, value: String) {
+fn generate_value<'a>(
+    mut level: impl Iterator<Item = &'a str>,
+    parent: &mut Value,
+    value: Translation,
+) {
     match level.next() {
         Some(current) => generate_value(level, &mut parent[current], value),
-        None => *parent = Value::String(value),
+        None => {
+            *parent = match value {
+                Translation::Text(text) => Value::String(text),
+                Translation::Plural(forms) => forms
+                    .into_iter()
+                    .map(|(category, form)| (category.to_string(), Value::String(form)))
+                    .collect::<Map<_, _>>()
+                    .into(),
+            }
+        }
     }
 }
 
@@ -61,6 +133,8 @@ fn generate_value<'a>(mut level: impl Iterator<Item = &'a str>, parent: &mut Val
 mod tests {
     use std::collections::HashMap;
 
+    use crate::translation::Translation;
+
     #[test]
     fn test_generate() {
         let json = serde_json::json!({
@@ -81,8 +155,8 @@ mod tests {
             (".evil {} is typing", "{} 正在女装"),
         ]
         .into_iter()
-        .map(|(k, v)| (k.to_string(), v.to_string()))
-        .collect::<HashMap<String, String>>();
+        .map(|(k, v)| (k.to_string(), v.into()))
+        .collect::<HashMap<String, Translation>>();
 
         assert_eq!(json, super::generate_inner(translation).unwrap());
     }
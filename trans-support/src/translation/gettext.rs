@@ -0,0 +1,889 @@
+//! gettext `.po` and `.mo` catalogs.
+//!
+//! `msgctxt` is mapped onto the prefix, e.g. `msgctxt "pua"` is looked up by
+//! `tr!([".pua"] ...)`, and `msgid` is the content. Plural messages are also
+//! looked up by their `msgid_plural`, and their `msgstr[n]` are mapped onto
+//! CLDR plural categories of well-known `Plural-Forms`, or by evaluating the
+//! `Plural-Forms` of the catalog.
+//! Fuzzy and untranslated messages are skipped like `msgfmt` does.
+
+use std::collections::{BTreeMap, HashMap};
+
+use super::{split_key, PluralCategory, Translation};
+
+/// A message of a catalog.
+#[derive(Default)]
+struct Message {
+    context: Option<String>,
+    id: String,
+    plural: Option<String>,
+    strings: Vec<String>,
+}
+
+/// Extracts translations from the source of a `.po` file.
+pub(crate) fn extract_po(source: &str) -> crate::Result<HashMap<String, Translation>> {
+    messages(parse_po(source)?)
+}
+
+/// Extracts translations from a compiled `.mo` file.
+pub(crate) fn extract_mo(bytes: &[u8]) -> crate::Result<HashMap<String, Translation>> {
+    messages(parse_mo(bytes)?)
+}
+
+fn messages(messages: Vec<Message>) -> crate::Result<HashMap<String, Translation>> {
+    let header = messages
+        .iter()
+        .find(|m| m.context.is_none() && m.id.is_empty())
+        .and_then(|m| m.strings.first());
+    let language = header.and_then(|h| header_field(h, "Language"));
+
+    let categories = match header.and_then(|h| header_field(h, "Plural-Forms")) {
+        // categories of well-known Plural-Forms are not guessed
+        Some(forms) => match language
+            .map(plural_forms_of)
+            .filter(|(known, _)| normalize(known) == normalize(forms))
+        {
+            Some((_, categories)) => categories.to_vec(),
+            None => plural_categories(forms).map_err(|e| format!("invalid Plural-Forms: {}", e))?,
+        },
+        None => vec![PluralCategory::One, PluralCategory::Other],
+    };
+
+    let mut ret = HashMap::new();
+
+    for message in messages.into_iter().filter(|m| !m.id.is_empty()) {
+        let prefix = match message.context {
+            Some(context) if context.starts_with('.') => context,
+            Some(context) => format!(".{}", context),
+            None => String::new(),
+        };
+
+        let translation = match message.plural {
+            None => match message.strings.into_iter().next() {
+                Some(text) if !text.is_empty() => Translation::Text(text),
+                _ => continue,
+            },
+            Some(_) => {
+                let last = message.strings.get(categories.len() - 1).cloned();
+                let mut forms = categories
+                    .iter()
+                    .zip(message.strings)
+                    .filter(|(_, form)| !form.is_empty())
+                    .map(|(category, form)| (*category, form))
+                    .collect::<BTreeMap<_, _>>();
+
+                // e.g. fractions of Russian, which gettext can not select
+                if let Some(last) = last.filter(|l| !l.is_empty()) {
+                    forms.entry(PluralCategory::Other).or_insert(last);
+                }
+
+                match forms.contains_key(&PluralCategory::Other) {
+                    true => Translation::Plural(forms),
+                    false => continue,
+                }
+            }
+        };
+
+        if let Some(plural) = message.plural.filter(|p| p != &message.id) {
+            ret.entry(format!("{} {}", prefix, plural))
+                .or_insert_with(|| translation.clone());
+        }
+
+        ret.insert(format!("{} {}", prefix, message.id), translation);
+    }
+
+    Ok(ret)
+}
+
+/// A field of [`Message`] continued by the following strings.
+#[derive(Clone, Copy)]
+enum Field {
+    Context,
+    Id,
+    Plural,
+    String(usize),
+}
+
+impl Message {
+    fn field(&mut self, field: Field) -> &mut String {
+        match field {
+            Field::Context => self.context.get_or_insert_with(String::new),
+            Field::Id => &mut self.id,
+            Field::Plural => self.plural.get_or_insert_with(String::new),
+            Field::String(i) => &mut self.strings[i],
+        }
+    }
+}
+
+fn parse_po(source: &str) -> crate::Result<Vec<Message>> {
+    let mut messages = Vec::new();
+    let mut message = Message::default();
+    let mut fuzzy = false;
+    let mut current = None;
+
+    let mut finish = |message: &mut Message, fuzzy: &mut bool| {
+        let message = std::mem::take(message);
+        if !*fuzzy && !message.strings.is_empty() {
+            messages.push(message);
+        }
+        *fuzzy = false;
+    };
+
+    for (i, line) in source.lines().enumerate() {
+        let error = |e: &str| format!("{} at line {}", e, i + 1);
+        let line = line.trim();
+
+        if line.is_empty() {
+            continue;
+        }
+
+        // obsolete messages are commented out
+        if line.starts_with('#') {
+            if !message.strings.is_empty() {
+                finish(&mut message, &mut fuzzy);
+                current = None;
+            }
+
+            if let Some(flags) = line.strip_prefix("#,") {
+                fuzzy |= flags.split(',').any(|f| f.trim() == "fuzzy");
+            }
+
+            continue;
+        }
+
+        if line.starts_with('"') {
+            let field = current.ok_or_else(|| error("unexpected string"))?;
+            let value = unquote(line).map_err(|e| error(&e))?;
+            message.field(field).push_str(&value);
+            continue;
+        }
+
+        let (keyword, value) = line
+            .split_once(|c: char| c.is_whitespace())
+            .ok_or_else(|| error("expected a string"))?;
+        let value = unquote(value.trim()).map_err(|e| error(&e))?;
+
+        if matches!(keyword, "msgctxt" | "msgid") && !message.strings.is_empty() {
+            finish(&mut message, &mut fuzzy);
+        }
+
+        let field = match keyword {
+            "msgctxt" => Field::Context,
+            "msgid" => Field::Id,
+            "msgid_plural" => Field::Plural,
+            "msgstr" => {
+                message.strings.push(String::new());
+                Field::String(message.strings.len() - 1)
+            }
+            _ => {
+                let index = keyword
+                    .strip_prefix("msgstr[")
+                    .and_then(|k| k.strip_suffix(']'))
+                    .and_then(|k| k.parse::<usize>().ok())
+                    .ok_or_else(|| error(&format!("unknown keyword `{}`", keyword)))?;
+
+                if index != message.strings.len() {
+                    return Err(error("unordered plural forms").into());
+                }
+
+                message.strings.push(String::new());
+                Field::String(index)
+            }
+        };
+
+        *message.field(field) = value;
+        current = Some(field);
+    }
+
+    finish(&mut message, &mut fuzzy);
+
+    Ok(messages)
+}
+
+/// Unquotes a C-like string literal.
+fn unquote(s: &str) -> Result<String, String> {
+    let inner = s
+        .strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .filter(|_| s.len() >= 2)
+        .ok_or_else(|| format!("invalid string {}", s))?;
+
+    let mut ret = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            ret.push(c);
+            continue;
+        }
+
+        ret.push(match chars.next() {
+            Some('n') => '\n',
+            Some('t') => '\t',
+            Some('r') => '\r',
+            Some('a') => '\x07',
+            Some('b') => '\x08',
+            Some('f') => '\x0c',
+            Some('v') => '\x0b',
+            Some(c @ ('"' | '\\' | '\'' | '?')) => c,
+            _ => return Err(format!("invalid escape in {}", s)),
+        });
+    }
+
+    Ok(ret)
+}
+
+fn parse_mo(bytes: &[u8]) -> crate::Result<Vec<Message>> {
+    let read = |offset: usize, big_endian: bool| -> crate::Result<usize> {
+        let word: [u8; 4] = bytes
+            .get(offset..offset + 4)
+            .and_then(|b| b.try_into().ok())
+            .ok_or("truncated .mo file")?;
+
+        Ok(match big_endian {
+            true => u32::from_be_bytes(word),
+            false => u32::from_le_bytes(word),
+        } as usize)
+    };
+
+    let big_endian = match read(0, false)? {
+        0x950412de => false,
+        0xde120495 => true,
+        _ => return Err("invalid .mo magic number".into()),
+    };
+
+    let count = read(8, big_endian)?;
+    let originals = read(12, big_endian)?;
+    let translations = read(16, big_endian)?;
+
+    let string = |table: usize, i: usize| -> crate::Result<&str> {
+        let len = read(table + i * 8, big_endian)?;
+        let offset = read(table + i * 8 + 4, big_endian)?;
+        let bytes = bytes
+            .get(offset..offset + len)
+            .ok_or("truncated .mo file")?;
+
+        Ok(std::str::from_utf8(bytes)?)
+    };
+
+    (0..count)
+        .map(|i| {
+            let original = string(originals, i)?;
+            let (context, original) = match original.split_once('\x04') {
+                Some((context, original)) => (Some(context.to_string()), original),
+                None => (None, original),
+            };
+            let (id, plural) = match original.split_once('\0') {
+                Some((id, plural)) => (id, Some(plural.to_string())),
+                None => (original, None),
+            };
+
+            Ok(Message {
+                context,
+                id: id.to_string(),
+                plural,
+                strings: string(translations, i)?
+                    .split('\0')
+                    .map(|s| s.to_string())
+                    .collect(),
+            })
+        })
+        .collect()
+}
+
+/// A message exported to gettext catalogs.
+pub struct PoMessage<'a> {
+    /// Key of the message, like `".pua Hello, {}"`.
+    pub key: &'a str,
+    /// Whether the message has plural forms.
+    pub plural: bool,
+    /// Source references, like `src/main.rs:5`.
+    pub references: Vec<String>,
+    pub translation: Option<&'a Translation>,
+}
+
+/// Returns the language of a `.po` file declared in its header.
+pub fn po_language(source: &str) -> Option<String> {
+    let messages = parse_po(source).ok()?;
+    let header = messages
+        .iter()
+        .find(|m| m.context.is_none() && m.id.is_empty())?
+        .strings
+        .first()?;
+
+    header_field(header, "Language")
+        .filter(|l| !l.is_empty())
+        .map(|l| l.to_string())
+}
+
+/// Returns the value of a field of the header.
+fn header_field<'h>(header: &'h str, name: &str) -> Option<&'h str> {
+    header.lines().find_map(|line| {
+        let (key, value) = line.split_once(':')?;
+        (key.trim() == name).then(|| value.trim())
+    })
+}
+
+/// Removes whitespaces of `Plural-Forms` to compare them.
+fn normalize(forms: &str) -> String {
+    forms
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .trim_end_matches(';')
+        .to_string()
+}
+
+/// Generates a `.po` file of `language`, or a `.pot` template if `language`
+/// is `None`.
+pub fn generate_po(language: Option<&str>, messages: &[PoMessage]) -> crate::Result<String> {
+    let mut document = String::from("msgid \"\"\nmsgstr \"\"\n");
+    document.push_str("\"Content-Type: text/plain; charset=UTF-8\\n\"\n");
+
+    let categories = match language {
+        Some(language) => {
+            let (forms, categories) = plural_forms_of(language);
+            document.push_str(&format!("\"Language: {}\\n\"\n", language));
+            document.push_str(&format!("\"Plural-Forms: {}\\n\"\n", forms));
+
+            categories.to_vec()
+        }
+        None => vec![PluralCategory::One, PluralCategory::Other],
+    };
+
+    for message in messages {
+        let (prefix, content) = split_key(message.key)?;
+
+        document.push('\n');
+
+        if !message.references.is_empty() {
+            document.push_str(&format!("#: {}\n", message.references.join(" ")));
+        }
+        if !prefix.is_empty() {
+            document.push_str(&format!("msgctxt {}\n", quote(&prefix[1..])));
+        }
+        document.push_str(&format!("msgid {}\n", quote(content)));
+
+        // templates have no translations
+        let translation = language.and(message.translation);
+        let form = |category| match translation {
+            Some(Translation::Text(text)) => text.as_str(),
+            Some(Translation::Plural(forms)) => forms
+                .get(&category)
+                .or_else(|| forms.get(&PluralCategory::Other))
+                .map_or("", |form| form.as_str()),
+            None => "",
+        };
+
+        match message.plural || matches!(translation, Some(Translation::Plural(_))) {
+            true => {
+                document.push_str(&format!("msgid_plural {}\n", quote(content)));
+
+                for (i, category) in categories.iter().enumerate() {
+                    document.push_str(&format!("msgstr[{}] {}\n", i, quote(form(*category))));
+                }
+            }
+            false => document.push_str(&format!("msgstr {}\n", quote(form(PluralCategory::Other)))),
+        }
+    }
+
+    Ok(document)
+}
+
+/// Quotes `s` as a string literal, which is split into lines after `\n`.
+fn quote(s: &str) -> String {
+    let escape = |line: &str| {
+        line.replace('\\', "\\\\")
+            .replace('"', "\\\"")
+            .replace('\t', "\\t")
+            .replace('\r', "\\r")
+            .replace('\n', "\\n")
+    };
+
+    match s.trim_end_matches('\n').contains('\n') {
+        true => s
+            .split_inclusive('\n')
+            .fold("\"\"".to_string(), |ret, line| {
+                format!("{}\n\"{}\"", ret, escape(line))
+            }),
+        false => format!("\"{}\"", escape(s)),
+    }
+}
+
+/// Returns CLDR plural categories used by `language`, which always include
+/// `other`.
+pub fn plural_categories_of(language: &str) -> Vec<PluralCategory> {
+    let mut categories = plural_forms_of(language).1.to_vec();
+    if !categories.contains(&PluralCategory::Other) {
+        categories.push(PluralCategory::Other);
+    }
+
+    categories
+}
+
+/// Returns `Plural-Forms` of `language` used by GNU gettext, with CLDR plural
+/// categories of its forms.
+fn plural_forms_of(language: &str) -> (&'static str, &'static [PluralCategory]) {
+    use PluralCategory::*;
+
+    match language.split(['-', '_']).next().unwrap_or_default() {
+        "ja" | "zh" | "ko" | "vi" | "th" | "id" | "ms" | "lo" | "my" | "km" => {
+            ("nplurals=1; plural=0;", &[Other])
+        }
+        "fr" | "hy" | "ff" | "kab" => ("nplurals=2; plural=(n > 1);", &[One, Other]),
+        "ru" | "uk" | "be" => (
+            "nplurals=3; plural=(n%10==1 && n%100!=11 ? 0 : n%10>=2 && n%10<=4 && (n%100<10 || \
+             n%100>=20) ? 1 : 2);",
+            &[One, Few, Many],
+        ),
+        "sr" | "hr" | "bs" => (
+            "nplurals=3; plural=(n%10==1 && n%100!=11 ? 0 : n%10>=2 && n%10<=4 && (n%100<10 || \
+             n%100>=20) ? 1 : 2);",
+            &[One, Few, Other],
+        ),
+        "pl" => (
+            "nplurals=3; plural=(n==1 ? 0 : n%10>=2 && n%10<=4 && (n%100<10 || n%100>=20) ? 1 : \
+             2);",
+            &[One, Few, Many],
+        ),
+        "cs" | "sk" => (
+            "nplurals=3; plural=(n==1 ? 0 : n>=2 && n<=4 ? 1 : 2);",
+            &[One, Few, Other],
+        ),
+        "lt" => (
+            "nplurals=3; plural=(n%10==1 && n%100!=11 ? 0 : n%10>=2 && (n%100<10 || n%100>=20) \
+             ? 1 : 2);",
+            &[One, Few, Other],
+        ),
+        "lv" => (
+            "nplurals=3; plural=(n%10==1 && n%100!=11 ? 0 : n != 0 ? 1 : 2);",
+            &[One, Other, Zero],
+        ),
+        "ro" => (
+            "nplurals=3; plural=(n==1 ? 0 : (n==0 || (n%100 > 0 && n%100 < 20)) ? 1 : 2);",
+            &[One, Few, Other],
+        ),
+        "sl" => (
+            "nplurals=4; plural=(n%100==1 ? 0 : n%100==2 ? 1 : n%100==3 || n%100==4 ? 2 : 3);",
+            &[One, Two, Few, Other],
+        ),
+        "ga" => (
+            "nplurals=5; plural=(n==1 ? 0 : n==2 ? 1 : n<7 ? 2 : n<11 ? 3 : 4);",
+            &[One, Two, Few, Many, Other],
+        ),
+        "ar" => (
+            "nplurals=6; plural=(n==0 ? 0 : n==1 ? 1 : n==2 ? 2 : n%100>=3 && n%100<=10 ? 3 : \
+             n%100>=11 ? 4 : 5);",
+            &[Zero, One, Two, Few, Many, Other],
+        ),
+        _ => ("nplurals=2; plural=(n != 1);", &[One, Other]),
+    }
+}
+
+/// Returns plural categories of each `msgstr[n]` selected by `Plural-Forms`.
+///
+/// The expression is evaluated with integers, and a form is `zero` if only `0`
+/// selects it, `one` if `1` selects it and `two` if `2` but not `3` selects
+/// it. The last remaining form is `other`, and the others are `few` and
+/// `many` in order.
+fn plural_categories(forms: &str) -> Result<Vec<PluralCategory>, String> {
+    let field = |name: &str| {
+        forms.split(';').find_map(|f| {
+            let (key, value) = f.split_once('=')?;
+            (key.trim() == name).then(|| value.trim())
+        })
+    };
+
+    let count = field("nplurals")
+        .and_then(|n| n.parse::<usize>().ok())
+        .filter(|n| (1..=6).contains(n))
+        .ok_or("invalid nplurals")?;
+    let expression = field("plural").ok_or("missing plural")?;
+    let expression = Expression::parse(expression)?;
+
+    let mut samples = vec![Vec::new(); count];
+    for n in 0..=1000 {
+        let index = expression.eval(n) as usize;
+        samples
+            .get_mut(index)
+            .ok_or_else(|| format!("plural form {} out of range", index))?
+            .push(n);
+    }
+
+    let mut categories = vec![None; count];
+    let mut assign = |category, f: &dyn Fn(&[u64]) -> bool| {
+        // a form is always left for `other`
+        if categories.iter().filter(|c| c.is_none()).count() < 2 {
+            return;
+        }
+
+        if let Some(i) = (0..count).find(|&i| categories[i].is_none() && f(&samples[i])) {
+            categories[i] = Some(category);
+        }
+    };
+
+    assign(PluralCategory::Zero, &|s| s == [0]);
+    assign(PluralCategory::One, &|s| s.contains(&1));
+    assign(PluralCategory::Two, &|s| s.contains(&2) && !s.contains(&3));
+
+    if let Some(last) = categories.iter().rposition(|c| c.is_none()) {
+        categories[last] = Some(PluralCategory::Other);
+    }
+
+    let mut rest = [PluralCategory::Few, PluralCategory::Many].into_iter();
+    categories
+        .into_iter()
+        .map(|c| c.or_else(|| rest.next()).ok_or_else(|| "too many plural forms".into()))
+        .collect()
+}
+
+/// A C expression of `Plural-Forms`.
+enum Expression {
+    N,
+    Number(u64),
+    Not(Box<Expression>),
+    Binary(Box<Expression>, &'static str, Box<Expression>),
+    Ternary(Box<Expression>, Box<Expression>, Box<Expression>),
+}
+
+const OPERATORS: [&[&str]; 6] = [
+    &["||"],
+    &["&&"],
+    &["==", "!="],
+    &["<=", ">=", "<", ">"],
+    &["+", "-"],
+    &["*", "/", "%"],
+];
+
+impl Expression {
+    fn parse(s: &str) -> Result<Self, String> {
+        let mut rest = s.trim();
+        let expression = Self::ternary(&mut rest)?;
+
+        match rest.trim().is_empty() {
+            true => Ok(expression),
+            false => Err(format!("unexpected `{}`", rest)),
+        }
+    }
+
+    fn ternary(s: &mut &str) -> Result<Self, String> {
+        let condition = Self::binary(s, 0)?;
+
+        match Self::eat(s, "?") {
+            true => {
+                let then = Self::ternary(s)?;
+                if !Self::eat(s, ":") {
+                    return Err("expected `:`".into());
+                }
+
+                Ok(Self::Ternary(
+                    Box::new(condition),
+                    Box::new(then),
+                    Box::new(Self::ternary(s)?),
+                ))
+            }
+            false => Ok(condition),
+        }
+    }
+
+    fn binary(s: &mut &str, level: usize) -> Result<Self, String> {
+        let Some(operators) = OPERATORS.get(level) else {
+            return Self::unary(s);
+        };
+
+        let mut lhs = Self::binary(s, level + 1)?;
+
+        while let Some(op) = operators.iter().find(|op| Self::eat(s, op)) {
+            lhs = Self::Binary(Box::new(lhs), op, Box::new(Self::binary(s, level + 1)?));
+        }
+
+        Ok(lhs)
+    }
+
+    fn unary(s: &mut &str) -> Result<Self, String> {
+        if Self::eat(s, "!") {
+            return Ok(Self::Not(Box::new(Self::unary(s)?)));
+        }
+
+        if Self::eat(s, "(") {
+            let expression = Self::ternary(s)?;
+
+            return match Self::eat(s, ")") {
+                true => Ok(expression),
+                false => Err("expected `)`".into()),
+            };
+        }
+
+        if Self::eat(s, "n") {
+            return Ok(Self::N);
+        }
+
+        *s = s.trim_start();
+        let digits = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+        let number = s[..digits]
+            .parse()
+            .map_err(|_| format!("unexpected `{}`", s))?;
+        *s = &s[digits..];
+
+        Ok(Self::Number(number))
+    }
+
+    fn eat(s: &mut &str, token: &str) -> bool {
+        let trimmed = s.trim_start();
+
+        // `<` and `!` must not eat `<=` and `!=`
+        let matched = trimmed.starts_with(token)
+            && !(matches!(token, "<" | ">" | "!") && trimmed[1..].starts_with('='));
+        if matched {
+            *s = &trimmed[token.len()..];
+        }
+
+        matched
+    }
+
+    fn eval(&self, n: u64) -> u64 {
+        match self {
+            Self::N => n,
+            Self::Number(v) => *v,
+            Self::Not(e) => (e.eval(n) == 0) as u64,
+            Self::Ternary(c, t, f) => match c.eval(n) != 0 {
+                true => t.eval(n),
+                false => f.eval(n),
+            },
+            Self::Binary(lhs, op, rhs) => {
+                let (l, r) = (lhs.eval(n), rhs.eval(n));
+
+                match *op {
+                    "||" => (l != 0 || r != 0) as u64,
+                    "&&" => (l != 0 && r != 0) as u64,
+                    "==" => (l == r) as u64,
+                    "!=" => (l != r) as u64,
+                    "<=" => (l <= r) as u64,
+                    ">=" => (l >= r) as u64,
+                    "<" => (l < r) as u64,
+                    ">" => (l > r) as u64,
+                    "+" => l.wrapping_add(r),
+                    "-" => l.wrapping_sub(r),
+                    "*" => l.wrapping_mul(r),
+                    "/" => l.checked_div(r).unwrap_or_default(),
+                    "%" => l.checked_rem(r).unwrap_or_default(),
+                    _ => unreachable!(),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::translation::{PluralCategory, Translation};
+
+    #[test]
+    fn plural_forms_test() {
+        use PluralCategory::*;
+
+        for (forms, categories) in [
+            ("nplurals=1; plural=0;", vec![Other]),
+            ("nplurals=2; plural=(n > 1);", vec![One, Other]),
+            (
+                "nplurals=3; plural=(n%10==1 && n%100!=11 ? 0 : n%10>=2 && n%10<=4 && \
+                 (n%100<10 || n%100>=20) ? 1 : 2);",
+                vec![One, Few, Other],
+            ),
+            (
+                "nplurals=3; plural=(n%10==1 && n%100!=11 ? 0 : n != 0 ? 1 : 2);",
+                vec![One, Other, Zero],
+            ),
+            (
+                "nplurals=6; plural=(n==0 ? 0 : n==1 ? 1 : n==2 ? 2 : n%100>=3 && \
+                 n%100<=10 ? 3 : n%100>=11 ? 4 : 5);",
+                vec![Zero, One, Two, Few, Many, Other],
+            ),
+        ] {
+            assert_eq!(
+                Ok(categories),
+                super::plural_categories(forms),
+                "{}",
+                forms
+            );
+        }
+
+        assert!(super::plural_categories("nplurals=2; plural=n+;").is_err());
+    }
+
+    #[test]
+    fn extract_po_test() {
+        let po = r#"
+msgid ""
+msgstr ""
+"Content-Type: text/plain; charset=UTF-8\n"
+"Plural-Forms: nplurals=3; plural=(n%10==1 && n%100!=11 ? 0 : n%10>=2 && "
+"n%10<=4 && (n%100<10 || n%100>=20) ? 1 : 2);\n"
+
+#: src/main.rs:5
+msgid "Hello, {}"
+msgstr "Привет, {}"
+
+msgctxt "pua"
+msgid "Hello, {}"
+msgstr ""
+"Привет, \"{}\"\n"
+"!"
+
+#, fuzzy
+msgid "Goodbye"
+msgstr "Пока"
+
+msgid "Untranslated"
+msgstr ""
+
+msgid "{} file"
+msgid_plural "{} files"
+msgstr[0] "{} файл"
+msgstr[1] "{} файла"
+msgstr[2] "{} файлов"
+
+#~ msgid "Obsolete"
+#~ msgstr "Устаревший"
+"#;
+
+        let files = Translation::Plural(
+            [
+                (PluralCategory::One, "{} файл"),
+                (PluralCategory::Few, "{} файла"),
+                (PluralCategory::Other, "{} файлов"),
+            ]
+            .into_iter()
+            .map(|(c, v)| (c, v.to_string()))
+            .collect(),
+        );
+
+        assert_eq!(
+            super::extract_po(po).unwrap(),
+            [
+                (" Hello, {}", "Привет, {}".into()),
+                (".pua Hello, {}", "Привет, \"{}\"\n!".into()),
+                (" {} file", files.clone()),
+                (" {} files", files),
+            ]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v))
+            .collect()
+        );
+
+        assert!(super::extract_po("msgid \"a\"\nmsgstr[1] \"b\"").is_err());
+        assert!(super::extract_po("msgid \"a\nmsgstr \"b\"").is_err());
+    }
+
+    #[test]
+    fn extract_mo_test() {
+        // (original, translation) sorted by original
+        let strings: [(&[u8], &[u8]); 3] = [
+            (b"", b"Plural-Forms: nplurals=2; plural=(n != 1);\n"),
+            (b"pua\x04Hello", b"Bonjour"),
+            (b"{} file\0{} files", b"{} fichier\0{} fichiers"),
+        ];
+
+        let mut mo = Vec::new();
+        let mut data = Vec::new();
+        let data_offset = 28 + strings.len() * 16;
+        let mut tables = Vec::new();
+
+        for s in strings.iter().map(|s| s.0).chain(strings.iter().map(|s| s.1)) {
+            tables.extend((s.len() as u32).to_le_bytes());
+            tables.extend(((data_offset + data.len()) as u32).to_le_bytes());
+            data.extend(s);
+            data.push(0);
+        }
+
+        for word in [0x950412de, 0, strings.len(), 28, 28 + strings.len() * 8, 0, 0] {
+            mo.extend((word as u32).to_le_bytes());
+        }
+        mo.extend(tables);
+        mo.extend(data);
+
+        let files = Translation::Plural(
+            [
+                (PluralCategory::One, "{} fichier"),
+                (PluralCategory::Other, "{} fichiers"),
+            ]
+            .into_iter()
+            .map(|(c, v)| (c, v.to_string()))
+            .collect(),
+        );
+
+        assert_eq!(
+            super::extract_mo(&mo).unwrap(),
+            [
+                (".pua Hello", "Bonjour".into()),
+                (" {} file", files.clone()),
+                (" {} files", files),
+            ]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v))
+            .collect()
+        );
+
+        assert!(super::extract_mo(b"not a catalog").is_err());
+    }
+
+    #[test]
+    fn generate_po_test() {
+        let plural = |forms: [(PluralCategory, &str); 4]| {
+            Translation::Plural(forms.into_iter().map(|(c, v)| (c, v.to_string())).collect())
+        };
+        let files = plural([
+            (PluralCategory::One, "{} файл"),
+            (PluralCategory::Few, "{} файла"),
+            (PluralCategory::Many, "{} файлов"),
+            (PluralCategory::Other, "{} файла"),
+        ]);
+        let hello = Translation::from("Привет, \"{}\"\nи пока");
+        let messages = [
+            super::PoMessage {
+                key: ".pua Hello, {}",
+                plural: false,
+                references: vec!["src/main.rs:5".into(), "src/lib.rs:1".into()],
+                translation: Some(&hello),
+            },
+            super::PoMessage {
+                key: " {} files",
+                plural: true,
+                references: vec![],
+                translation: Some(&files),
+            },
+            super::PoMessage {
+                key: " Untranslated",
+                plural: false,
+                references: vec![],
+                translation: None,
+            },
+        ];
+
+        let po = super::generate_po(Some("ru"), &messages).unwrap();
+        assert!(po.contains("msgstr[2] \"{} файлов\"\n"));
+        assert!(po.contains(
+            "#: src/main.rs:5 src/lib.rs:1\nmsgctxt \"pua\"\nmsgid \"Hello, {}\"\nmsgstr \"\"\n\"Привет, \\\"{}\\\"\\n\"\n\"и пока\"\n"
+        ));
+        assert_eq!(Some("ru".to_string()), super::po_language(&po));
+        // gettext can not select fractions, so `other` is the last form
+        let files = plural([
+            (PluralCategory::One, "{} файл"),
+            (PluralCategory::Few, "{} файла"),
+            (PluralCategory::Many, "{} файлов"),
+            (PluralCategory::Other, "{} файлов"),
+        ]);
+        assert_eq!(
+            super::extract_po(&po).unwrap(),
+            [(".pua Hello, {}", hello.clone()), (" {} files", files)]
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v))
+                .collect()
+        );
+
+        let pot = super::generate_po(None, &messages).unwrap();
+        assert!(pot.contains("msgid_plural \"{} files\"\nmsgstr[0] \"\"\nmsgstr[1] \"\"\n"));
+        assert_eq!(None, super::po_language(&pot));
+        assert!(super::extract_po(&pot).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,227 @@
+//! i18next JSON v4 files for web frontends.
+//!
+//! Keys are nested by the parts of prefixes, and texts are named by their
+//! identifiers like `pua.hello`, since i18next splits keys by `.` and `:`.
+//! Placeholders are interpolated by `{{name}}` and plural forms are keys with
+//! suffixes of their categories, or all texts are ICU MessageFormat messages
+//! for the `i18next-icu` plugin, which replaces the interpolation of i18next.
+
+use std::collections::HashMap;
+
+use serde_json::{Map, Value};
+
+use super::{generate_json, identifier, split_key, Comments, Translation};
+use crate::source::{segments, Segment};
+
+/// Rewrites placeholders of `text` to i18next interpolations, positional
+/// ones are named by their indexes, or `count` in plural forms.
+///
+/// Format specs are dropped, and ICU MessageFormat arguments are rejected.
+fn interpolate(text: &str, plural: bool) -> Result<String, String> {
+    segments(text, plural)
+        .into_iter()
+        .map(|segment| match segment {
+            Segment::Text(text) => Ok(text),
+            Segment::Placeholder { name, .. } => Ok(format!("{{{{{}}}}}", name)),
+            Segment::Message(message) => Err(format!(
+                "ICU MessageFormat argument `{{{}}}` needs the ICU style",
+                message
+            )),
+        })
+        .collect()
+}
+
+/// Rewrites `text` to an ICU MessageFormat message, where positional
+/// placeholders are named by their indexes, or `count` in plural forms.
+///
+/// Format specs are dropped, and texts are quoted by `'`.
+fn icu_message(text: &str, plural: bool) -> String {
+    segments(text, plural)
+        .into_iter()
+        .map(|segment| match segment {
+            Segment::Text(text) => text
+                .replace('\'', "''")
+                .replace('{', "'{'")
+                .replace('}', "'}'")
+                .replace('#', match plural {
+                    true => "'#'",
+                    false => "#",
+                }),
+            Segment::Placeholder { name, .. } => format!("{{{}}}", name),
+            Segment::Message(message) => format!("{{{}}}", message),
+        })
+        .collect()
+}
+
+fn insert(object: &mut Map<String, Value>, key: String, value: Value) -> crate::Result<()> {
+    match object.contains_key(&key) {
+        true => Err(format!("`{}` is the key of more than one text", key).into()),
+        false => {
+            object.insert(key, value);
+            Ok(())
+        }
+    }
+}
+
+/// Generates an i18next JSON file of `translations`, whose texts are ICU
+/// MessageFormat messages if `icu`.
+pub fn generate_i18next(
+    translations: &HashMap<String, Translation>,
+    icu: bool,
+) -> crate::Result<String> {
+    let mut keys = translations.keys().collect::<Vec<_>>();
+    keys.sort();
+
+    let mut root = Map::new();
+
+    for key in keys {
+        let (prefix, content) = split_key(key)?;
+        let mut object = &mut root;
+        let mut path = Vec::new();
+
+        for part in prefix.split('.').filter(|part| !part.is_empty()) {
+            let part = identifier(part);
+            path.push(part.clone());
+
+            object = match object
+                .entry(part)
+                .or_insert_with(|| Value::Object(Map::new()))
+            {
+                Value::Object(object) => object,
+                _ => return Err(format!("`{}` is the key of a text", path.join(".")).into()),
+            };
+        }
+
+        let name = identifier(content);
+        let error = |e: String| format!("`{}`: {}", key, e);
+
+        match &translations[key] {
+            Translation::Text(text) => {
+                let text = match icu {
+                    true => icu_message(text, false),
+                    false => interpolate(text, false).map_err(error)?,
+                };
+
+                insert(object, name, Value::String(text))?
+            }
+            Translation::Plural(forms) if icu => {
+                let forms = forms
+                    .iter()
+                    .map(|(category, form)| format!("{} {{{}}}", category, icu_message(form, true)))
+                    .collect::<Vec<_>>();
+
+                insert(
+                    object,
+                    name,
+                    Value::String(format!("{{count, plural, {}}}", forms.join(" "))),
+                )?
+            }
+            Translation::Plural(forms) => {
+                for (category, form) in forms {
+                    insert(
+                        object,
+                        format!("{}_{}", name, category),
+                        Value::String(interpolate(form, true).map_err(error)?),
+                    )?;
+                }
+            }
+        }
+    }
+
+    Ok(generate_json(&Value::Object(root), &Comments::default()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::translation::{fixture, Translation};
+
+    #[test]
+    fn interpolate_test() {
+        assert_eq!(
+            "{{1}} 收到了 {{0}} 的消息",
+            super::interpolate("{1} 收到了 {0} 的消息", false).unwrap()
+        );
+        assert_eq!(
+            "你好，{{0}} 和 {{1}}",
+            super::interpolate("你好，{} 和 {}", false).unwrap()
+        );
+        assert_eq!(
+            "合计：{{0}}，{{name}} {}",
+            super::interpolate("合计：{:>8.2}，{name:?} {{}}", false).unwrap()
+        );
+        assert_eq!("{{count}} 个文件", super::interpolate("{} 个文件", true).unwrap());
+        assert!(
+            super::interpolate("{gender, select, female {她} other {他们}}赞了你的帖子", false)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn icu_message_test() {
+        assert_eq!(
+            "{1} 收到了 {0} 的消息",
+            super::icu_message("{1} 收到了 {0} 的消息", false)
+        );
+        assert_eq!(
+            "合计：{0}，'{'name'}' It''s #1",
+            super::icu_message("合计：{:.2}，{{name}} It's #1", false)
+        );
+        assert_eq!("{count} 个文件 '#'", super::icu_message("{} 个文件 #", true));
+        assert_eq!(
+            "{gender, select, female {她} other {他们}}赞了{0}",
+            super::icu_message("{gender, select, female {她} other {他们}}赞了{}", false)
+        );
+    }
+
+    #[test]
+    fn generate_i18next_test() {
+        let mut translations = fixture();
+        translations.insert(".a.b Debug: {:?}".to_string(), "调试：{:?}".into());
+
+        let generate = |icu| {
+            serde_json::from_str::<serde_json::Value>(
+                &super::generate_i18next(&translations, icu).unwrap(),
+            )
+            .unwrap()
+        };
+
+        assert_eq!(
+            generate(false),
+            serde_json::json!({
+                "hello": "你好，\"{{0}}\"",
+                "pua": { "hello": "要到年底了，{{0}}" },
+                "a": { "b": { "debug": "调试：{{0}}" } },
+                "files_one": "{{count}} файл",
+                "files_other": "{{count}} файлов",
+            })
+        );
+        assert_eq!(
+            generate(true),
+            serde_json::json!({
+                "hello": "你好，\"{0}\"",
+                "pua": { "hello": "要到年底了，{0}" },
+                "a": { "b": { "debug": "调试：{0}" } },
+                "files": "{count, plural, one {{count} файл} other {{count} файлов}}",
+            })
+        );
+
+        let conflict = [
+            (" pua".to_string(), "text".into()),
+            (".pua Hello".to_string(), "prefixed".into()),
+        ]
+        .into_iter()
+        .collect::<HashMap<String, Translation>>();
+        assert!(super::generate_i18next(&conflict, false).is_err());
+
+        let message = [(
+            " {gender, select, female {She} other {They}} liked it".to_string(),
+            "{gender, select, female {她} other {他们}}赞了".into(),
+        )]
+        .into_iter()
+        .collect::<HashMap<String, Translation>>();
+        assert!(super::generate_i18next(&message, false).is_err());
+        assert!(super::generate_i18next(&message, true).is_ok());
+    }
+}
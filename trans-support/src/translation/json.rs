@@ -0,0 +1,561 @@
+//! JSON translation files.
+//!
+//! Comments, trailing commas and the other extensions of JSON5 are accepted,
+//! and comments are kept when a file is rewritten.
+//!
+//! The parser is our own, since JSON5 crates like `json5` drop comments, and
+//! comments have to be attached to the members they belong to for rewriting.
+//! `Infinity` and `NaN` are kept as strings, which are not valid JSON numbers.
+
+use std::collections::HashMap;
+
+use serde_json::{Map, Number, Value};
+
+/// Comments of a JSON document, keyed by the path of the member they belong
+/// to.
+#[derive(Debug, Default)]
+pub(crate) struct Comments {
+    /// Comments on the lines before a member, or before the root value.
+    leading: HashMap<Vec<String>, Vec<String>>,
+    /// A comment after a member on the same line.
+    trailing: HashMap<Vec<String>, String>,
+    /// Comments before the closing bracket of an object or array, or after
+    /// the root value.
+    closing: HashMap<Vec<String>, Vec<String>>,
+}
+
+struct Parser<'s> {
+    src: &'s str,
+    pos: usize,
+    path: Vec<String>,
+    comments: Comments,
+}
+
+impl<'s> Parser<'s> {
+    fn rest(&self) -> &'s str {
+        &self.src[self.pos..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn eat(&mut self, s: &str) -> bool {
+        let matched = self.rest().starts_with(s);
+        if matched {
+            self.pos += s.len();
+        }
+
+        matched
+    }
+
+    fn error(&self, message: &str) -> String {
+        let line = self.src[..self.pos].matches('\n').count() + 1;
+        format!("{} at line {}", message, line)
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start_matches([' ', '\t', '\u{feff}']).len();
+    }
+
+    /// Returns the comment at the current position.
+    fn comment(&mut self) -> Result<Option<String>, String> {
+        let rest = self.rest();
+
+        let end = if rest.starts_with("//") {
+            rest.find(['\n', '\r']).unwrap_or(rest.len())
+        } else if let Some(block) = rest.strip_prefix("/*") {
+            block
+                .find("*/")
+                .map(|i| i + 4)
+                .ok_or_else(|| self.error("unterminated comment"))?
+        } else {
+            return Ok(None);
+        };
+
+        self.pos += end;
+        Ok(Some(rest[..end].trim_end().to_string()))
+    }
+
+    /// Skips whitespaces and newlines, and returns the comments on the way.
+    fn comments(&mut self) -> Result<Vec<String>, String> {
+        let mut comments = Vec::new();
+
+        loop {
+            let rest = self.rest();
+            self.pos += rest.len() - rest.trim_start().len();
+
+            match self.comment()? {
+                Some(comment) => comments.push(comment),
+                None => return Ok(comments),
+            }
+        }
+    }
+
+    /// Returns the comment after a member on the same line.
+    fn trailing(&mut self) -> Result<Option<String>, String> {
+        self.skip_whitespace();
+        self.comment()
+    }
+
+    fn value(&mut self) -> Result<Value, String> {
+        match self.peek() {
+            Some('{') => {
+                self.pos += 1;
+                self.object()
+            }
+            Some('[') => {
+                self.pos += 1;
+                self.array()
+            }
+            Some(quote @ ('"' | '\'')) => {
+                self.pos += 1;
+                self.string(quote).map(Value::String)
+            }
+            _ => self.literal(),
+        }
+    }
+
+    /// Parses the members of an object or array until `close`, with `member`
+    /// parsing a member whose path has been pushed.
+    fn members(
+        &mut self,
+        close: &str,
+        mut member: impl FnMut(&mut Self, usize) -> Result<(), String>,
+    ) -> Result<(), String> {
+        let mut pending = Vec::new();
+
+        for i in 0.. {
+            pending.extend(self.comments()?);
+
+            if self.eat(close) {
+                if !pending.is_empty() {
+                    self.comments.closing.insert(self.path.clone(), pending);
+                }
+                return Ok(());
+            }
+
+            let depth = self.path.len();
+            member(self, i)?;
+
+            if !pending.is_empty() {
+                let path = self.path[..=depth].to_vec();
+                self.comments.leading.insert(path, std::mem::take(&mut pending));
+            }
+
+            self.skip_whitespace();
+            let mut separated = self.eat(",");
+
+            if let Some(comment) = self.trailing()? {
+                self.comments
+                    .trailing
+                    .insert(self.path[..=depth].to_vec(), comment);
+            }
+            self.path.truncate(depth);
+
+            if !separated {
+                pending.extend(self.comments()?);
+                separated = self.eat(",");
+            }
+
+            if !separated && !self.rest().starts_with(close) {
+                return Err(self.error(&format!("expected `,` or `{}`", close)));
+            }
+        }
+
+        unreachable!()
+    }
+
+    fn object(&mut self) -> Result<Value, String> {
+        let mut object = Map::new();
+
+        self.members("}", |parser, _| {
+            let key = parser.key()?;
+
+            if object.contains_key(&key) {
+                return Err(parser.error(&format!("duplicate key `{}`", key)));
+            }
+            parser.path.push(key.clone());
+
+            parser.comments()?;
+            if !parser.eat(":") {
+                return Err(parser.error("expected `:`"));
+            }
+            parser.comments()?;
+
+            object.insert(key, parser.value()?);
+            Ok(())
+        })?;
+
+        Ok(Value::Object(object))
+    }
+
+    fn array(&mut self) -> Result<Value, String> {
+        let mut array = Vec::new();
+
+        self.members("]", |parser, i| {
+            parser.path.push(i.to_string());
+            array.push(parser.value()?);
+            Ok(())
+        })?;
+
+        Ok(Value::Array(array))
+    }
+
+    fn key(&mut self) -> Result<String, String> {
+        if let Some(quote @ ('"' | '\'')) = self.peek() {
+            self.pos += 1;
+            return self.string(quote);
+        }
+
+        let rest = self.rest();
+        let end = rest
+            .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$'))
+            .unwrap_or(rest.len());
+
+        match end {
+            0 => Err(self.error("expected a key")),
+            _ => {
+                self.pos += end;
+                Ok(rest[..end].to_string())
+            }
+        }
+    }
+
+    fn hex(&mut self, len: usize) -> Result<u32, String> {
+        let code = self
+            .rest()
+            .get(..len)
+            .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+            .ok_or_else(|| self.error("invalid escape"))?;
+
+        self.pos += len;
+        Ok(code)
+    }
+
+    fn string(&mut self, quote: char) -> Result<String, String> {
+        let mut ret = String::new();
+
+        loop {
+            let c = self
+                .peek()
+                .ok_or_else(|| self.error("unterminated string"))?;
+            self.pos += c.len_utf8();
+
+            match c {
+                '\\' => {
+                    let c = self.peek().ok_or_else(|| self.error("invalid escape"))?;
+                    self.pos += c.len_utf8();
+
+                    match c {
+                        'b' => ret.push('\x08'),
+                        'f' => ret.push('\x0c'),
+                        'n' => ret.push('\n'),
+                        'r' => ret.push('\r'),
+                        't' => ret.push('\t'),
+                        'v' => ret.push('\x0b'),
+                        '0' => ret.push('\0'),
+                        'x' => {
+                            let code = self.hex(2)?;
+                            ret.push(char::from_u32(code).unwrap_or_default());
+                        }
+                        'u' => {
+                            let mut code = self.hex(4)?;
+
+                            // a surrogate pair
+                            if (0xD800..0xDC00).contains(&code) && self.eat("\\u") {
+                                let low = self.hex(4)?;
+                                if !(0xDC00..0xE000).contains(&low) {
+                                    return Err(self.error("invalid unicode escape"));
+                                }
+
+                                code = 0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00);
+                            }
+
+                            ret.push(
+                                char::from_u32(code)
+                                    .ok_or_else(|| self.error("invalid unicode escape"))?,
+                            );
+                        }
+                        // line continuations
+                        '\r' => {
+                            self.eat("\n");
+                        }
+                        '\n' | '\u{2028}' | '\u{2029}' => {}
+                        c => ret.push(c),
+                    }
+                }
+                '\n' | '\r' => return Err(self.error("unterminated string")),
+                c if c == quote => return Ok(ret),
+                c => ret.push(c),
+            }
+        }
+    }
+
+    fn literal(&mut self) -> Result<Value, String> {
+        let rest = self.rest();
+        let end = rest
+            .find(|c: char| c.is_whitespace() || matches!(c, ',' | ']' | '}' | '/'))
+            .unwrap_or(rest.len());
+        let token = &rest[..end];
+
+        let unsigned = token.trim_start_matches(['+', '-']);
+        let negative = token.starts_with('-');
+
+        let value = match token {
+            "" => None,
+            "null" => Some(Value::Null),
+            "true" => Some(Value::Bool(true)),
+            "false" => Some(Value::Bool(false)),
+            _ if matches!(unsigned, "Infinity" | "NaN") => Some(Value::String(token.to_string())),
+            _ => match unsigned
+                .strip_prefix("0x")
+                .or_else(|| unsigned.strip_prefix("0X"))
+            {
+                Some(hex) => i64::from_str_radix(hex, 16)
+                    .ok()
+                    .map(|n| Value::Number(if negative { -n } else { n }.into())),
+                None => match token.parse::<i64>() {
+                    Ok(n) => Some(Value::Number(n.into())),
+                    Err(_) => token
+                        .parse::<f64>()
+                        .ok()
+                        .and_then(Number::from_f64)
+                        .map(Value::Number),
+                },
+            },
+        };
+
+        let value = value.ok_or_else(|| self.error("expected a value"))?;
+        self.pos += end;
+
+        Ok(value)
+    }
+}
+
+/// Parses a JSON translation file, which may contain comments and the other
+/// extensions of JSON5.
+pub(crate) fn parse_json(source: &str) -> crate::Result<(Value, Comments)> {
+    let mut parser = Parser {
+        src: source,
+        pos: 0,
+        path: Vec::new(),
+        comments: Comments::default(),
+    };
+
+    let leading = parser.comments()?;
+    let value = parser.value()?;
+    let closing = parser.comments()?;
+
+    if parser.peek().is_some() {
+        return Err(parser.error("unexpected trailing characters").into());
+    }
+
+    if !leading.is_empty() {
+        parser.comments.leading.insert(Vec::new(), leading);
+    }
+    if !closing.is_empty() {
+        parser.comments.closing.insert(Vec::new(), closing);
+    }
+
+    Ok((value, parser.comments))
+}
+
+fn write_value(
+    path: &mut Vec<String>,
+    value: &Value,
+    comments: &Comments,
+    indent: usize,
+    out: &mut String,
+) {
+    let members = match value {
+        Value::Object(object) => object
+            .iter()
+            .map(|(k, v)| (k.clone(), Some(k), v))
+            .collect::<Vec<_>>(),
+        Value::Array(array) => array
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (i.to_string(), None, v))
+            .collect(),
+        value => {
+            out.push_str(&value.to_string());
+            return;
+        }
+    };
+    let (open, close) = match value.is_object() {
+        true => ('{', '}'),
+        false => ('[', ']'),
+    };
+    let closing = comments.closing.get(path.as_slice());
+
+    out.push(open);
+
+    if members.is_empty() && closing.is_none() {
+        out.push(close);
+        return;
+    }
+
+    let padding = "  ".repeat(indent + 1);
+
+    for (i, (segment, key, value)) in members.iter().enumerate() {
+        path.push(segment.clone());
+        out.push('\n');
+
+        for comment in comments.leading.get(path.as_slice()).into_iter().flatten() {
+            out.push_str(&format!("{}{}\n", padding, comment));
+        }
+
+        out.push_str(&padding);
+        if let Some(key) = key {
+            out.push_str(&format!("{}: ", Value::String(key.to_string())));
+        }
+        write_value(path, value, comments, indent + 1, out);
+
+        if i + 1 < members.len() {
+            out.push(',');
+        }
+        if let Some(comment) = comments.trailing.get(path.as_slice()) {
+            out.push_str(&format!(" {}", comment));
+        }
+
+        path.pop();
+    }
+
+    for comment in closing.into_iter().flatten() {
+        out.push_str(&format!("\n{}{}", padding, comment));
+    }
+
+    out.push_str(&format!("\n{}{}", "  ".repeat(indent), close));
+}
+
+/// Generates a JSON translation file with `comments` of the previous one.
+pub(crate) fn generate_json(value: &Value, comments: &Comments) -> String {
+    let mut out = String::new();
+
+    for comment in comments.leading.get(&Vec::new()).into_iter().flatten() {
+        out.push_str(&format!("{}\n", comment));
+    }
+
+    write_value(&mut Vec::new(), value, comments, 0, &mut out);
+
+    for comment in comments.closing.get(&Vec::new()).into_iter().flatten() {
+        out.push_str(&format!("\n{}", comment));
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Comments;
+
+    const JSON: &str = r#"// zh-CN translations
+{
+    // greetings
+    "Hello, {}": "你好，{}", // reviewed
+    'Debug: {:?}': '调试：{:?}',
+    /* kept as
+       it is */
+    pua: {
+        "Hello, {}": "要到年底了\
+了",
+        // TODO
+    },
+    "{} files": { one: "{} 个文件", other: "{} 个文件", },
+    "numbers": [0x1F, +1, .5, Infinity, null,],
+}
+"#;
+
+    #[test]
+    fn parse_json_test() {
+        let (value, _) = super::parse_json(JSON).unwrap();
+
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "Hello, {}": "你好，{}",
+                "Debug: {:?}": "调试：{:?}",
+                "pua": { "Hello, {}": "要到年底了了" },
+                "{} files": { "one": "{} 个文件", "other": "{} 个文件" },
+                "numbers": [31, 1, 0.5, "Infinity", null],
+            })
+        );
+
+        assert!(super::parse_json(r#"{ "a": "b" "c": "d" }"#).is_err());
+        assert!(super::parse_json(r#"{ "a": "b", "a": "c" }"#).is_err());
+        assert!(super::parse_json("{ /* a: \"b\" }").is_err());
+    }
+
+    #[test]
+    fn parse_json5_test() {
+        let parse = |source: &str| super::parse_json(source).map(|(value, _)| value);
+
+        // unquoted keys are ECMAScript identifiers
+        assert_eq!(
+            parse("{ hello: 1, $pua_2: 2, 你好: 3 }").unwrap(),
+            serde_json::json!({ "hello": 1, "$pua_2": 2, "你好": 3 })
+        );
+        assert!(parse("{ hello world: 1 }").is_err());
+
+        assert_eq!(
+            parse("[0x1f, 0XFF, -0x10, +0x0]").unwrap(),
+            serde_json::json!([31, 255, -16, 0])
+        );
+        assert!(parse("[0xg]").is_err());
+
+        // escaped line breaks continue strings
+        assert_eq!(
+            parse("['a\\\nb', \"c\\\r\nd\", 'e\\\u{2028}f', 'g\\\\nh']").unwrap(),
+            serde_json::json!(["ab", "cd", "ef", "g\\nh"])
+        );
+        assert!(parse("['a\nb']").is_err());
+
+        assert_eq!(
+            parse("[Infinity, -Infinity, +Infinity, NaN, -NaN, .5, 5., 1e3]").unwrap(),
+            serde_json::json!(["Infinity", "-Infinity", "+Infinity", "NaN", "-NaN", 0.5, 5.0, 1000.0])
+        );
+        assert!(parse("[Inf]").is_err());
+    }
+
+    #[test]
+    fn generate_json_test() {
+        let value = serde_json::json!({
+            "Hello, {}": "你好，{}",
+            "Debug: {:?}": "调试：{:?}",
+            "list": ["first", {}],
+            "pua": { "Hello, {}": "" },
+        });
+
+        assert_eq!(
+            serde_json::to_string_pretty(&value).unwrap(),
+            super::generate_json(&value, &Comments::default())
+        );
+
+        let (_, comments) = super::parse_json(JSON).unwrap();
+        let value = serde_json::json!({
+            "Debug: {:?}": "调试：{:?}",
+            "Goodbye, {name}": "[TODO] {name}",
+            "Hello, {}": "你好，{}",
+            "pua": { "Hello, {}": "要到年底了" },
+        });
+
+        assert_eq!(
+            super::generate_json(&value, &comments),
+            r#"// zh-CN translations
+{
+  "Debug: {:?}": "调试：{:?}",
+  "Goodbye, {name}": "[TODO] {name}",
+  // greetings
+  "Hello, {}": "你好，{}", // reviewed
+  /* kept as
+       it is */
+  "pua": {
+    "Hello, {}": "要到年底了"
+    // TODO
+  }
+}"#
+        );
+    }
+}
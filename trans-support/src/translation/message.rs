@@ -0,0 +1,127 @@
+/// Checks ICU MessageFormat arguments in `text`, like
+/// `{gender, select, female {She} other {They}}`.
+///
+/// Only `select`, `plural` and `selectordinal` arguments are supported, and
+/// each of them must have an `other` message.
+pub fn check_message(text: &str) -> Result<(), String> {
+    check(text, false)
+}
+
+fn check(text: &str, nested: bool) -> Result<(), String> {
+    let mut rest = text;
+
+    while let Some(i) = rest.find('{') {
+        let tail = &rest[i + 1..];
+
+        if let Some(tail) = tail.strip_prefix('{').filter(|_| !nested) {
+            rest = tail;
+            continue;
+        }
+
+        let end = closing(tail).ok_or_else(|| format!("unmatched brace in `{}`", text))?;
+        check_argument(&tail[..end])?;
+        rest = &tail[end + 1..];
+    }
+
+    Ok(())
+}
+
+fn check_argument(argument: &str) -> Result<(), String> {
+    // not an ICU argument, like `{name:>8}`
+    if argument.find([',', '{', '}', ':']).map(|i| &argument[i..i + 1]) != Some(",") {
+        return Ok(());
+    }
+
+    let mut parts = argument.splitn(3, ',').skip(1);
+    let (kind, mut options) = match (parts.next(), parts.next()) {
+        (Some(kind), Some(options)) => (kind.trim(), options),
+        _ => return Err(format!("missing messages in `{{{}}}`", argument)),
+    };
+
+    if !matches!(kind, "select" | "plural" | "selectordinal") {
+        return Err(format!("unsupported argument type `{}`", kind));
+    }
+
+    let mut other = false;
+
+    loop {
+        options = options.trim_start();
+        if options.is_empty() {
+            break;
+        }
+
+        let i = options
+            .find(|c: char| c.is_whitespace() || c == '{')
+            .unwrap_or(options.len());
+        let (selector, rest) = options.split_at(i);
+        let message = rest
+            .trim_start()
+            .strip_prefix('{')
+            .ok_or_else(|| format!("missing message of `{}` in `{{{}}}`", selector, argument))?;
+        let end = closing(message).ok_or_else(|| format!("unmatched brace in `{}`", message))?;
+
+        check(&message[..end], true)?;
+        other |= selector == "other";
+        options = &message[end + 1..];
+    }
+
+    match other {
+        true => Ok(()),
+        false => Err(format!("missing `other` message in `{{{}}}`", argument)),
+    }
+}
+
+/// Returns the index of the `}` closing a placeholder or sub-message, `s`
+/// starts after the opening `{`. Apostrophes quote braces like ICU
+/// MessageFormat.
+pub(crate) fn closing(s: &str) -> Option<usize> {
+    let mut depth = 0;
+    let mut quoted = false;
+    let mut chars = s.char_indices().peekable();
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\'' if quoted => quoted = false,
+            '\'' if matches!(chars.peek(), Some((_, '\''))) => {
+                chars.next();
+            }
+            '\'' if matches!(chars.peek(), Some((_, '{' | '}' | '#'))) => quoted = true,
+            _ if quoted => {}
+            '{' => depth += 1,
+            '}' if depth == 0 => return Some(i),
+            '}' => depth -= 1,
+            _ => {}
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::check_message;
+
+    #[test]
+    fn check_message_test() {
+        for text in [
+            "{{literal}} {} {0:>8} {name:,>8}",
+            "{gender, select, female {She} male {He} other {They}} liked your post",
+            "{count, plural, =0 {no files} one {# file} other {# files}}",
+            "{n, selectordinal, one {#st} two {#nd} few {#rd} other {#th}}",
+            "{gender, select, female {{count, plural, one {'{'#'}'} other {#}}} other {}}",
+        ] {
+            assert_eq!(Ok(()), check_message(text), "{}", text);
+        }
+
+        for text in [
+            "{gender, select, female {She}}",
+            "{gender, choice, other {They}}",
+            "{gender, select, other {They}",
+            "{gender, select, other They}",
+            "{gender, select}",
+            "{gender, select, female {{count, plural, one {#}}} other {}}",
+        ] {
+            assert!(check_message(text).is_err(), "{}", text);
+        }
+    }
+}
@@ -1 +1,4 @@
-mod_use::mod_use!(extractor, generator);
+mod_use::mod_use!(
+    android, apple, arb, csv, extractor, fluent, generator, gettext, i18next, json, message, model,
+    printf, toml, xliff, xml, yaml
+);
@@ -0,0 +1,115 @@
+use std::{collections::BTreeMap, fmt::Display, str::FromStr};
+
+use super::check_message;
+
+/// CLDR plural category.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+impl PluralCategory {
+    pub const ALL: [PluralCategory; 6] = [
+        PluralCategory::Zero,
+        PluralCategory::One,
+        PluralCategory::Two,
+        PluralCategory::Few,
+        PluralCategory::Many,
+        PluralCategory::Other,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PluralCategory::Zero => "zero",
+            PluralCategory::One => "one",
+            PluralCategory::Two => "two",
+            PluralCategory::Few => "few",
+            PluralCategory::Many => "many",
+            PluralCategory::Other => "other",
+        }
+    }
+}
+
+impl FromStr for PluralCategory {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|c| c.as_str() == s)
+            .ok_or_else(|| format!("Invalid plural category: {}", s))
+    }
+}
+
+impl Display for PluralCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A translated text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Translation {
+    Text(String),
+    /// Plural forms, which always contain [`PluralCategory::Other`].
+    Plural(BTreeMap<PluralCategory, String>),
+}
+
+/// Splits a translation key into its prefix and content, like `.pua` and
+/// `Hello, {}` of `".pua Hello, {}"`.
+pub fn split_key(key: &str) -> crate::Result<(&str, &str)> {
+    Ok(key
+        .split_once(' ')
+        .ok_or("Can not find whitespace in key")?)
+}
+
+impl Translation {
+    /// Checks ICU MessageFormat arguments of the text or all plural forms,
+    /// see [`check_message`].
+    pub fn check(&self) -> Result<(), String> {
+        match self {
+            Translation::Text(text) => check_message(text),
+            Translation::Plural(forms) => forms.values().try_for_each(|form| check_message(form)),
+        }
+    }
+}
+
+impl From<String> for Translation {
+    fn from(text: String) -> Self {
+        Translation::Text(text)
+    }
+}
+
+impl From<&str> for Translation {
+    fn from(text: &str) -> Self {
+        Translation::Text(text.to_string())
+    }
+}
+
+/// Translations of a text, a prefixed text and a plural text, which are
+/// exported by tests of formats.
+#[cfg(test)]
+pub(crate) fn fixture() -> std::collections::HashMap<String, Translation> {
+    [
+        (" Hello, {}".to_string(), "你好，\"{}\"".into()),
+        (".pua Hello, {}".to_string(), "要到年底了，{}".into()),
+        (
+            " {} files".to_string(),
+            Translation::Plural(
+                [
+                    (PluralCategory::One, "{} файл".to_string()),
+                    (PluralCategory::Other, "{} файлов".to_string()),
+                ]
+                .into_iter()
+                .collect(),
+            ),
+        ),
+    ]
+    .into_iter()
+    .collect()
+}
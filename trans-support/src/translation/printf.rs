@@ -0,0 +1,125 @@
+//! printf-style format strings of native string resources.
+//!
+//! Arguments are numbered by the order they first appear in the source text,
+//! and `{}` of plural forms is always the count.
+
+use crate::source::{segments, Segment};
+
+/// Returns names of arguments of `source` in the order they first appear.
+fn arguments(source: &str, plural: bool) -> Vec<String> {
+    let mut arguments = Vec::new();
+
+    for segment in segments(source, plural) {
+        if let Segment::Placeholder { name, .. } = segment {
+            if !arguments.contains(&name) {
+                arguments.push(name);
+            }
+        }
+    }
+
+    arguments
+}
+
+/// Returns the position of the count of plural forms translated from
+/// `source`, which starts from 1 like positional format specifiers.
+pub(crate) fn printf_count(source: &str) -> usize {
+    let arguments = arguments(source, true);
+
+    arguments
+        .iter()
+        .position(|a| a == "count")
+        .unwrap_or(arguments.len())
+        + 1
+}
+
+/// Rewrites placeholders of `text`, which is translated from `source`, to
+/// positional format specifiers, and `%` is escaped if there are any.
+///
+/// Arguments are converted by `object`, like `s` of Android or `@` of Apple,
+/// except counts of plural forms are `d` and precisions like `{:.2}` are `.2f`.
+/// ICU MessageFormat arguments are kept as texts.
+pub(crate) fn printf_format(source: &str, text: &str, plural: bool, object: &str) -> String {
+    let mut arguments = arguments(source, plural);
+
+    let segments = segments(text, plural);
+    // texts without arguments are not formatted by platforms
+    let escape = segments
+        .iter()
+        .any(|s| matches!(s, Segment::Placeholder { .. }));
+    let mut ret = String::new();
+
+    for segment in segments {
+        let text = match segment {
+            Segment::Text(text) => text,
+            Segment::Message(message) => format!("{{{}}}", message),
+            Segment::Placeholder { name, spec } => {
+                let conversion = match spec.rsplit_once('.') {
+                    Some((_, precision)) if precision.chars().all(|c| c.is_ascii_digit()) => {
+                        format!(".{}f", precision)
+                    }
+                    _ if plural && name == "count" => "d".to_string(),
+                    _ => object.to_string(),
+                };
+
+                let index = match arguments.iter().position(|a| *a == name) {
+                    Some(index) => index,
+                    None => {
+                        arguments.push(name);
+                        arguments.len() - 1
+                    }
+                };
+
+                ret.push_str(&format!("%{}${}", index + 1, conversion));
+                continue;
+            }
+        };
+
+        match escape {
+            true => ret.push_str(&text.replace('%', "%%")),
+            false => ret.push_str(&text),
+        }
+    }
+
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn printf_format_test() {
+        assert_eq!(
+            "你好，%1$s 和 %2$s",
+            super::printf_format("Hello, {} and {}", "你好，{} 和 {}", false, "s")
+        );
+        assert_eq!(
+            "%2$@ 收到了 %1$@ 的消息",
+            super::printf_format("{0} sent {1} a message", "{1} 收到了 {0} 的消息", false, "@")
+        );
+        assert_eq!(
+            "%2$d 条来自 %1$s 的消息",
+            super::printf_format(
+                "{name} sent {} messages",
+                "{} 条来自 {name} 的消息",
+                true,
+                "s"
+            )
+        );
+        assert_eq!(
+            "合计：%1$.2f%% {}",
+            super::printf_format("Total: {:.2}", "合计：{:.2}% {{}}", false, "s")
+        );
+        assert_eq!("100% 完成", super::printf_format("100% done", "100% 完成", false, "s"));
+        assert_eq!(1, super::printf_count("{} files"));
+        assert_eq!(2, super::printf_count("{name} sent {} messages"));
+        assert_eq!(1, super::printf_count("No files"));
+        assert_eq!(
+            "{gender, select, female {她} other {他们}}赞了%1$s",
+            super::printf_format(
+                "{gender, select, female {She} other {They}} liked {}",
+                "{gender, select, female {她} other {他们}}赞了{}",
+                false,
+                "s"
+            )
+        );
+    }
+}
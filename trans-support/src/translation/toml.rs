@@ -0,0 +1,153 @@
+//! TOML translation files, which are parsed by `toml`.
+
+use serde_json::{Map, Value};
+
+/// Parses a TOML translation file into JSON values.
+pub(crate) fn parse_toml(source: &str) -> crate::Result<Value> {
+    Ok(toml::from_str::<Value>(source).map_err(|e| format!("Invalid TOML: {}", e))?)
+}
+
+fn key(key: &str) -> String {
+    let bare = !key.is_empty()
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+
+    match bare {
+        true => key.to_string(),
+        false => serde_json::to_string(key).unwrap(),
+    }
+}
+
+fn string(s: &str) -> String {
+    match s.contains('\n') {
+        true => {
+            let mut ret = String::from("\"\"\"\n");
+
+            for c in s.chars() {
+                match c {
+                    '\\' => ret.push_str("\\\\"),
+                    '"' => ret.push_str("\\\""),
+                    '\n' | '\t' => ret.push(c),
+                    c if c.is_control() => ret.push_str(&format!("\\u{:04X}", c as u32)),
+                    c => ret.push(c),
+                }
+            }
+
+            ret.push_str("\"\"\"");
+            ret
+        }
+        false => serde_json::to_string(s).unwrap(),
+    }
+}
+
+fn write_table(path: &mut Vec<String>, table: &Map<String, Value>, out: &mut String) {
+    for (k, value) in table {
+        match value {
+            Value::Object(_) | Value::Null => {}
+            Value::String(s) => out.push_str(&format!("{} = {}\n", key(k), string(s))),
+            value => out.push_str(&format!("{} = {}\n", key(k), value)),
+        }
+    }
+
+    for (k, value) in table {
+        if let Value::Object(child) = value {
+            path.push(key(k));
+
+            if !out.is_empty() {
+                out.push('\n');
+            }
+            out.push_str(&format!("[{}]\n", path.join(".")));
+            write_table(path, child, out);
+
+            path.pop();
+        }
+    }
+}
+
+/// Generates a TOML translation file of nested translations.
+pub(crate) fn generate_toml(value: &Value) -> String {
+    let mut out = String::new();
+
+    if let Value::Object(table) = value {
+        write_table(&mut Vec::new(), table, &mut out);
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    const TOML: &str = r#"# translations of zh-CN
+"Hello, {}" = "你好，{}"
+'Debug: {:?}' = '调试：{:?}' # comment
+"{} files" = { one = "{} 个文件", other = "{} 个\u6587件" }
+count = 1_000
+multi = """
+First line\t
+  indented \
+  continued
+"""
+raw = '''
+C:\path
+'''
+list = [
+    "a", # comment
+    'b',
+]
+
+[pua]
+"Hello, {}" = "打地铺"
+nested.key = "value"
+
+[pua."{name}"]
+other = "{name}"
+"#;
+
+    #[test]
+    fn parse_toml_test() {
+        assert_eq!(
+            super::parse_toml(TOML).unwrap(),
+            json!({
+                "Hello, {}": "你好，{}",
+                "Debug: {:?}": "调试：{:?}",
+                "{} files": {
+                    "one": "{} 个文件",
+                    "other": "{} 个文件"
+                },
+                "count": 1000,
+                "multi": "First line\t\n  indented continued\n",
+                "raw": "C:\\path\n",
+                "list": ["a", "b"],
+                "pua": {
+                    "Hello, {}": "打地铺",
+                    "nested": { "key": "value" },
+                    "{name}": { "other": "{name}" }
+                }
+            })
+        );
+
+        for toml in ["a = \"b", "a = 1\na = 2", "a = \"b\" c", "a"] {
+            assert!(super::parse_toml(toml).is_err(), "{}", toml);
+        }
+    }
+
+    #[test]
+    fn generate_toml_test() {
+        let value = json!({
+            "Hello, {}": "你好，{}",
+            "{} files": { "one": "{} file", "other": "{} files" },
+            "multi": "First \"line\"\n\nsecond\\\n",
+            "pua": {
+                "Hello, {}": "Hello",
+                "evil": { "{}": "{}" }
+            }
+        });
+
+        let toml = super::generate_toml(&value);
+        assert_eq!(value, super::parse_toml(&toml).unwrap(), "{}", toml);
+        assert!(toml.contains("[pua.evil]\n\"{}\" = \"{}\"\n"));
+    }
+}
@@ -0,0 +1,409 @@
+//! XLIFF 2.0 files exchanged with CAT tools.
+//!
+//! Prefixes are nested `<group>`s named by their parts, source references
+//! are `location` notes, and plural forms are segments identified by their
+//! categories.
+
+use std::collections::{BTreeMap, HashMap};
+
+use super::{
+    escape_xml, parse_xml, plural_categories_of, split_key, Element, PluralCategory, Translation,
+};
+
+/// A message to export to XLIFF.
+pub struct XliffMessage<'a> {
+    /// Key of the message, like `".pua Hello, {}"`.
+    pub key: &'a str,
+    /// Whether the message has plural forms.
+    pub plural: bool,
+    /// Source references, like `src/main.rs:5`.
+    pub references: Vec<String>,
+    pub translation: Option<&'a Translation>,
+}
+
+/// A translated unit of a XLIFF file.
+#[derive(Debug, PartialEq)]
+pub struct XliffUnit {
+    /// Key of the unit, like `".pua Hello, {}"`.
+    pub key: String,
+    /// The translation, or why the unit is rejected.
+    pub translation: Result<Translation, String>,
+}
+
+/// A XLIFF file with translated units.
+#[derive(Debug, PartialEq)]
+pub struct Xliff {
+    pub target_language: Option<String>,
+    pub units: Vec<XliffUnit>,
+}
+
+#[derive(Default)]
+struct Group<'m, 'a> {
+    units: Vec<&'m XliffMessage<'a>>,
+    groups: BTreeMap<&'a str, Group<'m, 'a>>,
+}
+
+struct Writer<'l> {
+    document: String,
+    categories: &'l [PluralCategory],
+    units: usize,
+}
+
+impl Writer<'_> {
+    fn line(&mut self, depth: usize, line: &str) {
+        self.document.push_str(&"  ".repeat(depth));
+        self.document.push_str(line);
+        self.document.push('\n');
+    }
+
+    fn segment(&mut self, depth: usize, id: Option<&str>, source: &str, target: Option<&str>) {
+        let id = id.map_or(String::new(), |id| format!(" id=\"{}\"", id));
+        let state = match target {
+            Some(_) => "translated",
+            None => "initial",
+        };
+
+        self.line(depth, &format!("<segment{} state=\"{}\">", id, state));
+        self.line(depth + 1, &format!("<source>{}</source>", escape_xml(source)));
+        if let Some(target) = target {
+            self.line(depth + 1, &format!("<target>{}</target>", escape_xml(target)));
+        }
+        self.line(depth, "</segment>");
+    }
+
+    fn unit(&mut self, depth: usize, message: &XliffMessage) -> crate::Result<()> {
+        let (_, content) = split_key(message.key)?;
+
+        self.units += 1;
+        self.line(depth, &format!("<unit id=\"u{}\">", self.units));
+
+        if !message.references.is_empty() {
+            self.line(depth + 1, "<notes>");
+            for reference in &message.references {
+                self.line(
+                    depth + 2,
+                    &format!("<note category=\"location\">{}</note>", escape_xml(reference)),
+                );
+            }
+            self.line(depth + 1, "</notes>");
+        }
+
+        match message.translation {
+            Some(Translation::Plural(forms)) => {
+                for category in self.categories {
+                    let form = forms
+                        .get(category)
+                        .or_else(|| forms.get(&PluralCategory::Other));
+                    self.segment(
+                        depth + 1,
+                        Some(category.as_str()),
+                        content,
+                        form.map(|f| f.as_str()),
+                    );
+                }
+            }
+            None if message.plural => {
+                for category in self.categories {
+                    self.segment(depth + 1, Some(category.as_str()), content, None);
+                }
+            }
+            Some(Translation::Text(text)) => {
+                self.segment(depth + 1, None, content, Some(text));
+            }
+            None => self.segment(depth + 1, None, content, None),
+        }
+
+        self.line(depth, "</unit>");
+        Ok(())
+    }
+
+    fn group(&mut self, depth: usize, path: &str, group: &Group<'_, '_>) -> crate::Result<()> {
+        for message in &group.units {
+            self.unit(depth, message)?;
+        }
+
+        for (name, child) in &group.groups {
+            let id = match path {
+                "" => name.to_string(),
+                path => format!("{}.{}", path, name),
+            };
+
+            self.line(
+                depth,
+                &format!(
+                    "<group id=\"{}\" name=\"{}\">",
+                    escape_xml(&id),
+                    escape_xml(name)
+                ),
+            );
+            self.group(depth + 1, &id, child)?;
+            self.line(depth, "</group>");
+        }
+
+        Ok(())
+    }
+}
+
+/// Generates a XLIFF file from texts in `source_language` to translations in
+/// `target_language`.
+pub fn generate_xliff(
+    source_language: &str,
+    target_language: &str,
+    messages: &[XliffMessage],
+) -> crate::Result<String> {
+    let mut root = Group::default();
+
+    for message in messages {
+        let (prefix, _) = split_key(message.key)?;
+
+        prefix
+            .split('.')
+            .filter(|part| !part.is_empty())
+            .fold(&mut root, |group, part| group.groups.entry(part).or_default())
+            .units
+            .push(message);
+    }
+
+    let categories = plural_categories_of(target_language);
+    let mut writer = Writer {
+        document: String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n"),
+        categories: &categories,
+        units: 0,
+    };
+
+    writer.line(
+        0,
+        &format!(
+            "<xliff xmlns=\"urn:oasis:names:tc:xliff:document:2.0\" version=\"2.0\" \
+             srcLang=\"{}\" trgLang=\"{}\">",
+            escape_xml(source_language),
+            escape_xml(target_language)
+        ),
+    );
+    writer.line(1, "<file id=\"f1\">");
+    writer.group(2, "", &root)?;
+    writer.line(1, "</file>");
+    writer.line(0, "</xliff>");
+
+    Ok(writer.document)
+}
+
+/// Returns the translated unit of `unit`, or `None` if it is not translated.
+fn parse_unit(prefix: &str, unit: &Element) -> Option<XliffUnit> {
+    let segments = unit
+        .elements()
+        .filter(|e| e.name == "segment")
+        .collect::<Vec<_>>();
+
+    let plural = segments
+        .iter()
+        .all(|s| s.attribute("id").is_some_and(|id| id.parse::<PluralCategory>().is_ok()));
+
+    // plural forms share the same source, while a text may be split into
+    // segments
+    let sources = segments
+        .iter()
+        .filter_map(|s| Some(s.element("source")?.text()));
+    let source = match plural {
+        true => sources.take(1).collect::<String>(),
+        false => sources.collect(),
+    };
+    let key = format!("{} {}", prefix, source);
+
+    let targets = segments
+        .iter()
+        .filter_map(|s| Some((s.attribute("id"), s.element("target")?.text())))
+        .filter(|(_, target)| !target.is_empty())
+        .collect::<Vec<_>>();
+
+    if targets.is_empty() {
+        return None;
+    }
+
+    let translation = match plural {
+        true => {
+            let forms = targets
+                .into_iter()
+                .filter_map(|(id, target)| Some((id?.parse().ok()?, target)))
+                .collect::<HashMap<PluralCategory, String>>();
+
+            match forms.contains_key(&PluralCategory::Other) {
+                true => Ok(Translation::Plural(forms.into_iter().collect())),
+                false => Err("missing the `other` plural form".to_string()),
+            }
+        }
+        false => match targets.len() == segments.len() {
+            true => Ok(Translation::Text(
+                targets.into_iter().map(|(_, target)| target).collect(),
+            )),
+            false => Err("some segments are not translated".to_string()),
+        },
+    };
+
+    Some(XliffUnit { key, translation })
+}
+
+fn parse_group(prefix: &str, group: &Element, units: &mut Vec<XliffUnit>) {
+    for element in group.elements() {
+        match element.name.as_str() {
+            "unit" => units.extend(parse_unit(prefix, element)),
+            "group" => {
+                let name = element
+                    .attribute("name")
+                    .or_else(|| element.attribute("id"))
+                    .unwrap_or_default();
+
+                parse_group(&format!("{}.{}", prefix, name), element, units);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Parses translated units of a XLIFF 2.0 file.
+pub fn parse_xliff(source: &str) -> crate::Result<Xliff> {
+    let root = parse_xml(source)?;
+
+    if root.name != "xliff" || root.attribute("version").is_some_and(|v| !v.starts_with('2')) {
+        return Err("not a XLIFF 2.0 file".into());
+    }
+
+    let mut units = Vec::new();
+    for file in root.elements().filter(|e| e.name == "file") {
+        parse_group("", file, &mut units);
+    }
+
+    Ok(Xliff {
+        target_language: root.attribute("trgLang").map(|l| l.to_string()),
+        units,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::translation::{fixture, PluralCategory, Translation};
+
+    #[test]
+    fn generate_xliff_test() {
+        let translations = fixture();
+        let message = |key: &'static str, plural, references: &[&str]| super::XliffMessage {
+            key,
+            plural,
+            references: references.iter().map(|r| r.to_string()).collect(),
+            translation: translations.get(key),
+        };
+        let messages = [
+            message(" Hello, {}", false, &["src/main.rs:7"]),
+            message(" {} files", true, &[]),
+            message(".pua Hello, {}", false, &[]),
+            message(".pua.evil <{}> & {}", false, &[]),
+        ];
+
+        let xliff = super::generate_xliff("en", "de", &messages).unwrap();
+        assert_eq!(
+            xliff,
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<xliff xmlns="urn:oasis:names:tc:xliff:document:2.0" version="2.0" srcLang="en" trgLang="de">
+  <file id="f1">
+    <unit id="u1">
+      <notes>
+        <note category="location">src/main.rs:7</note>
+      </notes>
+      <segment state="translated">
+        <source>Hello, {}</source>
+        <target>你好，&quot;{}&quot;</target>
+      </segment>
+    </unit>
+    <unit id="u2">
+      <segment id="one" state="translated">
+        <source>{} files</source>
+        <target>{} файл</target>
+      </segment>
+      <segment id="other" state="translated">
+        <source>{} files</source>
+        <target>{} файлов</target>
+      </segment>
+    </unit>
+    <group id="pua" name="pua">
+      <unit id="u3">
+        <segment state="translated">
+          <source>Hello, {}</source>
+          <target>要到年底了，{}</target>
+        </segment>
+      </unit>
+      <group id="pua.evil" name="evil">
+        <unit id="u4">
+          <segment state="initial">
+            <source>&lt;{}&gt; &amp; {}</source>
+          </segment>
+        </unit>
+      </group>
+    </group>
+  </file>
+</xliff>
+"#
+        );
+
+        let parsed = super::parse_xliff(&xliff).unwrap();
+        assert_eq!(Some("de"), parsed.target_language.as_deref());
+        assert_eq!(
+            parsed
+                .units
+                .into_iter()
+                .map(|unit| (unit.key, unit.translation.unwrap()))
+                .collect::<std::collections::HashMap<_, _>>(),
+            translations
+        );
+    }
+
+    #[test]
+    fn parse_xliff_test() {
+        let xliff = r#"<xliff xmlns="urn:oasis:names:tc:xliff:document:2.0" version="2.0" srcLang="en" trgLang="ru">
+  <file id="f1">
+    <unit id="u1">
+      <segment id="one"><source>{} files</source><target>{} файл</target></segment>
+      <segment id="few"><source>{} files</source><target>{} файла</target></segment>
+      <segment id="other"><source>{} files</source><target>{} файлов</target></segment>
+    </unit>
+    <group id="g1" name="pua">
+      <unit id="u2">
+        <segment><source>Hello, </source><target>Привет, </target></segment>
+        <segment><source>{}</source><target>{}</target></segment>
+      </unit>
+      <unit id="u3">
+        <segment id="one"><source>{} days</source><target>{} день</target></segment>
+        <segment id="other"><source>{} days</source></segment>
+      </unit>
+    </group>
+  </file>
+</xliff>"#;
+
+        let parsed = super::parse_xliff(xliff).unwrap();
+        assert_eq!(
+            parsed.units,
+            vec![
+                super::XliffUnit {
+                    key: " {} files".to_string(),
+                    translation: Ok(Translation::Plural(
+                        [
+                            (PluralCategory::One, "{} файл"),
+                            (PluralCategory::Few, "{} файла"),
+                            (PluralCategory::Other, "{} файлов")
+                        ]
+                        .into_iter()
+                        .map(|(c, f)| (c, f.to_string()))
+                        .collect()
+                    )),
+                },
+                super::XliffUnit {
+                    key: ".pua Hello, {}".to_string(),
+                    translation: Ok("Привет, {}".into()),
+                },
+                super::XliffUnit {
+                    key: ".pua {} days".to_string(),
+                    translation: Err("missing the `other` plural form".to_string()),
+                },
+            ]
+        );
+    }
+}
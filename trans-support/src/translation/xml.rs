@@ -0,0 +1,289 @@
+//! A minimal XML reader for exchange formats.
+//!
+//! Namespace prefixes of names are dropped, and DTDs, processing instructions
+//! and comments are skipped.
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Node {
+    Element(Element),
+    Text(String),
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(crate) struct Element {
+    pub name: String,
+    pub attributes: Vec<(String, String)>,
+    pub children: Vec<Node>,
+}
+
+impl Element {
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    pub fn elements(&self) -> impl Iterator<Item = &Element> {
+        self.children.iter().filter_map(|node| match node {
+            Node::Element(element) => Some(element),
+            Node::Text(_) => None,
+        })
+    }
+
+    pub fn element(&self, name: &str) -> Option<&Element> {
+        self.elements().find(|e| e.name == name)
+    }
+
+    /// Returns the text content of the element and its descendants.
+    pub fn text(&self) -> String {
+        self.children
+            .iter()
+            .map(|node| match node {
+                Node::Element(element) => element.text(),
+                Node::Text(text) => text.clone(),
+            })
+            .collect()
+    }
+}
+
+struct Parser<'s> {
+    src: &'s str,
+    pos: usize,
+}
+
+impl<'s> Parser<'s> {
+    fn rest(&self) -> &'s str {
+        &self.src[self.pos..]
+    }
+
+    fn eat(&mut self, s: &str) -> bool {
+        let matched = self.rest().starts_with(s);
+        if matched {
+            self.pos += s.len();
+        }
+
+        matched
+    }
+
+    fn error(&self, message: &str) -> String {
+        let line = self.src[..self.pos].matches('\n').count() + 1;
+        format!("{} at line {}", message, line)
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    /// Skips the rest of a markup until `end`.
+    fn skip_until(&mut self, end: &str) -> Result<&'s str, String> {
+        let rest = self.rest();
+        let i = rest
+            .find(end)
+            .ok_or_else(|| self.error(&format!("expected `{}`", end)))?;
+
+        self.pos += i + end.len();
+        Ok(&rest[..i])
+    }
+
+    /// Skips declarations, processing instructions and comments.
+    fn skip_misc(&mut self) -> Result<(), String> {
+        loop {
+            self.skip_whitespace();
+
+            if self.eat("<?") {
+                self.skip_until("?>")?;
+            } else if self.eat("<!--") {
+                self.skip_until("-->")?;
+            } else if self.eat("<!") {
+                self.skip_until(">")?;
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    fn name(&mut self) -> Result<String, String> {
+        let rest = self.rest();
+        let end = rest
+            .find(|c: char| c.is_whitespace() || matches!(c, '/' | '>' | '='))
+            .unwrap_or(rest.len());
+
+        if end == 0 {
+            return Err(self.error("expected a name"));
+        }
+
+        self.pos += end;
+        let name = &rest[..end];
+
+        Ok(name.rsplit(':').next().unwrap_or(name).to_string())
+    }
+
+    /// Parses an element after `<`.
+    fn element(&mut self) -> Result<Element, String> {
+        let mut element = Element {
+            name: self.name()?,
+            ..Default::default()
+        };
+
+        loop {
+            self.skip_whitespace();
+
+            if self.eat("/>") {
+                return Ok(element);
+            }
+            if self.eat(">") {
+                break;
+            }
+
+            let name = self.name()?;
+            self.skip_whitespace();
+            if !self.eat("=") {
+                return Err(self.error("expected `=`"));
+            }
+            self.skip_whitespace();
+
+            let quote = match self.rest().chars().next() {
+                Some(quote @ ('"' | '\'')) => quote,
+                _ => return Err(self.error("expected a quoted value")),
+            };
+            self.pos += 1;
+
+            let value = self.skip_until(&quote.to_string())?;
+            element
+                .attributes
+                .push((name, unescape(value).map_err(|e| self.error(&e))?));
+        }
+
+        loop {
+            if self.eat("</") {
+                let name = self.name()?;
+                if name != element.name {
+                    return Err(self.error(&format!("expected `</{}>`", element.name)));
+                }
+
+                self.skip_whitespace();
+                if !self.eat(">") {
+                    return Err(self.error("expected `>`"));
+                }
+
+                return Ok(element);
+            }
+
+            if self.eat("<![CDATA[") {
+                let text = self.skip_until("]]>")?;
+                element.children.push(Node::Text(text.to_string()));
+            } else if self.eat("<!--") {
+                self.skip_until("-->")?;
+            } else if self.eat("<?") {
+                self.skip_until("?>")?;
+            } else if self.eat("<") {
+                element.children.push(Node::Element(self.element()?));
+            } else {
+                let rest = self.rest();
+                let end = rest.find('<').ok_or_else(|| self.error("unclosed element"))?;
+                let text = unescape(&rest[..end]).map_err(|e| self.error(&e))?;
+
+                self.pos += end;
+                element.children.push(Node::Text(text));
+            }
+        }
+    }
+}
+
+fn unescape(s: &str) -> Result<String, String> {
+    let mut ret = String::new();
+    let mut rest = s;
+
+    while let Some(i) = rest.find('&') {
+        ret.push_str(&rest[..i]);
+        rest = &rest[i + 1..];
+
+        let end = rest.find(';').ok_or("unterminated entity")?;
+        let entity = &rest[..end];
+        rest = &rest[end + 1..];
+
+        let c = match entity {
+            "lt" => '<',
+            "gt" => '>',
+            "amp" => '&',
+            "quot" => '"',
+            "apos" => '\'',
+            _ => entity
+                .strip_prefix("#x")
+                .map(|hex| u32::from_str_radix(hex, 16))
+                .or_else(|| entity.strip_prefix('#').map(|n| n.parse()))
+                .and_then(|code| char::from_u32(code.ok()?))
+                .ok_or_else(|| format!("unknown entity `&{};`", entity))?,
+        };
+
+        ret.push(c);
+    }
+
+    ret.push_str(rest);
+    Ok(ret)
+}
+
+/// Escapes `s` for texts and attribute values.
+pub(crate) fn escape_xml(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Parses the root element of a XML document.
+pub(crate) fn parse_xml(source: &str) -> crate::Result<Element> {
+    let mut parser = Parser { src: source, pos: 0 };
+
+    parser.skip_misc()?;
+    if !parser.eat("<") {
+        return Err(parser.error("expected the root element").into());
+    }
+
+    let root = parser.element()?;
+    parser.skip_misc()?;
+
+    match parser.rest().is_empty() {
+        true => Ok(root),
+        false => Err(parser.error("unexpected content after the root element").into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Element, Node};
+
+    #[test]
+    fn parse_xml_test() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!-- a comment -->
+<xliff xmlns="urn:oasis:names:tc:xliff:document:2.0" version="2.0">
+  <unit id='u1'><source>Hello, &lt;{}&gt; &amp; &#x4F60;</source><!-- skipped -->
+    <target><![CDATA[<你好>]]></target><x:empty/>
+  </unit>
+</xliff>
+"#;
+
+        let root = super::parse_xml(xml).unwrap();
+        assert_eq!("xliff", root.name);
+        assert_eq!(Some("2.0"), root.attribute("version"));
+
+        let unit = root.element("unit").unwrap();
+        assert_eq!(Some("u1"), unit.attribute("id"));
+        assert_eq!("Hello, <{}> & 你", unit.element("source").unwrap().text());
+        assert_eq!("<你好>", unit.element("target").unwrap().text());
+        assert_eq!(
+            Some(&Element {
+                name: "empty".to_string(),
+                ..Default::default()
+            }),
+            unit.element("empty")
+        );
+        assert!(matches!(unit.children[1], Node::Text(_)));
+
+        assert!(super::parse_xml("<a><b></a>").is_err());
+        assert!(super::parse_xml("<a>&unknown;</a>").is_err());
+    }
+}
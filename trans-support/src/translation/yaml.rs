@@ -0,0 +1,182 @@
+//! YAML translation files.
+//!
+//! Files are parsed by `serde_yaml`, and a single document is allowed.
+
+use serde_json::Value;
+
+/// Parses a YAML translation file into JSON values.
+pub(crate) fn parse_yaml(source: &str) -> crate::Result<Value> {
+    let value = serde_yaml::from_str::<Value>(source)
+        .map_err(|e| format!("Invalid YAML: {}", e))?;
+
+    // empty files have no translations
+    Ok(match value {
+        Value::Null => Value::Object(Default::default()),
+        value => value,
+    })
+}
+
+/// Returns `s` as a plain or double-quoted scalar.
+fn scalar(s: &str) -> String {
+    let plain = !s.is_empty()
+        && !s.starts_with(|c: char| c.is_whitespace() || "-?:,[]{}#&*!|>'\"%@`".contains(c))
+        && !s.ends_with(|c: char| c.is_whitespace() || c == ':')
+        && !s.contains(": ")
+        && !s.contains(" #")
+        && !s.contains(|c: char| c.is_control())
+        && s.parse::<f64>().is_err()
+        && !matches!(
+            s.to_lowercase().as_str(),
+            "true" | "false" | "yes" | "no" | "on" | "off" | "null" | "~"
+        );
+
+    match plain {
+        true => s.to_string(),
+        false => serde_json::to_string(s).unwrap(),
+    }
+}
+
+fn write_yaml(value: &Value, indent: usize, out: &mut String) {
+    let pad = " ".repeat(indent);
+
+    let Value::Object(map) = value else {
+        return;
+    };
+
+    for (key, value) in map {
+        out.push_str(&format!("{}{}:", pad, scalar(key)));
+
+        match value {
+            Value::Object(map) if map.is_empty() => out.push_str(" {}\n"),
+            Value::Object(_) => {
+                out.push('\n');
+                write_yaml(value, indent + 2, out);
+            }
+            Value::String(s) if s.trim_end_matches('\n').contains('\n') && !s.starts_with(' ') => {
+                let chomping = match s.len() - s.trim_end_matches('\n').len() {
+                    0 => "-",
+                    1 => "",
+                    _ => "+",
+                };
+
+                out.push_str(&format!(" |{}\n", chomping));
+
+                for line in s.trim_end_matches('\n').lines() {
+                    match line.is_empty() {
+                        true => out.push('\n'),
+                        false => out.push_str(&format!("{}  {}\n", pad, line)),
+                    }
+                }
+
+                // kept trailing newlines
+                if chomping == "+" {
+                    out.push_str(&"\n".repeat(s.len() - s.trim_end_matches('\n').len() - 1));
+                }
+            }
+            Value::String(s) => out.push_str(&format!(" {}\n", scalar(s))),
+            Value::Null => out.push_str(" ~\n"),
+            value => out.push_str(&format!(" {}\n", value)),
+        }
+    }
+}
+
+/// Generates a YAML translation file of nested translations.
+pub(crate) fn generate_yaml(value: &Value) -> String {
+    let mut out = String::new();
+    write_yaml(value, 0, &mut out);
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    const YAML: &str = r#"# translations of zh-CN
+---
+Hello, {}: 你好，{}
+"Debug: {:?}": '调试：{:?}' # comment
+"{} files":
+  one: "{} 个文件"
+  other: "{} 个\u6587件"
+long: This is a long
+  plain scalar
+literal: |
+  First line
+    indented
+
+  Third line
+folded: >-
+  First
+  second
+
+  third
+flow: {one: '{} 项', other: "{} 项"}
+list:
+- first
+- key: value
+  other: value
+-
+  - nested
+pua:
+  Hello, {}: ~
+  "{name}": '''{name}'''
+"#;
+
+    #[test]
+    fn parse_yaml_test() {
+        assert_eq!(
+            super::parse_yaml(YAML).unwrap(),
+            json!({
+                "Hello, {}": "你好，{}",
+                "Debug: {:?}": "调试：{:?}",
+                "{} files": {
+                    "one": "{} 个文件",
+                    "other": "{} 个文件"
+                },
+                "long": "This is a long plain scalar",
+                "literal": "First line\n  indented\n\nThird line\n",
+                "folded": "First second\nthird",
+                "flow": { "one": "{} 项", "other": "{} 项" },
+                "list": ["first", { "key": "value", "other": "value" }, ["nested"]],
+                "pua": {
+                    "Hello, {}": null,
+                    "{name}": "'{name}'"
+                }
+            })
+        );
+
+        assert_eq!(
+            super::parse_yaml("a: &a \"First\n  line\"\nb: *a\n").unwrap(),
+            json!({ "a": "First line", "b": "First line" })
+        );
+        assert_eq!(super::parse_yaml("# empty\n").unwrap(), json!({}));
+
+        for yaml in [
+            "a: 'b",
+            "a:\n    b: c\n  d: e",
+            "a: \"b\" c",
+            "a: {b: c",
+            "a: b\n---\nc: d",
+        ] {
+            assert!(super::parse_yaml(yaml).is_err(), "{}", yaml);
+        }
+    }
+
+    #[test]
+    fn generate_yaml_test() {
+        let value = json!({
+            "Hello, {}": "你好，{}",
+            "{} files": { "one": "{} file", "other": "{} files" },
+            "multi": "First\n\n  second\n",
+            "strip": "a\nb",
+            "true": "yes",
+            "empty": {}
+        });
+
+        let yaml = super::generate_yaml(&value);
+        assert_eq!(value, super::parse_yaml(&yaml).unwrap(), "{}", yaml);
+        assert!(yaml.contains("Hello, {}: 你好，{}\n"));
+        assert!(yaml.contains("multi: |\n  First\n\n    second\n"));
+    }
+}